@@ -41,6 +41,47 @@ pub fn to_string<V: Serialize>(
     key_value_delimiter: &str,
     field_delimiter: &str,
     flatten_boolean: bool,
+) -> Result<String, EncodingError> {
+    encode(
+        input,
+        fields_order,
+        key_value_delimiter,
+        field_delimiter,
+        flatten_boolean,
+        false,
+    )
+}
+
+/// Like `to_string`, but also quotes empty strings and strings containing
+/// the key-value delimiter, so that the output can be parsed back unambiguously.
+///
+/// # Errors
+///
+/// Returns an `EncodingError` if the input contains non-`String` map keys.
+pub fn to_string_unambiguous<V: Serialize>(
+    input: &BTreeMap<String, V>,
+    fields_order: &[String],
+    key_value_delimiter: &str,
+    field_delimiter: &str,
+    flatten_boolean: bool,
+) -> Result<String, EncodingError> {
+    encode(
+        input,
+        fields_order,
+        key_value_delimiter,
+        field_delimiter,
+        flatten_boolean,
+        true,
+    )
+}
+
+fn encode<V: Serialize>(
+    input: &BTreeMap<String, V>,
+    fields_order: &[String],
+    key_value_delimiter: &str,
+    field_delimiter: &str,
+    flatten_boolean: bool,
+    unambiguous: bool,
 ) -> Result<String, EncodingError> {
     let mut output = String::new();
 
@@ -50,11 +91,17 @@ pub fn to_string<V: Serialize>(
         match (input.remove(field), flatten_boolean) {
             (Some(Data::Boolean(false)), true) | (None, _) => (),
             (Some(Data::Boolean(true)), true) => {
-                encode_string(&mut output, field);
+                encode_string(&mut output, field, key_value_delimiter, unambiguous);
                 output.push_str(field_delimiter);
             }
             (Some(value), _) => {
-                encode_field(&mut output, field, &value.to_string(), key_value_delimiter);
+                encode_field(
+                    &mut output,
+                    field,
+                    &value.to_string(),
+                    key_value_delimiter,
+                    unambiguous,
+                );
                 output.push_str(field_delimiter);
             }
         };
@@ -64,11 +111,17 @@ pub fn to_string<V: Serialize>(
         match (value, flatten_boolean) {
             (Data::Boolean(false), true) => (),
             (Data::Boolean(true), true) => {
-                encode_string(&mut output, key);
+                encode_string(&mut output, key, key_value_delimiter, unambiguous);
                 output.push_str(field_delimiter);
             }
             (_, _) => {
-                encode_field(&mut output, key, &value.to_string(), key_value_delimiter);
+                encode_field(
+                    &mut output,
+                    key,
+                    &value.to_string(),
+                    key_value_delimiter,
+                    unambiguous,
+                );
                 output.push_str(field_delimiter);
             }
         };
@@ -92,14 +145,25 @@ fn flatten<'a>(
     Ok(map)
 }
 
-fn encode_field<'a>(output: &mut String, key: &str, value: &str, key_value_delimiter: &'a str) {
-    encode_string(output, key);
+fn encode_field<'a>(
+    output: &mut String,
+    key: &str,
+    value: &str,
+    key_value_delimiter: &'a str,
+    unambiguous: bool,
+) {
+    encode_string(output, key, key_value_delimiter, unambiguous);
     output.push_str(key_value_delimiter);
-    encode_string(output, value);
+    encode_string(output, value, key_value_delimiter, unambiguous);
 }
 
-fn encode_string(output: &mut String, str: &str) {
-    let needs_quoting = str.chars().any(|c| c.is_whitespace() || c == '"');
+fn encode_string(output: &mut String, str: &str, key_value_delimiter: &str, unambiguous: bool) {
+    // When asked to be unambiguous, empty strings and strings containing the key-value delimiter
+    // are quoted too, so that decoders don't mistake them for a missing value or the end of a key.
+    let needs_quoting = (unambiguous
+        && (str.is_empty()
+            || (!key_value_delimiter.is_empty() && str.contains(key_value_delimiter))))
+        || str.chars().any(|c| c.is_whitespace() || c == '"');
 
     if needs_quoting {
         output.write_char('"').unwrap();
@@ -650,6 +714,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn empty_strings_and_delimiters_are_quoted() {
+        assert_eq!(
+            &to_string_unambiguous::<Value>(
+                &btreemap! {
+                    "empty" => "",
+                    "query" => "a=b",
+                },
+                &[],
+                "=",
+                " ",
+                true
+            )
+            .unwrap(),
+            r#"empty="" query="a=b""#
+        );
+    }
+
+    #[test]
+    fn custom_key_value_delimiter_is_quoted() {
+        assert_eq!(
+            &to_string_unambiguous::<Value>(
+                &btreemap! {
+                    "query" => "a=b",
+                    "time" => "12:30",
+                },
+                &[],
+                ":",
+                " ",
+                true
+            )
+            .unwrap(),
+            r#"query:a=b time:"12:30""#
+        );
+    }
+
+    #[test]
+    fn empty_strings_and_delimiters_are_not_quoted_by_default() {
+        assert_eq!(
+            &to_string::<Value>(
+                &btreemap! {
+                    "empty" => "",
+                    "query" => "a=b",
+                },
+                &[],
+                "=",
+                " ",
+                true
+            )
+            .unwrap(),
+            r#"empty= query=a=b"#
+        );
+    }
+
     #[test]
     fn nested_fields() {
         assert_eq!(
@@ -101,6 +101,14 @@ pub struct GlobalOptions {
     /// a small amount of memory for each metric.
     #[serde(skip_serializing_if = "crate::serde::skip_serializing_if_default")]
     pub expire_metrics_secs: Option<f64>,
+
+    /// The number of sinks a single component output can feed before Vector warns about it.
+    ///
+    /// Broad input globs, such as `*`, can make the same events flow into many sinks. This is
+    /// sometimes intended, but often a mistake, so Vector warns at startup when an output feeds more
+    /// sinks than this. Defaults to 5.
+    #[serde(skip_serializing_if = "crate::serde::skip_serializing_if_default")]
+    pub fan_out_warning_threshold: Option<usize>,
 }
 
 impl GlobalOptions {
@@ -206,6 +214,9 @@ impl GlobalOptions {
                 proxy: self.proxy.merge(&with.proxy),
                 expire_metrics: self.expire_metrics.or(with.expire_metrics),
                 expire_metrics_secs: self.expire_metrics_secs.or(with.expire_metrics_secs),
+                fan_out_warning_threshold: self
+                    .fan_out_warning_threshold
+                    .or(with.fan_out_warning_threshold),
             })
         } else {
             Err(errors)
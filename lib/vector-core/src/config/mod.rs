@@ -115,6 +115,14 @@ pub struct Output {
     /// For a *transform*, a `None` schema means the transform inherits the merged [`Definition`]
     /// of its inputs, without modifying the schema further.
     pub log_schema_definition: Option<schema::Definition>,
+
+    /// A human-readable description of the condition an event must match to be sent to this
+    /// output, for outputs that only receive a subset of the component's events.
+    pub condition: Option<String>,
+
+    /// Whether this output can be statically determined to never receive any events, such as a
+    /// named output for errors that the component can't encounter.
+    pub unreachable: bool,
 }
 
 impl Output {
@@ -127,6 +135,8 @@ impl Output {
             port: None,
             ty,
             log_schema_definition: None,
+            condition: None,
+            unreachable: false,
         }
     }
 
@@ -143,6 +153,20 @@ impl Output {
         self.port = Some(name.into());
         self
     }
+
+    /// Set the condition that guards this `Output`.
+    #[must_use]
+    pub fn with_condition(mut self, condition: impl Into<String>) -> Self {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    /// Set whether this `Output` never receives any events.
+    #[must_use]
+    pub fn with_unreachable(mut self, unreachable: bool) -> Self {
+        self.unreachable = unreachable;
+        self
+    }
 }
 
 /// Source-specific end-to-end acknowledgements configuration.
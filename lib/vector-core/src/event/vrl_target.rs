@@ -604,6 +604,8 @@ mod test {
                 abortable: false,
                 target_queries: vec![],
                 target_assignments: vec![],
+                target_deletions: vec![],
+                function_calls: BTreeMap::new(),
             };
             let target = VrlTarget::new(Event::Log(LogEvent::from(value)), &info);
             let path = OwnedTargetPath::event(path);
@@ -710,6 +712,8 @@ mod test {
                 abortable: false,
                 target_queries: vec![],
                 target_assignments: vec![],
+                target_deletions: vec![],
+                function_calls: BTreeMap::new(),
             };
             let mut target = VrlTarget::new(Event::Log(LogEvent::from(object)), &info);
             let expect = LogEvent::from(expect);
@@ -809,6 +813,8 @@ mod test {
                 abortable: false,
                 target_queries: vec![],
                 target_assignments: vec![],
+                target_deletions: vec![],
+                function_calls: BTreeMap::new(),
             };
             let mut target = VrlTarget::new(Event::Log(LogEvent::from(object)), &info);
             let path = OwnedTargetPath::event(path);
@@ -869,6 +875,8 @@ mod test {
                 abortable: false,
                 target_queries: vec![],
                 target_assignments: vec![],
+                target_deletions: vec![],
+                function_calls: BTreeMap::new(),
             };
             let mut target = VrlTarget::new(
                 Event::Log(LogEvent::new_with_metadata(metadata.clone())),
@@ -919,6 +927,8 @@ mod test {
                 OwnedTargetPath::event(owned_value_path!("tags")),
             ],
             target_assignments: vec![],
+            target_deletions: vec![],
+            function_calls: BTreeMap::new(),
         };
         let target = VrlTarget::new(Event::Metric(metric), &info);
 
@@ -996,6 +1006,8 @@ mod test {
                 OwnedTargetPath::event(owned_value_path!("kind")),
             ],
             target_assignments: vec![],
+            target_deletions: vec![],
+            function_calls: BTreeMap::new(),
         };
         let mut target = VrlTarget::new(Event::Metric(metric), &info);
 
@@ -1040,6 +1052,8 @@ mod test {
             abortable: false,
             target_queries: vec![],
             target_assignments: vec![],
+            target_deletions: vec![],
+            function_calls: BTreeMap::new(),
         };
         let mut target = VrlTarget::new(Event::Metric(metric), &info);
         let _result = target.target_insert(
@@ -1083,6 +1097,8 @@ mod test {
             abortable: false,
             target_queries: vec![],
             target_assignments: vec![],
+            target_deletions: vec![],
+            function_calls: BTreeMap::new(),
         };
         let mut target = VrlTarget::new(Event::Metric(metric), &info);
 
@@ -105,6 +105,7 @@ impl Requirement {
                         // kind, so we can't use it in the sink.
                         errors.push(ValidationError::MeaningKind {
                             identifier,
+                            path: path.clone(),
                             want: req_meaning.kind.clone(),
                             got: definition_kind,
                         });
@@ -167,6 +168,7 @@ pub enum ValidationError {
     /// A semantic meaning has an invalid `[Kind]`.
     MeaningKind {
         identifier: &'static str,
+        path: LookupBuf,
         want: Kind,
         got: Kind,
     },
@@ -200,12 +202,13 @@ impl std::fmt::Display for ValidationError {
             }
             Self::MeaningKind {
                 identifier,
+                path,
                 want,
                 got,
             } => write!(
                 f,
-                "invalid semantic meaning: {} (expected {}, got {})",
-                identifier, want, got
+                "invalid semantic meaning: {} at field {} (expected {}, got {})",
+                identifier, path, want, got
             ),
             Self::MeaningDuplicate { identifier, paths } => write!(
                 f,
@@ -304,6 +307,7 @@ mod tests {
                     ),
                     errors: vec![ValidationError::MeaningKind {
                         identifier: "foo",
+                        path: "foo".into(),
                         want: Kind::boolean(),
                         got: Kind::integer(),
                     }],
@@ -320,6 +324,7 @@ mod tests {
                     ),
                     errors: vec![ValidationError::MeaningKind {
                         identifier: "foo",
+                        path: "foo".into(),
                         want: Kind::boolean(),
                         got: Kind::integer(),
                     }],
@@ -9,7 +9,7 @@ use std::{
 
 use ::value::Value;
 use clap::Parser;
-use lookup::{owned_value_path, OwnedTargetPath};
+use lookup::owned_value_path;
 use value::Secrets;
 use vector_common::TimeZone;
 use vrl::state::TypeState;
@@ -125,20 +125,16 @@ fn run(opts: &Opts) -> Result<(), Error> {
         let objects = opts.read_into_objects()?;
         let source = opts.read_program()?;
 
-        // The CLI should be moved out of the "vrl" module, and then it can use the `vector-core::compile_vrl` function which includes this automatically
-        let mut config = CompileConfig::default();
-        config.set_read_only_path(OwnedTargetPath::metadata(owned_value_path!("vector")), true);
-
+        let config = compile_config();
         let state = TypeState::default();
 
         let CompilationResult {
             program,
             warnings,
             config: _,
-        } = vrl::compile_with_state(&source, &stdlib::all(), &state, CompileConfig::default())
-            .map_err(|diagnostics| {
-                Error::Parse(Formatter::new(&source, diagnostics).colored().to_string())
-            })?;
+        } = vrl::compile_with_state(&source, &stdlib::all(), &state, config).map_err(
+            |diagnostics| Error::Parse(Formatter::new(&source, diagnostics).colored().to_string()),
+        )?;
 
         #[allow(clippy::print_stderr)]
         if opts.print_warnings {
@@ -177,6 +173,14 @@ fn run(opts: &Opts) -> Result<(), Error> {
     }
 }
 
+/// The config programs run by the CLI are compiled with.
+///
+/// The CLI should be moved out of the "vrl" module, and then it can use the `vector-core::compile_vrl` function which
+/// includes this automatically.
+fn compile_config() -> CompileConfig {
+    CompileConfig::default().with_read_only_metadata_path(owned_value_path!("vector"), true)
+}
+
 #[cfg(feature = "repl")]
 #[allow(clippy::unnecessary_wraps)]
 fn repl(objects: Vec<Value>, timezone: TimeZone, vrl_runtime: VrlRuntime) -> Result<(), Error> {
@@ -242,3 +246,31 @@ fn read<R: Read>(mut reader: R) -> Result<String, Error> {
 fn default_objects() -> Vec<Value> {
     vec![Value::Object(BTreeMap::new())]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile(source: &str) -> Result<(), String> {
+        vrl::compile_with_state(
+            source,
+            &stdlib::all(),
+            &TypeState::default(),
+            compile_config(),
+        )
+        .map(|_| ())
+        .map_err(|diagnostics| Formatter::new(source, diagnostics).to_string())
+    }
+
+    #[test]
+    fn vector_metadata_is_read_only() {
+        let error = compile(r#"%vector.ingest_timestamp = now()"#).unwrap_err();
+
+        assert!(error.contains("read-only"), "{}", error);
+    }
+
+    #[test]
+    fn other_metadata_is_writable() {
+        assert_eq!(compile(r#"%custom = "foo""#), Ok(()));
+    }
+}
@@ -47,6 +47,7 @@ const RESERVED_TERMS: &[&str] = &[
     "help funcs",
     "help fs",
     "help docs",
+    "explain",
 ];
 
 pub(crate) fn run(
@@ -57,6 +58,7 @@ pub(crate) fn run(
     let mut index = 0;
     let func_docs_regex = Regex::new(r"^help\sdocs\s(\w{1,})$").unwrap();
     let error_docs_regex = Regex::new(r"^help\serror\s(\w{1,})$").unwrap();
+    let explain_regex = Regex::new(r"^explain\s(.+)$").unwrap();
 
     let mut state = TypeState::default();
 
@@ -82,6 +84,11 @@ pub(crate) fn run(
             Ok(line) if error_docs_regex.is_match(line) => show_error_docs(line, &error_docs_regex),
             // Capture "help docs <func_name>"
             Ok(line) if func_docs_regex.is_match(line) => show_func_docs(line, &func_docs_regex),
+            // Capture "explain <program>"
+            Ok(line) if explain_regex.is_match(line) => {
+                rl.add_history_entry(line);
+                explain(line, &explain_regex, &state);
+            }
             Ok(line) => {
                 rl.add_history_entry(line);
 
@@ -159,6 +166,13 @@ fn resolve(
     timezone: TimeZone,
     vrl_runtime: VrlRuntime,
 ) -> Result<Value, String> {
+    let program = compile(program, state)?;
+
+    *state = program.final_type_state();
+    execute(runtime, &program, target, timezone, vrl_runtime)
+}
+
+fn compile(program: &str, state: &TypeState) -> Result<vrl::Program, String> {
     let mut functions = stdlib::all();
     functions.extend(vector_vrl_functions::vrl_functions());
 
@@ -166,15 +180,65 @@ fn resolve(
     // The CLI should be moved out of the "vrl" module, and then it can use the `vector-core::compile_vrl` function which includes this automatically
     config.set_read_only_path(OwnedTargetPath::metadata(owned_value_path!("vector")), true);
 
-    let program = match vrl::compile_with_state(program, &functions, state, config) {
-        Ok(result) => result.program,
-        Err(diagnostics) => {
-            return Err(Formatter::new(program, diagnostics).colored().to_string());
+    // Variables live across many statements in a REPL session, so it's easy to lose track of
+    // what type a variable currently holds, or to mistake one for a function of the same name.
+    config.set_kind_change_warnings(true);
+    config.set_shadowed_function_warnings(true);
+
+    match vrl::compile_with_state(program, &functions, state, config) {
+        Ok(result) => {
+            if !result.warnings.is_empty() {
+                #[allow(clippy::print_stdout)]
+                {
+                    println!("{}", Formatter::new(program, result.warnings).colored());
+                }
+            }
+
+            Ok(result.program)
         }
-    };
+        Err(diagnostics) => Err(Formatter::new(program, diagnostics).colored().to_string()),
+    }
+}
 
-    *state = program.final_type_state();
-    execute(runtime, &program, target, timezone, vrl_runtime)
+/// Compiles the program without running it, and prints the type of the value it resolves to,
+/// along with the event and metadata paths it reads and writes. Neither the object nor the
+/// variables of the session are changed.
+fn explain(line: &str, pattern: &Regex, state: &TypeState) {
+    // Unwrap is okay here, as the line is only passed in if it matches
+    let matches = pattern.captures(line).unwrap();
+    let program = matches.get(1).unwrap().as_str();
+
+    let explanation = compile(program, state).map_or_else(
+        |err| err,
+        |program| {
+            let type_def = program.result_type_def();
+            let info = program.info();
+            let paths = |paths: &[OwnedTargetPath]| {
+                if paths.is_empty() {
+                    "none".to_owned()
+                } else {
+                    paths
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                }
+            };
+
+            format!(
+                "type:     {}\nfallible: {}\nreads:    {}\nwrites:   {}",
+                type_def.kind(),
+                info.fallible || info.abortable,
+                paths(&info.read_paths()),
+                paths(&info.write_paths()),
+            )
+        },
+    );
+
+    #[allow(clippy::print_stdout)]
+    {
+        println!("{}\n", explanation);
+    }
 }
 
 fn execute(
@@ -414,6 +478,7 @@ const HELP_TEXT: &str = indoc! {r#"
       help docs          Navigate to the VRL docs on the Vector website
       help docs <func>   Navigate to the VRL docs for the specified function
       help error <code>  Navigate to the docs for a specific error code
+      explain <program>  Display the result type of a program and the fields it reads and writes, without running it
       next               Load the next object or create a new one
       prev               Load the previous object
       exit               Terminate the program
@@ -448,6 +513,7 @@ const BANNER_TEXT: &str = indoc! {r#"
     >
     > VRL REPL commands:
     >   help              Learn more about VRL
+    >   explain <program> Show what a program does, without running it
     >   next              Load the next object or create a new one
     >   prev              Load the previous object
     >   exit              Terminate the program
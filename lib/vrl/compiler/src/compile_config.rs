@@ -1,11 +1,13 @@
 use anymap::AnyMap;
-use lookup::OwnedTargetPath;
+use lookup::{OwnedTargetPath, OwnedValuePath, PathPrefix};
 use std::collections::BTreeSet;
 
 pub struct CompileConfig {
     /// Custom context injected by the external environment
     custom: AnyMap,
     read_only_paths: BTreeSet<ReadOnlyPath>,
+    kind_change_warnings: bool,
+    shadowed_function_warnings: bool,
 }
 
 impl CompileConfig {
@@ -36,6 +38,13 @@ impl CompileConfig {
         self.set_read_only_path(OwnedTargetPath::metadata_root(), true);
     }
 
+    /// Marks everything as read only, see [`CompileConfig::set_read_only`].
+    #[must_use]
+    pub fn read_only(mut self) -> Self {
+        self.set_read_only();
+        self
+    }
+
     #[must_use]
     pub fn is_read_only_path(&self, path: &OwnedTargetPath) -> bool {
         for read_only_path in &self.read_only_paths {
@@ -61,6 +70,47 @@ impl CompileConfig {
         self.read_only_paths
             .insert(ReadOnlyPath { path, recursive });
     }
+
+    /// Marks a path of the event as read only, see [`CompileConfig::set_read_only_path`].
+    #[must_use]
+    pub fn with_read_only_event_path(mut self, path: OwnedValuePath, recursive: bool) -> Self {
+        self.set_read_only_path(OwnedTargetPath::event(path), recursive);
+        self
+    }
+
+    /// Marks a path of the metadata as read only, see [`CompileConfig::set_read_only_path`].
+    #[must_use]
+    pub fn with_read_only_metadata_path(mut self, path: OwnedValuePath, recursive: bool) -> Self {
+        self.set_read_only_path(OwnedTargetPath::metadata(path), recursive);
+        self
+    }
+
+    /// Returns the paths that have been marked as read only.
+    pub fn read_only_paths(&self) -> impl Iterator<Item = &ReadOnlyPath> {
+        self.read_only_paths.iter()
+    }
+
+    /// Emits a warning when a variable that already has a known type is assigned a value
+    /// of an incompatible type. This is disabled by default.
+    pub fn set_kind_change_warnings(&mut self, enabled: bool) {
+        self.kind_change_warnings = enabled;
+    }
+
+    #[must_use]
+    pub fn kind_change_warnings(&self) -> bool {
+        self.kind_change_warnings
+    }
+
+    /// Emits a warning when a variable is first assigned under the name of a known function.
+    /// This is disabled by default.
+    pub fn set_shadowed_function_warnings(&mut self, enabled: bool) {
+        self.shadowed_function_warnings = enabled;
+    }
+
+    #[must_use]
+    pub fn shadowed_function_warnings(&self) -> bool {
+        self.shadowed_function_warnings
+    }
 }
 
 impl Default for CompileConfig {
@@ -68,12 +118,35 @@ impl Default for CompileConfig {
         Self {
             custom: AnyMap::new(),
             read_only_paths: BTreeSet::new(),
+            kind_change_warnings: false,
+            shadowed_function_warnings: false,
         }
     }
 }
 
+/// A path that can't be assigned to by the program.
 #[derive(Debug, Clone, Ord, Eq, PartialEq, PartialOrd)]
-struct ReadOnlyPath {
+pub struct ReadOnlyPath {
     path: OwnedTargetPath,
     recursive: bool,
 }
+
+impl ReadOnlyPath {
+    /// The path that is read only.
+    #[must_use]
+    pub fn path(&self) -> &OwnedTargetPath {
+        &self.path
+    }
+
+    /// Whether all the children of the path are read only as well.
+    #[must_use]
+    pub fn recursive(&self) -> bool {
+        self.recursive
+    }
+
+    /// The root (event or metadata) the path belongs to.
+    #[must_use]
+    pub fn root(&self) -> PathPrefix {
+        self.path.prefix
+    }
+}
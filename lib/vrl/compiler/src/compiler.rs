@@ -1,9 +1,15 @@
+use std::collections::BTreeMap;
+
 use core::Value;
 use diagnostic::{DiagnosticList, DiagnosticMessage, Note, Severity, Span};
 use lookup::{OwnedTargetPath, OwnedValuePath, PathPrefix};
 use parser::ast::{self, Node, QueryTarget};
 
 use crate::function::ArgumentList;
+#[cfg(feature = "expr-assignment")]
+use crate::kind_change_warning::KindChangeWarning;
+#[cfg(feature = "expr-assignment")]
+use crate::shadowed_function_warning::ShadowedFunctionWarning;
 use crate::state::TypeState;
 use crate::value::VrlValueConvert;
 use crate::{
@@ -14,7 +20,7 @@ use crate::{
     },
     parser::ast::RootExpr,
     program::ProgramInfo,
-    CompileConfig, DeprecationWarning, Function, Program, TypeDef,
+    CompileConfig, DeprecationWarning, Function, FunctionCallStats, Program, TypeDef,
 };
 
 pub(crate) type Diagnostics = Vec<Box<dyn DiagnosticMessage>>;
@@ -36,6 +42,11 @@ pub struct Compiler<'a> {
     abortable: bool,
     external_queries: Vec<OwnedTargetPath>,
     external_assignments: Vec<OwnedTargetPath>,
+    external_deletions: Vec<OwnedTargetPath>,
+    function_calls: BTreeMap<&'static str, FunctionCallStats>,
+
+    /// The number of closures of iterating functions the expression being compiled is nested in.
+    iteration_depth: usize,
 
     /// A list of variables that are missing, because the rhs expression of the
     /// assignment failed to compile.
@@ -71,11 +82,14 @@ impl<'a> Compiler<'a> {
             abortable: false,
             external_queries: vec![],
             external_assignments: vec![],
+            external_deletions: vec![],
+            function_calls: BTreeMap::new(),
+            iteration_depth: 0,
             skip_missing_query_target: vec![],
             fallible_expression_error: None,
             config,
         };
-        let expressions = compiler.compile_root_exprs(ast, &mut state);
+        let (expressions, spans) = compiler.compile_root_exprs(ast, &mut state);
 
         let (errors, warnings): (Vec<_>, Vec<_>) =
             compiler.diagnostics.into_iter().partition(|diagnostic| {
@@ -89,11 +103,14 @@ impl<'a> Compiler<'a> {
         let result = CompilationResult {
             program: Program {
                 expressions: Block::new_inline(expressions),
+                spans,
                 info: ProgramInfo {
                     fallible: compiler.fallible,
                     abortable: compiler.abortable,
                     target_queries: compiler.external_queries,
                     target_assignments: compiler.external_assignments,
+                    target_deletions: compiler.external_deletions,
+                    function_calls: compiler.function_calls,
                 },
                 initial_state,
             },
@@ -224,20 +241,23 @@ impl<'a> Compiler<'a> {
         &mut self,
         nodes: impl IntoIterator<Item = Node<ast::RootExpr>>,
         state: &mut TypeState,
-    ) -> Vec<Expr> {
+    ) -> (Vec<Expr>, Vec<Span>) {
         let mut node_exprs = vec![];
+        let mut spans = vec![];
 
         for root_expr in nodes {
             match root_expr.into_inner() {
                 RootExpr::Expr(node_expr) => {
                     self.fallible_expression_error = None;
 
+                    let span = node_expr.span();
                     if let Some(expr) = self.compile_expr(node_expr, state) {
                         if let Some(error) = self.fallible_expression_error.take() {
                             self.diagnostics.push(error);
                         }
 
                         node_exprs.push(expr);
+                        spans.push(span);
                     }
                 }
                 RootExpr::Error(err) => self.handle_parser_error(err),
@@ -246,8 +266,9 @@ impl<'a> Compiler<'a> {
 
         if node_exprs.is_empty() {
             node_exprs.push(Expr::Noop(Noop));
+            spans.push(Span::default());
         }
-        node_exprs
+        (node_exprs, spans)
     }
 
     fn compile_block(&mut self, node: Node<ast::Block>, state: &mut TypeState) -> Option<Block> {
@@ -278,8 +299,6 @@ impl<'a> Compiler<'a> {
     }
 
     fn compile_object(&mut self, node: Node<ast::Object>, state: &mut TypeState) -> Option<Object> {
-        use std::collections::BTreeMap;
-
         let (keys, exprs): (Vec<String>, Vec<Option<Expr>>) = node
             .into_inner()
             .into_iter()
@@ -442,6 +461,7 @@ impl<'a> Compiler<'a> {
 
         let original_state = state.clone();
 
+        let assignment_span = node.span();
         let assignment = node.into_inner();
 
         let node = match assignment {
@@ -529,11 +549,76 @@ impl<'a> Compiler<'a> {
             }
         }
 
+        // Warn about new variables named after a function. Only the first assignment is
+        // reported, to avoid repeating the warning for every update of the variable.
+        if self.config.shadowed_function_warnings() {
+            for target in assignment.targets() {
+                let ident = match target {
+                    assignment::Target::Internal(ident, _) => ident,
+                    _ => continue,
+                };
+
+                if original_state.local.variable(&ident).is_some() {
+                    continue;
+                }
+
+                if let Some(function) = self.fns.iter().find(|f| f.identifier() == &*ident) {
+                    self.diagnostics.push(Box::new(ShadowedFunctionWarning::new(
+                        ident,
+                        function.identifier(),
+                        assignment_span,
+                    )));
+                }
+            }
+        }
+
+        // Remember the type of any variable that is about to be overwritten as a whole, so we
+        // can warn if the assignment changes it to something unrelated.
+        let previous_kinds = if self.config.kind_change_warnings() {
+            assignment
+                .targets()
+                .into_iter()
+                .filter_map(|target| match target {
+                    assignment::Target::Internal(ident, path) if path.is_root() => {
+                        let kind = original_state
+                            .local
+                            .variable(&ident)?
+                            .type_def
+                            .kind()
+                            .clone();
+                        Some((ident, kind))
+                    }
+                    _ => None,
+                })
+                .collect()
+        } else {
+            vec![]
+        };
+
         // The state hasn't been updated from the actual assignment yet. Recalculate the type
         // from the new assignment expression.
         *state = original_state;
         assignment.apply_type_info(state);
 
+        for (ident, from) in previous_kinds {
+            let to = match state.local.variable(&ident) {
+                Some(details) => details.type_def.kind().clone(),
+                None => continue,
+            };
+
+            // `null` is commonly used as a placeholder before a variable gets its real value.
+            if from.is_null() || to.is_null() || from.intersects(&to) {
+                continue;
+            }
+
+            self.diagnostics.push(Box::new(KindChangeWarning::new(
+                ident,
+                from,
+                to,
+                assignment_span,
+            )));
+        }
+
         Some(assignment)
     }
 
@@ -759,11 +844,33 @@ impl<'a> Compiler<'a> {
         .map_err(|err| self.diagnostics.push(Box::new(err)))
         .ok()
         .and_then(|builder| {
+            let function = builder.function();
+            let stats = self
+                .function_calls
+                .entry(function.identifier())
+                .or_default();
+            stats.calls += 1;
+            if self.iteration_depth > 0 {
+                stats.calls_in_iteration += 1;
+            }
+
             let block = match closure_block {
                 None => None,
                 Some(block) => {
                     let span = block.span();
-                    match self.compile_block_with_type(block, state) {
+                    let is_iterator = function
+                        .closure()
+                        .map_or(false, |definition| definition.is_iterator);
+
+                    if is_iterator {
+                        self.iteration_depth += 1;
+                    }
+                    let block_with_type = self.compile_block_with_type(block, state);
+                    if is_iterator {
+                        self.iteration_depth -= 1;
+                    }
+
+                    match block_with_type {
                         Some(block_with_type) => Some(Node::new(span, block_with_type)),
                         None => return None,
                     }
@@ -780,6 +887,7 @@ impl<'a> Compiler<'a> {
                     local_snapshot,
                     &mut self.fallible_expression_error,
                     &mut self.config,
+                    &mut self.external_deletions,
                 )
                 .map_err(|err| self.diagnostics.push(Box::new(err)))
                 .ok()
@@ -14,7 +14,7 @@ use crate::{
     state::{TypeInfo, TypeState},
     type_def::Details,
     value::kind::DefaultValue,
-    CompileConfig, Context, Expression, Span, TypeDef,
+    CompileConfig, Context, Expression, ExpressionError, Span, TypeDef,
 };
 
 #[derive(Clone, PartialEq)]
@@ -38,12 +38,33 @@ impl Assignment {
                 let assignment_span = Span::new(target_span.start(), expr_span.start() - 1);
 
                 // Fallible expressions require infallible assignment.
-                if fallible_rhs.is_some() {
+                if let Some(fallible_rhs) = fallible_rhs {
+                    // Point at the part of the expression that can fail, if it isn't the
+                    // expression as a whole.
+                    let fallible_span = fallible_rhs
+                        .labels()
+                        .into_iter()
+                        .find(|label| label.primary)
+                        .map(|label| label.span)
+                        .filter(|span| *span != expr_span);
+
+                    // Function calls can be made to raise their error instead.
+                    let raising = match (fallible_span, expr.inner()) {
+                        (None, Expr::FunctionCall(call)) => {
+                            let expr = expr.to_string();
+                            Some(format!("{}!{}", call.ident, &expr[call.ident.len()..]))
+                        }
+                        _ => None,
+                    };
+
                     return Err(Error {
-                        variant: ErrorVariant::FallibleAssignment(
-                            target.to_string(),
-                            expr.to_string(),
-                        ),
+                        variant: ErrorVariant::FallibleAssignment {
+                            target: target.to_string(),
+                            expr: expr.to_string(),
+                            fallible_span,
+                            default: default_literal(expr.inner().type_def(state).kind()),
+                            raising,
+                        },
                         expr_span,
                         assignment_span,
                     });
@@ -282,6 +303,25 @@ fn verify_overwriteable(
     Ok(())
 }
 
+/// A literal to suggest as the fallback value of a fallible expression of the given kind.
+fn default_literal(kind: &Kind) -> &'static str {
+    if kind.is_bytes() {
+        r#""""#
+    } else if kind.is_integer() {
+        "0"
+    } else if kind.is_float() {
+        "0.0"
+    } else if kind.is_boolean() {
+        "false"
+    } else if kind.is_object() {
+        "{}"
+    } else if kind.is_array() {
+        "[]"
+    } else {
+        "null"
+    }
+}
+
 impl Expression for Assignment {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         self.variant.resolve(ctx)
@@ -364,33 +404,36 @@ impl Target {
         }
     }
 
-    fn insert(&self, value: Value, ctx: &mut Context) {
+    fn insert(&self, value: Value, ctx: &mut Context) -> Result<(), ExpressionError> {
         use Target::{External, Internal, Noop};
 
         match self {
-            Noop => {}
+            Noop => Ok(()),
             Internal(ident, path) => {
                 // Get the provided path, or else insert into the variable
-                // without any path appended and return early.
-                let path = match path.is_root() {
-                    false => path,
-                    true => return ctx.state_mut().insert_variable(ident.clone(), value),
-                };
-
-                // Update existing variable using the provided path, or create a
-                // new value in the store.
-                match ctx.state_mut().variable_mut(ident) {
-                    Some(stored) => {
-                        stored.insert(path, value);
+                // without any path appended.
+                if path.is_root() {
+                    ctx.state_mut().insert_variable(ident.clone(), value);
+                } else {
+                    // Update existing variable using the provided path, or create a
+                    // new value in the store.
+                    match ctx.state_mut().variable_mut(ident) {
+                        Some(stored) => {
+                            stored.insert(path, value);
+                        }
+                        None => ctx
+                            .state_mut()
+                            .insert_variable(ident.clone(), value.at_path(path)),
                     }
-                    None => ctx
-                        .state_mut()
-                        .insert_variable(ident.clone(), value.at_path(path)),
                 }
+
+                ctx.state_mut().check_memory_limit(None)
             }
 
             External(path) => {
+                ctx.state_mut().check_memory_limit(Some(&value))?;
                 let _ = ctx.target_mut().target_insert(path, value);
+                Ok(())
             }
         }
     }
@@ -505,7 +548,7 @@ where
         let value = match self {
             Single { target, expr } => {
                 let value = expr.resolve(ctx)?;
-                target.insert(value.clone(), ctx);
+                target.insert(value.clone(), ctx)?;
                 value
             }
             Infallible {
@@ -515,14 +558,18 @@ where
                 default,
             } => match expr.resolve(ctx) {
                 Ok(value) => {
-                    ok.insert(value.clone(), ctx);
-                    err.insert(Value::Null, ctx);
+                    ok.insert(value.clone(), ctx)?;
+                    err.insert(Value::Null, ctx)?;
                     value
                 }
+                // Running out of memory isn't an error the program can handle.
+                Err(error @ ExpressionError::MemoryLimitExceeded { .. }) => return Err(error),
                 Err(error) => {
-                    ok.insert(default.clone(), ctx);
+                    ctx.state_mut()
+                        .count(|counters| counters.handled_errors += 1);
+                    ok.insert(default.clone(), ctx)?;
                     let value = Value::from(error.to_string());
-                    err.insert(value.clone(), ctx);
+                    err.insert(value.clone(), ctx)?;
                     value
                 }
             },
@@ -598,7 +645,13 @@ pub(crate) enum ErrorVariant {
     UnnecessaryNoop(Span),
 
     #[error("unhandled fallible assignment")]
-    FallibleAssignment(String, String),
+    FallibleAssignment {
+        target: String,
+        expr: String,
+        fallible_span: Option<Span>,
+        default: &'static str,
+        raising: Option<String>,
+    },
 
     #[error("unnecessary error assignment")]
     InfallibleAssignment(String, String, Span, Span),
@@ -641,7 +694,7 @@ impl DiagnosticMessage for Error {
 
         match &self.variant {
             UnnecessaryNoop(..) => 640,
-            FallibleAssignment(..) => 103,
+            FallibleAssignment { .. } => 103,
             InfallibleAssignment(..) => 104,
             InvalidTarget(..) => 641,
             InvalidParentPathSegment { .. } => 642,
@@ -660,15 +713,28 @@ impl DiagnosticMessage for Error {
                 Label::context("either assign to a path or variable here", *target_span),
                 Label::context("or remove the assignment", self.assignment_span),
             ],
-            FallibleAssignment(target, expr) => vec![
-                Label::primary("this expression is fallible", self.expr_span),
-                Label::context("update the expression to be infallible", self.expr_span),
-                Label::context(
-                    "or change this to an infallible assignment:",
-                    self.assignment_span,
-                ),
-                Label::context(format!("{}, err = {}", target, expr), self.assignment_span),
-            ],
+            FallibleAssignment {
+                target,
+                expr,
+                fallible_span,
+                ..
+            } => {
+                let mut labels = vec![
+                    Label::primary("this expression is fallible", self.expr_span),
+                    Label::context("update the expression to be infallible", self.expr_span),
+                    Label::context(
+                        "or change this to an infallible assignment:",
+                        self.assignment_span,
+                    ),
+                    Label::context(format!("{}, err = {}", target, expr), self.assignment_span),
+                ];
+
+                if let Some(span) = fallible_span {
+                    labels.push(Label::context("because this expression can fail", span));
+                }
+
+                labels
+            }
             InfallibleAssignment(target, expr, ok_span, err_span) => vec![
                 Label::primary("this error assignment is unnecessary", err_span),
                 Label::context("because this expression can't fail", self.expr_span),
@@ -709,7 +775,29 @@ impl DiagnosticMessage for Error {
         use ErrorVariant::{FallibleAssignment, InfallibleAssignment};
 
         match &self.variant {
-            FallibleAssignment(..) | InfallibleAssignment(..) => vec![Note::SeeErrorDocs],
+            FallibleAssignment {
+                target,
+                expr,
+                default,
+                raising,
+                ..
+            } => {
+                let mut notes = Note::solution(
+                    "specifying a default value in case the expression fails",
+                    vec![format!("{} = {} ?? {}", target, expr, default)],
+                );
+
+                if let Some(raising) = raising {
+                    notes.append(&mut Note::solution(
+                        "aborting the program if the expression fails",
+                        vec![format!("{} = {}", target, raising)],
+                    ));
+                }
+
+                notes.push(Note::SeeErrorDocs);
+                notes
+            }
+            InfallibleAssignment(..) => vec![Note::SeeErrorDocs],
             InvalidParentPathSegment {
                 variant,
                 parent_str,
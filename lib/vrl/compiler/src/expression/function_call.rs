@@ -1,4 +1,5 @@
 use diagnostic::{DiagnosticMessage, Label, Note, Urls};
+use lookup::OwnedTargetPath;
 use std::{fmt, sync::Arc};
 
 use super::Block;
@@ -33,6 +34,10 @@ impl<'a> Builder<'a> {
         &self.list
     }
 
+    pub(crate) fn function(&self) -> &'a dyn Function {
+        self.function
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         call_span: Span,
@@ -142,14 +147,18 @@ impl<'a> Builder<'a> {
                 arguments_with_unknown_type_validity.push((*parameter, node.clone()));
             }
 
-            // Check if the argument is infallible.
-            if argument_type_def.is_fallible() {
+            // Check if the argument is infallible, unless the function
+            // handles the errors of the argument itself.
+            if argument_type_def.is_fallible()
+                && !function.accepts_fallible_argument(parameter.keyword)
+            {
                 return Err(Error::FallibleArgument {
                     expr_span: argument.span(),
                 });
             }
 
-            list.insert(parameter.keyword, argument.into_inner());
+            let expr_span = argument.expr_span();
+            list.insert(parameter.keyword, argument.into_inner(), expr_span);
         }
 
         // Check missing required arguments.
@@ -211,7 +220,7 @@ impl<'a> Builder<'a> {
             }
 
             // Error if closure is missing from function that expects one.
-            (Some(definition), None) => {
+            (Some(definition), None) if !definition.is_optional => {
                 let example = definition.inputs.get(0).map(|input| input.example);
 
                 return Err(Error::MissingClosure { call_span, example });
@@ -393,6 +402,7 @@ impl<'a> Builder<'a> {
         local_snapshot: LocalEnv,
         fallible_expression_error: &mut Option<Box<dyn DiagnosticMessage>>,
         config: &mut CompileConfig,
+        external_deletions: &mut Vec<OwnedTargetPath>,
     ) -> Result<FunctionCall, Error> {
         let (closure, closure_fallible) =
             self.compile_closure(closure_block, local_snapshot, state)?;
@@ -417,6 +427,7 @@ impl<'a> Builder<'a> {
             .map_err(|error| Error::Compilation { call_span, error })?;
 
         // Re-insert the external context into the compiler state.
+        external_deletions.extend(compile_ctx.take_external_deletions());
         let _ = std::mem::replace(config, compile_ctx.into_config());
 
         // Asking for an infallible function to abort on error makes no sense.
@@ -626,11 +637,15 @@ impl FunctionCall {
 
 impl Expression for FunctionCall {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
-        self.expr.resolve(ctx).map_err(|err| match err {
+        ctx.state_mut()
+            .count(|counters| counters.function_calls += 1);
+
+        let value = self.expr.resolve(ctx).map_err(|err| match err {
             #[cfg(feature = "expr-abort")]
             ExpressionError::Abort { .. } => {
                 panic!("abort errors must only be defined by `abort` statement")
             }
+            err @ ExpressionError::MemoryLimitExceeded { .. } => err,
             ExpressionError::Error {
                 message,
                 mut labels,
@@ -650,7 +665,13 @@ impl Expression for FunctionCall {
                     notes,
                 }
             }
-        })
+        })?;
+
+        // Function results can be large, so they count against the memory limit along with the
+        // variables, even before they are stored anywhere.
+        ctx.state_mut().check_memory_limit(Some(&value))?;
+
+        Ok(value)
     }
 
     fn type_info(&self, state: &TypeState) -> TypeInfo {
@@ -978,7 +999,10 @@ impl DiagnosticMessage for Error {
                 .labels()
                 .into_iter()
                 .map(|mut label| {
-                    label.span = *call_span;
+                    // Labels without a span of their own point at the whole call.
+                    if label.span == Span::default() {
+                        label.span = *call_span;
+                    }
                     label
                 })
                 .collect(),
@@ -1278,6 +1302,7 @@ mod tests {
             LocalEnv::default(),
             &mut None,
             &mut config,
+            &mut vec![],
         )
         .unwrap()
     }
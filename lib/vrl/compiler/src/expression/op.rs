@@ -8,7 +8,7 @@ use crate::{
     expression::{self, Expr, Resolved},
     parser::{ast, Node},
     value::VrlValueArithmetic,
-    Context, Expression, TypeDef,
+    Context, Expression, ExpressionError, TypeDef,
 };
 
 #[derive(Clone, PartialEq)]
@@ -86,7 +86,18 @@ impl Expression for Op {
         use value::Value::{Boolean, Null};
 
         match self.opcode {
-            Err => return self.lhs.resolve(ctx).or_else(|_| self.rhs.resolve(ctx)),
+            Err => {
+                return match self.lhs.resolve(ctx) {
+                    // Only errors the program can handle are coalesced. Running out of memory, or
+                    // aborting, still terminates the program.
+                    Result::Err(ExpressionError::Error { .. }) => {
+                        ctx.state_mut()
+                            .count(|counters| counters.handled_errors += 1);
+                        self.rhs.resolve(ctx)
+                    }
+                    result => result,
+                };
+            }
             Or => {
                 return self
                     .lhs
@@ -95,6 +95,8 @@ impl Expression for Query {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         use Target::{Container, External, FunctionCall, Internal};
 
+        ctx.state_mut().count(|counters| counters.path_lookups += 1);
+
         let value = match &self.target {
             External(prefix) => {
                 let path = OwnedTargetPath {
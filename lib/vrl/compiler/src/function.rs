@@ -68,6 +68,15 @@ pub trait Function: Send + Sync + fmt::Debug {
     fn closure(&self) -> Option<closure::Definition> {
         None
     }
+
+    /// Whether the argument for the given parameter is allowed to be fallible.
+    ///
+    /// This returns `false` by default, requiring errors to be handled before
+    /// the argument is passed to the function. Functions returning `true` are
+    /// responsible for handling the errors of the argument themselves.
+    fn accepts_fallible_argument(&self, _keyword: &'static str) -> bool {
+        false
+    }
 }
 
 // -----------------------------------------------------------------------------
@@ -82,12 +91,17 @@ pub struct Example {
 pub struct FunctionCompileContext {
     span: Span,
     config: CompileConfig,
+    external_deletions: Vec<OwnedTargetPath>,
 }
 
 impl FunctionCompileContext {
     #[must_use]
     pub fn new(span: Span, config: CompileConfig) -> Self {
-        Self { span, config }
+        Self {
+            span,
+            config,
+            external_deletions: vec![],
+        }
     }
 
     /// Span information for the function call.
@@ -112,6 +126,17 @@ impl FunctionCompileContext {
         self.config.is_read_only_path(path)
     }
 
+    /// Record that the function can delete the given path of the external target at runtime.
+    ///
+    /// These deletions are exposed through [`ProgramInfo`](crate::ProgramInfo), like assignments.
+    pub fn mark_external_deletion(&mut self, path: OwnedTargetPath) {
+        self.external_deletions.push(path);
+    }
+
+    pub(crate) fn take_external_deletions(&mut self) -> Vec<OwnedTargetPath> {
+        std::mem::take(&mut self.external_deletions)
+    }
+
     /// Consume the `FunctionCompileContext`, returning the (potentially mutated) `AnyMap`.
     #[must_use]
     pub fn into_config(self) -> CompileConfig {
@@ -200,6 +225,9 @@ impl Parameter {
 pub struct ArgumentList {
     pub(crate) arguments: HashMap<&'static str, Expr>,
 
+    /// The source spans of the arguments, if they were parsed from a program.
+    spans: HashMap<&'static str, Span>,
+
     /// A closure argument differs from regular arguments, in that it isn't an
     /// expression by itself, and it also isn't tied to a parameter string in
     /// the function call.
@@ -401,14 +429,25 @@ impl ArgumentList {
             .ok_or(Error::ExpectedFunctionClosure)
     }
 
+    /// The span of the expression passed as the argument for `keyword`.
+    ///
+    /// Functions can use this to point their compile-time diagnostics at the argument, rather
+    /// than at the whole function call. Returns `None` for missing arguments, and for argument
+    /// lists that weren't parsed from a program.
+    #[must_use]
+    pub fn span(&self, keyword: &'static str) -> Option<Span> {
+        self.spans.get(keyword).copied()
+    }
+
     #[cfg(feature = "expr-function_call")]
     pub(crate) fn keywords(&self) -> Vec<&'static str> {
         self.arguments.keys().copied().collect::<Vec<_>>()
     }
 
     #[cfg(feature = "expr-function_call")]
-    pub(crate) fn insert(&mut self, k: &'static str, v: Expr) {
+    pub(crate) fn insert(&mut self, k: &'static str, v: Expr, span: Span) {
         self.arguments.insert(k, v);
+        self.spans.insert(k, span);
     }
 
     #[cfg(feature = "expr-function_call")]
@@ -443,6 +482,7 @@ mod test_impls {
                     .into_iter()
                     .map(|(k, v)| (k, v.into()))
                     .collect::<HashMap<_, _>>(),
+                spans: HashMap::new(),
                 closure: None,
             }
         }
@@ -29,6 +29,12 @@ pub struct Definition {
     /// collection elements to determine the eventual type definition of the
     /// closure variable(s) (see `Variable`).
     pub is_iterator: bool,
+
+    /// Defines whether the function can be called without a closure.
+    ///
+    /// If this is `true`, the function has to check for the closure itself, using
+    /// `ArgumentList::optional_closure`.
+    pub is_optional: bool,
 }
 
 /// One input variant for a function-closure.
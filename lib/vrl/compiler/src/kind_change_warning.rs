@@ -0,0 +1,60 @@
+use crate::{parser::Ident, Span};
+use diagnostic::{DiagnosticMessage, Label, Note, Severity};
+use std::fmt::{Display, Formatter};
+use value::Kind;
+
+/// Emitted when a variable is assigned a value whose type has nothing in common
+/// with the type the variable held before the assignment.
+#[derive(Debug)]
+pub(crate) struct KindChangeWarning {
+    ident: Ident,
+    from: Kind,
+    to: Kind,
+    span: Span,
+}
+
+impl KindChangeWarning {
+    pub(crate) fn new(ident: Ident, from: Kind, to: Kind, span: Span) -> Self {
+        Self {
+            ident,
+            from,
+            to,
+            span,
+        }
+    }
+}
+
+impl std::error::Error for KindChangeWarning {}
+
+impl Display for KindChangeWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl DiagnosticMessage for KindChangeWarning {
+    fn code(&self) -> usize {
+        802
+    }
+
+    fn message(&self) -> String {
+        format!("variable `{}` changes type", self.ident)
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        vec![Label::primary(
+            format!("type changes from {} to {}", self.from, self.to),
+            self.span,
+        )]
+    }
+
+    fn notes(&self) -> Vec<Note> {
+        vec![Note::Hint(
+            "use a new variable name if the previous value is no longer needed".to_owned(),
+        )]
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+}
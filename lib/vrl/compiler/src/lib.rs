@@ -32,7 +32,11 @@ mod compile_config;
 mod compiler;
 mod context;
 mod deprecation_warning;
+#[cfg(feature = "expr-assignment")]
+mod kind_change_warning;
 mod program;
+#[cfg(feature = "expr-assignment")]
+mod shadowed_function_warning;
 mod test_util;
 
 pub mod expression;
@@ -41,7 +45,7 @@ pub mod state;
 pub mod type_def;
 pub mod value;
 
-pub use self::compile_config::CompileConfig;
+pub use self::compile_config::{CompileConfig, ReadOnlyPath};
 pub use self::deprecation_warning::DeprecationWarning;
 pub use compiler::{CompilationResult, Compiler};
 pub use core::{
@@ -57,7 +61,7 @@ pub(crate) use diagnostic::Span;
 pub use expression::{Expression, FunctionExpression};
 pub use function::{Function, Parameter};
 pub use paste::paste;
-pub use program::{Program, ProgramInfo};
+pub use program::{FunctionCallStats, Program, ProgramInfo};
 pub use state::{TypeInfo, TypeState};
 pub use type_def::TypeDef;
 use vector_config::configurable_component;
@@ -1,9 +1,13 @@
+use std::collections::BTreeMap;
+
+use core::Value;
+use diagnostic::Span;
 use lookup::OwnedTargetPath;
 
 use crate::state::TypeState;
 use crate::{
     expression::{Block, Resolved},
-    Context, Expression,
+    Context, Expression, ExpressionError, TypeDef,
 };
 
 #[derive(Debug, Clone)]
@@ -11,6 +15,8 @@ pub struct Program {
     /// The initial state that the program was compiled with.
     pub(crate) initial_state: TypeState,
     pub(crate) expressions: Block,
+    /// The source span of each top-level expression in `expressions`.
+    pub(crate) spans: Vec<Span>,
     pub(crate) info: ProgramInfo,
 }
 
@@ -27,6 +33,12 @@ impl Program {
         self.expressions.type_info(&self.initial_state).state
     }
 
+    /// Retrieves the type of the value the program resolves to.
+    #[must_use]
+    pub fn result_type_def(&self) -> TypeDef {
+        self.expressions.type_info(&self.initial_state).result
+    }
+
     /// Get detailed information about the program, as collected by the VRL
     /// compiler.
     #[must_use]
@@ -42,6 +54,60 @@ impl Program {
     pub fn resolve(&self, ctx: &mut Context) -> Resolved {
         self.expressions.resolve(ctx)
     }
+
+    /// Resolve the program like [`Program::resolve`], calling `trace` after each top-level
+    /// expression with the expression's source span and the event as it is at that point.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the program resulted in a runtime error.
+    pub fn resolve_traced(
+        &self,
+        ctx: &mut Context,
+        trace: &mut dyn FnMut(Span, &Value),
+    ) -> Resolved {
+        let mut result = Value::Null;
+
+        for (expr, span) in self.expressions.exprs().iter().zip(&self.spans) {
+            result = expr.resolve(ctx)?;
+
+            if let Ok(Some(event)) = ctx.target().target_get(&OwnedTargetPath::event_root()) {
+                trace(*span, event);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Resolve the program like [`Program::resolve`], but carry on with the next top-level
+    /// expression when one raises an error, instead of terminating the program. Such errors are
+    /// pushed onto `errors` in the order they were raised.
+    ///
+    /// The result is that of the last top-level expression, or `null` if it raised an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the program is aborted, or exceeds the memory limit of the runtime.
+    pub fn resolve_lenient(
+        &self,
+        ctx: &mut Context,
+        errors: &mut Vec<ExpressionError>,
+    ) -> Resolved {
+        let mut result = Value::Null;
+
+        for expr in self.expressions.exprs() {
+            result = match expr.resolve(ctx) {
+                Ok(value) => value,
+                Err(error @ ExpressionError::Error { .. }) => {
+                    errors.push(error);
+                    Value::Null
+                }
+                Err(error) => return Err(error),
+            };
+        }
+
+        Ok(result)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -64,4 +130,75 @@ pub struct ProgramInfo {
     /// A list of possible assignments made to the external [`Target`] at
     /// runtime.
     pub target_assignments: Vec<OwnedTargetPath>,
+
+    /// A list of possible deletions made from the external [`Target`] at
+    /// runtime, such as through the `del` function.
+    pub target_deletions: Vec<OwnedTargetPath>,
+
+    /// How often each function is called in the source, keyed by function name.
+    pub function_calls: BTreeMap<&'static str, FunctionCallStats>,
+}
+
+impl ProgramInfo {
+    /// Returns the distinct paths of the external [`Target`] the program can read, in the order
+    /// they first appear in the source.
+    #[must_use]
+    pub fn read_paths(&self) -> Vec<OwnedTargetPath> {
+        distinct_paths(&self.target_queries)
+    }
+
+    /// Returns whether the program can read the entire event, such as by querying `.` or passing
+    /// it to a function that iterates over it, rather than only specific paths within it.
+    ///
+    /// If it can't, the program only reads the event at its [`read_paths`](Self::read_paths).
+    #[must_use]
+    pub fn reads_entire_event(&self) -> bool {
+        self.target_queries.contains(&OwnedTargetPath::event_root())
+    }
+
+    /// Returns the distinct paths of the external [`Target`] the program can write, either by
+    /// assigning to or deleting them.
+    #[must_use]
+    pub fn write_paths(&self) -> Vec<OwnedTargetPath> {
+        let paths = self
+            .target_assignments
+            .iter()
+            .chain(&self.target_deletions)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        distinct_paths(&paths)
+    }
+
+    /// Returns how often each function is called in the source, keyed by function name.
+    ///
+    /// Functions that aren't called don't have an entry.
+    #[must_use]
+    pub fn function_call_stats(&self) -> &BTreeMap<&'static str, FunctionCallStats> {
+        &self.function_calls
+    }
+}
+
+fn distinct_paths(paths: &[OwnedTargetPath]) -> Vec<OwnedTargetPath> {
+    let mut distinct: Vec<OwnedTargetPath> = Vec::with_capacity(paths.len());
+    for path in paths {
+        if !distinct.contains(path) {
+            distinct.push(path.clone());
+        }
+    }
+    distinct
+}
+
+/// The calls made to a single function in a program.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FunctionCallStats {
+    /// The number of places the function is called in the source.
+    pub calls: usize,
+
+    /// The number of those calls made inside the closure of an iterating function, such as
+    /// `for_each` or `map_values`.
+    ///
+    /// These calls can run once for every element that is iterated over, so expensive ones are
+    /// worth moving out of the closure if their result doesn't depend on the element.
+    pub calls_in_iteration: usize,
 }
@@ -0,0 +1,64 @@
+use crate::{parser::Ident, Span};
+use diagnostic::{DiagnosticMessage, Label, Note, Severity};
+use std::fmt::{Display, Formatter};
+
+/// Emitted when a variable is given the same name as a function, which makes it easy to confuse
+/// the two further down the program.
+#[derive(Debug)]
+pub(crate) struct ShadowedFunctionWarning {
+    ident: Ident,
+    function: &'static str,
+    span: Span,
+}
+
+impl ShadowedFunctionWarning {
+    pub(crate) fn new(ident: Ident, function: &'static str, span: Span) -> Self {
+        Self {
+            ident,
+            function,
+            span,
+        }
+    }
+}
+
+impl std::error::Error for ShadowedFunctionWarning {}
+
+impl Display for ShadowedFunctionWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl DiagnosticMessage for ShadowedFunctionWarning {
+    fn code(&self) -> usize {
+        803
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "variable `{}` shares its name with the `{}` function",
+            self.ident, self.function
+        )
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        vec![Label::primary(
+            format!("`{}` is also a function", self.ident),
+            self.span,
+        )]
+    }
+
+    fn notes(&self) -> Vec<Note> {
+        vec![
+            Note::Hint(format!(
+                "`{}` refers to the variable, while `{}(...)` still calls the function",
+                self.ident, self.function
+            )),
+            Note::SeeFunctionDocs(self.function),
+        ]
+    }
+
+    fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+}
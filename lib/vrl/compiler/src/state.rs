@@ -1,8 +1,9 @@
 use lookup::PathPrefix;
 use std::collections::{hash_map::Entry, HashMap};
 use value::{Kind, Value};
+use vector_common::byte_size_of::ByteSizeOf;
 
-use crate::{parser::ast::Ident, type_def::Details, value::Collection, TypeDef};
+use crate::{parser::ast::Ident, type_def::Details, value::Collection, ExpressionError, TypeDef};
 
 #[derive(Debug, Clone)]
 pub struct TypeInfo {
@@ -137,6 +138,15 @@ impl ExternalEnv {
         self.target().type_def.kind()
     }
 
+    /// Replaces the [`Kind`] of the target, preserving any known value of the
+    /// target.
+    ///
+    /// This allows embedders to progressively refine the schema of the
+    /// target before compiling a program against it.
+    pub fn set_target_kind(&mut self, kind: Kind) {
+        *self.target.type_def.kind_mut() = kind;
+    }
+
     pub fn kind(&self, prefix: PathPrefix) -> Kind {
         match prefix {
             PathPrefix::Event => self.target_kind(),
@@ -164,6 +174,38 @@ impl ExternalEnv {
 pub struct Runtime {
     /// The [`Value`] stored in each variable.
     variables: HashMap<Ident, Value>,
+
+    /// The memory budget of the stored variables, if any.
+    memory: Option<MemoryBudget>,
+
+    /// Counts of the work done while resolving programs, if enabled.
+    counters: Option<Counters>,
+}
+
+/// Counts of the work done by the programs resolved with a [`Runtime`], to help find expensive
+/// programs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Counters {
+    /// The number of function calls made.
+    pub function_calls: u64,
+
+    /// The number of errors raised by fallible expressions that were handled by the program,
+    /// such as with `??` or an `ok, err = ...` assignment.
+    pub handled_errors: u64,
+
+    /// The number of paths looked up in the target or in variables.
+    pub path_lookups: u64,
+}
+
+/// Approximate accounting of the memory taken up by the variables.
+#[derive(Debug)]
+struct MemoryBudget {
+    /// The maximum number of bytes the variables may take up.
+    limit: usize,
+
+    /// The size of each variable, or `None` if it has been handed out mutably since it was last
+    /// measured.
+    sizes: HashMap<Ident, Option<usize>>,
 }
 
 impl Runtime {
@@ -174,6 +216,47 @@ impl Runtime {
 
     pub fn clear(&mut self) {
         self.variables.clear();
+        if let Some(memory) = &mut self.memory {
+            memory.sizes.clear();
+        }
+    }
+
+    /// Sets the approximate number of bytes the variables may take up, or removes the limit if
+    /// `None` is given.
+    pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+        self.memory = limit.map(|limit| MemoryBudget {
+            limit,
+            sizes: self
+                .variables
+                .iter()
+                .map(|(ident, value)| (ident.clone(), Some(value.size_of())))
+                .collect(),
+        });
+    }
+
+    #[must_use]
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.memory.as_ref().map(|memory| memory.limit)
+    }
+
+    /// Starts counting the work done by resolved programs if `enabled`, or stops counting and
+    /// discards the counts otherwise.
+    pub fn set_counters_enabled(&mut self, enabled: bool) {
+        self.counters = enabled.then(Counters::default);
+    }
+
+    /// The counts accumulated since counting was enabled, or `None` if it isn't.
+    #[must_use]
+    pub fn counters(&self) -> Option<&Counters> {
+        self.counters.as_ref()
+    }
+
+    /// Updates the counts, if enabled.
+    #[inline]
+    pub fn count(&mut self, update: impl FnOnce(&mut Counters)) {
+        if let Some(counters) = &mut self.counters {
+            update(counters);
+        }
     }
 
     #[must_use]
@@ -182,18 +265,31 @@ impl Runtime {
     }
 
     pub fn variable_mut(&mut self, ident: &Ident) -> Option<&mut Value> {
-        self.variables.get_mut(ident)
+        let value = self.variables.get_mut(ident)?;
+        if let Some(memory) = &mut self.memory {
+            memory.sizes.insert(ident.clone(), None);
+        }
+        Some(value)
     }
 
     pub(crate) fn insert_variable(&mut self, ident: Ident, value: Value) {
+        if let Some(memory) = &mut self.memory {
+            memory.sizes.insert(ident.clone(), Some(value.size_of()));
+        }
         self.variables.insert(ident, value);
     }
 
     pub(crate) fn remove_variable(&mut self, ident: &Ident) {
+        if let Some(memory) = &mut self.memory {
+            memory.sizes.remove(ident);
+        }
         self.variables.remove(ident);
     }
 
     pub(crate) fn swap_variable(&mut self, ident: Ident, value: Value) -> Option<Value> {
+        if let Some(memory) = &mut self.memory {
+            memory.sizes.insert(ident.clone(), Some(value.size_of()));
+        }
         match self.variables.entry(ident) {
             Entry::Occupied(mut v) => Some(std::mem::replace(v.get_mut(), value)),
             Entry::Vacant(v) => {
@@ -202,4 +298,34 @@ impl Runtime {
             }
         }
     }
+
+    /// Checks that the variables, together with a `pending` value that is about to be stored
+    /// outside of them, fit in the memory limit.
+    pub(crate) fn check_memory_limit(
+        &mut self,
+        pending: Option<&Value>,
+    ) -> Result<(), ExpressionError> {
+        let memory = match &mut self.memory {
+            Some(memory) => memory,
+            None => return Ok(()),
+        };
+
+        let variables = &self.variables;
+        let used = memory
+            .sizes
+            .iter_mut()
+            .map(|(ident, size)| {
+                *size.get_or_insert_with(|| variables.get(ident).map_or(0, ByteSizeOf::size_of))
+            })
+            .sum::<usize>()
+            + pending.map_or(0, ByteSizeOf::size_of);
+
+        if used > memory.limit {
+            return Err(ExpressionError::MemoryLimitExceeded {
+                limit: memory.limit,
+            });
+        }
+
+        Ok(())
+    }
 }
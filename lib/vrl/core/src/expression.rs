@@ -15,6 +15,11 @@ pub enum ExpressionError {
         labels: Vec<Label>,
         notes: Vec<Note>,
     },
+
+    /// The values held by the program grew beyond the memory limit of the runtime.
+    MemoryLimitExceeded {
+        limit: usize,
+    },
 }
 
 impl std::fmt::Display for ExpressionError {
@@ -35,17 +40,18 @@ impl DiagnosticMessage for ExpressionError {
     }
 
     fn message(&self) -> String {
-        use ExpressionError::{Abort, Error};
+        use ExpressionError::{Abort, Error, MemoryLimitExceeded};
 
         match self {
             #[cfg(feature = "expr-abort")]
             Abort { message, .. } => message.clone().unwrap_or_else(|| "aborted".to_owned()),
             Error { message, .. } => message.clone(),
+            MemoryLimitExceeded { limit } => format!("memory limit of {} bytes exceeded", limit),
         }
     }
 
     fn labels(&self) -> Vec<Label> {
-        use ExpressionError::{Abort, Error};
+        use ExpressionError::{Abort, Error, MemoryLimitExceeded};
 
         match self {
             #[cfg(feature = "expr-abort")]
@@ -53,16 +59,18 @@ impl DiagnosticMessage for ExpressionError {
                 vec![Label::primary("aborted", span)]
             }
             Error { labels, .. } => labels.clone(),
+            MemoryLimitExceeded { .. } => vec![],
         }
     }
 
     fn notes(&self) -> Vec<Note> {
-        use ExpressionError::{Abort, Error};
+        use ExpressionError::{Abort, Error, MemoryLimitExceeded};
 
         match self {
             #[cfg(feature = "expr-abort")]
             Abort { .. } => vec![],
             Error { notes, .. } => notes.clone(),
+            MemoryLimitExceeded { .. } => vec![],
         }
     }
 }
@@ -87,6 +87,8 @@ criterion_group!(
               parse_aws_vpc_flow_log,
               parse_common_log,
               parse_csv,
+              parse_csv_line,
+              parse_csv_rows,
               parse_duration,
               parse_glog,
               parse_grok,
@@ -1512,6 +1514,29 @@ bench_function! {
     }
 }
 
+bench_function! {
+    parse_csv_line => vrl_stdlib::ParseCsvLine;
+
+    literal {
+        args: func_args![value: "foo,bar"],
+        want: Ok(value!(["foo","bar"]))
+    }
+}
+
+bench_function! {
+    parse_csv_rows => vrl_stdlib::ParseCsvRows;
+
+    literal {
+        args: func_args![value: "foo,bar\nbaz,qux"],
+        want: Ok(value!([["foo","bar"],["baz","qux"]]))
+    }
+
+    headers {
+        args: func_args![value: "name,age\nalice,42", headers: true],
+        want: Ok(value!([{"name": "alice", "age": "42"}]))
+    }
+}
+
 bench_function! {
     parse_duration => vrl_stdlib::ParseDuration;
 
@@ -0,0 +1,297 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+const SI_SUFFIXES: [&str; 6] = ["K", "M", "G", "T", "P", "E"];
+const BINARY_SUFFIXES: [&str; 6] = ["Ki", "Mi", "Gi", "Ti", "Pi", "Ei"];
+
+/// The largest supported precision. A 64-bit float holds at most 17 significant digits, so more
+/// decimal places than that can't add any information.
+const MAX_PRECISION: i64 = 17;
+
+#[derive(Clone, Copy, Debug)]
+enum Base {
+    Si,
+    Binary,
+}
+
+impl Base {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        match bytes {
+            b"si" => Self::Si,
+            b"binary" => Self::Binary,
+            _ => unreachable!("enum invariant"),
+        }
+    }
+
+    const fn factor(self) -> f64 {
+        match self {
+            Self::Si => 1000.0,
+            Self::Binary => 1024.0,
+        }
+    }
+
+    const fn suffixes(self) -> &'static [&'static str] {
+        match self {
+            Self::Si => &SI_SUFFIXES,
+            Self::Binary => &BINARY_SUFFIXES,
+        }
+    }
+}
+
+fn bases() -> Vec<Value> {
+    vec![value!("si"), value!("binary")]
+}
+
+fn abbreviate_number(value: Value, base: Base, precision: Value, unit: Value) -> Resolved {
+    let value = match value {
+        Value::Integer(v) => v as f64,
+        Value::Float(v) => *v,
+        value => {
+            return Err(value::Error::Expected {
+                got: value.kind(),
+                expected: Kind::integer() | Kind::float(),
+            }
+            .into())
+        }
+    };
+    let precision = get_precision(precision.try_integer()?)?;
+    let unit = unit.try_bytes_utf8_lossy()?;
+
+    let suffixes = base.suffixes();
+    let factor = base.factor();
+    let rounding = 10_f64.powi(precision as i32);
+    let round = |v: f64| (v * rounding).round() / rounding;
+
+    let mut scaled = value.abs();
+    let mut suffix = "";
+    for next in suffixes {
+        // Compare the rounded value, so that e.g. `999_999` becomes `1.0M` rather than `1000.0K`.
+        if round(scaled) < factor {
+            break;
+        }
+        scaled /= factor;
+        suffix = next;
+    }
+
+    let sign = if value.is_sign_negative() && round(scaled) > 0.0 {
+        "-"
+    } else {
+        ""
+    };
+
+    Ok(format!("{}{:.*}{}{}", sign, precision, scaled, suffix, unit).into())
+}
+
+fn get_precision(precision: i64) -> std::result::Result<usize, &'static str> {
+    if precision < 0 {
+        return Err("precision can't be negative");
+    }
+    if precision > MAX_PRECISION {
+        return Err("precision is too large, the maximum is 17");
+    }
+    Ok(precision as usize)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AbbreviateNumber;
+
+impl Function for AbbreviateNumber {
+    fn identifier(&self) -> &'static str {
+        "abbreviate_number"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "base",
+                kind: kind::BYTES,
+                required: false,
+            },
+            Parameter {
+                keyword: "precision",
+                kind: kind::INTEGER,
+                required: false,
+            },
+            Parameter {
+                keyword: "unit",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "si",
+                source: r#"abbreviate_number(1500)"#,
+                result: Ok(r#""1.5K""#),
+            },
+            Example {
+                title: "binary",
+                source: r#"abbreviate_number(1048576, base: "binary")"#,
+                result: Ok(r#""1.0Mi""#),
+            },
+            Example {
+                title: "precision and unit",
+                source: r#"abbreviate_number(123456789, base: "binary", precision: 2, unit: "B")"#,
+                result: Ok(r#""117.74MiB""#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let base = arguments
+            .optional_enum("base", &bases())?
+            .map(|base| Base::from_bytes(&base.try_bytes().expect("base not bytes")))
+            .unwrap_or(Base::Si);
+        let precision = arguments.optional("precision").unwrap_or_else(|| expr!(1));
+
+        if let Some(literal) = precision.as_value() {
+            let precision = literal.try_integer().expect("precision must be an integer");
+
+            if let Err(error) = get_precision(precision) {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "precision",
+                    value: literal,
+                    error,
+                }
+                .into());
+            }
+        }
+        let unit = arguments.optional("unit").unwrap_or_else(|| expr!(""));
+
+        Ok(AbbreviateNumberFn {
+            value,
+            base,
+            precision,
+            unit,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AbbreviateNumberFn {
+    value: Box<dyn Expression>,
+    base: Base,
+    precision: Box<dyn Expression>,
+    unit: Box<dyn Expression>,
+}
+
+impl FunctionExpression for AbbreviateNumberFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let precision = self.precision.resolve(ctx)?;
+        let unit = self.unit.resolve(ctx)?;
+
+        abbreviate_number(value, self.base, precision, unit)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // A literal precision is already checked at compile time.
+        TypeDef::bytes().with_fallibility(self.precision.as_value().is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        abbreviate_number => AbbreviateNumber;
+
+        below_base {
+            args: func_args![value: 999],
+            want: Ok("999.0"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        si {
+            args: func_args![value: 1500],
+            want: Ok("1.5K"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        si_rounds_up_to_next_suffix {
+            args: func_args![value: 999_999],
+            want: Ok("1.0M"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        binary {
+            args: func_args![value: 1_048_576, base: "binary"],
+            want: Ok("1.0Mi"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        precision {
+            args: func_args![value: 1_234_567, precision: 3],
+            want: Ok("1.235M"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        zero_precision {
+            args: func_args![value: 2_600_000_000_i64, precision: 0],
+            want: Ok("3G"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        unit {
+            args: func_args![value: 2048, base: "binary", unit: "B"],
+            want: Ok("2.0KiB"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        negative {
+            args: func_args![value: -1500.0],
+            want: Ok("-1.5K"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        negative_precision {
+            args: func_args![value: 1500, precision: -1],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        max_precision {
+            args: func_args![value: 1500, precision: 17],
+            want: Ok("1.50000000000000000K"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        precision_too_large {
+            args: func_args![value: 1500, precision: 18],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        largest_suffix {
+            args: func_args![value: 5.0e21],
+            want: Ok("5000.0E"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+
+    #[test]
+    fn runtime_precision_limit() {
+        let error = abbreviate_number(1500.into(), Base::Si, 18.into(), "".into()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "precision is too large, the maximum is 17"
+        );
+    }
+}
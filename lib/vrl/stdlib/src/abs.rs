@@ -0,0 +1,111 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn abs(value: Value) -> Resolved {
+    match value {
+        // The absolute value of `i64::MIN` doesn't fit in an integer, so it saturates.
+        Value::Integer(i) => Ok(i.saturating_abs().into()),
+        Value::Float(f) => Ok(Value::from_f64_or_zero(f.into_inner().abs())),
+        value => Err(value::Error::Expected {
+            got: value.kind(),
+            expected: Kind::float() | Kind::integer(),
+        }
+        .into()),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Abs;
+
+impl Function for Abs {
+    fn identifier(&self) -> &'static str {
+        "abs"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::INTEGER | kind::FLOAT,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "integer",
+                source: r#"abs(-42)"#,
+                result: Ok("42"),
+            },
+            Example {
+                title: "float",
+                source: r#"abs(-4.2)"#,
+                result: Ok("4.2"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(AbsFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AbsFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for AbsFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        abs(value)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        match Kind::from(self.value.type_def(state)) {
+            v if v.is_float() || v.is_integer() => v.into(),
+            _ => Kind::integer().or_float().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        abs => Abs;
+
+        negative_integer {
+            args: func_args![value: -42],
+            want: Ok(value!(42)),
+            tdef: TypeDef::integer(),
+        }
+
+        positive_integer {
+            args: func_args![value: 42],
+            want: Ok(value!(42)),
+            tdef: TypeDef::integer(),
+        }
+
+        min_integer {
+            args: func_args![value: i64::MIN],
+            want: Ok(i64::MAX),
+            tdef: TypeDef::integer(),
+        }
+
+        negative_float {
+            args: func_args![value: -4.2],
+            want: Ok(value!(4.2)),
+            tdef: TypeDef::float(),
+        }
+    ];
+}
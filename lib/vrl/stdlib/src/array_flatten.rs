@@ -0,0 +1,144 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn array_flatten(value: Value, depth: Value) -> Resolved {
+    let array = value.try_array()?;
+    let depth = depth.try_integer()?;
+
+    // A negative depth flattens all levels of nesting.
+    let depth = usize::try_from(depth).ok();
+
+    if depth == Some(0) {
+        return Ok(array.into());
+    }
+
+    let mut flattened = Vec::with_capacity(array.len());
+    flatten_into(array, depth, &mut flattened);
+
+    Ok(flattened.into())
+}
+
+fn flatten_into(values: Vec<Value>, depth: Option<usize>, out: &mut Vec<Value>) {
+    for value in values {
+        match value {
+            Value::Array(inner) if depth != Some(0) => {
+                flatten_into(inner, depth.map(|depth| depth - 1), out);
+            }
+            value => out.push(value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ArrayFlatten;
+
+impl Function for ArrayFlatten {
+    fn identifier(&self) -> &'static str {
+        "array_flatten"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "depth",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "default depth",
+                source: r#"array_flatten([1, [2, [3, [4]]]])"#,
+                result: Ok("[1, 2, [3, [4]]]"),
+            },
+            Example {
+                title: "custom depth",
+                source: r#"array_flatten([1, [2, [3, [4]]]], depth: 2)"#,
+                result: Ok("[1, 2, 3, [4]]"),
+            },
+            Example {
+                title: "fully recursive",
+                source: r#"array_flatten([1, [2, [3, [4]]]], depth: -1)"#,
+                result: Ok("[1, 2, 3, 4]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let depth = arguments.optional("depth").unwrap_or_else(|| expr!(1));
+
+        Ok(ArrayFlattenFn { value, depth }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ArrayFlattenFn {
+    value: Box<dyn Expression>,
+    depth: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ArrayFlattenFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let depth = self.depth.resolve(ctx)?;
+
+        array_flatten(value, depth)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::array(Collection::any())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        array_flatten => ArrayFlatten;
+
+        default_depth {
+            args: func_args![value: value!([1, [2, [3, [4]]], [], [[5]]])],
+            want: Ok(value!([1, 2, [3, [4]], [5]])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        depth_zero {
+            args: func_args![value: value!([1, [2, [3]]]), depth: 0],
+            want: Ok(value!([1, [2, [3]]])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        depth_two {
+            args: func_args![value: value!([[1, [2, [3]]], "foo", [{"bar": [4]}]]), depth: 2],
+            want: Ok(value!([1, 2, [3], "foo", {"bar": [4]}])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        fully_recursive {
+            args: func_args![value: value!([1, [2, [3, [4, [[5]]]]], [[[]]]]), depth: -1],
+            want: Ok(value!([1, 2, 3, 4, 5])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        empty {
+            args: func_args![value: value!([])],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+    ];
+}
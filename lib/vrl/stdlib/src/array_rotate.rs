@@ -0,0 +1,136 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn array_rotate(value: Value, count: Value) -> Resolved {
+    let mut array = value.try_array()?;
+    let count = count.try_integer()?;
+
+    if array.is_empty() {
+        return Ok(array.into());
+    }
+
+    // `rem_euclid` maps negative counts onto the equivalent rotation to the right.
+    let count = count.rem_euclid(array.len() as i64);
+    array.rotate_right(count as usize);
+
+    Ok(array.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ArrayRotate;
+
+impl Function for ArrayRotate {
+    fn identifier(&self) -> &'static str {
+        "array_rotate"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "count",
+                kind: kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "rotate right",
+                source: r#"array_rotate([1, 2, 3, 4], 1)"#,
+                result: Ok("[4, 1, 2, 3]"),
+            },
+            Example {
+                title: "rotate left",
+                source: r#"array_rotate([1, 2, 3, 4], -1)"#,
+                result: Ok("[2, 3, 4, 1]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let count = arguments.required("count");
+
+        Ok(ArrayRotateFn { value, count }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ArrayRotateFn {
+    value: Box<dyn Expression>,
+    count: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ArrayRotateFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let count = self.count.resolve(ctx)?;
+
+        array_rotate(value, count)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        self.value
+            .type_def(state)
+            .infallible()
+            .restrict_array()
+            .collect_subtypes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        array_rotate => ArrayRotate;
+
+        right {
+            args: func_args![value: value!([1, 2, 3, 4]), count: 1],
+            want: Ok(value!([4, 1, 2, 3])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())),
+        }
+
+        left {
+            args: func_args![value: value!([1, 2, 3, 4]), count: -1],
+            want: Ok(value!([2, 3, 4, 1])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())),
+        }
+
+        wraps_around {
+            args: func_args![value: value!([1, 2, 3]), count: 7],
+            want: Ok(value!([3, 1, 2])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())),
+        }
+
+        wraps_around_left {
+            args: func_args![value: value!([1, 2, 3]), count: -7],
+            want: Ok(value!([2, 3, 1])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())),
+        }
+
+        zero {
+            args: func_args![value: value!([1, "foo", true]), count: 0],
+            want: Ok(value!([1, "foo", true])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer().or_bytes().or_boolean())),
+        }
+
+        empty {
+            args: func_args![value: value!([]), count: 3],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::empty()),
+        }
+    ];
+}
@@ -105,3 +105,30 @@ impl fmt::Display for AssertFn {
         f.write_str("")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        assert => Assert;
+
+        pass {
+            args: func_args![condition: true],
+            want: Ok(true),
+            tdef: TypeDef::boolean().fallible(),
+        }
+
+        fail {
+            args: func_args![condition: false],
+            want: Err("assertion failed"),
+            tdef: TypeDef::boolean().fallible(),
+        }
+
+        message {
+            args: func_args![condition: false, message: "failure!"],
+            want: Err("failure!"),
+            tdef: TypeDef::boolean().fallible(),
+        }
+    ];
+}
@@ -0,0 +1,130 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+/// The size counted for each array and object, on top of the size of its contents.
+const CONTAINER_OVERHEAD: usize = 8;
+
+/// Estimates the size of the value in bytes.
+///
+/// The estimate is deliberately independent of the platform and of how values are laid out in
+/// memory, so that it stays stable across releases:
+///
+/// - strings and regular expressions count their length in bytes,
+/// - integers, floats and timestamps count 8 bytes,
+/// - booleans and `null` count 1 byte,
+/// - arrays count [`CONTAINER_OVERHEAD`] plus the size of their elements,
+/// - objects count [`CONTAINER_OVERHEAD`] plus the length of their keys and the size of their
+///   values.
+fn estimate(value: &Value) -> usize {
+    match value {
+        Value::Bytes(bytes) => bytes.len(),
+        Value::Regex(regex) => regex.as_str().len(),
+        Value::Integer(_) | Value::Float(_) | Value::Timestamp(_) => 8,
+        Value::Boolean(_) | Value::Null => 1,
+        Value::Array(array) => CONTAINER_OVERHEAD + array.iter().map(estimate).sum::<usize>(),
+        Value::Object(object) => {
+            CONTAINER_OVERHEAD
+                + object
+                    .iter()
+                    .map(|(key, value)| key.len() + estimate(value))
+                    .sum::<usize>()
+        }
+    }
+}
+
+fn byte_size(value: &Value) -> Resolved {
+    Ok(estimate(value).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ByteSize;
+
+impl Function for ByteSize {
+    fn identifier(&self) -> &'static str {
+        "byte_size"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ANY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "string",
+                source: r#"byte_size("foobar")"#,
+                result: Ok("6"),
+            },
+            Example {
+                title: "object",
+                source: r#"byte_size({ "foo": "bar", "baz": [1, true] })"#,
+                result: Ok("34"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ByteSizeFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ByteSizeFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ByteSizeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        byte_size(&value)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::integer().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        byte_size => ByteSize;
+
+        string {
+            args: func_args![value: "foobar"],
+            want: Ok(6),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        scalars {
+            args: func_args![value: value!([1, 1.5, true, null])],
+            want: Ok(8 + 8 + 8 + 1 + 1),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        nested {
+            args: func_args![value: value!({"foo": "bar", "baz": [1, true]})],
+            want: Ok(8 + 3 + 3 + 3 + 8 + 8 + 1),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        empty_object {
+            args: func_args![value: value!({})],
+            want: Ok(8),
+            tdef: TypeDef::integer().infallible(),
+        }
+    ];
+}
@@ -0,0 +1,203 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn center(value: Value, width: Value, pad: Value) -> Resolved {
+    let value = value.try_bytes_utf8_lossy()?;
+    let width = util::pad_length(width.try_integer()?)?;
+    let pad = pad.try_bytes_utf8_lossy()?;
+    if pad.is_empty() {
+        return Err("pad can't be empty".into());
+    }
+
+    // Any odd character of padding goes after the value.
+    let fill = width.saturating_sub(value.chars().count());
+    let start = util::pad(&value, width - fill + fill / 2, &pad, true);
+
+    Ok(util::pad(&start, width, &pad, false).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Center;
+
+impl Function for Center {
+    fn identifier(&self) -> &'static str {
+        "center"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "width",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "pad",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "default pad",
+                source: r#"center("abc", 7)"#,
+                result: Ok("  abc  "),
+            },
+            Example {
+                title: "heading",
+                source: r#"center(" Report ", 16, "=")"#,
+                result: Ok("==== Report ===="),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let width = arguments.required("width");
+        let pad = arguments.optional("pad").unwrap_or_else(|| expr!(" "));
+
+        if let Some(literal) = width.as_value() {
+            let width = literal.try_integer().expect("width must be an integer");
+
+            if let Err(error) = util::pad_length(width) {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "width",
+                    value: literal,
+                    error,
+                }
+                .into());
+            }
+        }
+
+        if let Some(literal) = pad.as_value() {
+            if literal.as_bytes().map_or(false, Bytes::is_empty) {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "pad",
+                    value: literal,
+                    error: "pad can't be empty",
+                }
+                .into());
+            }
+        }
+
+        Ok(CenterFn { value, width, pad }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CenterFn {
+    value: Box<dyn Expression>,
+    width: Box<dyn Expression>,
+    pad: Box<dyn Expression>,
+}
+
+impl FunctionExpression for CenterFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let width = self.width.resolve(ctx)?;
+        let pad = self.pad.resolve(ctx)?;
+
+        center(value, width, pad)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // A constant width and pad are checked at compile time.
+        let checked = self.width.as_value().is_some() && self.pad.as_value().is_some();
+
+        TypeDef::bytes().with_fallibility(!checked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        center => Center;
+
+        even {
+            args: func_args![value: "ab", width: 6, pad: "*"],
+            want: Ok("**ab**"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        odd {
+            args: func_args![value: "ab", width: 5, pad: "*"],
+            want: Ok("*ab**"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        default_pad {
+            args: func_args![value: "foo", width: 7],
+            want: Ok("  foo  "),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        counts_characters {
+            args: func_args![value: "ñandú", width: 9, pad: "♔"],
+            want: Ok("♔♔ñandú♔♔"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        multi_character_pad {
+            args: func_args![value: "x", width: 7, pad: "abc"],
+            want: Ok("abcxabc"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        already_wide_enough {
+            args: func_args![value: "foobar", width: 3],
+            want: Ok("foobar"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        negative_width {
+            args: func_args![value: "foo", width: -1],
+            want: Ok("foo"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        empty_pad {
+            args: func_args![value: "foo", width: 5, pad: ""],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        at_limit {
+            args: func_args![value: "", width: 1024 * 1024],
+            want: Ok(" ".repeat(1024 * 1024)),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        too_wide {
+            args: func_args![value: "foo", width: 1024 * 1024 + 1],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+
+    #[test]
+    fn runtime_limit() {
+        let error = center("foo".into(), Value::from(i64::MAX), " ".into()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "can't pad to more than the maximum of 1Mi characters"
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::util::promoted_numeric_kind;
+
+const INVALID_BOUNDS: &str = r#""min" must not be greater than "max""#;
+
+fn clamp(value: Value, min: Value, max: Value) -> Resolved {
+    if let (Value::Integer(value), Value::Integer(min), Value::Integer(max)) = (&value, &min, &max)
+    {
+        if min > max {
+            return Err(INVALID_BOUNDS.into());
+        }
+
+        return Ok((*value).clamp(*min, *max).into());
+    }
+
+    let value = value.try_into_f64()?;
+    let min = min.try_into_f64()?;
+    let max = max.try_into_f64()?;
+    if min > max {
+        return Err(INVALID_BOUNDS.into());
+    }
+
+    Ok(Value::from_f64_or_zero(value.clamp(min, max)))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Clamp;
+
+impl Function for Clamp {
+    fn identifier(&self) -> &'static str {
+        "clamp"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "min",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "max",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "above max",
+                source: r#"clamp(150, 0, 100)"#,
+                result: Ok("100"),
+            },
+            Example {
+                title: "within bounds",
+                source: r#"clamp(42, 0, 100)"#,
+                result: Ok("42"),
+            },
+            Example {
+                title: "float bounds",
+                source: r#"clamp(2, 0, 1.5)"#,
+                result: Ok("1.5"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let min = arguments.required("min");
+        let max = arguments.required("max");
+
+        if let (Some(lower), Some(upper)) = (min.as_value(), max.as_value()) {
+            if let (Ok(lower), Ok(upper)) = (lower.try_into_f64(), upper.try_into_f64()) {
+                if lower > upper {
+                    return Err(vrl::function::Error::InvalidArgument {
+                        keyword: "max",
+                        value: max.as_value().expect("literal"),
+                        error: INVALID_BOUNDS,
+                    }
+                    .into());
+                }
+            }
+        }
+
+        Ok(ClampFn { value, min, max }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ClampFn {
+    value: Box<dyn Expression>,
+    min: Box<dyn Expression>,
+    max: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ClampFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let min = self.min.resolve(ctx)?;
+        let max = self.max.resolve(ctx)?;
+
+        clamp(value, min, max)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let kind = promoted_numeric_kind(&[
+            self.value.type_def(state).kind().clone(),
+            self.min.type_def(state).kind().clone(),
+            self.max.type_def(state).kind().clone(),
+        ]);
+
+        // Literal bounds are validated at compile time.
+        let fallible = self.min.as_value().is_none() || self.max.as_value().is_none();
+
+        TypeDef::from(kind).with_fallibility(fallible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        clamp => Clamp;
+
+        above {
+            args: func_args![value: 150, min: 0, max: 100],
+            want: Ok(value!(100)),
+            tdef: TypeDef::integer(),
+        }
+
+        below {
+            args: func_args![value: -5, min: 0, max: 100],
+            want: Ok(value!(0)),
+            tdef: TypeDef::integer(),
+        }
+
+        within {
+            args: func_args![value: 42, min: 0, max: 100],
+            want: Ok(value!(42)),
+            tdef: TypeDef::integer(),
+        }
+
+        floats {
+            args: func_args![value: 0.75, min: 0.0, max: 0.5],
+            want: Ok(value!(0.5)),
+            tdef: TypeDef::float(),
+        }
+
+        mixed {
+            args: func_args![value: 42, min: 0, max: 1.5],
+            want: Ok(value!(1.5)),
+            tdef: TypeDef::float(),
+        }
+
+        invalid_bounds {
+            args: func_args![value: 1, min: 10, max: 0],
+            want: Err("invalid argument"),
+            tdef: TypeDef::integer(),
+        }
+    ];
+
+    #[test]
+    fn invalid_runtime_bounds() {
+        assert_eq!(
+            clamp(value!(1), value!(10), value!(0))
+                .unwrap_err()
+                .to_string(),
+            INVALID_BOUNDS
+        );
+        assert_eq!(
+            clamp(value!(1.0), value!(10), value!(0))
+                .unwrap_err()
+                .to_string(),
+            INVALID_BOUNDS
+        );
+    }
+}
@@ -189,13 +189,15 @@ impl CompactOptions {
             return true;
         }
 
-        match value {
-            Value::Bytes(bytes) => self.string && bytes.len() == 0,
+        let enabled = match value {
+            Value::Bytes(_) => self.string,
             Value::Null => self.null,
-            Value::Object(object) => self.object && object.is_empty(),
-            Value::Array(array) => self.array && array.is_empty(),
+            Value::Object(_) => self.object,
+            Value::Array(_) => self.array,
             _ => false,
-        }
+        };
+
+        enabled && util::is_empty(value)
     }
 }
 
@@ -237,10 +239,16 @@ impl FunctionExpression for CompactFn {
     }
 
     fn type_def(&self, state: &state::TypeState) -> TypeDef {
-        if self.value.type_def(state).is_array() {
+        let value = self.value.type_def(state);
+
+        if value.is_array() {
             TypeDef::array(Collection::any())
-        } else {
+        } else if value.is_object() {
             TypeDef::object(Collection::any())
+        } else {
+            // The same container type that was given is returned, which isn't
+            // known until runtime.
+            TypeDef::array(Collection::any()).or_object(Collection::any())
         }
     }
 }
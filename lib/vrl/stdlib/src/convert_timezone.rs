@@ -0,0 +1,148 @@
+use ::value::Value;
+use chrono::{TimeZone, Utc};
+use vrl::prelude::*;
+
+use crate::util;
+
+fn convert_timezone(value: Value, timezone: Value) -> Resolved {
+    let ts = value.try_timestamp()?;
+    let tz = util::parse_timezone(&timezone.try_bytes()?)?;
+
+    // The timestamp's UTC date and time is read as the local date and time in `tz`. Local times
+    // that occur twice when clocks go back resolve to the earlier instant.
+    let local = ts.naive_utc();
+    match tz.from_local_datetime(&local).earliest() {
+        Some(dt) => Ok(dt.with_timezone(&Utc).into()),
+        None => Err(format!("{} doesn't exist in timezone \"{}\"", local, tz.name()).into()),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ConvertTimezone;
+
+impl Function for ConvertTimezone {
+    fn identifier(&self) -> &'static str {
+        "convert_timezone"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+            Parameter {
+                keyword: "timezone",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "local time",
+                source: r#"convert_timezone!(t'2021-02-10T23:32:00Z', "America/New_York")"#,
+                result: Ok("t'2021-02-11T04:32:00Z'"),
+            },
+            Example {
+                title: "daylight saving time",
+                source: r#"convert_timezone!(t'2021-07-01T09:00:00Z', "Europe/Paris")"#,
+                result: Ok("t'2021-07-01T07:00:00Z'"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let timezone = arguments.required("timezone");
+
+        if let Some(literal) = timezone.as_value() {
+            let is_valid = literal
+                .as_bytes()
+                .map_or(false, |name| util::parse_timezone(name).is_ok());
+            if !is_valid {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "timezone",
+                    value: literal,
+                    error: "unknown timezone",
+                }
+                .into());
+            }
+        }
+
+        Ok(ConvertTimezoneFn { value, timezone }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ConvertTimezoneFn {
+    value: Box<dyn Expression>,
+    timezone: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ConvertTimezoneFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let timezone = self.timezone.resolve(ctx)?;
+
+        convert_timezone(value, timezone)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Always fallible, as local times skipped when clocks go forward don't exist.
+        TypeDef::timestamp().fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        convert_timezone => ConvertTimezone;
+
+        winter_time {
+            args: func_args![value: Utc.ymd(2021, 2, 10).and_hms(23, 32, 0), timezone: "America/New_York"],
+            want: Ok(Utc.ymd(2021, 2, 11).and_hms(4, 32, 0)),
+            tdef: TypeDef::timestamp().fallible(),
+        }
+
+        summer_time {
+            args: func_args![value: Utc.ymd(2021, 7, 10).and_hms(23, 32, 0), timezone: "America/New_York"],
+            want: Ok(Utc.ymd(2021, 7, 11).and_hms(3, 32, 0)),
+            tdef: TypeDef::timestamp().fallible(),
+        }
+
+        utc {
+            args: func_args![value: Utc.ymd(2021, 2, 10).and_hms(23, 32, 0), timezone: "UTC"],
+            want: Ok(Utc.ymd(2021, 2, 10).and_hms(23, 32, 0)),
+            tdef: TypeDef::timestamp().fallible(),
+        }
+
+        repeated_local_time {
+            args: func_args![value: Utc.ymd(2021, 10, 31).and_hms(2, 30, 0), timezone: "Europe/Paris"],
+            want: Ok(Utc.ymd(2021, 10, 31).and_hms(0, 30, 0)),
+            tdef: TypeDef::timestamp().fallible(),
+        }
+
+        skipped_local_time {
+            args: func_args![value: Utc.ymd(2021, 3, 28).and_hms(2, 30, 0), timezone: "Europe/Paris"],
+            want: Err(r#"2021-03-28 02:30:00 doesn't exist in timezone "Europe/Paris""#),
+            tdef: TypeDef::timestamp().fallible(),
+        }
+
+        unknown_timezone {
+            args: func_args![value: Utc.ymd(2021, 2, 10).and_hms(23, 32, 0), timezone: "Mars/Olympus_Mons"],
+            want: Err("invalid argument"),
+            tdef: TypeDef::timestamp().fallible(),
+        }
+    ];
+}
@@ -0,0 +1,166 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn decode_charset(value: Value, charset: Value, lossy: Value) -> Resolved {
+    let bytes = value.try_bytes()?;
+    let encoding = util::parse_charset(&charset.try_bytes()?)?;
+    let lossy = lossy.try_boolean()?;
+
+    let decoded = if lossy {
+        encoding.decode_without_bom_handling(&bytes).0
+    } else {
+        encoding
+            .decode_without_bom_handling_and_without_replacement(&bytes)
+            .ok_or_else(|| format!("value isn't valid {}", encoding.name()))?
+    };
+
+    Ok(decoded.into_owned().into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeCharset;
+
+impl Function for DecodeCharset {
+    fn identifier(&self) -> &'static str {
+        "decode_charset"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "charset",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "lossy",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "latin1",
+                source: r#"decode_charset!(decode_base64!("Y2Fm6Q=="), "latin1")"#,
+                result: Ok("café"),
+            },
+            Example {
+                title: "lossy",
+                source: r#"decode_charset(decode_base64!("gg=="), "shift_jis", lossy: true)"#,
+                result: Ok("\u{FFFD}"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let charset = arguments.required("charset");
+        let lossy = arguments.optional("lossy").unwrap_or(expr!(false));
+
+        if let Some(literal) = charset.as_value() {
+            let is_valid = literal
+                .as_bytes()
+                .map_or(false, |label| util::parse_charset(label).is_ok());
+            if !is_valid {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "charset",
+                    value: literal,
+                    error: "unknown charset",
+                }
+                .into());
+            }
+        }
+
+        Ok(DecodeCharsetFn {
+            value,
+            charset,
+            lossy,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DecodeCharsetFn {
+    value: Box<dyn Expression>,
+    charset: Box<dyn Expression>,
+    lossy: Box<dyn Expression>,
+}
+
+impl FunctionExpression for DecodeCharsetFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let charset = self.charset.resolve(ctx)?;
+        let lossy = self.lossy.resolve(ctx)?;
+
+        decode_charset(value, charset, lossy)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Only a lossy decode with a literal charset, checked at compile time, can't fail.
+        let infallible = self.charset.as_value().is_some()
+            && matches!(self.lossy.as_value(), Some(Value::Boolean(true)));
+
+        TypeDef::bytes().with_fallibility(!infallible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        decode_charset => DecodeCharset;
+
+        latin1 {
+            args: func_args![value: Bytes::from_static(b"caf\xe9"), charset: "latin1"],
+            want: Ok("café"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        shift_jis {
+            args: func_args![value: Bytes::from_static(b"\x93\xfa\x96\x7b"), charset: "shift_jis"],
+            want: Ok("日本"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        windows_1252 {
+            args: func_args![value: Bytes::from_static(b"\x80 5"), charset: "windows-1252"],
+            want: Ok("€ 5"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        malformed {
+            args: func_args![value: Bytes::from_static(b"\x82"), charset: "shift_jis"],
+            want: Err("value isn't valid Shift_JIS"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        malformed_lossy {
+            args: func_args![value: Bytes::from_static(b"a\xffb"), charset: "utf-8", lossy: true],
+            want: Ok("a\u{FFFD}b"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        unknown_charset {
+            args: func_args![value: "foo", charset: "klingon"],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+    ];
+}
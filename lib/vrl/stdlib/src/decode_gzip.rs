@@ -0,0 +1,118 @@
+use ::value::Value;
+use flate2::read::MultiGzDecoder;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn decode_gzip(value: Value) -> Resolved {
+    let value = value.try_bytes()?;
+    let decoder = MultiGzDecoder::new(value.as_ref());
+
+    let decoded = util::decompress(decoder, "Gzip")?;
+
+    Ok(Value::from(Bytes::from(decoded)))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeGzip;
+
+impl Function for DecodeGzip {
+    fn identifier(&self) -> &'static str {
+        "decode_gzip"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(DecodeGzipFn { value }.as_expr())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "demo string",
+            source: r#"decode_gzip!(decode_base64!("H4sIAAAAAAACAyvISU0sTlVISU3OT0lVyE0FAJsZ870QAAAA"))"#,
+            result: Ok("please decode me"),
+        }]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DecodeGzipFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for DecodeGzipFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        decode_gzip(value)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Always fallible, as there's no way to tell whether the value holds valid Gzip data
+        // before decoding it.
+        TypeDef::bytes().fallible()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::*;
+
+    fn encode(value: &str) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(value.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    test_function![
+        decode_gzip => DecodeGzip;
+
+        valid {
+            args: func_args![value: Bytes::from(encode("some string value"))],
+            want: Ok(value!("some string value")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        concatenated_members {
+            args: func_args![value: Bytes::from([encode("first"), encode(" second")].concat())],
+            want: Ok(value!("first second")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        invalid {
+            args: func_args![value: value!("some string value")],
+            want: Err("unable to decode value with Gzip decoder"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        at_limit {
+            args: func_args![value: Bytes::from(encode(&"0".repeat(1024 * 1024)))],
+            want: Ok("0".repeat(1024 * 1024)),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        too_large {
+            args: func_args![value: Bytes::from(encode(&"0".repeat(1024 * 1024 + 1)))],
+            want: Err("decoded value is larger than the maximum of 1Mi bytes"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+    ];
+}
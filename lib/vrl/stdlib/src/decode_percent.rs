@@ -3,14 +3,43 @@ use percent_encoding::percent_decode;
 use vrl::prelude::expression::FunctionExpression;
 use vrl::prelude::*;
 
-fn decode_percent(value: Value) -> Resolved {
+fn decode_percent(value: Value, strict: Value) -> Resolved {
     let value = value.try_bytes()?;
+    let strict = strict.try_boolean()?;
+
+    if strict {
+        check_sequences(&value)?;
+    }
+
     Ok(percent_decode(&value)
         .decode_utf8_lossy()
         .to_string()
         .into())
 }
 
+/// Checks that every `%` in `bytes` is followed by two hexadecimal digits.
+fn check_sequences(bytes: &[u8]) -> Result<(), String> {
+    let mut iter = bytes.iter().enumerate();
+    while let Some((index, byte)) = iter.next() {
+        if *byte != b'%' {
+            continue;
+        }
+
+        match (iter.next(), iter.next()) {
+            (Some((_, high)), Some((_, low)))
+                if high.is_ascii_hexdigit() && low.is_ascii_hexdigit() => {}
+            _ => {
+                return Err(format!(
+                    "invalid percent-encoded sequence at byte {}",
+                    index
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct DecodePercent;
 
@@ -20,11 +49,18 @@ impl Function for DecodePercent {
     }
 
     fn parameters(&self) -> &'static [Parameter] {
-        &[Parameter {
-            keyword: "value",
-            kind: kind::BYTES,
-            required: true,
-        }]
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "strict",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
     }
 
     fn compile(
@@ -34,33 +70,47 @@ impl Function for DecodePercent {
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
+        let strict = arguments.optional("strict").unwrap_or_else(|| expr!(false));
 
-        Ok(DecodePercentFn { value }.as_expr())
+        Ok(DecodePercentFn { value, strict }.as_expr())
     }
 
     fn examples(&self) -> &'static [Example] {
-        &[Example {
-            title: "percent decode string",
-            source: r#"decode_percent("foo%20bar%3F")"#,
-            result: Ok(r#"foo bar?"#),
-        }]
+        &[
+            Example {
+                title: "percent decode string",
+                source: r#"decode_percent("foo%20bar%3F")"#,
+                result: Ok(r#"foo bar?"#),
+            },
+            Example {
+                title: "strict percent decode",
+                source: r#"decode_percent!("100%", strict: true)"#,
+                result: Err(
+                    r#"function call error for "decode_percent" at (0:37): invalid percent-encoded sequence at byte 3"#,
+                ),
+            },
+        ]
     }
 }
 
 #[derive(Clone, Debug)]
 struct DecodePercentFn {
     value: Box<dyn Expression>,
+    strict: Box<dyn Expression>,
 }
 
 impl FunctionExpression for DecodePercentFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
+        let strict = self.strict.resolve(ctx)?;
 
-        decode_percent(value)
+        decode_percent(value, strict)
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
-        TypeDef::bytes().infallible()
+        let lenient = matches!(self.strict.as_value(), Some(Value::Boolean(false)));
+
+        TypeDef::bytes().with_fallibility(!lenient)
     }
 }
 
@@ -76,5 +126,35 @@ mod test {
             want: Ok(value!(r#"foo #"<>?`{}/:;=@[\]^|$%&+,!'()~bar"#)),
             tdef: TypeDef::bytes().infallible(),
         }
+
+        lenient_malformed {
+            args: func_args![value: "100% %zz %4"],
+            want: Ok(value!("100% %zz %4")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        strict {
+            args: func_args![value: "foo%20bar%3F", strict: true],
+            want: Ok(value!("foo bar?")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        strict_trailing_percent {
+            args: func_args![value: "100%", strict: true],
+            want: Err("invalid percent-encoded sequence at byte 3"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        strict_invalid_hex {
+            args: func_args![value: "foo%zzbar", strict: true],
+            want: Err("invalid percent-encoded sequence at byte 3"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        strict_truncated {
+            args: func_args![value: "foo%2", strict: true],
+            want: Err("invalid percent-encoded sequence at byte 3"),
+            tdef: TypeDef::bytes().fallible(),
+        }
     ];
 }
@@ -0,0 +1,112 @@
+use ::value::Value;
+use flate2::read::ZlibDecoder;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn decode_zlib(value: Value) -> Resolved {
+    let value = value.try_bytes()?;
+    let decoder = ZlibDecoder::new(value.as_ref());
+
+    let decoded = util::decompress(decoder, "Zlib")?;
+
+    Ok(Value::from(Bytes::from(decoded)))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeZlib;
+
+impl Function for DecodeZlib {
+    fn identifier(&self) -> &'static str {
+        "decode_zlib"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(DecodeZlibFn { value }.as_expr())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "demo string",
+            source: r#"decode_zlib!(decode_base64!("eJwryElNLE5VSElNzk9JVchNBQAz9AXx"))"#,
+            result: Ok("please decode me"),
+        }]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DecodeZlibFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for DecodeZlibFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        decode_zlib(value)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Always fallible, as there's no way to tell whether the value holds valid Zlib data
+        // before decoding it.
+        TypeDef::bytes().fallible()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use flate2::{write::ZlibEncoder, Compression};
+
+    use super::*;
+
+    fn encode(value: &str) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(value.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    test_function![
+        decode_zlib => DecodeZlib;
+
+        valid {
+            args: func_args![value: Bytes::from(encode("some string value"))],
+            want: Ok(value!("some string value")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        invalid {
+            args: func_args![value: value!("some string value")],
+            want: Err("unable to decode value with Zlib decoder"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        at_limit {
+            args: func_args![value: Bytes::from(encode(&"0".repeat(1024 * 1024)))],
+            want: Ok("0".repeat(1024 * 1024)),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        too_large {
+            args: func_args![value: Bytes::from(encode(&"0".repeat(1024 * 1024 + 1)))],
+            want: Err("decoded value is larger than the maximum of 1Mi bytes"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+    ];
+}
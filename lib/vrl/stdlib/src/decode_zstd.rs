@@ -0,0 +1,106 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn decode_zstd(value: Value) -> Resolved {
+    let value = value.try_bytes()?;
+    let decoder = zstd::stream::read::Decoder::new(value.as_ref())
+        .map_err(|_| "unable to decode value with Zstandard decoder")?;
+
+    let decoded = util::decompress(decoder, "Zstandard")?;
+
+    Ok(Value::from(Bytes::from(decoded)))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeZstd;
+
+impl Function for DecodeZstd {
+    fn identifier(&self) -> &'static str {
+        "decode_zstd"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(DecodeZstdFn { value }.as_expr())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "demo string",
+            source: r#"decode_zstd!(decode_base64!("KLUv/QBYgQAAcGxlYXNlIGRlY29kZSBtZQ=="))"#,
+            result: Ok("please decode me"),
+        }]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DecodeZstdFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for DecodeZstdFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        decode_zstd(value)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Always fallible, as there's no way to tell whether the value holds valid Zstandard data
+        // before decoding it.
+        TypeDef::bytes().fallible()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encode(value: &str) -> Vec<u8> {
+        zstd::stream::encode_all(value.as_bytes(), zstd::DEFAULT_COMPRESSION_LEVEL).unwrap()
+    }
+
+    test_function![
+        decode_zstd => DecodeZstd;
+
+        valid {
+            args: func_args![value: Bytes::from(encode("some string value"))],
+            want: Ok(value!("some string value")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        invalid {
+            args: func_args![value: value!("some string value")],
+            want: Err("unable to decode value with Zstandard decoder"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        at_limit {
+            args: func_args![value: Bytes::from(encode(&"0".repeat(1024 * 1024)))],
+            want: Ok("0".repeat(1024 * 1024)),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        too_large {
+            args: func_args![value: Bytes::from(encode(&"0".repeat(1024 * 1024 + 1)))],
+            want: Err("decoded value is larger than the maximum of 1Mi bytes"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+    ];
+}
@@ -123,6 +123,8 @@ impl Function for Del {
                 }
                 .into());
             }
+
+            ctx.mark_external_deletion(target_path);
         }
 
         Ok(Box::new(DelFn { query, compact }))
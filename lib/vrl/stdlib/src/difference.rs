@@ -0,0 +1,109 @@
+use ::value::{kind::Collection, Value};
+use vrl::prelude::*;
+
+use crate::util::dedup_values;
+
+fn difference(value: Value, other: Value) -> Resolved {
+    let value = value.try_array()?;
+    let other = other.try_array()?;
+
+    Ok(dedup_values(value.into_iter().filter(|value| !other.contains(value))).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Difference;
+
+impl Function for Difference {
+    fn identifier(&self) -> &'static str {
+        "difference"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "other",
+                kind: kind::ARRAY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "difference",
+            source: r#"difference(["a", "b", "c", "a"], ["b"])"#,
+            result: Ok(r#"["a", "c"]"#),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let other = arguments.required("other");
+
+        Ok(DifferenceFn { value, other }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DifferenceFn {
+    value: Box<dyn Expression>,
+    other: Box<dyn Expression>,
+}
+
+impl FunctionExpression for DifferenceFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let other = self.other.resolve(ctx)?;
+
+        difference(value, other)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let kind = self
+            .value
+            .type_def(state)
+            .restrict_array()
+            .as_array()
+            .expect("must be an array")
+            .reduced_kind();
+
+        TypeDef::array(Collection::from_unknown(kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        difference => Difference;
+
+        strings {
+            args: func_args![value: value!(["a", "b", "c", "a"]), other: value!(["b"])],
+            want: Ok(value!(["a", "c"])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
+        }
+
+        empty_other {
+            args: func_args![value: value!([1, 1, 2]), other: value!([])],
+            want: Ok(value!([1, 2])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())),
+        }
+
+        everything_removed {
+            args: func_args![value: value!([1, 2]), other: value!([2, 1, 3])],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())),
+        }
+    ];
+}
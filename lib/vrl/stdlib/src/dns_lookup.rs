@@ -0,0 +1,201 @@
+use std::{io, net::IpAddr};
+
+use ::dns_lookup::lookup_host;
+use ::value::{kind::Collection, Value};
+use vrl::prelude::*;
+
+use crate::dns_resolution::Resolver;
+
+fn record_types() -> Vec<Value> {
+    vec![value!("A"), value!("AAAA")]
+}
+
+type LookupHost = fn(&str) -> io::Result<Vec<IpAddr>>;
+
+fn dns_lookup(
+    value: Value,
+    record_type: &Bytes,
+    resolver: &Resolver,
+    lookup_host: LookupHost,
+) -> Resolved {
+    let host = value.try_bytes_utf8_lossy()?.into_owned();
+    let ipv6 = match record_type.as_ref() {
+        b"A" => false,
+        b"AAAA" => true,
+        _ => unreachable!("enum invariant"),
+    };
+
+    resolver.resolve(
+        format!("{} {}", String::from_utf8_lossy(record_type), host),
+        move || {
+            let addrs = lookup_host(&host)
+                .map_err(|err| format!("unable to perform a lookup : {}", err))?;
+
+            // The system resolver can return each address once per socket type.
+            let mut found = Vec::new();
+            for addr in addrs {
+                if matches!(addr, IpAddr::V6(_)) == ipv6 && !found.contains(&addr) {
+                    found.push(addr);
+                }
+            }
+
+            Ok(found
+                .into_iter()
+                .map(|addr| Value::from(addr.to_string()))
+                .collect::<Vec<_>>()
+                .into())
+        },
+    )
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DnsLookup;
+
+impl Function for DnsLookup {
+    fn identifier(&self) -> &'static str {
+        "dns_lookup"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "record_type",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "IPv4 addresses",
+                source: r#"dns_lookup!("localhost")"#,
+                result: Ok(r#"["127.0.0.1"]"#),
+            },
+            Example {
+                title: "IPv6 addresses",
+                source: r#"dns_lookup!("dns.google", record_type: "AAAA")"#,
+                result: Ok(r#"["2001:4860:4860::8844", "2001:4860:4860::8888"]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let resolver = Resolver::new(self.identifier(), ctx)?;
+        let value = arguments.required("value");
+        let record_type = arguments
+            .optional_enum("record_type", &record_types())?
+            .unwrap_or_else(|| value!("A"))
+            .try_bytes()
+            .expect("record type not bytes");
+
+        Ok(DnsLookupFn {
+            value,
+            record_type,
+            resolver,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DnsLookupFn {
+    value: Box<dyn Expression>,
+    record_type: Bytes,
+    resolver: Resolver,
+}
+
+impl FunctionExpression for DnsLookupFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        dns_lookup(value, &self.record_type, &self.resolver, lookup_host)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::bytes())).fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vrl::{diagnostic::Span, CompileConfig};
+
+    use super::*;
+
+    fn tdef() -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::bytes())).fallible()
+    }
+
+    fn resolver() -> Resolver {
+        let ctx = FunctionCompileContext::new(Span::default(), CompileConfig::default());
+
+        Resolver::new("dns_lookup", &ctx).unwrap()
+    }
+
+    fn mock_lookup_host(host: &str) -> io::Result<Vec<IpAddr>> {
+        match host {
+            "example.com" => Ok(["127.0.0.1", "::1", "127.0.0.1", "10.0.0.1"]
+                .iter()
+                .map(|addr| addr.parse().unwrap())
+                .collect()),
+            _ => Err(io::Error::new(io::ErrorKind::NotFound, "no such host")),
+        }
+    }
+
+    fn lookup(host: &str, record_type: &str) -> Resolved {
+        dns_lookup(
+            host.into(),
+            &Bytes::from(record_type.to_owned()),
+            &resolver(),
+            mock_lookup_host,
+        )
+    }
+
+    #[test]
+    fn ipv4_addresses() {
+        assert_eq!(
+            lookup("example.com", "A").unwrap(),
+            value!(["127.0.0.1", "10.0.0.1"])
+        );
+    }
+
+    #[test]
+    fn ipv6_addresses() {
+        assert_eq!(lookup("example.com", "AAAA").unwrap(), value!(["::1"]));
+    }
+
+    #[test]
+    fn failed_lookup() {
+        assert_eq!(
+            lookup("unknown.example.com", "A").unwrap_err().to_string(),
+            "unable to perform a lookup : no such host"
+        );
+    }
+
+    test_function![
+        dns_lookup => DnsLookup;
+
+        invalid_record_type {
+            args: func_args![value: value!("localhost"), record_type: "MX"],
+            want: Err("invalid enum variant"),
+            tdef: tdef(),
+        }
+
+        invalid_type {
+            args: func_args![value: value!(1)],
+            want: Err("expected string, got integer"),
+            tdef: tdef(),
+        }
+    ];
+}
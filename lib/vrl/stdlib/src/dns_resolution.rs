@@ -0,0 +1,301 @@
+use std::{
+    fmt,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use ::value::Value;
+use lru::LruCache;
+use once_cell::sync::Lazy;
+use vrl::{
+    diagnostic::{Label, Span},
+    prelude::*,
+};
+
+/// Controls the functions that perform DNS resolution, `dns_lookup` and `reverse_dns`.
+///
+/// Set this as external context (see `CompileConfig::set_custom`) to tune the lookup timeout and
+/// cache, or to disable the functions entirely, in which case programs calling them fail to
+/// compile. Without it, the [`Default`] values are used.
+///
+/// Lookups block the thread resolving the program until they complete or time out. Embedders
+/// running programs on an async runtime should do so where blocking is acceptable, or disable these
+/// functions. Lookups run on a fixed pool of threads shared by all programs. A lookup that times
+/// out keeps its thread busy until the system resolver gives up, but its result is discarded, and
+/// when too many lookups are waiting for a thread new ones fail right away.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DnsConfig {
+    /// Whether programs are allowed to call the DNS functions.
+    pub enabled: bool,
+
+    /// How long to wait for a single lookup.
+    pub timeout: Duration,
+
+    /// The maximum number of results cached by each function call in a program. `0` disables
+    /// caching.
+    pub cache_size: usize,
+
+    /// How long a cached result is used before it's looked up again.
+    pub cache_ttl: Duration,
+}
+
+impl Default for DnsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            timeout: Duration::from_secs(1),
+            cache_size: 1024,
+            cache_ttl: Duration::from_secs(60),
+        }
+    }
+}
+
+/// The number of threads performing lookups.
+const WORKERS: usize = 8;
+
+/// The number of lookups that can wait for a free thread.
+const QUEUE_SIZE: usize = 256;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Hands lookups to the worker threads, which are started on first use.
+static POOL: Lazy<Mutex<mpsc::SyncSender<Job>>> = Lazy::new(|| {
+    let (tx, rx) = mpsc::sync_channel::<Job>(QUEUE_SIZE);
+    let rx = Arc::new(Mutex::new(rx));
+
+    for _ in 0..WORKERS {
+        let rx = Arc::clone(&rx);
+        // If no worker can be started, the receiver is dropped and lookups fail instead.
+        let _ = thread::Builder::new()
+            .name("vrl-dns-lookup".to_owned())
+            .spawn(move || loop {
+                let job = rx.lock().expect("lookup queue lock poisoned").recv();
+                match job {
+                    // A panicking lookup drops its result sender, which its caller reports.
+                    Ok(job) => {
+                        let _ = panic::catch_unwind(AssertUnwindSafe(job));
+                    }
+                    Err(_) => return,
+                }
+            });
+    }
+
+    Mutex::new(tx)
+});
+
+#[derive(Debug)]
+pub(crate) enum Error {
+    Disabled { function: &'static str, span: Span },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Disabled { function, .. } => write!(f, "{} is disabled", function),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl DiagnosticMessage for Error {
+    fn code(&self) -> usize {
+        112
+    }
+
+    fn labels(&self) -> Vec<Label> {
+        match self {
+            Error::Disabled { function, span } => vec![Label::primary(
+                format!("{} is disabled: DNS lookups aren't allowed here", function),
+                *span,
+            )],
+        }
+    }
+}
+
+type Cache = LruCache<String, (Instant, Value)>;
+
+/// Performs lookups with the timeout and cache from the `DnsConfig` a program was compiled with.
+#[derive(Clone)]
+pub(crate) struct Resolver {
+    timeout: Duration,
+    cache_ttl: Duration,
+    cache: Option<Arc<Mutex<Cache>>>,
+}
+
+impl Resolver {
+    pub(crate) fn new(
+        function: &'static str,
+        ctx: &FunctionCompileContext,
+    ) -> std::result::Result<Self, Box<dyn DiagnosticMessage>> {
+        let config = ctx
+            .get_external_context::<DnsConfig>()
+            .copied()
+            .unwrap_or_default();
+
+        if !config.enabled {
+            return Err(Box::new(Error::Disabled {
+                function,
+                span: ctx.span(),
+            }));
+        }
+
+        Ok(Self {
+            timeout: config.timeout,
+            cache_ttl: config.cache_ttl,
+            cache: NonZeroUsize::new(config.cache_size)
+                .map(|size| Arc::new(Mutex::new(LruCache::new(size)))),
+        })
+    }
+
+    /// Returns the cached result for `query`, or runs `lookup` on the lookup threads, waiting at
+    /// most the configured timeout for it. Only successful lookups are cached.
+    pub(crate) fn resolve<F>(&self, query: String, lookup: F) -> Resolved
+    where
+        F: FnOnce() -> std::result::Result<Value, String> + Send + 'static,
+    {
+        if let Some(cache) = &self.cache {
+            let mut cache = cache.lock().expect("cache lock poisoned");
+            match cache.get(&query) {
+                Some((resolved_at, value)) if resolved_at.elapsed() < self.cache_ttl => {
+                    return Ok(value.clone());
+                }
+                Some(_) => {
+                    cache.pop(&query);
+                }
+                None => {}
+            }
+        }
+
+        let (tx, rx) = mpsc::channel();
+        let job: Job = Box::new(move || {
+            // The receiver is gone if the lookup timed out, so there's nobody to tell.
+            let _ = tx.send(lookup());
+        });
+        match POOL
+            .lock()
+            .expect("lookup queue lock poisoned")
+            .try_send(job)
+        {
+            Ok(()) => {}
+            Err(mpsc::TrySendError::Full(_)) => {
+                return Err("unable to perform a lookup: too many lookups in progress".into())
+            }
+            Err(mpsc::TrySendError::Disconnected(_)) => {
+                return Err("unable to perform a lookup: no resolver threads are running".into())
+            }
+        }
+
+        let value = match rx.recv_timeout(self.timeout) {
+            Ok(result) => result?,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                return Err(format!("lookup timed out after {}ms", self.timeout.as_millis()).into())
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                return Err("unable to perform a lookup: resolver panicked".into())
+            }
+        };
+
+        if let Some(cache) = &self.cache {
+            cache
+                .lock()
+                .expect("cache lock poisoned")
+                .put(query, (Instant::now(), value.clone()));
+        }
+
+        Ok(value)
+    }
+}
+
+impl fmt::Debug for Resolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Resolver")
+            .field("timeout", &self.timeout)
+            .field("cache_ttl", &self.cache_ttl)
+            .field("cached", &self.cache.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vrl::CompileConfig;
+
+    use super::*;
+
+    fn resolver(timeout: Duration, cache_size: usize) -> Resolver {
+        let mut config = CompileConfig::default();
+        config.set_custom(DnsConfig {
+            timeout,
+            cache_size,
+            ..Default::default()
+        });
+        let ctx = FunctionCompileContext::new(Span::default(), config);
+
+        Resolver::new("test", &ctx).unwrap()
+    }
+
+    #[test]
+    fn disabled() {
+        let mut config = CompileConfig::default();
+        config.set_custom(DnsConfig {
+            enabled: false,
+            ..Default::default()
+        });
+        let ctx = FunctionCompileContext::new(Span::new(4, 20), config);
+
+        let error = Resolver::new("reverse_dns", &ctx).unwrap_err();
+        assert_eq!(error.code(), 112);
+        assert_eq!(error.to_string(), "reverse_dns is disabled");
+        assert_eq!(error.labels()[0].span, Span::new(4, 20));
+    }
+
+    #[test]
+    fn timeout() {
+        let resolver = resolver(Duration::from_millis(10), 0);
+        let result = resolver.resolve("slow".to_owned(), || {
+            thread::sleep(Duration::from_secs(1));
+            Ok(Value::Null)
+        });
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "lookup timed out after 10ms"
+        );
+    }
+
+    #[test]
+    fn caches_successful_lookups() {
+        let resolver = resolver(Duration::from_secs(1), 16);
+
+        let first = resolver.resolve("query".to_owned(), || Ok("first".into()));
+        let second = resolver.resolve("query".to_owned(), || Ok("second".into()));
+        assert_eq!(first.unwrap(), Value::from("first"));
+        assert_eq!(second.unwrap(), Value::from("first"));
+
+        let failed = resolver.resolve("failing".to_owned(), || Err("failed".to_owned()));
+        let retried = resolver.resolve("failing".to_owned(), || Ok("retried".into()));
+        assert_eq!(failed.unwrap_err().to_string(), "failed");
+        assert_eq!(retried.unwrap(), Value::from("retried"));
+    }
+
+    #[test]
+    fn panicking_lookup() {
+        let resolver = resolver(Duration::from_secs(1), 0);
+
+        let panicked = resolver.resolve("panics".to_owned(), || panic!("lookup failed"));
+        assert_eq!(
+            panicked.unwrap_err().to_string(),
+            "unable to perform a lookup: resolver panicked"
+        );
+
+        // The worker thread survives the panic.
+        for _ in 0..=WORKERS {
+            let result = resolver.resolve("query".to_owned(), || Ok("resolved".into()));
+            assert_eq!(result.unwrap(), Value::from("resolved"));
+        }
+    }
+}
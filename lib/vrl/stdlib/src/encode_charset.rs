@@ -0,0 +1,221 @@
+use ::value::Value;
+use encoding_rs::{Encoder, EncoderResult, Encoding};
+use vrl::prelude::*;
+
+use crate::util;
+
+fn encode_charset(value: Value, charset: Value, lossy: Value) -> Resolved {
+    let bytes = value.try_bytes()?;
+    let encoding = util::parse_charset(&charset.try_bytes()?)?;
+    let lossy = lossy.try_boolean()?;
+
+    let string = if lossy {
+        String::from_utf8_lossy(&bytes)
+    } else {
+        std::str::from_utf8(&bytes)
+            .map_err(|_| "value isn't valid UTF-8")?
+            .into()
+    };
+
+    encode(&string, encoding, lossy)
+        .map(|bytes| Value::from(Bytes::from(bytes)))
+        .map_err(Into::into)
+}
+
+/// Encodes `input` with `encoding`, replacing characters it can't represent with `?` if `lossy`
+/// is set.
+fn encode(mut input: &str, encoding: &'static Encoding, lossy: bool) -> Result<Vec<u8>, String> {
+    if !can_encode(encoding) {
+        return Err(format!("can't encode to {}", encoding.name()));
+    }
+
+    let mut encoder = encoding.new_encoder();
+    let mut output = Vec::new();
+    loop {
+        reserve(&mut output, &encoder, input.len());
+        let (result, read) =
+            encoder.encode_from_utf8_to_vec_without_replacement(input, &mut output, true);
+        input = &input[read..];
+
+        match result {
+            EncoderResult::InputEmpty => return Ok(output),
+            EncoderResult::OutputFull => {}
+            EncoderResult::Unmappable(_) if lossy => {
+                // Going through the encoder keeps stateful encodings, such as ISO-2022-JP, in
+                // the right mode for the replacement.
+                reserve(&mut output, &encoder, 1);
+                encoder.encode_from_utf8_to_vec_without_replacement("?", &mut output, false);
+            }
+            EncoderResult::Unmappable(c) => {
+                return Err(format!("{:?} can't be encoded as {}", c, encoding.name()))
+            }
+        }
+    }
+}
+
+fn reserve(output: &mut Vec<u8>, encoder: &Encoder, len: usize) {
+    output.reserve(
+        encoder
+            .max_buffer_length_from_utf8_without_replacement(len)
+            .unwrap_or(len),
+    );
+}
+
+/// UTF-16 is only supported for decoding, encoding it produces UTF-8.
+fn can_encode(encoding: &'static Encoding) -> bool {
+    encoding.output_encoding() == encoding
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeCharset;
+
+impl Function for EncodeCharset {
+    fn identifier(&self) -> &'static str {
+        "encode_charset"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "charset",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "lossy",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "latin1",
+                source: r#"encode_base64(encode_charset!("café", "latin1"))"#,
+                result: Ok("Y2Fm6Q=="),
+            },
+            Example {
+                title: "lossy",
+                source: r#"encode_charset("snowman ☃", "latin1", lossy: true)"#,
+                result: Ok("snowman ?"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let charset = arguments.required("charset");
+        let lossy = arguments.optional("lossy").unwrap_or(expr!(false));
+
+        if let Some(literal) = charset.as_value() {
+            let error = match literal.as_bytes().map(|label| util::parse_charset(label)) {
+                Some(Ok(encoding)) if can_encode(encoding) => None,
+                Some(Ok(_)) => Some("charset can only be decoded"),
+                _ => Some("unknown charset"),
+            };
+            if let Some(error) = error {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "charset",
+                    value: literal,
+                    error,
+                }
+                .into());
+            }
+        }
+
+        Ok(EncodeCharsetFn {
+            value,
+            charset,
+            lossy,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EncodeCharsetFn {
+    value: Box<dyn Expression>,
+    charset: Box<dyn Expression>,
+    lossy: Box<dyn Expression>,
+}
+
+impl FunctionExpression for EncodeCharsetFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let charset = self.charset.resolve(ctx)?;
+        let lossy = self.lossy.resolve(ctx)?;
+
+        encode_charset(value, charset, lossy)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Only a lossy encode with a literal charset, checked at compile time, can't fail.
+        let infallible = self.charset.as_value().is_some()
+            && matches!(self.lossy.as_value(), Some(Value::Boolean(true)));
+
+        TypeDef::bytes().with_fallibility(!infallible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        encode_charset => EncodeCharset;
+
+        latin1 {
+            args: func_args![value: "café", charset: "latin1"],
+            want: Ok(Bytes::from_static(b"caf\xe9")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        shift_jis {
+            args: func_args![value: "日本", charset: "shift_jis"],
+            want: Ok(Bytes::from_static(b"\x93\xfa\x96\x7b")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        unmappable {
+            args: func_args![value: "日本", charset: "latin1"],
+            want: Err(r#"'日' can't be encoded as windows-1252"#),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        unmappable_lossy {
+            args: func_args![value: "a日b本", charset: "latin1", lossy: true],
+            want: Ok("a?b?"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        invalid_utf8 {
+            args: func_args![value: Bytes::from_static(b"a\xffb"), charset: "latin1"],
+            want: Err("value isn't valid UTF-8"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        decode_only_charset {
+            args: func_args![value: "foo", charset: "utf-16le"],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        unknown_charset {
+            args: func_args![value: "foo", charset: "klingon"],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+    ];
+}
@@ -0,0 +1,160 @@
+use std::io::Read;
+
+use ::value::Value;
+use flate2::{read::GzEncoder, Compression};
+use vrl::prelude::*;
+
+const MAX_COMPRESSION_LEVEL: u32 = 9;
+
+fn get_compression_level(level: i64) -> std::result::Result<Compression, &'static str> {
+    match u32::try_from(level) {
+        Ok(level) if level <= MAX_COMPRESSION_LEVEL => Ok(Compression::new(level)),
+        _ => Err("compression level must be between 0 and 9"),
+    }
+}
+
+fn encode_gzip(value: Value, compression_level: Option<Value>) -> Resolved {
+    let level = match compression_level {
+        Some(level) => get_compression_level(level.try_integer()?)?,
+        None => Compression::default(),
+    };
+    let value = value.try_bytes()?;
+    let mut encoder = GzEncoder::new(value.as_ref(), level);
+    let mut encoded = Vec::new();
+    encoder
+        .read_to_end(&mut encoded)
+        .expect("in-memory compression can't fail");
+
+    Ok(Value::from(Bytes::from(encoded)))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeGzip;
+
+impl Function for EncodeGzip {
+    fn identifier(&self) -> &'static str {
+        "encode_gzip"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "compression_level",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let compression_level = arguments.optional("compression_level");
+
+        if let Some(literal) = compression_level
+            .as_ref()
+            .and_then(|level| level.as_value())
+        {
+            let level = literal
+                .clone()
+                .try_integer()
+                .expect("compression level must be an integer");
+            let _ = get_compression_level(level).map_err(|err| {
+                vrl::function::Error::InvalidArgument {
+                    keyword: "compression_level",
+                    value: literal,
+                    error: err,
+                }
+            })?;
+        }
+
+        Ok(EncodeGzipFn {
+            value,
+            compression_level,
+        }
+        .as_expr())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "demo string",
+            source: r#"encode_base64(encode_gzip("please encode me"))"#,
+            result: Ok("H4sIAAAAAAAA/yvISU0sTlVIzUvOT0lVyE0FAI4R4vcQAAAA"),
+        }]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct EncodeGzipFn {
+    value: Box<dyn Expression>,
+    compression_level: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for EncodeGzipFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let compression_level = self
+            .compression_level
+            .as_ref()
+            .map(|level| level.resolve(ctx))
+            .transpose()?;
+
+        encode_gzip(value, compression_level)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Literal compression levels are checked at compile time.
+        let fallible = self
+            .compression_level
+            .as_ref()
+            .map_or(false, |level| level.as_value().is_none());
+
+        TypeDef::bytes().with_fallibility(fallible)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+
+    use super::*;
+
+    fn encode(value: &str, level: Compression) -> Bytes {
+        let mut encoder = GzEncoder::new(Vec::new(), level);
+        encoder.write_all(value.as_bytes()).unwrap();
+        encoder.finish().unwrap().into()
+    }
+
+    test_function![
+        encode_gzip => EncodeGzip;
+
+        with_defaults {
+            args: func_args![value: value!("please encode me")],
+            want: Ok(Bytes::from_static(b"\x1f\x8b\x08\x00\x00\x00\x00\x00\x00\xff+\xc8IM,NUH\xcdK\xceOIU\xc8M\x05\x00\x8e\x11\xe2\xf7\x10\x00\x00\x00")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        with_compression_level {
+            args: func_args![value: value!("please encode me"), compression_level: 9],
+            want: Ok(encode("please encode me", Compression::best())),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        invalid_compression_level {
+            args: func_args![value: value!("please encode me"), compression_level: 10],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+}
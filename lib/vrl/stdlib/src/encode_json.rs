@@ -2,9 +2,15 @@ use ::value::Value;
 use vrl::prelude::expression::FunctionExpression;
 use vrl::prelude::*;
 
-fn encode_json(value: Value) -> Resolved {
+fn encode_json(value: Value, pretty: bool) -> Resolved {
+    let encoded = if pretty {
+        serde_json::to_string_pretty(&value)
+    } else {
+        serde_json::to_string(&value)
+    };
+
     // With `vrl::Value` it should not be possible to get `Err`.
-    match serde_json::to_string(&value) {
+    match encoded {
         Ok(value) => Ok(value.into()),
         Err(error) => unreachable!("unable encode to json: {}", error),
     }
@@ -19,11 +25,18 @@ impl Function for EncodeJson {
     }
 
     fn parameters(&self) -> &'static [Parameter] {
-        &[Parameter {
-            keyword: "value",
-            kind: kind::ANY,
-            required: true,
-        }]
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "pretty",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
     }
 
     fn compile(
@@ -33,28 +46,39 @@ impl Function for EncodeJson {
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
+        let pretty = arguments.optional("pretty").unwrap_or(expr!(false));
 
-        Ok(EncodeJsonFn { value }.as_expr())
+        Ok(EncodeJsonFn { value, pretty }.as_expr())
     }
 
     fn examples(&self) -> &'static [Example] {
-        &[Example {
-            title: "encode object",
-            source: r#"encode_json({"field": "value", "another": [1,2,3]})"#,
-            result: Ok(r#"s'{"another":[1,2,3],"field":"value"}'"#),
-        }]
+        &[
+            Example {
+                title: "encode object",
+                source: r#"encode_json({"field": "value", "another": [1,2,3]})"#,
+                result: Ok(r#"s'{"another":[1,2,3],"field":"value"}'"#),
+            },
+            Example {
+                title: "encode object pretty",
+                source: r#"encode_json({"field": "value"}, pretty: true)"#,
+                result: Ok(r#""{\n  \"field\": \"value\"\n}""#),
+            },
+        ]
     }
 }
 
 #[derive(Clone, Debug)]
 struct EncodeJsonFn {
     value: Box<dyn Expression>,
+    pretty: Box<dyn Expression>,
 }
 
 impl FunctionExpression for EncodeJsonFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
-        encode_json(value)
+        let pretty = self.pretty.resolve(ctx)?.try_boolean()?;
+
+        encode_json(value, pretty)
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -129,5 +153,17 @@ mod tests {
             want: Ok("null"),
             tdef: TypeDef::bytes().infallible(),
         }
+
+        pretty {
+            args: func_args![value: value!({ "b": [1, 2], "a": { "c": null } }), pretty: true],
+            want: Ok("{\n  \"a\": {\n    \"c\": null\n  },\n  \"b\": [\n    1,\n    2\n  ]\n}"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        pretty_scalar {
+            args: func_args![value: 42, pretty: true],
+            want: Ok("42"),
+            tdef: TypeDef::bytes().infallible(),
+        }
     ];
 }
@@ -21,7 +21,7 @@ pub(crate) fn encode_key_value(
     let key_value_delimiter = key_value_delimiter.try_bytes_utf8_lossy()?;
     let field_delimiter = field_delimiter.try_bytes_utf8_lossy()?;
     let flatten_boolean = flatten_boolean.try_boolean()?;
-    Ok(encode_key_value::to_string(
+    Ok(encode_key_value::to_string_unambiguous(
         &object,
         &fields[..],
         &key_value_delimiter,
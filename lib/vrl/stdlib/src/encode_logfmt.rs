@@ -1,7 +1,32 @@
+use ::value::Value;
 use vrl::prelude::expression::FunctionExpression;
 use vrl::prelude::*;
 
-use crate::encode_key_value::EncodeKeyValueFn;
+use crate::encode_key_value::encode_key_value;
+
+fn encode_logfmt(fields: Option<Value>, value: Value, flatten: Value) -> Resolved {
+    if !flatten.try_boolean()? {
+        let object = value.as_object().ok_or_else(|| value::Error::Expected {
+            got: value.kind(),
+            expected: Kind::object(Collection::any()),
+        })?;
+        if let Some((key, nested)) = object
+            .iter()
+            .find(|(_, value)| value.is_object() || value.is_array())
+        {
+            return Err(format!(
+                r#"field "{}" is an {}, which can't be encoded without flattening"#,
+                key,
+                nested.kind_str()
+            )
+            .into());
+        }
+    }
+
+    // logfmt is key-value encoding with fixed delimiters, where `true` booleans are written as a
+    // bare key.
+    encode_key_value(fields, value, value!("="), value!(" "), value!(true))
+}
 
 #[derive(Clone, Copy, Debug)]
 pub struct EncodeLogfmt;
@@ -23,6 +48,11 @@ impl Function for EncodeLogfmt {
                 kind: kind::ARRAY,
                 required: false,
             },
+            Parameter {
+                keyword: "flatten",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
         ]
     }
 
@@ -32,21 +62,14 @@ impl Function for EncodeLogfmt {
         _ctx: &mut FunctionCompileContext,
         arguments: ArgumentList,
     ) -> Compiled {
-        // The encode_logfmt function is just an alias for `encode_key_value` with the following
-        // parameters for the delimiters.
-        let key_value_delimiter = expr!("=");
-        let field_delimiter = expr!(" ");
-        let flatten_boolean = expr!(true);
-
         let value = arguments.required("value");
         let fields = arguments.optional("fields_ordering");
+        let flatten = arguments.optional("flatten").unwrap_or_else(|| expr!(true));
 
-        Ok(EncodeKeyValueFn {
+        Ok(EncodeLogfmtFn {
             value,
             fields,
-            key_value_delimiter,
-            field_delimiter,
-            flatten_boolean,
+            flatten,
         }
         .as_expr())
     }
@@ -63,6 +86,104 @@ impl Function for EncodeLogfmt {
                 source: r#"encode_logfmt!({"msg": "This is a message", "lvl": "info", "log_id": 12345}, ["lvl", "msg"])"#,
                 result: Ok(r#"s'lvl=info msg="This is a message" log_id=12345'"#),
             },
+            Example {
+                title: "reject nested fields",
+                source: r#"encode_logfmt({"agent": {"name": "vector"}}, flatten: false) ?? "nested""#,
+                result: Ok(r#"nested"#),
+            },
         ]
     }
 }
+
+#[derive(Clone, Debug)]
+struct EncodeLogfmtFn {
+    value: Box<dyn Expression>,
+    fields: Option<Box<dyn Expression>>,
+    flatten: Box<dyn Expression>,
+}
+
+impl FunctionExpression for EncodeLogfmtFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let fields = self
+            .fields
+            .as_ref()
+            .map(|expr| expr.resolve(ctx))
+            .transpose()?;
+        let flatten = self.flatten.resolve(ctx)?;
+
+        encode_logfmt(fields, value, flatten)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        let flattens = matches!(self.flatten.as_value(), Some(Value::Boolean(true)));
+
+        TypeDef::bytes().with_fallibility(self.fields.is_some() || !flattens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vector_common::btreemap;
+
+    use super::*;
+
+    test_function![
+        encode_logfmt => EncodeLogfmt;
+
+        flat {
+            args: func_args![value: btreemap! {
+                "lvl" => "info",
+                "msg" => "This is a log message",
+                "beta" => true,
+                "prod" => false,
+            }],
+            want: Ok(r#"beta lvl=info msg="This is a log message""#),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        special_characters {
+            args: func_args![value: btreemap! {
+                "empty" => "",
+                "query" => "a=b",
+                "msg" => r#"said "hi""#,
+            }],
+            want: Ok(r#"empty="" msg="said \"hi\"" query="a=b""#),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        non_string_values {
+            args: func_args![value: btreemap! {
+                "int" => 1,
+                "float" => 1.5,
+                "null" => Value::Null,
+            }],
+            want: Ok("float=1.5 int=1 null=null"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        flattened {
+            args: func_args![value: btreemap! {
+                "agent" => btreemap! { "name" => "vector" },
+                "ip" => value!([127, 0, 0, 1]),
+            }],
+            want: Ok("agent.name=vector ip.0=127 ip.1=0 ip.2=0 ip.3=1"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        not_flattened {
+            args: func_args![
+                value: btreemap! { "agent" => btreemap! { "name" => "vector" } },
+                flatten: false,
+            ],
+            want: Err(r#"field "agent" is an object, which can't be encoded without flattening"#),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        not_flattened_flat {
+            args: func_args![value: btreemap! { "lvl" => "info" }, flatten: false],
+            want: Ok("lvl=info"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+    ];
+}
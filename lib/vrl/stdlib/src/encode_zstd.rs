@@ -0,0 +1,151 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+const MIN_COMPRESSION_LEVEL: i32 = 1;
+const MAX_COMPRESSION_LEVEL: i32 = 22;
+
+fn get_compression_level(level: i64) -> std::result::Result<i32, &'static str> {
+    match i32::try_from(level) {
+        Ok(level) if (MIN_COMPRESSION_LEVEL..=MAX_COMPRESSION_LEVEL).contains(&level) => Ok(level),
+        _ => Err("compression level must be between 1 and 22"),
+    }
+}
+
+fn encode_zstd(value: Value, compression_level: Option<Value>) -> Resolved {
+    let level = match compression_level {
+        Some(level) => get_compression_level(level.try_integer()?)?,
+        None => zstd::DEFAULT_COMPRESSION_LEVEL,
+    };
+    let value = value.try_bytes()?;
+    let encoded =
+        zstd::stream::encode_all(value.as_ref(), level).expect("in-memory compression can't fail");
+
+    Ok(Value::from(Bytes::from(encoded)))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeZstd;
+
+impl Function for EncodeZstd {
+    fn identifier(&self) -> &'static str {
+        "encode_zstd"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "compression_level",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let compression_level = arguments.optional("compression_level");
+
+        if let Some(literal) = compression_level
+            .as_ref()
+            .and_then(|level| level.as_value())
+        {
+            let level = literal
+                .clone()
+                .try_integer()
+                .expect("compression level must be an integer");
+            let _ = get_compression_level(level).map_err(|err| {
+                vrl::function::Error::InvalidArgument {
+                    keyword: "compression_level",
+                    value: literal,
+                    error: err,
+                }
+            })?;
+        }
+
+        Ok(EncodeZstdFn {
+            value,
+            compression_level,
+        }
+        .as_expr())
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "demo string",
+            source: r#"encode_base64(encode_zstd("please encode me"))"#,
+            result: Ok("KLUv/QBYgQAAcGxlYXNlIGVuY29kZSBtZQ=="),
+        }]
+    }
+}
+
+#[derive(Clone, Debug)]
+struct EncodeZstdFn {
+    value: Box<dyn Expression>,
+    compression_level: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for EncodeZstdFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let compression_level = self
+            .compression_level
+            .as_ref()
+            .map(|level| level.resolve(ctx))
+            .transpose()?;
+
+        encode_zstd(value, compression_level)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Literal compression levels are checked at compile time.
+        let fallible = self
+            .compression_level
+            .as_ref()
+            .map_or(false, |level| level.as_value().is_none());
+
+        TypeDef::bytes().with_fallibility(fallible)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn encode(value: &str, level: i32) -> Bytes {
+        zstd::stream::encode_all(value.as_bytes(), level)
+            .unwrap()
+            .into()
+    }
+
+    test_function![
+        encode_zstd => EncodeZstd;
+
+        with_defaults {
+            args: func_args![value: value!("please encode me")],
+            want: Ok(Bytes::from_static(b"(\xb5/\xfd\x00X\x81\x00\x00please encode me")),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        with_compression_level {
+            args: func_args![value: value!("please encode me"), compression_level: 19],
+            want: Ok(encode("please encode me", 19)),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        invalid_compression_level {
+            args: func_args![value: value!("please encode me"), compression_level: 0],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+}
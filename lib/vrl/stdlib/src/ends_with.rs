@@ -1,26 +1,32 @@
+use std::borrow::Cow;
+
 use ::value::Value;
 use vrl::prelude::expression::FunctionExpression;
 use vrl::prelude::*;
 
+fn normalize(string: Cow<'_, str>, case_sensitive: bool) -> String {
+    match case_sensitive {
+        true => string.into_owned(),
+        false => string.to_lowercase(),
+    }
+}
+
 fn ends_with(value: Value, substring: Value, case_sensitive: bool) -> Resolved {
-    let substring = {
-        let bytes = substring.try_bytes()?;
-        let string = String::from_utf8_lossy(&bytes);
+    let value = normalize(value.try_bytes_utf8_lossy()?, case_sensitive);
 
-        match case_sensitive {
-            true => string.into_owned(),
-            false => string.to_lowercase(),
-        }
+    // Either a single suffix, or an array of candidate suffixes.
+    let candidates = match substring {
+        Value::Array(candidates) => candidates,
+        substring => vec![substring],
     };
-    let value = {
-        let string = value.try_bytes_utf8_lossy()?;
-
-        match case_sensitive {
-            true => string.into_owned(),
-            false => string.to_lowercase(),
+    for candidate in candidates {
+        let bytes = candidate.try_bytes()?;
+        if value.ends_with(&normalize(String::from_utf8_lossy(&bytes), case_sensitive)) {
+            return Ok(true.into());
         }
-    };
-    Ok(value.ends_with(&substring).into())
+    }
+
+    Ok(false.into())
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -40,7 +46,7 @@ impl Function for EndsWith {
             },
             Parameter {
                 keyword: "substring",
-                kind: kind::BYTES,
+                kind: kind::BYTES | kind::ARRAY,
                 required: true,
             },
             Parameter {
@@ -86,6 +92,11 @@ impl Function for EndsWith {
                 source: r#"ends_with("foobar", "foo")"#,
                 result: Ok("false"),
             },
+            Example {
+                title: "any of multiple suffixes",
+                source: r#"ends_with("foobar", ["foo", "bar"])"#,
+                result: Ok("true"),
+            },
         ]
     }
 }
@@ -107,8 +118,15 @@ impl FunctionExpression for EndsWithFn {
         ends_with(value, substring, case_sensitive)
     }
 
-    fn type_def(&self, _: &state::TypeState) -> TypeDef {
-        TypeDef::boolean().infallible()
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        // Candidate suffixes given as an array fail at runtime if any of them isn't a string.
+        let fallible = self
+            .substring
+            .type_def(state)
+            .as_array()
+            .map_or(false, |array| !array.reduced_kind().is_bytes());
+
+        TypeDef::boolean().with_fallibility(fallible)
     }
 }
 
@@ -177,5 +195,34 @@ mod tests {
             want: Ok(value!(true)),
             tdef: TypeDef::boolean().infallible(),
         }
+
+        array_match {
+            args: func_args![value: "foobar",
+                             substring: value!(["baz", "bar"])],
+            want: Ok(value!(true)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        array_no_match {
+            args: func_args![value: "foobar",
+                             substring: value!(["baz", "foo"])],
+            want: Ok(value!(false)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        array_case_insensitive {
+            args: func_args![value: "foobar",
+                             substring: value!(["baz", "BAR"]),
+                             case_sensitive: false],
+            want: Ok(value!(true)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        array_not_string {
+            args: func_args![value: "foobar",
+                             substring: value!([1, "bar"])],
+            want: Err("expected string, got integer"),
+            tdef: TypeDef::boolean().fallible(),
+        }
     ];
 }
@@ -0,0 +1,86 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn enumerate(value: Value) -> Resolved {
+    Ok(value
+        .try_array()?
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| Value::Array(vec![Value::Integer(index as i64), value]))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Enumerate;
+
+impl Function for Enumerate {
+    fn identifier(&self) -> &'static str {
+        "enumerate"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "enumerate",
+            source: r#"enumerate(["a", "b"])"#,
+            result: Ok(r#"[[0, "a"], [1, "b"]]"#),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(EnumerateFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EnumerateFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for EnumerateFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        enumerate(value)
+    }
+
+    fn type_def(&self, _state: &state::TypeState) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::array(Collection::any())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        enumerate => Enumerate;
+
+        values {
+            args: func_args![value: value!(["a", true, null])],
+            want: Ok(value!([[0, "a"], [1, true], [2, null]])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))),
+        }
+
+        empty {
+            args: func_args![value: value!([])],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))),
+        }
+    ];
+}
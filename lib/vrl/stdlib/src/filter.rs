@@ -8,34 +8,34 @@ where
     match value {
         Value::Object(object) => object
             .into_iter()
-            .filter_map(
-                |(key, value)| match runner.run_key_value(ctx, &key, &value) {
-                    Ok(v) => v
-                        .as_boolean()
-                        .expect("compiler guarantees boolean return type")
-                        .then_some(Ok((key, value))),
+            .filter_map(|(key, value)| {
+                match runner
+                    .run_key_value(ctx, &key, &value)
+                    .and_then(|keep| keep.try_boolean().map_err(Into::into))
+                {
+                    Ok(keep) => keep.then_some(Ok((key, value))),
                     Err(err) => Some(Err(err)),
-                },
-            )
+                }
+            })
             .collect::<Result<BTreeMap<_, _>>>()
             .map(Into::into),
 
         Value::Array(array) => array
             .into_iter()
             .enumerate()
-            .filter_map(
-                |(index, value)| match runner.run_index_value(ctx, index, &value) {
-                    Ok(v) => v
-                        .as_boolean()
-                        .expect("compiler guarantees boolean return type")
-                        .then_some(Ok(value)),
+            .filter_map(|(index, value)| {
+                match runner
+                    .run_index_value(ctx, index, &value)
+                    .and_then(|keep| keep.try_boolean().map_err(Into::into))
+                {
+                    Ok(keep) => keep.then_some(Ok(value)),
                     Err(err) => Some(Err(err)),
-                },
-            )
+                }
+            })
             .collect::<Result<Vec<_>>>()
             .map(Into::into),
 
-        _ => unreachable!("function requires collection types as input"),
+        value => Err(format!("expected array or object, got {}", value.kind_str()).into()),
     }
 }
 
@@ -105,6 +105,7 @@ impl Function for Filter {
                 },
             }],
             is_iterator: true,
+            is_optional: false,
         })
     }
 }
@@ -0,0 +1,138 @@
+use ::value::Value;
+use regex::Regex;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn find_all(value: Value, pattern: &Regex, group: usize) -> Resolved {
+    let bytes = value.try_bytes()?;
+    let value = String::from_utf8_lossy(&bytes);
+
+    Ok(pattern
+        .captures_iter(&value)
+        .filter_map(|captures| captures.get(group))
+        .map(|found| Value::from(found.as_str()))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FindAll;
+
+impl Function for FindAll {
+    fn identifier(&self) -> &'static str {
+        "find_all"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "pattern",
+                kind: kind::REGEX,
+                required: true,
+            },
+            Parameter {
+                keyword: "group",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "all matches",
+                source: r#"find_all("a1 b22 c333", r'\d+')"#,
+                result: Ok(r#"["1", "22", "333"]"#),
+            },
+            Example {
+                title: "capture group",
+                source: r#"find_all("a=1 b=2", r'(\w)=(\d)', group: 1)"#,
+                result: Ok(r#"["a", "b"]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let pattern = arguments.required_regex("pattern")?;
+        let group = util::regex_capture_group(&arguments, &pattern)?;
+
+        Ok(FindAllFn {
+            value,
+            pattern,
+            group,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FindAllFn {
+    value: Box<dyn Expression>,
+    pattern: Regex,
+    group: usize,
+}
+
+impl FunctionExpression for FindAllFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        find_all(value, &self.pattern, self.group)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::bytes()))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::trivial_regex)]
+mod tests {
+    use super::*;
+
+    test_function![
+        find_all => FindAll;
+
+        matches {
+            args: func_args![value: "a1 b22 c333", pattern: Regex::new(r"\d+").unwrap()],
+            want: Ok(value!(["1", "22", "333"])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
+        }
+
+        no_matches {
+            args: func_args![value: "abc", pattern: Regex::new(r"\d+").unwrap()],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
+        }
+
+        group {
+            args: func_args![value: "a=1 b=2", pattern: Regex::new(r"(\w)=(\d)").unwrap(), group: 2],
+            want: Ok(value!(["1", "2"])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
+        }
+
+        optional_group {
+            args: func_args![value: "a=1 b", pattern: Regex::new(r"(\w)(=\d)?").unwrap(), group: 2],
+            want: Ok(value!(["=1"])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
+        }
+
+        invalid_group {
+            args: func_args![value: "a=1", pattern: Regex::new(r"(\w)=(\d)").unwrap(), group: 3],
+            want: Err("invalid argument"),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
+        }
+    ];
+}
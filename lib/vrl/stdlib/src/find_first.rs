@@ -0,0 +1,135 @@
+use ::value::Value;
+use regex::Regex;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn find_first(value: Value, pattern: &Regex, group: usize) -> Resolved {
+    let bytes = value.try_bytes()?;
+    let value = String::from_utf8_lossy(&bytes);
+
+    Ok(pattern
+        .captures(&value)
+        .and_then(|captures| captures.get(group))
+        .map_or(Value::Null, |found| found.as_str().into()))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FindFirst;
+
+impl Function for FindFirst {
+    fn identifier(&self) -> &'static str {
+        "find_first"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "pattern",
+                kind: kind::REGEX,
+                required: true,
+            },
+            Parameter {
+                keyword: "group",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "first match",
+                source: r#"find_first("a1 b22 c333", r'\d+')"#,
+                result: Ok(r#""1""#),
+            },
+            Example {
+                title: "capture group",
+                source: r#"find_first("a=1 b=2", r'(\w)=(\d)', group: 2)"#,
+                result: Ok(r#""1""#),
+            },
+            Example {
+                title: "no match",
+                source: r#"find_first("abc", r'\d+')"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let pattern = arguments.required_regex("pattern")?;
+        let group = util::regex_capture_group(&arguments, &pattern)?;
+
+        Ok(FindFirstFn {
+            value,
+            pattern,
+            group,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FindFirstFn {
+    value: Box<dyn Expression>,
+    pattern: Regex,
+    group: usize,
+}
+
+impl FunctionExpression for FindFirstFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        find_first(value, &self.pattern, self.group)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::bytes().or_null()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::trivial_regex)]
+mod tests {
+    use super::*;
+
+    test_function![
+        find_first => FindFirst;
+
+        matches {
+            args: func_args![value: "a1 b22 c333", pattern: Regex::new(r"\d+").unwrap()],
+            want: Ok("1"),
+            tdef: TypeDef::bytes().or_null(),
+        }
+
+        no_match {
+            args: func_args![value: "abc", pattern: Regex::new(r"\d+").unwrap()],
+            want: Ok(Value::Null),
+            tdef: TypeDef::bytes().or_null(),
+        }
+
+        group {
+            args: func_args![value: "a=1 b=2", pattern: Regex::new(r"(\w)=(\d)").unwrap(), group: 1],
+            want: Ok("a"),
+            tdef: TypeDef::bytes().or_null(),
+        }
+
+        invalid_group {
+            args: func_args![value: "a=1", pattern: Regex::new(r"(\w)=(\d)").unwrap(), group: -1],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().or_null(),
+        }
+    ];
+}
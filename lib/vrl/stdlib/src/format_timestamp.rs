@@ -1,16 +1,24 @@
 use ::value::Value;
 use chrono::{
     format::{strftime::StrftimeItems, Item},
-    DateTime, Utc,
+    DateTime, TimeZone,
 };
 use vrl::prelude::*;
 
-fn format_timestamp(bytes: Value, ts: Value) -> Resolved {
+use crate::util;
+
+fn format_timestamp(bytes: Value, ts: Value, timezone: Option<Value>) -> Resolved {
     let bytes = bytes.try_bytes()?;
     let format = String::from_utf8_lossy(&bytes);
     let ts = ts.try_timestamp()?;
 
-    try_format(&ts, &format).map(Into::into)
+    match timezone {
+        Some(timezone) => {
+            let tz = util::parse_timezone(&timezone.try_bytes()?)?;
+            try_format(&ts.with_timezone(&tz), &format).map(Into::into)
+        }
+        None => try_format(&ts, &format).map(Into::into),
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -33,27 +41,67 @@ impl Function for FormatTimestamp {
                 kind: kind::BYTES,
                 required: true,
             },
+            Parameter {
+                keyword: "timezone",
+                kind: kind::BYTES,
+                required: false,
+            },
         ]
     }
 
     fn compile(
         &self,
         _state: &state::TypeState,
-        _ctx: &mut FunctionCompileContext,
+        ctx: &mut FunctionCompileContext,
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
         let format = arguments.required("format");
+        let timezone = arguments.optional("timezone");
+
+        if let Some(format) = format.as_value() {
+            if let Some(format) = format.as_bytes() {
+                let span = arguments.span("format").unwrap_or_else(|| ctx.span());
+                util::validate_strftime(&String::from_utf8_lossy(format), span)
+                    .map_err(|error| Box::new(error) as Box<dyn DiagnosticMessage>)?;
+            }
+        }
 
-        Ok(FormatTimestampFn { value, format }.as_expr())
+        if let Some(literal) = timezone.as_ref().and_then(|timezone| timezone.as_value()) {
+            let is_valid = literal
+                .as_bytes()
+                .map_or(false, |name| util::parse_timezone(name).is_ok());
+            if !is_valid {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "timezone",
+                    value: literal,
+                    error: "unknown timezone",
+                }
+                .into());
+            }
+        }
+
+        Ok(FormatTimestampFn {
+            value,
+            format,
+            timezone,
+        }
+        .as_expr())
     }
 
     fn examples(&self) -> &'static [Example] {
-        &[Example {
-            title: "format timestamp",
-            source: r#"format_timestamp!(t'2021-02-10T23:32:00+00:00', "%d %B %Y %H:%M")"#,
-            result: Ok("10 February 2021 23:32"),
-        }]
+        &[
+            Example {
+                title: "format timestamp",
+                source: r#"format_timestamp!(t'2021-02-10T23:32:00+00:00', "%d %B %Y %H:%M")"#,
+                result: Ok("10 February 2021 23:32"),
+            },
+            Example {
+                title: "format timestamp in timezone",
+                source: r#"format_timestamp!(t'2021-02-10T23:32:00+00:00', "%d %B %Y %H:%M %:z", timezone: "Asia/Tokyo")"#,
+                result: Ok("11 February 2021 08:32 +09:00"),
+            },
+        ]
     }
 }
 
@@ -61,14 +109,20 @@ impl Function for FormatTimestamp {
 struct FormatTimestampFn {
     value: Box<dyn Expression>,
     format: Box<dyn Expression>,
+    timezone: Option<Box<dyn Expression>>,
 }
 
 impl FunctionExpression for FormatTimestampFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let bytes = self.format.resolve(ctx)?;
         let ts = self.value.resolve(ctx)?;
+        let timezone = self
+            .timezone
+            .as_ref()
+            .map(|timezone| timezone.resolve(ctx))
+            .transpose()?;
 
-        format_timestamp(bytes, ts)
+        format_timestamp(bytes, ts, timezone)
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -76,7 +130,11 @@ impl FunctionExpression for FormatTimestampFn {
     }
 }
 
-fn try_format(dt: &DateTime<Utc>, format: &str) -> Result<String> {
+fn try_format<T>(dt: &DateTime<T>, format: &str) -> Result<String>
+where
+    T: TimeZone,
+    T::Offset: std::fmt::Display,
+{
     let items = StrftimeItems::new(format)
         .map(|item| match item {
             Item::Error => Err("invalid format".into()),
@@ -89,7 +147,7 @@ fn try_format(dt: &DateTime<Utc>, format: &str) -> Result<String> {
 
 #[cfg(test)]
 mod tests {
-    use chrono::TimeZone;
+    use chrono::Utc;
 
     use super::*;
 
@@ -99,7 +157,21 @@ mod tests {
         invalid {
             args: func_args![value: Utc.timestamp(10, 0),
                              format: "%Q INVALID"],
-            want: Err("invalid format"),
+            want: Err(r#"invalid strftime directive "%Q" at offset 0"#),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        invalid_modifier {
+            args: func_args![value: Utc.timestamp(10, 0),
+                             format: "%Y-%m-%d %%Q %-5"],
+            want: Err(r#"invalid strftime directive "%-5" at offset 13"#),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        escaped_percent {
+            args: func_args![value: Utc.timestamp(10, 0),
+                             format: "100%% at %s"],
+            want: Ok(value!("100% at 10")),
             tdef: TypeDef::bytes().fallible(),
         }
 
@@ -116,5 +188,21 @@ mod tests {
             want: Ok(value!("1970-01-01T00:00:10+00:00")),
             tdef: TypeDef::bytes().fallible(),
         }
+
+        timezone {
+            args: func_args![value: Utc.timestamp(10, 0),
+                             format: "%+",
+                             timezone: "America/New_York"],
+            want: Ok(value!("1969-12-31T19:00:10-05:00")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        unknown_timezone {
+            args: func_args![value: Utc.timestamp(10, 0),
+                             format: "%+",
+                             timezone: "Mars/Olympus_Mons"],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().fallible(),
+        }
     ];
 }
@@ -0,0 +1,103 @@
+use ::value::Value;
+use unicode_segmentation::UnicodeSegmentation;
+use vrl::prelude::*;
+
+fn grapheme_length(value: Value) -> Resolved {
+    let v = value.try_bytes()?;
+
+    Ok(String::from_utf8_lossy(&v).graphemes(true).count().into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GraphemeLength;
+
+impl Function for GraphemeLength {
+    fn identifier(&self) -> &'static str {
+        "grapheme_length"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Graphemes",
+                source: r#"grapheme_length("ñandú")"#,
+                result: Ok("5"),
+            },
+            Example {
+                title: "Emoji with modifier",
+                source: r#"grapheme_length("👍🏽")"#,
+                result: Ok("1"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(GraphemeLengthFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GraphemeLengthFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for GraphemeLengthFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        grapheme_length(value)
+    }
+
+    fn type_def(&self, _state: &state::TypeState) -> TypeDef {
+        TypeDef::integer().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        grapheme_length => GraphemeLength;
+
+        ascii {
+            args: func_args![value: value!("foobar")],
+            want: Ok(value!(6)),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        combining_mark {
+            // "e" followed by a combining acute accent.
+            args: func_args![value: value!("cafe\u{301}")],
+            want: Ok(value!(4)),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        emoji_with_modifier {
+            args: func_args![value: value!("👍🏽👍")],
+            want: Ok(value!(2)),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        emoji_zwj_sequence {
+            args: func_args![value: value!("👩‍👩‍👧")],
+            want: Ok(value!(1)),
+            tdef: TypeDef::integer().infallible(),
+        }
+    ];
+}
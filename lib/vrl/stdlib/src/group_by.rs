@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+
+use ::value::{kind::Collection, Value};
+use vrl::prelude::*;
+
+fn group_by<T>(value: Value, ctx: &mut Context, runner: closure::Runner<T>) -> Resolved
+where
+    T: Fn(&mut Context) -> Resolved,
+{
+    let array = value.try_array()?;
+    let mut groups: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+
+    for (index, value) in array.into_iter().enumerate() {
+        let key = match runner.run_index_value(ctx, index, &value)? {
+            Value::Bytes(key) => String::from_utf8_lossy(&key).into_owned(),
+            key => {
+                return Err(format!(
+                    "group key must be a string, got {} for element at index {}",
+                    key.kind_str(),
+                    index
+                )
+                .into())
+            }
+        };
+
+        groups.entry(key).or_default().push(value);
+    }
+
+    Ok(groups
+        .into_iter()
+        .map(|(key, values)| (key, Value::Array(values)))
+        .collect::<BTreeMap<_, _>>()
+        .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GroupBy;
+
+impl Function for GroupBy {
+    fn identifier(&self) -> &'static str {
+        "group_by"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "group by field",
+                source: r#"group_by!([{ "level": "info", "n": 1 }, { "level": "error", "n": 2 }, { "level": "info", "n": 3 }]) -> |_index, value| { value.level }"#,
+                result: Ok(
+                    r#"{ "error": [{ "level": "error", "n": 2 }], "info": [{ "level": "info", "n": 1 }, { "level": "info", "n": 3 }] }"#,
+                ),
+            },
+            Example {
+                title: "group by derived key",
+                source: r#"group_by(["foo", "bar", "FOO"]) -> |_index, value| { downcase(string!(value)) }"#,
+                result: Ok(r#"{ "bar": ["bar"], "foo": ["foo", "FOO"] }"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let closure = arguments.required_closure()?;
+
+        Ok(GroupByFn { value, closure }.as_expr())
+    }
+
+    fn closure(&self) -> Option<closure::Definition> {
+        use closure::{Definition, Input, Output, Variable, VariableKind};
+
+        Some(Definition {
+            inputs: vec![Input {
+                parameter_keyword: "value",
+                kind: Kind::array(Collection::any()),
+                variables: vec![
+                    Variable {
+                        kind: VariableKind::TargetInnerKey,
+                    },
+                    Variable {
+                        kind: VariableKind::TargetInnerValue,
+                    },
+                ],
+                output: Output::Kind(Kind::any()),
+                example: Example {
+                    title: "group by field",
+                    source: r#"group_by!([{ "id": "a" }, { "id": "b" }]) -> |_index, value| { value.id }"#,
+                    result: Ok(r#"{ "a": [{ "id": "a" }], "b": [{ "id": "b" }] }"#),
+                },
+            }],
+            is_iterator: true,
+            is_optional: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GroupByFn {
+    value: Box<dyn Expression>,
+    closure: FunctionClosure,
+}
+
+impl FunctionExpression for GroupByFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let FunctionClosure {
+            variables,
+            block,
+            block_type_def: _,
+        } = &self.closure;
+        let runner = closure::Runner::new(variables, |ctx| block.resolve(ctx));
+
+        group_by(value, ctx, runner)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let elements = self
+            .value
+            .type_def(state)
+            .kind()
+            .as_array()
+            .map_or_else(Kind::any, Collection::reduced_kind);
+
+        // Only closures that always return a string are guaranteed to produce valid keys.
+        let fallible = !self.closure.block_type_def.kind().is_bytes();
+
+        TypeDef::object(Collection::from_unknown(Kind::array(
+            Collection::from_unknown(elements),
+        )))
+        .with_fallibility(fallible)
+    }
+}
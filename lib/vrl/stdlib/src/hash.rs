@@ -0,0 +1,194 @@
+use ::value::Value;
+use sha_2::Digest;
+use vrl::prelude::*;
+
+const ALGORITHMS: [&str; 4] = ["md5", "sha1", "sha256", "sha512"];
+const ENCODINGS: [&str; 2] = ["hex", "base64"];
+
+fn hash(value: Value, algorithm: Value, encoding: Value) -> Resolved {
+    let value = value.try_bytes()?;
+    let algorithm = algorithm.try_bytes_utf8_lossy()?;
+    let encoding = encoding.try_bytes_utf8_lossy()?;
+
+    let digest = match algorithm.as_ref() {
+        "md5" => md5::Md5::digest(&value).to_vec(),
+        "sha1" => sha1::Sha1::digest(&value).to_vec(),
+        "sha256" => sha_2::Sha256::digest(&value).to_vec(),
+        "sha512" => sha_2::Sha512::digest(&value).to_vec(),
+        other => return Err(format!("Invalid algorithm: {}", other).into()),
+    };
+
+    let encoded = match encoding.as_ref() {
+        "hex" => hex::encode(digest),
+        "base64" => base64::encode(digest),
+        other => return Err(format!("Invalid encoding: {}", other).into()),
+    };
+
+    Ok(encoded.into())
+}
+
+/// Checks a constant argument against the supported values, so typos are caught when compiling
+/// the program rather than when it runs.
+fn check_literal(
+    keyword: &'static str,
+    argument: &dyn Expression,
+    valid: &[&str],
+    error: &'static str,
+) -> std::result::Result<(), Box<dyn DiagnosticMessage>> {
+    if let Some(value) = argument.as_value() {
+        let is_valid = value.as_bytes().map_or(false, |bytes| {
+            valid.iter().any(|v| bytes.as_ref() == v.as_bytes())
+        });
+        if !is_valid {
+            return Err(vrl::function::Error::InvalidArgument {
+                keyword,
+                value,
+                error,
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Hash;
+
+impl Function for Hash {
+    fn identifier(&self) -> &'static str {
+        "hash"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "algorithm",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "encoding",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "hex",
+                source: r#"hash("foo", "sha256")"#,
+                result: Ok("2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae"),
+            },
+            Example {
+                title: "base64",
+                source: r#"hash("foo", "md5", encoding: "base64")"#,
+                result: Ok("rL0Y20zC+Fzt72VPzMSk2A=="),
+            },
+            Example {
+                title: "dynamic algorithm",
+                source: r#"algorithm = "sha1"; hash!("foo", algorithm)"#,
+                result: Ok("0beec7b5ea3f0fdbc95d0dd47f3c5bc275da8a33"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let algorithm = arguments.required("algorithm");
+        let encoding = arguments
+            .optional("encoding")
+            .unwrap_or_else(|| expr!("hex"));
+
+        check_literal("algorithm", &*algorithm, &ALGORITHMS, "Invalid algorithm")?;
+        check_literal("encoding", &*encoding, &ENCODINGS, "Invalid encoding")?;
+
+        Ok(HashFn {
+            value,
+            algorithm,
+            encoding,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct HashFn {
+    value: Box<dyn Expression>,
+    algorithm: Box<dyn Expression>,
+    encoding: Box<dyn Expression>,
+}
+
+impl FunctionExpression for HashFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let algorithm = self.algorithm.resolve(ctx)?;
+        let encoding = self.encoding.resolve(ctx)?;
+
+        hash(value, algorithm, encoding)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Constant arguments are validated at compile time.
+        let not_literal = self.algorithm.as_value().is_none() || self.encoding.as_value().is_none();
+
+        TypeDef::bytes().with_fallibility(not_literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        hash => Hash;
+
+        md5 {
+            args: func_args![value: "foo", algorithm: "md5"],
+            want: Ok("acbd18db4cc2f85cedef654fccc4a4d8"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        sha1 {
+            args: func_args![value: "foo", algorithm: "sha1"],
+            want: Ok("0beec7b5ea3f0fdbc95d0dd47f3c5bc275da8a33"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        sha256 {
+            args: func_args![value: "foo", algorithm: "sha256"],
+            want: Ok("2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        sha512_base64 {
+            args: func_args![value: "foo", algorithm: "sha512", encoding: "base64"],
+            want: Ok("9/u6bgY2+JDlb7vzKD5STG+jIErimDgtYkdB0NxmODJuKCxBvl5CVNiCB3LFUYosWowMf37aGVlKfrU5RT4e1w=="),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        invalid_algorithm {
+            args: func_args![value: "foo", algorithm: "sha3"],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        invalid_encoding {
+            args: func_args![value: "foo", algorithm: "md5", encoding: "base32"],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+}
@@ -0,0 +1,135 @@
+use ::value::{kind::Collection, Value};
+use vrl::prelude::*;
+
+use crate::util::{dedup_values, set_operand_kinds, set_operands};
+
+fn intersection(value: Value, other: Option<Value>) -> Resolved {
+    let mut arrays = set_operands(value, other)?.into_iter();
+    let first = match arrays.next() {
+        Some(first) => first,
+        None => return Ok(Value::Array(vec![])),
+    };
+    let rest = arrays.collect::<Vec<_>>();
+
+    Ok(dedup_values(
+        first
+            .into_iter()
+            .filter(|value| rest.iter().all(|array| array.contains(value))),
+    )
+    .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Intersection;
+
+impl Function for Intersection {
+    fn identifier(&self) -> &'static str {
+        "intersection"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "other",
+                kind: kind::ARRAY,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "two arrays",
+                source: r#"intersection(["a", "b", "c", "b"], ["b", "d", "a"])"#,
+                result: Ok(r#"["a", "b"]"#),
+            },
+            Example {
+                title: "many arrays",
+                source: r#"intersection([[1, 2, 3], [2, 3, 4], [3, 4, 5]])"#,
+                result: Ok("[3]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let other = arguments.optional("other");
+
+        Ok(IntersectionFn { value, other }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IntersectionFn {
+    value: Box<dyn Expression>,
+    other: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for IntersectionFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let other = self
+            .other
+            .as_ref()
+            .map(|expr| expr.resolve(ctx))
+            .transpose()?;
+
+        intersection(value, other)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let (kinds, fallible) = set_operand_kinds(
+            self.value.type_def(state),
+            self.other.as_ref().map(|expr| expr.type_def(state)),
+        );
+
+        // Every element of the result is taken from the first array.
+        let kind = kinds.into_iter().next().expect("at least one operand");
+
+        TypeDef::array(Collection::from_unknown(kind)).with_fallibility(fallible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        intersection => Intersection;
+
+        two_arrays {
+            args: func_args![value: value!(["a", "b", "c", "b"]), other: value!(["b", "d", "a"])],
+            want: Ok(value!(["a", "b"])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
+        }
+
+        disjoint {
+            args: func_args![value: value!([1, 2]), other: value!(["1", 2.0])],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())),
+        }
+
+        many_arrays {
+            args: func_args![value: value!([[1, 2, 3], [2, 3, 4], [3, 4, 5]])],
+            want: Ok(value!([3])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())),
+        }
+
+        duplicates {
+            args: func_args![value: value!(["a", "a", "b"]), other: value!(["b", "a", "a"])],
+            want: Ok(value!(["a", "b"])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
+        }
+    ];
+}
@@ -0,0 +1,105 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn is_after(value: Value, other: Value) -> Resolved {
+    Ok((value.try_timestamp()? > other.try_timestamp()?).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct IsAfter;
+
+impl Function for IsAfter {
+    fn identifier(&self) -> &'static str {
+        "is_after"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+            Parameter {
+                keyword: "other",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "after",
+                source: r#"is_after(t'2021-01-02T00:00:00Z', t'2021-01-01T00:00:00Z')"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "same time",
+                source: r#"is_after(t'2021-01-01T00:00:00Z', t'2021-01-01T00:00:00Z')"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let other = arguments.required("other");
+
+        Ok(IsAfterFn { value, other }.as_expr())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct IsAfterFn {
+    value: Box<dyn Expression>,
+    other: Box<dyn Expression>,
+}
+
+impl FunctionExpression for IsAfterFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let other = self.other.resolve(ctx)?;
+
+        is_after(value, other)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::boolean().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    test_function![
+        is_after => IsAfter;
+
+        before {
+            args: func_args![value: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0), other: Utc.ymd(2021, 1, 1).and_hms(0, 0, 1)],
+            want: Ok(false),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        equal {
+            args: func_args![value: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0), other: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0)],
+            want: Ok(false),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        after {
+            args: func_args![value: Utc.ymd(2021, 1, 2).and_hms(0, 0, 0), other: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0)],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+    ];
+}
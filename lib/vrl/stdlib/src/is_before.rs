@@ -0,0 +1,105 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn is_before(value: Value, other: Value) -> Resolved {
+    Ok((value.try_timestamp()? < other.try_timestamp()?).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct IsBefore;
+
+impl Function for IsBefore {
+    fn identifier(&self) -> &'static str {
+        "is_before"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+            Parameter {
+                keyword: "other",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "before",
+                source: r#"is_before(t'2021-01-01T00:00:00Z', t'2021-01-02T00:00:00Z')"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "same time",
+                source: r#"is_before(t'2021-01-01T00:00:00Z', t'2021-01-01T00:00:00Z')"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let other = arguments.required("other");
+
+        Ok(IsBeforeFn { value, other }.as_expr())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct IsBeforeFn {
+    value: Box<dyn Expression>,
+    other: Box<dyn Expression>,
+}
+
+impl FunctionExpression for IsBeforeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let other = self.other.resolve(ctx)?;
+
+        is_before(value, other)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::boolean().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    test_function![
+        is_before => IsBefore;
+
+        before {
+            args: func_args![value: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0), other: Utc.ymd(2021, 1, 1).and_hms(0, 0, 1)],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        equal {
+            args: func_args![value: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0), other: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0)],
+            want: Ok(false),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        after {
+            args: func_args![value: Utc.ymd(2021, 1, 2).and_hms(0, 0, 0), other: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0)],
+            want: Ok(false),
+            tdef: TypeDef::boolean().infallible(),
+        }
+    ];
+}
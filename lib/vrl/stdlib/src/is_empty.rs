@@ -1,23 +1,10 @@
 use ::value::Value;
 use vrl::prelude::*;
 
-fn is_empty(value: Value) -> Resolved {
-    let empty = match value {
-        Value::Object(v) => v.is_empty(),
-        Value::Array(v) => v.is_empty(),
-        Value::Bytes(v) => v.is_empty(),
-        value => {
-            return Err(value::Error::Expected {
-                got: value.kind(),
-                expected: Kind::array(Collection::any())
-                    | Kind::object(Collection::any())
-                    | Kind::bytes(),
-            }
-            .into())
-        }
-    };
+use crate::util;
 
-    Ok(empty.into())
+fn is_empty(value: Value) -> Resolved {
+    Ok(util::is_empty(&value).into())
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -31,7 +18,7 @@ impl Function for IsEmpty {
     fn parameters(&self) -> &'static [Parameter] {
         &[Parameter {
             keyword: "value",
-            kind: kind::OBJECT | kind::ARRAY | kind::BYTES,
+            kind: kind::ANY,
             required: true,
         }]
     }
@@ -58,6 +45,16 @@ impl Function for IsEmpty {
                 source: r#"is_empty([1,2,3])"#,
                 result: Ok("false"),
             },
+            Example {
+                title: "null",
+                source: r#"is_empty(null)"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "scalar",
+                source: r#"is_empty(0)"#,
+                result: Ok("false"),
+            },
         ]
     }
 
@@ -131,5 +128,23 @@ mod tests {
             want: Ok(value!(false)),
             tdef: TypeDef::boolean().infallible(),
         }
+
+        null {
+            args: func_args![value: value!(null)],
+            want: Ok(value!(true)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        zero {
+            args: func_args![value: 0],
+            want: Ok(value!(false)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        false_boolean {
+            args: func_args![value: false],
+            want: Ok(value!(false)),
+            tdef: TypeDef::boolean().infallible(),
+        }
     ];
 }
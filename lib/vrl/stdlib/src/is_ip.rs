@@ -0,0 +1,121 @@
+use std::net::IpAddr;
+
+use ::value::Value;
+use vrl::prelude::*;
+use vrl::state::TypeState;
+
+fn is_ip(value: Value) -> Resolved {
+    let is_ip = match value {
+        Value::Bytes(bytes) => String::from_utf8_lossy(&bytes).parse::<IpAddr>().is_ok(),
+        _ => false,
+    };
+    Ok(is_ip.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct IsIp;
+
+impl Function for IsIp {
+    fn identifier(&self) -> &'static str {
+        "is_ip"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ANY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "random string",
+                source: r#"is_ip("foobar")"#,
+                result: Ok("false"),
+            },
+            Example {
+                title: "IPv4 address",
+                source: r#"is_ip("1.1.1.1")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "IPv6 address",
+                source: r#"is_ip("2001:0db8:85a3:0000:0000:8a2e:0370:7334")"#,
+                result: Ok("true"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(IsIpFn { value }.as_expr())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct IsIpFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for IsIpFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        self.value.resolve(ctx).and_then(is_ip)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::boolean().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        is_ip => IsIp;
+
+        not_string {
+            args: func_args![value: value!(42)],
+            want: Ok(value!(false)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        random_string {
+            args: func_args![value: value!("foobar")],
+            want: Ok(value!(false)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        ipv4_address_valid {
+            args: func_args![value: value!("1.1.1.1")],
+            want: Ok(value!(true)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        ipv4_address_invalid {
+            args: func_args![value: value!("1.1.1.314")],
+            want: Ok(value!(false)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        ipv6_address_valid {
+            args: func_args![value: value!("2001:0db8:85a3:0000:0000:8a2e:0370:7334")],
+            want: Ok(value!(true)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        ipv6_address_invalid {
+            args: func_args![value: value!("2001:0db8:85a3:zzzz:0000:8a2e:0370:7334")],
+            want: Ok(value!(false)),
+            tdef: TypeDef::boolean().infallible(),
+        }
+    ];
+}
@@ -5,8 +5,11 @@ use vrl::prelude::*;
 use vrl::state::TypeState;
 
 fn is_ipv4(value: Value) -> Resolved {
-    let value_str = value.try_bytes_utf8_lossy()?;
-    Ok(value_str.parse::<Ipv4Addr>().is_ok().into())
+    let is_ipv4 = match value {
+        Value::Bytes(bytes) => String::from_utf8_lossy(&bytes).parse::<Ipv4Addr>().is_ok(),
+        _ => false,
+    };
+    Ok(is_ipv4.into())
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -20,7 +23,7 @@ impl Function for IsIpv4 {
     fn parameters(&self) -> &'static [Parameter] {
         &[Parameter {
             keyword: "value",
-            kind: kind::BYTES,
+            kind: kind::ANY,
             required: true,
         }]
     }
@@ -81,7 +84,7 @@ mod tests {
 
         not_string {
             args: func_args![value: value!(42)],
-            want: Err("expected string, got integer"),
+            want: Ok(value!(false)),
             tdef: TypeDef::boolean().infallible(),
         }
 
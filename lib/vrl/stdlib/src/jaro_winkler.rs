@@ -0,0 +1,138 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn jaro_winkler(value: Value, other: Value) -> Resolved {
+    let value = value.try_bytes_utf8_lossy()?;
+    let other = other.try_bytes_utf8_lossy()?;
+
+    Ok(Value::from_f64_or_zero(strsim::jaro_winkler(
+        &value, &other,
+    )))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct JaroWinkler;
+
+impl Function for JaroWinkler {
+    fn identifier(&self) -> &'static str {
+        "jaro_winkler"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "other",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "similar strings",
+                source: r#"round(jaro_winkler("martha", "marhta"), 3)"#,
+                result: Ok("0.961"),
+            },
+            Example {
+                title: "identical strings",
+                source: r#"jaro_winkler("vector", "vector")"#,
+                result: Ok("1.0"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let other = arguments.required("other");
+
+        Ok(JaroWinklerFn { value, other }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct JaroWinklerFn {
+    value: Box<dyn Expression>,
+    other: Box<dyn Expression>,
+}
+
+impl FunctionExpression for JaroWinklerFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let other = self.other.resolve(ctx)?;
+
+        jaro_winkler(value, other)
+    }
+
+    fn type_def(&self, _state: &state::TypeState) -> TypeDef {
+        TypeDef::float().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        jaro_winkler => JaroWinkler;
+
+        identical {
+            args: func_args![value: "vector", other: "vector"],
+            want: Ok(1.0),
+            tdef: TypeDef::float().infallible(),
+        }
+
+        nothing_in_common {
+            args: func_args![value: "abc", other: "xyz"],
+            want: Ok(0.0),
+            tdef: TypeDef::float().infallible(),
+        }
+
+        both_empty {
+            args: func_args![value: "", other: ""],
+            want: Ok(1.0),
+            tdef: TypeDef::float().infallible(),
+        }
+
+        one_empty {
+            args: func_args![value: "", other: "vector"],
+            want: Ok(0.0),
+            tdef: TypeDef::float().infallible(),
+        }
+    ];
+
+    #[test]
+    fn reference_values() {
+        // Reference values from Winkler's "String Comparator Metrics and Enhanced Decision Rules in
+        // the Fellegi-Sunter Model of Record Linkage" (1990).
+        for (value, other, want) in [
+            ("MARTHA", "MARHTA", 0.961),
+            ("DWAYNE", "DUANE", 0.840),
+            ("DIXON", "DICKSONX", 0.813),
+        ] {
+            let got = jaro_winkler(value.into(), other.into())
+                .unwrap()
+                .as_float()
+                .unwrap()
+                .into_inner();
+            assert!(
+                (got - want).abs() < 0.001,
+                "{} vs {}: {}",
+                value,
+                other,
+                got
+            );
+        }
+    }
+}
@@ -1,15 +1,46 @@
 use std::borrow::Cow;
 
-use ::value::Value;
+use ::value::{kind::Collection, Value};
 use vrl::prelude::*;
 
-fn join(array: Value, separator: Option<Value>) -> Resolved {
+fn join<T>(
+    array: Value,
+    separator: Option<Value>,
+    coerce: Value,
+    ctx: &mut Context,
+    runner: Option<closure::Runner<T>>,
+) -> Resolved
+where
+    T: Fn(&mut Context) -> Resolved,
+{
     let array = array.try_array()?;
-    let string_vec = array
-        .iter()
-        .map(|s| s.try_bytes_utf8_lossy().map_err(Into::into))
-        .collect::<Result<Vec<Cow<'_, str>>>>()
-        .map_err(|_| "all array items must be strings")?;
+    let coerce = coerce.try_boolean()?;
+
+    let string_vec = match runner {
+        Some(runner) => array
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let formatted = runner.run_index_value(ctx, index, value)?;
+                Ok(formatted.try_bytes_utf8_lossy()?.into_owned().into())
+            })
+            .collect::<Result<Vec<Cow<'_, str>>>>()?,
+        None if coerce => array
+            .iter()
+            .map(|value| match value {
+                Value::Null => Ok("".into()),
+                Value::Array(_) | Value::Object(_) => {
+                    Err("arrays and objects can't be joined, use a closure to format them".into())
+                }
+                value => Ok(value.to_string_lossy()),
+            })
+            .collect::<Result<Vec<Cow<'_, str>>>>()?,
+        None => array
+            .iter()
+            .map(|s| s.try_bytes_utf8_lossy().map_err(Into::into))
+            .collect::<Result<Vec<Cow<'_, str>>>>()
+            .map_err(|_| "all array items must be strings")?,
+    };
     let separator: String = separator
         .map(Value::try_bytes)
         .transpose()?
@@ -38,6 +69,11 @@ impl Function for Join {
                 kind: kind::BYTES,
                 required: false,
             },
+            Parameter {
+                keyword: "coerce",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
         ]
     }
 
@@ -49,16 +85,63 @@ impl Function for Join {
     ) -> Compiled {
         let value = arguments.required("value");
         let separator = arguments.optional("separator");
-
-        Ok(JoinFn { value, separator }.as_expr())
+        let coerce = arguments.optional("coerce").unwrap_or_else(|| expr!(true));
+        let closure = arguments.optional_closure().cloned();
+
+        Ok(JoinFn {
+            value,
+            separator,
+            coerce,
+            closure,
+        }
+        .as_expr())
     }
 
     fn examples(&self) -> &'static [Example] {
-        &[Example {
-            title: "join",
-            source: r#"join!(["a","b","c"], ",")"#,
-            result: Ok(r#"a,b,c"#),
-        }]
+        &[
+            Example {
+                title: "join",
+                source: r#"join!(["a","b","c"], ",")"#,
+                result: Ok(r#"a,b,c"#),
+            },
+            Example {
+                title: "join scalars",
+                source: r#"join!(["a", 1, true], ",")"#,
+                result: Ok(r#"a,1,true"#),
+            },
+            Example {
+                title: "join formatted",
+                source: r#"join!([{ "k": "a", "v": 1 }, { "k": "b", "v": 2 }], ", ") -> |_index, value| { value.k + "=" + to_string(value.v) }"#,
+                result: Ok(r#"a=1, b=2"#),
+            },
+        ]
+    }
+
+    fn closure(&self) -> Option<closure::Definition> {
+        use closure::{Definition, Input, Output, Variable, VariableKind};
+
+        Some(Definition {
+            inputs: vec![Input {
+                parameter_keyword: "value",
+                kind: Kind::array(Collection::any()),
+                variables: vec![
+                    Variable {
+                        kind: VariableKind::TargetInnerKey,
+                    },
+                    Variable {
+                        kind: VariableKind::TargetInnerValue,
+                    },
+                ],
+                output: Output::Kind(Kind::bytes()),
+                example: Example {
+                    title: "join formatted",
+                    source: r#"join!([{ "k": "a" }, { "k": "b" }], ", ") -> |_index, value| { string!(value.k) }"#,
+                    result: Ok(r#"a, b"#),
+                },
+            }],
+            is_iterator: true,
+            is_optional: true,
+        })
     }
 }
 
@@ -66,6 +149,8 @@ impl Function for Join {
 struct JoinFn {
     value: Box<dyn Expression>,
     separator: Option<Box<dyn Expression>>,
+    coerce: Box<dyn Expression>,
+    closure: Option<FunctionClosure>,
 }
 
 impl FunctionExpression for JoinFn {
@@ -76,8 +161,18 @@ impl FunctionExpression for JoinFn {
             .as_ref()
             .map(|s| s.resolve(ctx))
             .transpose()?;
-
-        join(array, separator)
+        let coerce = self.coerce.resolve(ctx)?;
+        let runner = self.closure.as_ref().map(|function_closure| {
+            let FunctionClosure {
+                variables,
+                block,
+                block_type_def: _,
+            } = function_closure;
+
+            closure::Runner::new(variables, |ctx| block.resolve(ctx))
+        });
+
+        join(array, separator, coerce, ctx, runner)
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -109,8 +204,20 @@ mod test {
             tdef: TypeDef::bytes().fallible(),
         }
 
-        non_string_array_item_throws_error {
-            args: func_args![value: value!(["one", "two", 3])],
+        coerces_scalar_array_items {
+            args: func_args![value: value!(["one", 2, 3.5, true, null]), separator: ","],
+            want: Ok(value!("one,2,3.5,true,")),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        collection_array_item_throws_error {
+            args: func_args![value: value!(["one", ["two"]])],
+            want: Err("arrays and objects can't be joined, use a closure to format them"),
+            tdef: TypeDef::bytes().fallible(),
+        }
+
+        non_string_array_item_throws_error_without_coercion {
+            args: func_args![value: value!(["one", "two", 3]), coerce: false],
             want: Err("all array items must be strings"),
             tdef: TypeDef::bytes().fallible(),
         }
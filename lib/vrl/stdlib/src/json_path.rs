@@ -0,0 +1,177 @@
+//! The subset of JSONPath supported by `query` and `path_exists`.
+
+use ::value::Value;
+use vrl::prelude::Expression;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Segment {
+    Field(String),
+    Index(isize),
+    Wildcard,
+}
+
+/// Parses a literal `path` argument once at compile time, rather than for every event.
+///
+/// Returns `None` if the path isn't a literal, and has to be parsed at runtime instead.
+pub(crate) fn parse_literal_path(
+    path: &dyn Expression,
+) -> std::result::Result<Option<Vec<Segment>>, vrl::function::Error> {
+    let literal = match path.as_value() {
+        Some(literal) => literal,
+        None => return Ok(None),
+    };
+
+    let parsed = literal
+        .as_bytes()
+        .ok_or("path must be a string")
+        .and_then(|path| parse_path(path));
+    match parsed {
+        Ok(segments) => Ok(Some(segments)),
+        Err(error) => Err(vrl::function::Error::InvalidArgument {
+            keyword: "path",
+            value: literal,
+            error,
+        }),
+    }
+}
+
+/// Parses the supported subset of JSONPath: an optional leading `$`, followed by any number of
+/// `.field`, `."quoted field"`, `.*`, `[index]`, `["quoted field"]` and `[*]` segments.
+pub(crate) fn parse_path(path: &[u8]) -> std::result::Result<Vec<Segment>, &'static str> {
+    let path = std::str::from_utf8(path).map_err(|_| "path must be valid UTF-8")?;
+    let mut chars = path.chars().peekable();
+    let mut segments = Vec::new();
+
+    if chars.peek() == Some(&'$') {
+        chars.next();
+    }
+
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => match chars.peek() {
+                // A lone `.` refers to the root value.
+                None if segments.is_empty() => {}
+                Some('*') => {
+                    chars.next();
+                    segments.push(Segment::Wildcard);
+                }
+                Some('"') => {
+                    chars.next();
+                    segments.push(Segment::Field(parse_quoted(&mut chars)?));
+                }
+                _ => {
+                    let mut field = String::new();
+                    while let Some(&c) = chars.peek() {
+                        if !(c.is_alphanumeric() || c == '_' || c == '-' || c == '@') {
+                            break;
+                        }
+                        field.push(c);
+                        chars.next();
+                    }
+                    if field.is_empty() {
+                        return Err(r#"expected a field name after ".""#);
+                    }
+                    segments.push(Segment::Field(field));
+                }
+            },
+            '[' => {
+                let segment = match chars.peek() {
+                    Some('*') => {
+                        chars.next();
+                        Segment::Wildcard
+                    }
+                    Some('"') => {
+                        chars.next();
+                        Segment::Field(parse_quoted(&mut chars)?)
+                    }
+                    _ => {
+                        let mut index = String::new();
+                        while let Some(&c) = chars.peek() {
+                            if !(c.is_ascii_digit() || c == '-') {
+                                break;
+                            }
+                            index.push(c);
+                            chars.next();
+                        }
+                        Segment::Index(index.parse().map_err(|_| "invalid array index")?)
+                    }
+                };
+                if chars.next() != Some(']') {
+                    return Err(r#"expected "]""#);
+                }
+                segments.push(segment);
+            }
+            _ => return Err(r#"expected "." or "[""#),
+        }
+    }
+
+    Ok(segments)
+}
+
+fn parse_quoted(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+) -> std::result::Result<String, &'static str> {
+    let mut field = String::new();
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(field),
+            Some('\\') => match chars.next() {
+                Some(c @ ('"' | '\\')) => field.push(c),
+                _ => return Err("invalid escape sequence in quoted field"),
+            },
+            Some(c) => field.push(c),
+            None => return Err("unterminated quoted field"),
+        }
+    }
+}
+
+/// The values in `value` matched by the path `segments`.
+pub(crate) fn matches<'a>(value: &'a Value, segments: &[Segment]) -> Vec<&'a Value> {
+    let mut matches = vec![value];
+
+    for segment in segments {
+        matches = matches
+            .into_iter()
+            .flat_map(|value| -> Vec<&Value> {
+                match (segment, value) {
+                    (Segment::Field(field), Value::Object(object)) => {
+                        object.get(field.as_str()).into_iter().collect()
+                    }
+                    (Segment::Index(index), Value::Array(array)) => {
+                        let index = if *index < 0 {
+                            array.len().checked_sub(index.unsigned_abs())
+                        } else {
+                            Some(*index as usize)
+                        };
+                        index
+                            .and_then(|index| array.get(index))
+                            .into_iter()
+                            .collect()
+                    }
+                    (Segment::Wildcard, Value::Object(object)) => object.values().collect(),
+                    (Segment::Wildcard, Value::Array(array)) => array.iter().collect(),
+                    _ => vec![],
+                }
+            })
+            .collect();
+    }
+
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse_path(b"a"), Err(r#"expected "." or "[""#));
+        assert_eq!(
+            parse_path(b".a..b"),
+            Err(r#"expected a field name after ".""#)
+        );
+        assert_eq!(parse_path(b".a[x]"), Err("invalid array index"));
+        assert_eq!(parse_path(b".a[0"), Err(r#"expected "]""#));
+        assert_eq!(parse_path(br#"."a"#), Err("unterminated quoted field"));
+    }
+}
@@ -0,0 +1,111 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn levenshtein(value: Value, other: Value) -> Resolved {
+    let value = value.try_bytes_utf8_lossy()?;
+    let other = other.try_bytes_utf8_lossy()?;
+
+    Ok(strsim::levenshtein(&value, &other).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Levenshtein;
+
+impl Function for Levenshtein {
+    fn identifier(&self) -> &'static str {
+        "levenshtein"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "other",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "edit distance",
+            source: r#"levenshtein("kitten", "sitting")"#,
+            result: Ok("3"),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let other = arguments.required("other");
+
+        Ok(LevenshteinFn { value, other }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LevenshteinFn {
+    value: Box<dyn Expression>,
+    other: Box<dyn Expression>,
+}
+
+impl FunctionExpression for LevenshteinFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let other = self.other.resolve(ctx)?;
+
+        levenshtein(value, other)
+    }
+
+    fn type_def(&self, _state: &state::TypeState) -> TypeDef {
+        TypeDef::integer().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        levenshtein => Levenshtein;
+
+        kitten_sitting {
+            args: func_args![value: "kitten", other: "sitting"],
+            want: Ok(3),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        flaw_lawn {
+            args: func_args![value: "flaw", other: "lawn"],
+            want: Ok(2),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        identical {
+            args: func_args![value: "vector", other: "vector"],
+            want: Ok(0),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        empty {
+            args: func_args![value: "", other: "abc"],
+            want: Ok(3),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        counts_characters {
+            args: func_args![value: "ñandú", other: "nandu"],
+            want: Ok(2),
+            tdef: TypeDef::integer().infallible(),
+        }
+    ];
+}
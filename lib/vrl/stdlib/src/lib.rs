@@ -28,38 +28,73 @@
 
 mod util;
 
+#[cfg(any(feature = "dns_lookup", feature = "reverse_dns"))]
+mod dns_resolution;
+
+#[cfg(feature = "abbreviate_number")]
+mod abbreviate_number;
+#[cfg(feature = "abs")]
+mod abs;
 #[cfg(feature = "append")]
 mod append;
 #[cfg(feature = "array")]
 mod array;
+#[cfg(feature = "array_flatten")]
+mod array_flatten;
+#[cfg(feature = "array_rotate")]
+mod array_rotate;
 #[cfg(feature = "assert")]
 mod assert;
 #[cfg(feature = "assert_eq")]
 mod assert_eq;
 #[cfg(feature = "boolean")]
 mod boolean;
+#[cfg(feature = "byte_size")]
+mod byte_size;
 #[cfg(feature = "ceil")]
 mod ceil;
+#[cfg(feature = "center")]
+mod center;
 #[cfg(feature = "chunks")]
 mod chunks;
+#[cfg(feature = "clamp")]
+mod clamp;
 #[cfg(feature = "compact")]
 mod compact;
 #[cfg(feature = "contains")]
 mod contains;
+#[cfg(feature = "convert_timezone")]
+mod convert_timezone;
 #[cfg(feature = "decode_base64")]
 mod decode_base64;
+#[cfg(feature = "decode_charset")]
+mod decode_charset;
+#[cfg(feature = "decode_gzip")]
+mod decode_gzip;
 #[cfg(feature = "decode_mime_q")]
 mod decode_mime_q;
 #[cfg(feature = "decode_percent")]
 mod decode_percent;
+#[cfg(feature = "decode_zlib")]
+mod decode_zlib;
+#[cfg(feature = "decode_zstd")]
+mod decode_zstd;
 #[cfg(feature = "decrypt")]
 mod decrypt;
 #[cfg(feature = "del")]
 mod del;
+#[cfg(feature = "difference")]
+mod difference;
+#[cfg(feature = "dns_lookup")]
+mod dns_lookup;
 #[cfg(feature = "downcase")]
 mod downcase;
 #[cfg(feature = "encode_base64")]
 mod encode_base64;
+#[cfg(feature = "encode_charset")]
+mod encode_charset;
+#[cfg(feature = "encode_gzip")]
+mod encode_gzip;
 #[cfg(feature = "encode_json")]
 mod encode_json;
 #[cfg(feature = "encode_key_value")]
@@ -68,16 +103,26 @@ mod encode_key_value;
 mod encode_logfmt;
 #[cfg(feature = "encode_percent")]
 mod encode_percent;
+#[cfg(feature = "encode_zlib")]
+mod encode_zlib;
+#[cfg(feature = "encode_zstd")]
+mod encode_zstd;
 #[cfg(feature = "encrypt")]
 mod encrypt;
 #[cfg(feature = "ends_with")]
 mod ends_with;
+#[cfg(feature = "enumerate")]
+mod enumerate;
 #[cfg(feature = "exists")]
 mod exists;
 #[cfg(feature = "filter")]
 mod filter;
 #[cfg(feature = "find")]
 mod find;
+#[cfg(feature = "find_all")]
+mod find_all;
+#[cfg(feature = "find_first")]
+mod find_first;
 #[cfg(feature = "flatten")]
 mod flatten;
 #[cfg(feature = "float")]
@@ -98,10 +143,18 @@ mod get;
 mod get_env_var;
 #[cfg(feature = "get_hostname")]
 mod get_hostname;
+#[cfg(feature = "grapheme_length")]
+mod grapheme_length;
+#[cfg(feature = "group_by")]
+mod group_by;
+#[cfg(feature = "hash")]
+mod hash;
 #[cfg(feature = "includes")]
 mod includes;
 #[cfg(feature = "integer")]
 mod integer;
+#[cfg(feature = "intersection")]
+mod intersection;
 #[cfg(feature = "ip_aton")]
 mod ip_aton;
 #[cfg(feature = "ip_cidr_contains")]
@@ -118,8 +171,12 @@ mod ip_subnet;
 mod ip_to_ipv6;
 #[cfg(feature = "ipv6_to_ipv4")]
 mod ipv6_to_ipv4;
+#[cfg(feature = "is_after")]
+mod is_after;
 #[cfg(feature = "is_array")]
 mod is_array;
+#[cfg(feature = "is_before")]
+mod is_before;
 #[cfg(feature = "is_boolean")]
 mod is_boolean;
 #[cfg(feature = "is_empty")]
@@ -128,6 +185,8 @@ mod is_empty;
 mod is_float;
 #[cfg(feature = "is_integer")]
 mod is_integer;
+#[cfg(feature = "is_ip")]
+mod is_ip;
 #[cfg(feature = "is_ipv4")]
 mod is_ipv4;
 #[cfg(feature = "is_ipv6")]
@@ -146,12 +205,18 @@ mod is_regex;
 mod is_string;
 #[cfg(feature = "is_timestamp")]
 mod is_timestamp;
+#[cfg(feature = "jaro_winkler")]
+mod jaro_winkler;
 #[cfg(feature = "join")]
 mod join;
+#[cfg(any(feature = "path_exists", feature = "query"))]
+mod json_path;
 #[cfg(feature = "keys")]
 mod keys;
 #[cfg(feature = "length")]
 mod length;
+#[cfg(feature = "levenshtein")]
+mod levenshtein;
 #[cfg(feature = "log")]
 mod log;
 #[cfg(any(
@@ -160,6 +225,8 @@ mod log;
     feature = "parse_nginx_log"
 ))]
 mod log_util;
+#[cfg(feature = "logarithm")]
+mod logarithm;
 #[cfg(feature = "map_keys")]
 mod map_keys;
 #[cfg(feature = "map_values")]
@@ -172,22 +239,34 @@ mod match_any;
 mod match_array;
 #[cfg(feature = "match_datadog_query")]
 mod match_datadog_query;
+#[cfg(feature = "max")]
+mod max;
 #[cfg(feature = "md5")]
 mod md5;
 #[cfg(feature = "merge")]
 mod merge;
+#[cfg(feature = "min")]
+mod min;
 #[cfg(feature = "mod")]
 mod mod_func;
 #[cfg(feature = "now")]
 mod now;
 #[cfg(feature = "object")]
 mod object;
+#[cfg(feature = "object_from_entries")]
+mod object_from_entries;
+#[cfg(feature = "ok")]
+mod ok;
 #[cfg(feature = "only_fields")]
 mod only_fields;
+#[cfg(any(feature = "pad_end", feature = "pad_start"))]
+mod pad;
 #[cfg(feature = "parse_apache_log")]
 mod parse_apache_log;
 #[cfg(feature = "parse_aws_alb_log")]
 mod parse_aws_alb_log;
+#[cfg(feature = "parse_aws_cloudfront_log")]
+mod parse_aws_cloudfront_log;
 #[cfg(feature = "parse_aws_cloudwatch_log_subscription_message")]
 mod parse_aws_cloudwatch_log_subscription_message;
 #[cfg(feature = "parse_aws_vpc_flow_log")]
@@ -198,6 +277,12 @@ mod parse_cef;
 mod parse_common_log;
 #[cfg(feature = "parse_csv")]
 mod parse_csv;
+#[cfg(feature = "parse_csv_line")]
+mod parse_csv_line;
+#[cfg(feature = "parse_csv_rows")]
+mod parse_csv_rows;
+#[cfg(feature = "parse_domain")]
+mod parse_domain;
 #[cfg(feature = "parse_duration")]
 mod parse_duration;
 #[cfg(feature = "parse_glog")]
@@ -240,8 +325,18 @@ mod parse_url;
 mod parse_user_agent;
 #[cfg(feature = "parse_xml")]
 mod parse_xml;
+#[cfg(feature = "path_exists")]
+mod path_exists;
+#[cfg(feature = "percentage")]
+mod percentage;
+#[cfg(feature = "pow")]
+mod pow;
 #[cfg(feature = "push")]
 mod push;
+#[cfg(feature = "query")]
+mod query;
+#[cfg(feature = "random_bool")]
+mod random_bool;
 #[cfg(feature = "random_bytes")]
 mod random_bytes;
 #[cfg(feature = "redact")]
@@ -250,10 +345,18 @@ mod redact;
 mod remove;
 #[cfg(feature = "replace")]
 mod replace;
+#[cfg(feature = "replace_with")]
+mod replace_with;
+#[cfg(feature = "reverse")]
+mod reverse;
 #[cfg(feature = "reverse_dns")]
 mod reverse_dns;
 #[cfg(feature = "round")]
 mod round;
+#[cfg(feature = "safe_divide")]
+mod safe_divide;
+#[cfg(feature = "sample")]
+mod sample;
 #[cfg(feature = "set")]
 mod set;
 #[cfg(feature = "sha1")]
@@ -262,14 +365,26 @@ mod sha1;
 mod sha2;
 #[cfg(feature = "sha3")]
 mod sha3;
+#[cfg(feature = "signum")]
+mod signum;
 #[cfg(feature = "slice")]
 mod slice;
+#[cfg(feature = "slugify")]
+mod slugify;
+#[cfg(feature = "sort")]
+mod sort;
+#[cfg(feature = "sort_by")]
+mod sort_by;
 #[cfg(feature = "split")]
 mod split;
+#[cfg(feature = "sqrt")]
+mod sqrt;
 #[cfg(feature = "starts_with")]
 mod starts_with;
 #[cfg(feature = "string")]
 mod string;
+#[cfg(feature = "string_repeat")]
+mod string_repeat;
 #[cfg(feature = "strip_ansi_escape_codes")]
 mod strip_ansi_escape_codes;
 #[cfg(feature = "strip_whitespace")]
@@ -280,8 +395,12 @@ mod strlen;
 mod tag_types_externally;
 #[cfg(feature = "tally")]
 mod tally;
+#[cfg(feature = "tally_by")]
+mod tally_by;
 #[cfg(feature = "tally_value")]
 mod tally_value;
+#[cfg(feature = "time_diff")]
+mod time_diff;
 #[cfg(feature = "timestamp")]
 mod timestamp;
 #[cfg(feature = "to_bool")]
@@ -308,10 +427,16 @@ mod to_unix_timestamp;
 mod truncate;
 #[cfg(feature = "type_def")]
 mod type_def;
+#[cfg(feature = "union")]
+mod union;
 #[cfg(feature = "unique")]
 mod unique;
+#[cfg(feature = "unique_by")]
+mod unique_by;
 #[cfg(feature = "unnest")]
 mod unnest;
+#[cfg(feature = "unwrap_or")]
+mod unwrap_or;
 #[cfg(feature = "upcase")]
 mod upcase;
 #[cfg(feature = "uuid_v4")]
@@ -321,36 +446,77 @@ mod values;
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "windows")]
+mod windows;
+#[cfg(feature = "without_fields")]
+mod without_fields;
+#[cfg(feature = "zip")]
+mod zip;
+#[cfg(feature = "abbreviate_number")]
+pub use abbreviate_number::AbbreviateNumber;
+#[cfg(feature = "abs")]
+pub use abs::Abs;
 #[cfg(feature = "append")]
 pub use append::Append;
+#[cfg(feature = "array_flatten")]
+pub use array_flatten::ArrayFlatten;
+#[cfg(feature = "array_rotate")]
+pub use array_rotate::ArrayRotate;
 #[cfg(feature = "assert")]
 pub use assert::Assert;
 #[cfg(feature = "assert_eq")]
 pub use assert_eq::AssertEq;
 #[cfg(feature = "boolean")]
 pub use boolean::Boolean;
+#[cfg(feature = "byte_size")]
+pub use byte_size::ByteSize;
 #[cfg(feature = "ceil")]
 pub use ceil::Ceil;
+#[cfg(feature = "center")]
+pub use center::Center;
 #[cfg(feature = "chunks")]
 pub use chunks::Chunks;
+#[cfg(feature = "clamp")]
+pub use clamp::Clamp;
 #[cfg(feature = "compact")]
 pub use compact::Compact;
 #[cfg(feature = "contains")]
 pub use contains::Contains;
+#[cfg(feature = "convert_timezone")]
+pub use convert_timezone::ConvertTimezone;
 #[cfg(feature = "decode_base64")]
 pub use decode_base64::DecodeBase64;
+#[cfg(feature = "decode_charset")]
+pub use decode_charset::DecodeCharset;
+#[cfg(feature = "decode_gzip")]
+pub use decode_gzip::DecodeGzip;
 #[cfg(feature = "decode_mime_q")]
 pub use decode_mime_q::DecodeMimeQ;
 #[cfg(feature = "decode_percent")]
 pub use decode_percent::DecodePercent;
+#[cfg(feature = "decode_zlib")]
+pub use decode_zlib::DecodeZlib;
+#[cfg(feature = "decode_zstd")]
+pub use decode_zstd::DecodeZstd;
 #[cfg(feature = "decrypt")]
 pub use decrypt::Decrypt;
 #[cfg(feature = "del")]
 pub use del::Del;
+#[cfg(feature = "difference")]
+pub use difference::Difference;
+// `self::` distinguishes the module from the `dns_lookup` crate.
+#[cfg(feature = "dns_lookup")]
+pub use self::dns_lookup::DnsLookup;
+#[cfg(any(feature = "dns_lookup", feature = "reverse_dns"))]
+pub use dns_resolution::DnsConfig;
 #[cfg(feature = "downcase")]
 pub use downcase::Downcase;
 #[cfg(feature = "encode_base64")]
 pub use encode_base64::EncodeBase64;
+#[cfg(feature = "encode_charset")]
+pub use encode_charset::EncodeCharset;
+#[cfg(feature = "encode_gzip")]
+pub use encode_gzip::EncodeGzip;
 #[cfg(feature = "encode_json")]
 pub use encode_json::EncodeJson;
 #[cfg(feature = "encode_key_value")]
@@ -359,16 +525,26 @@ pub use encode_key_value::EncodeKeyValue;
 pub use encode_logfmt::EncodeLogfmt;
 #[cfg(feature = "encode_percent")]
 pub use encode_percent::EncodePercent;
+#[cfg(feature = "encode_zlib")]
+pub use encode_zlib::EncodeZlib;
+#[cfg(feature = "encode_zstd")]
+pub use encode_zstd::EncodeZstd;
 #[cfg(feature = "encrypt")]
 pub use encrypt::Encrypt;
 #[cfg(feature = "ends_with")]
 pub use ends_with::EndsWith;
+#[cfg(feature = "enumerate")]
+pub use enumerate::Enumerate;
 #[cfg(feature = "exists")]
 pub use exists::Exists;
 #[cfg(feature = "filter")]
 pub use filter::Filter;
 #[cfg(feature = "find")]
 pub use find::Find;
+#[cfg(feature = "find_all")]
+pub use find_all::FindAll;
+#[cfg(feature = "find_first")]
+pub use find_first::FindFirst;
 #[cfg(feature = "flatten")]
 pub use flatten::Flatten;
 #[cfg(feature = "float")]
@@ -389,10 +565,18 @@ pub use get::Get;
 pub use get_env_var::GetEnvVar;
 #[cfg(feature = "get_hostname")]
 pub use get_hostname::GetHostname;
+#[cfg(feature = "grapheme_length")]
+pub use grapheme_length::GraphemeLength;
+#[cfg(feature = "group_by")]
+pub use group_by::GroupBy;
+#[cfg(feature = "hash")]
+pub use hash::Hash;
 #[cfg(feature = "includes")]
 pub use includes::Includes;
 #[cfg(feature = "integer")]
 pub use integer::Integer;
+#[cfg(feature = "intersection")]
+pub use intersection::Intersection;
 #[cfg(feature = "ip_aton")]
 pub use ip_aton::IpAton;
 #[cfg(feature = "ip_cidr_contains")]
@@ -409,8 +593,12 @@ pub use ip_subnet::IpSubnet;
 pub use ip_to_ipv6::IpToIpv6;
 #[cfg(feature = "ipv6_to_ipv4")]
 pub use ipv6_to_ipv4::Ipv6ToIpV4;
+#[cfg(feature = "is_after")]
+pub use is_after::IsAfter;
 #[cfg(feature = "is_array")]
 pub use is_array::IsArray;
+#[cfg(feature = "is_before")]
+pub use is_before::IsBefore;
 #[cfg(feature = "is_boolean")]
 pub use is_boolean::IsBoolean;
 #[cfg(feature = "is_empty")]
@@ -419,6 +607,8 @@ pub use is_empty::IsEmpty;
 pub use is_float::IsFloat;
 #[cfg(feature = "is_integer")]
 pub use is_integer::IsInteger;
+#[cfg(feature = "is_ip")]
+pub use is_ip::IsIp;
 #[cfg(feature = "is_ipv4")]
 pub use is_ipv4::IsIpv4;
 #[cfg(feature = "is_ipv6")]
@@ -437,14 +627,20 @@ pub use is_regex::IsRegex;
 pub use is_string::IsString;
 #[cfg(feature = "is_timestamp")]
 pub use is_timestamp::IsTimestamp;
+#[cfg(feature = "jaro_winkler")]
+pub use jaro_winkler::JaroWinkler;
 #[cfg(feature = "join")]
 pub use join::Join;
 #[cfg(feature = "keys")]
 pub use keys::Keys;
 #[cfg(feature = "length")]
 pub use length::Length;
+#[cfg(feature = "levenshtein")]
+pub use levenshtein::Levenshtein;
 #[cfg(feature = "log")]
 pub use log::Log;
+#[cfg(feature = "logarithm")]
+pub use logarithm::Logarithm;
 #[cfg(feature = "map_keys")]
 pub use map_keys::MapKeys;
 #[cfg(feature = "map_values")]
@@ -455,20 +651,34 @@ pub use match_any::MatchAny;
 pub use match_array::MatchArray;
 #[cfg(feature = "match_datadog_query")]
 pub use match_datadog_query::MatchDatadogQuery;
+#[cfg(feature = "max")]
+pub use max::Max;
 #[cfg(feature = "merge")]
 pub use merge::Merge;
+#[cfg(feature = "min")]
+pub use min::Min;
 #[cfg(feature = "mod")]
 pub use mod_func::Mod;
 #[cfg(feature = "now")]
-pub use now::Now;
+pub use now::{MockTime, Now};
 #[cfg(feature = "object")]
 pub use object::Object;
+#[cfg(feature = "object_from_entries")]
+pub use object_from_entries::ObjectFromEntries;
+#[cfg(feature = "ok")]
+pub use ok::OkResult;
 #[cfg(feature = "only_fields")]
 pub use only_fields::OnlyFields;
+#[cfg(feature = "pad_end")]
+pub use pad::PadEnd;
+#[cfg(feature = "pad_start")]
+pub use pad::PadStart;
 #[cfg(feature = "parse_apache_log")]
 pub use parse_apache_log::ParseApacheLog;
 #[cfg(feature = "parse_aws_alb_log")]
 pub use parse_aws_alb_log::ParseAwsAlbLog;
+#[cfg(feature = "parse_aws_cloudfront_log")]
+pub use parse_aws_cloudfront_log::ParseAwsCloudfrontLog;
 #[cfg(feature = "parse_aws_cloudwatch_log_subscription_message")]
 pub use parse_aws_cloudwatch_log_subscription_message::ParseAwsCloudWatchLogSubscriptionMessage;
 #[cfg(feature = "parse_aws_vpc_flow_log")]
@@ -479,6 +689,12 @@ pub use parse_cef::ParseCef;
 pub use parse_common_log::ParseCommonLog;
 #[cfg(feature = "parse_csv")]
 pub use parse_csv::ParseCsv;
+#[cfg(feature = "parse_csv_line")]
+pub use parse_csv_line::ParseCsvLine;
+#[cfg(feature = "parse_csv_rows")]
+pub use parse_csv_rows::ParseCsvRows;
+#[cfg(feature = "parse_domain")]
+pub use parse_domain::ParseDomain;
 #[cfg(feature = "parse_duration")]
 pub use parse_duration::ParseDuration;
 #[cfg(feature = "parse_glog")]
@@ -521,10 +737,20 @@ pub use parse_url::ParseUrl;
 pub use parse_user_agent::ParseUserAgent;
 #[cfg(feature = "parse_xml")]
 pub use parse_xml::ParseXml;
+#[cfg(feature = "path_exists")]
+pub use path_exists::PathExists;
+#[cfg(feature = "percentage")]
+pub use percentage::Percentage;
+#[cfg(feature = "pow")]
+pub use pow::Pow;
 #[cfg(feature = "push")]
 pub use push::Push;
+#[cfg(feature = "query")]
+pub use query::Query;
 #[cfg(feature = "match")]
 pub use r#match::Match;
+#[cfg(feature = "random_bool")]
+pub use random_bool::RandomBool;
 #[cfg(feature = "random_bytes")]
 pub use random_bytes::RandomBytes;
 #[cfg(feature = "redact")]
@@ -533,24 +759,44 @@ pub use redact::Redact;
 pub use remove::Remove;
 #[cfg(feature = "replace")]
 pub use replace::Replace;
+#[cfg(feature = "replace_with")]
+pub use replace_with::ReplaceWith;
+#[cfg(feature = "reverse")]
+pub use reverse::Reverse;
 #[cfg(feature = "reverse_dns")]
 pub use reverse_dns::ReverseDns;
 #[cfg(feature = "round")]
 pub use round::Round;
+#[cfg(feature = "safe_divide")]
+pub use safe_divide::SafeDivide;
+#[cfg(feature = "sample")]
+pub use sample::Sample;
 #[cfg(feature = "set")]
 pub use set::Set;
 #[cfg(feature = "sha2")]
 pub use sha2::Sha2;
 #[cfg(feature = "sha3")]
 pub use sha3::Sha3;
+#[cfg(feature = "signum")]
+pub use signum::Signum;
 #[cfg(feature = "slice")]
 pub use slice::Slice;
+#[cfg(feature = "slugify")]
+pub use slugify::Slugify;
+#[cfg(feature = "sort")]
+pub use sort::Sort;
+#[cfg(feature = "sort_by")]
+pub use sort_by::SortBy;
 #[cfg(feature = "split")]
 pub use split::Split;
+#[cfg(feature = "sqrt")]
+pub use sqrt::Sqrt;
 #[cfg(feature = "starts_with")]
 pub use starts_with::StartsWith;
 #[cfg(feature = "string")]
 pub use string::String;
+#[cfg(feature = "string_repeat")]
+pub use string_repeat::StringRepeat;
 #[cfg(feature = "strip_ansi_escape_codes")]
 pub use strip_ansi_escape_codes::StripAnsiEscapeCodes;
 #[cfg(feature = "strip_whitespace")]
@@ -561,8 +807,12 @@ pub use strlen::Strlen;
 pub use tag_types_externally::TagTypesExternally;
 #[cfg(feature = "tally")]
 pub use tally::Tally;
+#[cfg(feature = "tally_by")]
+pub use tally_by::TallyBy;
 #[cfg(feature = "tally_value")]
 pub use tally_value::TallyValue;
+#[cfg(feature = "time_diff")]
+pub use time_diff::TimeDiff;
 #[cfg(feature = "timestamp")]
 pub use timestamp::Timestamp;
 #[cfg(feature = "to_bool")]
@@ -589,10 +839,16 @@ pub use to_unix_timestamp::ToUnixTimestamp;
 pub use truncate::Truncate;
 #[cfg(feature = "type_def")]
 pub use type_def::TypeDef;
+#[cfg(feature = "union")]
+pub use union::Union;
 #[cfg(feature = "unique")]
 pub use unique::Unique;
+#[cfg(feature = "unique_by")]
+pub use unique_by::UniqueBy;
 #[cfg(feature = "unnest")]
 pub use unnest::Unnest;
+#[cfg(feature = "unwrap_or")]
+pub use unwrap_or::UnwrapOr;
 #[cfg(feature = "upcase")]
 pub use upcase::Upcase;
 #[cfg(feature = "uuid_v4")]
@@ -606,42 +862,80 @@ pub use crate::array::Array;
 pub use crate::md5::Md5;
 #[cfg(feature = "sha1")]
 pub use crate::sha1::Sha1;
+#[cfg(feature = "windows")]
+pub use windows::Windows;
+#[cfg(feature = "without_fields")]
+pub use without_fields::WithoutFields;
+#[cfg(feature = "zip")]
+pub use zip::Zip;
 
 #[must_use]
 pub fn all() -> Vec<Box<dyn vrl::Function>> {
     vec![
+        #[cfg(feature = "abbreviate_number")]
+        Box::new(AbbreviateNumber),
+        #[cfg(feature = "abs")]
+        Box::new(Abs),
         #[cfg(feature = "append")]
         Box::new(Append),
         #[cfg(feature = "array")]
         Box::new(Array),
+        #[cfg(feature = "array_flatten")]
+        Box::new(ArrayFlatten),
+        #[cfg(feature = "array_rotate")]
+        Box::new(ArrayRotate),
         #[cfg(feature = "assert")]
         Box::new(Assert),
         #[cfg(feature = "assert_eq")]
         Box::new(AssertEq),
         #[cfg(feature = "boolean")]
         Box::new(Boolean),
+        #[cfg(feature = "byte_size")]
+        Box::new(ByteSize),
         #[cfg(feature = "ceil")]
         Box::new(Ceil),
+        #[cfg(feature = "center")]
+        Box::new(Center),
         #[cfg(feature = "chunks")]
         Box::new(Chunks),
+        #[cfg(feature = "clamp")]
+        Box::new(Clamp),
         #[cfg(feature = "compact")]
         Box::new(Compact),
         #[cfg(feature = "contains")]
         Box::new(Contains),
+        #[cfg(feature = "convert_timezone")]
+        Box::new(ConvertTimezone),
         #[cfg(feature = "decode_base64")]
         Box::new(DecodeBase64),
+        #[cfg(feature = "decode_charset")]
+        Box::new(DecodeCharset),
+        #[cfg(feature = "decode_gzip")]
+        Box::new(DecodeGzip),
         #[cfg(feature = "decode_percent")]
         Box::new(DecodePercent),
         #[cfg(feature = "decode_mime_q")]
         Box::new(DecodeMimeQ),
+        #[cfg(feature = "decode_zlib")]
+        Box::new(DecodeZlib),
+        #[cfg(feature = "decode_zstd")]
+        Box::new(DecodeZstd),
         #[cfg(feature = "decrypt")]
         Box::new(Decrypt),
         #[cfg(feature = "del")]
         Box::new(Del),
+        #[cfg(feature = "difference")]
+        Box::new(Difference),
+        #[cfg(feature = "dns_lookup")]
+        Box::new(DnsLookup),
         #[cfg(feature = "downcase")]
         Box::new(Downcase),
         #[cfg(feature = "encode_base64")]
         Box::new(EncodeBase64),
+        #[cfg(feature = "encode_charset")]
+        Box::new(EncodeCharset),
+        #[cfg(feature = "encode_gzip")]
+        Box::new(EncodeGzip),
         #[cfg(feature = "encode_json")]
         Box::new(EncodeJson),
         #[cfg(feature = "encode_key_value")]
@@ -650,16 +944,26 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(EncodeLogfmt),
         #[cfg(feature = "encode_percent")]
         Box::new(EncodePercent),
+        #[cfg(feature = "encode_zlib")]
+        Box::new(EncodeZlib),
+        #[cfg(feature = "encode_zstd")]
+        Box::new(EncodeZstd),
         #[cfg(feature = "encrypt")]
         Box::new(Encrypt),
         #[cfg(feature = "ends_with")]
         Box::new(EndsWith),
+        #[cfg(feature = "enumerate")]
+        Box::new(Enumerate),
         #[cfg(feature = "exists")]
         Box::new(Exists),
         #[cfg(feature = "filter")]
         Box::new(Filter),
         #[cfg(feature = "find")]
         Box::new(Find),
+        #[cfg(feature = "find_all")]
+        Box::new(FindAll),
+        #[cfg(feature = "find_first")]
+        Box::new(FindFirst),
         #[cfg(feature = "flatten")]
         Box::new(Flatten),
         #[cfg(feature = "float")]
@@ -680,10 +984,18 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(GetEnvVar),
         #[cfg(feature = "get_hostname")]
         Box::new(GetHostname),
+        #[cfg(feature = "grapheme_length")]
+        Box::new(GraphemeLength),
+        #[cfg(feature = "group_by")]
+        Box::new(GroupBy),
+        #[cfg(feature = "hash")]
+        Box::new(Hash),
         #[cfg(feature = "includes")]
         Box::new(Includes),
         #[cfg(feature = "integer")]
         Box::new(Integer),
+        #[cfg(feature = "intersection")]
+        Box::new(Intersection),
         #[cfg(feature = "ip_aton")]
         Box::new(IpAton),
         #[cfg(feature = "ip_cidr_contains")]
@@ -700,8 +1012,12 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(IpToIpv6),
         #[cfg(feature = "ipv6_to_ipv4")]
         Box::new(Ipv6ToIpV4),
+        #[cfg(feature = "is_after")]
+        Box::new(IsAfter),
         #[cfg(feature = "is_array")]
         Box::new(IsArray),
+        #[cfg(feature = "is_before")]
+        Box::new(IsBefore),
         #[cfg(feature = "is_boolean")]
         Box::new(IsBoolean),
         #[cfg(feature = "is_empty")]
@@ -710,6 +1026,8 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(IsFloat),
         #[cfg(feature = "is_integer")]
         Box::new(IsInteger),
+        #[cfg(feature = "is_ip")]
+        Box::new(IsIp),
         #[cfg(feature = "is_ipv4")]
         Box::new(IsIpv4),
         #[cfg(feature = "is_ipv6")]
@@ -728,14 +1046,20 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(IsString),
         #[cfg(feature = "is_timestamp")]
         Box::new(IsTimestamp),
+        #[cfg(feature = "jaro_winkler")]
+        Box::new(JaroWinkler),
         #[cfg(feature = "join")]
         Box::new(Join),
         #[cfg(feature = "keys")]
         Box::new(Keys),
         #[cfg(feature = "length")]
         Box::new(Length),
+        #[cfg(feature = "levenshtein")]
+        Box::new(Levenshtein),
         #[cfg(feature = "log")]
         Box::new(Log),
+        #[cfg(feature = "logarithm")]
+        Box::new(Logarithm),
         #[cfg(feature = "map_keys")]
         Box::new(MapKeys),
         #[cfg(feature = "map_values")]
@@ -748,24 +1072,38 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(MatchArray),
         #[cfg(feature = "match_datadog_query")]
         Box::new(MatchDatadogQuery),
+        #[cfg(feature = "max")]
+        Box::new(Max),
         #[cfg(feature = "md5")]
         Box::new(Md5),
         #[cfg(feature = "merge")]
         Box::new(Merge),
+        #[cfg(feature = "min")]
+        Box::new(Min),
         #[cfg(feature = "mod")]
         Box::new(Mod),
         #[cfg(feature = "now")]
         Box::new(Now),
         // We are not sure if this is the way we want to expose this functionality yet
         // https://github.com/vectordotdev/vector/issues/5607
-        //#[cfg(feature = "only_fields")]
-        //Box::new(OnlyFields),
+        #[cfg(feature = "object_from_entries")]
+        Box::new(ObjectFromEntries),
+        #[cfg(feature = "ok")]
+        Box::new(OkResult),
         #[cfg(feature = "object")]
         Box::new(Object),
+        #[cfg(feature = "only_fields")]
+        Box::new(OnlyFields),
+        #[cfg(feature = "pad_end")]
+        Box::new(PadEnd),
+        #[cfg(feature = "pad_start")]
+        Box::new(PadStart),
         #[cfg(feature = "parse_apache_log")]
         Box::new(ParseApacheLog),
         #[cfg(feature = "parse_aws_alb_log")]
         Box::new(ParseAwsAlbLog),
+        #[cfg(feature = "parse_aws_cloudfront_log")]
+        Box::new(ParseAwsCloudfrontLog),
         #[cfg(feature = "parse_aws_cloudwatch_log_subscription_message")]
         Box::new(ParseAwsCloudWatchLogSubscriptionMessage),
         #[cfg(feature = "parse_aws_vpc_flow_log")]
@@ -776,6 +1114,12 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(ParseCommonLog),
         #[cfg(feature = "parse_csv")]
         Box::new(ParseCsv),
+        #[cfg(feature = "parse_csv_line")]
+        Box::new(ParseCsvLine),
+        #[cfg(feature = "parse_csv_rows")]
+        Box::new(ParseCsvRows),
+        #[cfg(feature = "parse_domain")]
+        Box::new(ParseDomain),
         #[cfg(feature = "parse_duration")]
         Box::new(ParseDuration),
         #[cfg(feature = "parse_glog")]
@@ -818,8 +1162,18 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(ParseUserAgent),
         #[cfg(feature = "parse_xml")]
         Box::new(ParseXml),
+        #[cfg(feature = "path_exists")]
+        Box::new(PathExists),
+        #[cfg(feature = "percentage")]
+        Box::new(Percentage),
+        #[cfg(feature = "pow")]
+        Box::new(Pow),
         #[cfg(feature = "push")]
         Box::new(Push),
+        #[cfg(feature = "query")]
+        Box::new(Query),
+        #[cfg(feature = "random_bool")]
+        Box::new(RandomBool),
         #[cfg(feature = "random_bytes")]
         Box::new(RandomBytes),
         #[cfg(feature = "redact")]
@@ -828,10 +1182,18 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(Remove),
         #[cfg(feature = "replace")]
         Box::new(Replace),
+        #[cfg(feature = "replace_with")]
+        Box::new(ReplaceWith),
+        #[cfg(feature = "reverse")]
+        Box::new(Reverse),
         #[cfg(feature = "reverse_dns")]
         Box::new(ReverseDns),
         #[cfg(feature = "round")]
         Box::new(Round),
+        #[cfg(feature = "safe_divide")]
+        Box::new(SafeDivide),
+        #[cfg(feature = "sample")]
+        Box::new(Sample),
         #[cfg(feature = "set")]
         Box::new(Set),
         #[cfg(feature = "sha1")]
@@ -840,14 +1202,26 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(Sha2),
         #[cfg(feature = "sha3")]
         Box::new(Sha3),
+        #[cfg(feature = "signum")]
+        Box::new(Signum),
         #[cfg(feature = "slice")]
         Box::new(Slice),
+        #[cfg(feature = "slugify")]
+        Box::new(Slugify),
+        #[cfg(feature = "sort")]
+        Box::new(Sort),
+        #[cfg(feature = "sort_by")]
+        Box::new(SortBy),
         #[cfg(feature = "split")]
         Box::new(Split),
+        #[cfg(feature = "sqrt")]
+        Box::new(Sqrt),
         #[cfg(feature = "starts_with")]
         Box::new(StartsWith),
         #[cfg(feature = "string")]
         Box::new(String),
+        #[cfg(feature = "string_repeat")]
+        Box::new(StringRepeat),
         #[cfg(feature = "strip_ansi_escape_codes")]
         Box::new(StripAnsiEscapeCodes),
         #[cfg(feature = "strip_whitespace")]
@@ -856,10 +1230,14 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(Strlen),
         #[cfg(feature = "tally")]
         Box::new(Tally),
+        #[cfg(feature = "tally_by")]
+        Box::new(TallyBy),
         #[cfg(feature = "tally_value")]
         Box::new(TallyValue),
         #[cfg(feature = "tag_types_externally")]
         Box::new(TagTypesExternally),
+        #[cfg(feature = "time_diff")]
+        Box::new(TimeDiff),
         #[cfg(feature = "timestamp")]
         Box::new(Timestamp),
         #[cfg(feature = "to_bool")]
@@ -886,15 +1264,58 @@ pub fn all() -> Vec<Box<dyn vrl::Function>> {
         Box::new(Truncate),
         #[cfg(feature = "type_def")]
         Box::new(TypeDef),
+        #[cfg(feature = "union")]
+        Box::new(Union),
         #[cfg(feature = "unique")]
         Box::new(Unique),
+        #[cfg(feature = "unique_by")]
+        Box::new(UniqueBy),
         #[cfg(feature = "unnest")]
         Box::new(Unnest),
+        #[cfg(feature = "unwrap_or")]
+        Box::new(UnwrapOr),
         #[cfg(feature = "upcase")]
         Box::new(Upcase),
         #[cfg(feature = "uuid_v4")]
         Box::new(UuidV4),
         #[cfg(feature = "values")]
         Box::new(Values),
+        #[cfg(feature = "windows")]
+        Box::new(Windows),
+        #[cfg(feature = "without_fields")]
+        Box::new(WithoutFields),
+        #[cfg(feature = "zip")]
+        Box::new(Zip),
     ]
 }
+
+/// All enabled functions for which `predicate` returns `true`.
+///
+/// This filters the functions at runtime, functions can be excluded from the build entirely by
+/// disabling their feature instead.
+#[must_use]
+pub fn functions_matching(
+    predicate: impl Fn(&dyn vrl::Function) -> bool,
+) -> Vec<Box<dyn vrl::Function>> {
+    all()
+        .into_iter()
+        .filter(|function| predicate(function.as_ref()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "get_env_var", feature = "get_hostname"))]
+    fn functions_matching_filters_all() {
+        let excluded = ["get_env_var", "get_hostname"];
+        let functions = functions_matching(|function| !excluded.contains(&function.identifier()));
+
+        assert_eq!(functions.len(), all().len() - excluded.len());
+        assert!(functions
+            .iter()
+            .all(|function| !excluded.contains(&function.identifier())));
+    }
+}
@@ -0,0 +1,133 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn logarithm(value: Value, base: Value) -> Resolved {
+    let value = value.try_into_f64()?;
+    let base = base.try_into_f64()?;
+    if value <= 0.0 {
+        return Err("can't take the logarithm of a number that isn't positive".into());
+    }
+    if base <= 0.0 || (base - 1.0).abs() < f64::EPSILON {
+        return Err("logarithm base must be positive and not 1".into());
+    }
+
+    // The dedicated functions are exact for powers of their base, which `log` isn't.
+    let result = if (base - 2.0).abs() < f64::EPSILON {
+        value.log2()
+    } else if (base - 10.0).abs() < f64::EPSILON {
+        value.log10()
+    } else {
+        value.log(base)
+    };
+
+    Ok(Value::from_f64_or_zero(result))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Logarithm;
+
+impl Function for Logarithm {
+    fn identifier(&self) -> &'static str {
+        "logarithm"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "base",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "base 10",
+                source: r#"logarithm!(1000, 10)"#,
+                result: Ok("3.0"),
+            },
+            Example {
+                title: "base 2",
+                source: r#"logarithm!(0.25, 2)"#,
+                result: Ok("-2.0"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let base = arguments.required("base");
+
+        Ok(LogarithmFn { value, base }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LogarithmFn {
+    value: Box<dyn Expression>,
+    base: Box<dyn Expression>,
+}
+
+impl FunctionExpression for LogarithmFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let base = self.base.resolve(ctx)?;
+
+        logarithm(value, base)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::float().fallible(/* value or base out of range */)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        logarithm => Logarithm;
+
+        base_2 {
+            args: func_args![value: 8, base: 2],
+            want: Ok(value!(3.0)),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        fractional_base {
+            args: func_args![value: 4.0, base: 0.5],
+            want: Ok(value!(-2.0)),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        zero {
+            args: func_args![value: 0, base: 10],
+            want: Err("can't take the logarithm of a number that isn't positive"),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        negative {
+            args: func_args![value: -1.5, base: 10],
+            want: Err("can't take the logarithm of a number that isn't positive"),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        base_one {
+            args: func_args![value: 10, base: 1],
+            want: Err("logarithm base must be positive and not 1"),
+            tdef: TypeDef::float().fallible(),
+        }
+    ];
+}
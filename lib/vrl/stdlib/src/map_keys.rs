@@ -100,6 +100,7 @@ impl Function for MapKeys {
                 },
             }],
             is_iterator: true,
+            is_optional: false,
         })
     }
 }
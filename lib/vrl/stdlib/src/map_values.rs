@@ -99,6 +99,7 @@ impl Function for MapValues {
                 },
             }],
             is_iterator: true,
+            is_optional: false,
         })
     }
 }
@@ -0,0 +1,155 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::util::{numeric_extremum, numeric_extremum_type_def};
+
+fn max(value: Value, other: Option<Value>) -> Resolved {
+    let mut values = match value {
+        Value::Array(values) => values,
+        value => vec![value],
+    };
+    values.extend(other);
+
+    Ok(numeric_extremum(&values, true)?)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Max;
+
+impl Function for Max {
+    fn identifier(&self) -> &'static str {
+        "max"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY | kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "other",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "two integers",
+                source: r#"max(3, 1)"#,
+                result: Ok("3"),
+            },
+            Example {
+                title: "integer and float",
+                source: r#"max(3, 1.5)"#,
+                result: Ok("3.0"),
+            },
+            Example {
+                title: "array",
+                source: r#"max!([4, 2, 8])"#,
+                result: Ok("8"),
+            },
+            Example {
+                title: "mixed array",
+                source: r#"max!([4, 2, 8.5])"#,
+                result: Ok("8.5"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let other = arguments.optional("other");
+
+        Ok(MaxFn { value, other }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MaxFn {
+    value: Box<dyn Expression>,
+    other: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for MaxFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let other = self
+            .other
+            .as_ref()
+            .map(|expr| expr.resolve(ctx))
+            .transpose()?;
+
+        max(value, other)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        numeric_extremum_type_def(self.value.as_ref(), self.other.as_deref(), state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        max => Max;
+
+        integers {
+            args: func_args![value: 3, other: 1],
+            want: Ok(value!(3)),
+            tdef: TypeDef::integer(),
+        }
+
+        floats {
+            args: func_args![value: 3.5, other: 1.5],
+            want: Ok(value!(3.5)),
+            tdef: TypeDef::float(),
+        }
+
+        mixed {
+            args: func_args![value: 1, other: 1.5],
+            want: Ok(value!(1.5)),
+            tdef: TypeDef::float(),
+        }
+
+        array {
+            args: func_args![value: value!([4, 2, 8])],
+            want: Ok(value!(8)),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        mixed_array {
+            args: func_args![value: value!([4, 2, 8.5])],
+            want: Ok(value!(8.5)),
+            tdef: TypeDef::integer().or_float().fallible(),
+        }
+
+        array_and_other {
+            args: func_args![value: value!([4, 2]), other: 1],
+            want: Ok(value!(4)),
+            tdef: TypeDef::integer(),
+        }
+
+        empty_array {
+            args: func_args![value: value!([])],
+            want: Err("expected at least one number"),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        non_numeric_array {
+            args: func_args![value: value!([1, "2"])],
+            want: Err("expected integer or float, got string"),
+            tdef: TypeDef::integer().or_float().fallible(),
+        }
+    ];
+}
@@ -1,6 +1,14 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use vrl::prelude::*;
 
+/// A fixed "current time" for `now()` to return instead of reading the wall clock.
+///
+/// Set this as external context (see `CompileConfig::set_custom`) to make programs that stamp
+/// timestamps deterministic, e.g. in unit tests. This only affects `now()`; other functions that
+/// depend on the current time, such as the year inference in `parse_syslog`, are unaffected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MockTime(pub DateTime<Utc>);
+
 #[derive(Clone, Copy, Debug)]
 pub struct Now;
 
@@ -20,19 +28,25 @@ impl Function for Now {
     fn compile(
         &self,
         _state: &state::TypeState,
-        _ctx: &mut FunctionCompileContext,
+        ctx: &mut FunctionCompileContext,
         _: ArgumentList,
     ) -> Compiled {
-        Ok(NowFn.as_expr())
+        let mock_time = ctx
+            .get_external_context::<MockTime>()
+            .map(|mock_time| mock_time.0);
+
+        Ok(NowFn { mock_time }.as_expr())
     }
 }
 
 #[derive(Debug, Clone)]
-struct NowFn;
+struct NowFn {
+    mock_time: Option<DateTime<Utc>>,
+}
 
 impl FunctionExpression for NowFn {
     fn resolve(&self, _: &mut Context) -> Resolved {
-        Ok(Utc::now().into())
+        Ok(self.mock_time.unwrap_or_else(Utc::now).into())
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
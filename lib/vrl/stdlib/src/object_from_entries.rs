@@ -0,0 +1,256 @@
+use std::{collections::btree_map::Entry, str::FromStr};
+
+use ::value::Value;
+use vrl::prelude::*;
+
+fn object_from_entries(value: Value, on_duplicate: OnDuplicate) -> Resolved {
+    let mut object = BTreeMap::new();
+
+    for (index, entry) in value.try_array()?.into_iter().enumerate() {
+        let (key, value) = split_entry(entry).map_err(|err| format!("entry {}: {}", index, err))?;
+
+        match object.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+            Entry::Occupied(mut entry) => match on_duplicate {
+                OnDuplicate::Error => {
+                    return Err(format!("entry {}: duplicate key {:?}", index, entry.key()).into())
+                }
+                OnDuplicate::LastWins => {
+                    entry.insert(value);
+                }
+                OnDuplicate::FirstWins => {}
+            },
+        }
+    }
+
+    Ok(object.into())
+}
+
+/// Splits an entry into its key and value. Entries are either `[key, value]` arrays or
+/// `{ "key": key, "value": value }` objects, mirroring the output of `entries`.
+fn split_entry(entry: Value) -> std::result::Result<(String, Value), String> {
+    let (key, value) = match entry {
+        Value::Array(mut pair) if pair.len() == 2 => {
+            let value = pair.pop().expect("pair has two elements");
+            let key = pair.pop().expect("pair has two elements");
+            (key, value)
+        }
+        Value::Object(mut object)
+            if object.len() == 2 && object.contains_key("key") && object.contains_key("value") =>
+        {
+            let key = object.remove("key").expect("key exists");
+            let value = object.remove("value").expect("value exists");
+            (key, value)
+        }
+        other => {
+            return Err(format!(
+                "expected a [key, value] array or a {{key, value}} object, got {}",
+                other.kind()
+            ))
+        }
+    };
+
+    match key {
+        Value::Bytes(bytes) => Ok((String::from_utf8_lossy(&bytes).into_owned(), value)),
+        other => Err(format!("expected key to be a string, got {}", other.kind())),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ObjectFromEntries;
+
+impl Function for ObjectFromEntries {
+    fn identifier(&self) -> &'static str {
+        "object_from_entries"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "on_duplicate",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "pairs",
+                source: r#"object_from_entries([["foo", 1], ["bar", true]])"#,
+                result: Ok(r#"{ "bar": true, "foo": 1 }"#),
+            },
+            Example {
+                title: "key/value objects",
+                source: r#"object_from_entries([{ "key": "foo", "value": 1 }])"#,
+                result: Ok(r#"{ "foo": 1 }"#),
+            },
+            Example {
+                title: "last wins",
+                source: r#"object_from_entries([["foo", 1], ["foo", 2]], on_duplicate: "last_wins")"#,
+                result: Ok(r#"{ "foo": 2 }"#),
+            },
+            Example {
+                title: "duplicate error",
+                source: r#"object_from_entries!([["foo", 1], ["foo", 2]])"#,
+                result: Err(
+                    r#"function call error for "object_from_entries" at (0:46): entry 1: duplicate key "foo""#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let on_duplicate = arguments
+            .optional_enum("on_duplicate", OnDuplicate::all_value().as_slice())?
+            .map(|s| {
+                OnDuplicate::from_str(&s.try_bytes_utf8_lossy().expect("policy not bytes"))
+                    .expect("validated enum")
+            })
+            .unwrap_or_default();
+
+        Ok(ObjectFromEntriesFn {
+            value,
+            on_duplicate,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OnDuplicate {
+    Error,
+    LastWins,
+    FirstWins,
+}
+
+impl OnDuplicate {
+    fn all_value() -> Vec<Value> {
+        use OnDuplicate::{Error, FirstWins, LastWins};
+
+        vec![Error, LastWins, FirstWins]
+            .into_iter()
+            .map(|p| p.as_str().into())
+            .collect::<Vec<_>>()
+    }
+
+    const fn as_str(self) -> &'static str {
+        use OnDuplicate::{Error, FirstWins, LastWins};
+
+        match self {
+            Error => "error",
+            LastWins => "last_wins",
+            FirstWins => "first_wins",
+        }
+    }
+}
+
+impl Default for OnDuplicate {
+    fn default() -> Self {
+        OnDuplicate::Error
+    }
+}
+
+impl FromStr for OnDuplicate {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        use OnDuplicate::{Error, FirstWins, LastWins};
+
+        match s {
+            "error" => Ok(Error),
+            "last_wins" => Ok(LastWins),
+            "first_wins" => Ok(FirstWins),
+            _ => Err("policy not recognized"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ObjectFromEntriesFn {
+    value: Box<dyn Expression>,
+    on_duplicate: OnDuplicate,
+}
+
+impl FunctionExpression for ObjectFromEntriesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        object_from_entries(value, self.on_duplicate)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::object(Collection::any()).fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        object_from_entries => ObjectFromEntries;
+
+        pairs {
+            args: func_args![value: value!([["foo", 1], ["bar", "baz"]])],
+            want: Ok(value!({ "foo": 1, "bar": "baz" })),
+            tdef: TypeDef::object(Collection::any()).fallible(),
+        }
+
+        key_value_objects {
+            args: func_args![value: value!([{ "key": "foo", "value": [1, 2] }])],
+            want: Ok(value!({ "foo": [1, 2] })),
+            tdef: TypeDef::object(Collection::any()).fallible(),
+        }
+
+        empty {
+            args: func_args![value: value!([])],
+            want: Ok(value!({})),
+            tdef: TypeDef::object(Collection::any()).fallible(),
+        }
+
+        duplicate_error {
+            args: func_args![value: value!([["foo", 1], ["foo", 2]])],
+            want: Err(r#"entry 1: duplicate key "foo""#),
+            tdef: TypeDef::object(Collection::any()).fallible(),
+        }
+
+        duplicate_last_wins {
+            args: func_args![value: value!([["foo", 1], ["foo", 2]]), on_duplicate: "last_wins"],
+            want: Ok(value!({ "foo": 2 })),
+            tdef: TypeDef::object(Collection::any()).fallible(),
+        }
+
+        duplicate_first_wins {
+            args: func_args![value: value!([["foo", 1], ["foo", 2]]), on_duplicate: "first_wins"],
+            want: Ok(value!({ "foo": 1 })),
+            tdef: TypeDef::object(Collection::any()).fallible(),
+        }
+
+        non_string_key {
+            args: func_args![value: value!([[1, 2]])],
+            want: Err("entry 0: expected key to be a string, got integer"),
+            tdef: TypeDef::object(Collection::any()).fallible(),
+        }
+
+        malformed_entry {
+            args: func_args![value: value!([["foo"]])],
+            want: Err("entry 0: expected a [key, value] array or a {key, value} object, got array"),
+            tdef: TypeDef::object(Collection::any()).fallible(),
+        }
+    ];
+}
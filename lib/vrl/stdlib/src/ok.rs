@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+
+use ::value::Value;
+use vrl::prelude::*;
+
+fn ok(value: Resolved) -> Resolved {
+    let (value, error) = match value {
+        Ok(value) => (value, Value::Null),
+        Err(error @ ExpressionError::Error { .. }) => (Value::Null, error.to_string().into()),
+        // Aborts terminate the program, they are not errors of the expression.
+        Err(error) => return Err(error),
+    };
+
+    Ok(BTreeMap::from([("value".to_owned(), value), ("error".to_owned(), error)]).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct OkResult;
+
+impl Function for OkResult {
+    fn identifier(&self) -> &'static str {
+        "ok"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ANY,
+            required: true,
+        }]
+    }
+
+    fn accepts_fallible_argument(&self, keyword: &'static str) -> bool {
+        keyword == "value"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "success",
+                source: r#"ok(parse_json("[1]"))"#,
+                result: Ok(r#"{ "value": [1], "error": null }"#),
+            },
+            Example {
+                title: "error",
+                source: r#"ok(assert(1 == 2, "unexpected value"))"#,
+                result: Ok(r#"{ "value": null, "error": "unexpected value" }"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(OkResultFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct OkResultFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for OkResultFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx);
+        if let Err(ExpressionError::Error { .. }) = value {
+            ctx.state_mut()
+                .count(|counters| counters.handled_errors += 1);
+        }
+
+        ok(value)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let value = self.value.type_def(state);
+        let error = if value.is_fallible() {
+            Kind::bytes().or_null()
+        } else {
+            Kind::null()
+        };
+
+        TypeDef::object(BTreeMap::from([
+            (Field::from("value"), value.kind().clone().or_null()),
+            (Field::from("error"), error),
+        ]))
+        .infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        ok => OkResult;
+
+        value {
+            args: func_args![value: "foo"],
+            want: Ok(value!({ "value": "foo", "error": null })),
+            tdef: TypeDef::object(BTreeMap::from([
+                (Field::from("value"), Kind::bytes().or_null()),
+                (Field::from("error"), Kind::null()),
+            ])),
+        }
+    ];
+
+    #[test]
+    fn error() {
+        let error = ExpressionError::from("oops");
+
+        assert_eq!(
+            ok(Err(error)).unwrap(),
+            value!({ "value": null, "error": "oops" })
+        );
+    }
+
+    #[test]
+    fn memory_limit_is_not_handled() {
+        let error = ExpressionError::MemoryLimitExceeded { limit: 1 };
+
+        assert!(ok(Err(error)).is_err());
+    }
+}
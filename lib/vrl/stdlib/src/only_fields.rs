@@ -1,5 +1,23 @@
+use std::collections::BTreeMap;
+
+use ::value::Value;
+use lookup_lib::lookup_v2::OwnedValuePath;
 use vrl::prelude::*;
 
+use crate::util::parse_field_paths;
+
+fn only_fields(value: &Value, paths: &[OwnedValuePath]) -> Value {
+    let mut result = Value::Object(BTreeMap::new());
+
+    for path in paths {
+        if let Some(field) = value.get(path) {
+            result.insert(path, field.clone());
+        }
+    }
+
+    result
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct OnlyFields;
 
@@ -9,59 +27,91 @@ impl Function for OnlyFields {
     }
 
     fn parameters(&self) -> &'static [Parameter] {
-        generate_param_list! {
-            accepts = |_| true,
-            required = false,
-            keywords = [
-                "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16",
-            ],
-        }
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "paths",
+                kind: kind::ARRAY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "top-level fields",
+                source: r#"only_fields({ "message": "hi", "host": "a", "pid": 1 }, ["message", "host"])"#,
+                result: Ok(r#"{ "host": "a", "message": "hi" }"#),
+            },
+            Example {
+                title: "nested fields",
+                source: r#"only_fields({ "user": { "id": 1, "email": "a@b.c" }, "pid": 1 }, ["user.id", "missing"])"#,
+                result: Ok(r#"{ "user": { "id": 1 } }"#),
+            },
+        ]
     }
 
     fn compile(
         &self,
         _state: &state::TypeState,
         _ctx: &mut FunctionCompileContext,
-        mut arguments: ArgumentList,
+        arguments: ArgumentList,
     ) -> Compiled {
-        let mut paths = vec![];
-        paths.push(arguments.required_path("1")?);
+        let value = arguments.required("value");
+        let paths = arguments.required("paths");
 
-        for i in 2..=16 {
-            if let Some(path) = arguments.optional_path(&i.to_string())? {
-                paths.push(path)
-            }
-        }
+        // Literal paths are parsed once here, rather than for every event.
+        let parsed = match paths.as_value() {
+            Some(literal) => match parse_field_paths(&literal) {
+                Ok(parsed) => Some(parsed),
+                Err(_) => {
+                    return Err(vrl::function::Error::InvalidArgument {
+                        keyword: "paths",
+                        value: literal,
+                        error: "must be an array of valid field paths",
+                    }
+                    .into())
+                }
+            },
+            None => None,
+        };
 
-        Ok(OnlyFieldsFn { paths }.as_expr())
+        Ok(OnlyFieldsFn {
+            value,
+            paths,
+            parsed,
+        }
+        .as_expr())
     }
 }
 
 #[derive(Debug, Clone)]
-pub struct OnlyFieldsFn {
-    paths: Vec<Path>,
+struct OnlyFieldsFn {
+    value: Box<dyn Expression>,
+    paths: Box<dyn Expression>,
+    parsed: Option<Vec<OwnedValuePath>>,
 }
 
 impl FunctionExpression for OnlyFieldsFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
-        let paths = self.paths.iter().map(Path::to_string).collect::<Vec<_>>();
+        let value = self.value.resolve(ctx)?;
 
-        object
-            .paths()?
-            .iter()
-            .map(|path| (path, path.to_string()))
-            .filter(|(_, key)| paths.iter().find(|p| key.starts_with(p.as_str())).is_none())
-            .try_for_each(|(path, _)| object.remove(&path, true).map(|_| ()))?;
-
-        Ok(Value::Null)
+        match &self.parsed {
+            Some(paths) => Ok(only_fields(&value, paths)),
+            None => {
+                let paths = parse_field_paths(&self.paths.resolve(ctx)?)?;
+                Ok(only_fields(&value, &paths))
+            }
+        }
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
-        TypeDef {
-            fallible: true,
-            kind: value::Kind::null(),
-            ..Default::default()
-        }
+        TypeDef::object(Collection::any()).with_fallibility(self.parsed.is_none())
     }
 }
 
@@ -69,14 +119,52 @@ impl FunctionExpression for OnlyFieldsFn {
 mod tests {
     use super::*;
 
-    test_type_def![static_type_def {
-        expr: |_| OnlyFieldsFn {
-            paths: vec![Path::from("foo")]
-        },
-        def: TypeDef {
-            fallible: true,
-            kind: value::Kind::null(),
-            ..Default::default()
-        },
-    }];
+    test_function![
+        only_fields => OnlyFields;
+
+        top_level {
+            args: func_args![
+                value: value!({ "message": "hi", "host": "a", "pid": 1 }),
+                paths: value!(["message", "host"]),
+            ],
+            want: Ok(value!({ "message": "hi", "host": "a" })),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        nested {
+            args: func_args![
+                value: value!({ "user": { "id": 1, "email": "a@b.c" }, "tags": ["a", "b"] }),
+                paths: value!(["user.id", "tags[1]", "missing.field"]),
+            ],
+            want: Ok(value!({ "user": { "id": 1 }, "tags": [null, "b"] })),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        quoted {
+            args: func_args![
+                value: value!({ "a.b": 1, "a": { "b": 2 } }),
+                paths: value!([r#""a.b""#]),
+            ],
+            want: Ok(value!({ "a.b": 1 })),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        invalid_path {
+            args: func_args![
+                value: value!({}),
+                paths: value!(["foo$"]),
+            ],
+            want: Err("invalid argument"),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        non_string_path {
+            args: func_args![
+                value: value!({}),
+                paths: value!([1]),
+            ],
+            want: Err("invalid argument"),
+            tdef: TypeDef::object(Collection::any()),
+        }
+    ];
 }
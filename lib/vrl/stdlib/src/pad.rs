@@ -0,0 +1,300 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::util;
+
+const PARAMETERS: &[Parameter] = &[
+    Parameter {
+        keyword: "value",
+        kind: kind::BYTES,
+        required: true,
+    },
+    Parameter {
+        keyword: "length",
+        kind: kind::INTEGER,
+        required: true,
+    },
+    Parameter {
+        keyword: "pad",
+        kind: kind::BYTES,
+        required: false,
+    },
+];
+
+fn pad(value: Value, length: Value, pad: Value, at_start: bool) -> Resolved {
+    let value = value.try_bytes_utf8_lossy()?;
+    let length = util::pad_length(length.try_integer()?)?;
+    let pad = pad.try_bytes_utf8_lossy()?;
+    if pad.is_empty() {
+        return Err("pad can't be empty".into());
+    }
+
+    Ok(util::pad(&value, length, &pad, at_start).into())
+}
+
+/// Compiles `pad_start` or `pad_end`, which only differ in the side they pad.
+fn compile(arguments: ArgumentList, at_start: bool) -> Compiled {
+    let value = arguments.required("value");
+    let length = arguments.required("length");
+    let pad = arguments.optional("pad").unwrap_or_else(|| expr!(" "));
+
+    if let Some(literal) = length.as_value() {
+        let length = literal.try_integer().expect("length must be an integer");
+
+        if let Err(error) = util::pad_length(length) {
+            return Err(vrl::function::Error::InvalidArgument {
+                keyword: "length",
+                value: literal,
+                error,
+            }
+            .into());
+        }
+    }
+
+    if let Some(literal) = pad.as_value() {
+        if literal.as_bytes().map_or(false, Bytes::is_empty) {
+            return Err(vrl::function::Error::InvalidArgument {
+                keyword: "pad",
+                value: literal,
+                error: "pad can't be empty",
+            }
+            .into());
+        }
+    }
+
+    Ok(PadFn {
+        value,
+        length,
+        pad,
+        at_start,
+    }
+    .as_expr())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PadStart;
+
+impl Function for PadStart {
+    fn identifier(&self) -> &'static str {
+        "pad_start"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "pad with zeros",
+                source: r#"pad_start("5", 3, "0")"#,
+                result: Ok("005"),
+            },
+            Example {
+                title: "default pad",
+                source: r#"pad_start("abc", 6)"#,
+                result: Ok("   abc"),
+            },
+            Example {
+                title: "multi-character pad",
+                source: r#"pad_start("7", 4, "ab")"#,
+                result: Ok("aba7"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, true)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PadEnd;
+
+impl Function for PadEnd {
+    fn identifier(&self) -> &'static str {
+        "pad_end"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        PARAMETERS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "pad with zeros",
+                source: r#"pad_end("5", 3, "0")"#,
+                result: Ok("500"),
+            },
+            Example {
+                title: "default pad",
+                source: r#"pad_end("abc", 6)"#,
+                result: Ok("abc   "),
+            },
+            Example {
+                title: "multi-character pad",
+                source: r#"pad_end("7", 4, "ab")"#,
+                result: Ok("7aba"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        compile(arguments, false)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PadFn {
+    value: Box<dyn Expression>,
+    length: Box<dyn Expression>,
+    pad: Box<dyn Expression>,
+    at_start: bool,
+}
+
+impl FunctionExpression for PadFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let length = self.length.resolve(ctx)?;
+        let pad_value = self.pad.resolve(ctx)?;
+
+        pad(value, length, pad_value, self.at_start)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // A constant length and pad are checked at compile time.
+        let checked = self.length.as_value().is_some() && self.pad.as_value().is_some();
+
+        TypeDef::bytes().with_fallibility(!checked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        pad_start => PadStart;
+
+        zeros {
+            args: func_args![value: "42", length: 5, pad: "0"],
+            want: Ok("00042"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        default_pad {
+            args: func_args![value: "foo", length: 5],
+            want: Ok("  foo"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        counts_characters {
+            args: func_args![value: "ñandú", length: 7, pad: "♔"],
+            want: Ok("♔♔ñandú"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        multi_character_pad {
+            args: func_args![value: "x", length: 6, pad: "abc"],
+            want: Ok("abcabx"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        already_long_enough {
+            args: func_args![value: "foobar", length: 3, pad: "0"],
+            want: Ok("foobar"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        negative_length {
+            args: func_args![value: "foo", length: -1],
+            want: Ok("foo"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        empty_pad {
+            args: func_args![value: "foo", length: 5, pad: ""],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        at_limit {
+            args: func_args![value: "", length: 1024 * 1024],
+            want: Ok(" ".repeat(1024 * 1024)),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        too_long {
+            args: func_args![value: "foo", length: 1024 * 1024 + 1],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+
+    test_function![
+        pad_end => PadEnd;
+
+        zeros {
+            args: func_args![value: "42", length: 5, pad: "0"],
+            want: Ok("42000"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        default_pad {
+            args: func_args![value: "foo", length: 5],
+            want: Ok("foo  "),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        counts_characters {
+            args: func_args![value: "ñandú", length: 7, pad: "♔"],
+            want: Ok("ñandú♔♔"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        multi_character_pad {
+            args: func_args![value: "x", length: 6, pad: "abc"],
+            want: Ok("xabcab"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        already_long_enough {
+            args: func_args![value: "foobar", length: 3, pad: "0"],
+            want: Ok("foobar"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        empty_pad {
+            args: func_args![value: "foo", length: 5, pad: ""],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        too_long {
+            args: func_args![value: "foo", length: i64::MAX],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+
+    #[test]
+    fn runtime_limit() {
+        let error = pad("foo".into(), Value::from(1024 * 1024 + 1), " ".into(), true).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "can't pad to more than the maximum of 1Mi characters"
+        );
+    }
+}
@@ -5,6 +5,9 @@ use vrl::prelude::*;
 
 use crate::log_util;
 
+/// The timestamp format Apache httpd uses in its error log, e.g. `Wed Oct 11 14:32:52 2000`.
+const ERROR_LOG_TIMESTAMP_FORMAT: &str = "%a %b %d %H:%M:%S%.f %Y";
+
 fn parse_apache_log(
     bytes: Value,
     timestamp_format: Option<Value>,
@@ -12,24 +15,30 @@ fn parse_apache_log(
     ctx: &Context,
 ) -> Resolved {
     let message = bytes.try_bytes_utf8_lossy()?;
-    let timestamp_format = match timestamp_format {
-        None => "%d/%b/%Y:%T %z".to_owned(),
-        Some(timestamp_format) => timestamp_format.try_bytes_utf8_lossy()?.to_string(),
-    };
     let regexes = match format.as_ref() {
         b"common" => &*log_util::REGEX_APACHE_COMMON_LOG,
         b"combined" => &*log_util::REGEX_APACHE_COMBINED_LOG,
         b"error" => &*log_util::REGEX_APACHE_ERROR_LOG,
         _ => unreachable!(),
     };
+    let parse = |timestamp_format: &str| {
+        log_util::parse_message(
+            regexes,
+            &message,
+            timestamp_format,
+            ctx.timezone(),
+            std::str::from_utf8(format.as_ref()).unwrap(),
+        )
+    };
 
-    log_util::parse_message(
-        regexes,
-        &message,
-        &timestamp_format,
-        ctx.timezone(),
-        std::str::from_utf8(format.as_ref()).unwrap(),
-    )
+    match timestamp_format {
+        Some(timestamp_format) => parse(&timestamp_format.try_bytes_utf8_lossy()?),
+        // Error logs written by Apache itself use their own timestamp format, so fall back to it
+        // when the default doesn't match.
+        None if format.as_ref() == b"error" => parse("%d/%b/%Y:%T %z")
+            .or_else(|error| parse(ERROR_LOG_TIMESTAMP_FORMAT).map_err(|_| error)),
+        None => parse("%d/%b/%Y:%T %z"),
+    }
     .map_err(Into::into)
 }
 
@@ -331,6 +340,24 @@ mod tests {
             tz: vector_common::TimeZone::default(),
         }
 
+        error_line_native_timestamp {
+            args: func_args![value: r#"[Wed Oct 11 14:32:52 2000] [core:error] [pid 35708:tid 4328636416] [client 192.168.1.10:58522] File does not exist: /usr/local/apache2/htdocs/favicon.ico"#,
+                             format: "error"
+                             ],
+            want: Ok(btreemap! {
+                "timestamp" => Value::Timestamp(DateTime::parse_from_rfc3339("2000-10-11T14:32:52Z").unwrap().into()),
+                "message" => "File does not exist: /usr/local/apache2/htdocs/favicon.ico",
+                "module" => "core",
+                "severity" => "error",
+                "pid" => 35708,
+                "thread" => "4328636416",
+                "client" => "192.168.1.10",
+                "port" => 58522
+            }),
+            tdef: TypeDef::object(kind_error()).fallible(),
+            tz: vector_common::TimeZone::Named(chrono_tz::Tz::UTC),
+        }
+
         log_line_valid_empty {
             args: func_args![value: "- - - - - - -",
                              format: "common",
@@ -0,0 +1,283 @@
+use std::collections::BTreeMap;
+
+use ::value::Value;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use vrl::prelude::*;
+
+fn parse_aws_cloudfront_log(value: Value) -> Resolved {
+    let bytes = value.try_bytes()?;
+
+    parse_log(&String::from_utf8_lossy(&bytes)).map_err(Into::into)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseAwsCloudfrontLog;
+
+impl Function for ParseAwsCloudfrontLog {
+    fn identifier(&self) -> &'static str {
+        "parse_aws_cloudfront_log"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "standard log",
+            source: r#"parse_aws_cloudfront_log!("2019-12-04\t21:02:31\tLAX1\t392\t192.0.2.100\tGET\td111111abcdef8.cloudfront.net\t/index.html\t200\t-\tMozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)\t-\t-\tHit\tSOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==\td111111abcdef8.cloudfront.net\thttps\t23\t0.001\t-\tTLSv1.2\tECDHE-RSA-AES128-GCM-SHA256\tHit\tHTTP/2.0\t-\t-\t11040\t0.001\tHit\ttext/html\t78\t-\t-")"#,
+            result: Ok(indoc! { r#"{
+                "timestamp": t'2019-12-04T21:02:31Z',
+                "x_edge_location": "LAX1",
+                "sc_bytes": 392,
+                "c_ip": "192.0.2.100",
+                "cs_method": "GET",
+                "cs_host": "d111111abcdef8.cloudfront.net",
+                "cs_uri_stem": "/index.html",
+                "sc_status": 200,
+                "cs_referer": null,
+                "cs_user_agent": "Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)",
+                "cs_uri_query": null,
+                "cs_cookie": null,
+                "x_edge_result_type": "Hit",
+                "x_edge_request_id": "SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==",
+                "x_host_header": "d111111abcdef8.cloudfront.net",
+                "cs_protocol": "https",
+                "cs_bytes": 23,
+                "time_taken": 0.001,
+                "x_forwarded_for": null,
+                "ssl_protocol": "TLSv1.2",
+                "ssl_cipher": "ECDHE-RSA-AES128-GCM-SHA256",
+                "x_edge_response_result_type": "Hit",
+                "cs_protocol_version": "HTTP/2.0",
+                "fle_status": null,
+                "fle_encrypted_fields": null,
+                "c_port": 11040,
+                "time_to_first_byte": 0.001,
+                "x_edge_detailed_result_type": "Hit",
+                "sc_content_type": "text/html",
+                "sc_content_len": 78,
+                "sc_range_start": null,
+                "sc_range_end": null
+            }"# }),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ParseAwsCloudfrontLogFn { value }.as_expr())
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseAwsCloudfrontLogFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ParseAwsCloudfrontLogFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        parse_aws_cloudfront_log(value)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::object(inner_kind()).fallible(/* log parsing error */)
+    }
+}
+
+fn inner_kind() -> BTreeMap<Field, Kind> {
+    let mut kind = BTreeMap::from([(Field::from("timestamp"), Kind::timestamp() | Kind::null())]);
+
+    for (name, parser) in FIELDS {
+        let field = match parser {
+            FieldParser::Bytes => Kind::bytes(),
+            FieldParser::Integer => Kind::integer(),
+            FieldParser::Float => Kind::float(),
+        };
+
+        kind.insert(Field::from(*name), field | Kind::null());
+    }
+
+    kind
+}
+
+#[derive(Clone, Copy)]
+enum FieldParser {
+    Bytes,
+    Integer,
+    Float,
+}
+
+/// The fields following `date` and `time` in a CloudFront standard log, in order.
+///
+/// The names are the documented field names, in snake case.
+const FIELDS: &[(&str, FieldParser)] = &[
+    ("x_edge_location", FieldParser::Bytes),
+    ("sc_bytes", FieldParser::Integer),
+    ("c_ip", FieldParser::Bytes),
+    ("cs_method", FieldParser::Bytes),
+    ("cs_host", FieldParser::Bytes),
+    ("cs_uri_stem", FieldParser::Bytes),
+    ("sc_status", FieldParser::Integer),
+    ("cs_referer", FieldParser::Bytes),
+    ("cs_user_agent", FieldParser::Bytes),
+    ("cs_uri_query", FieldParser::Bytes),
+    ("cs_cookie", FieldParser::Bytes),
+    ("x_edge_result_type", FieldParser::Bytes),
+    ("x_edge_request_id", FieldParser::Bytes),
+    ("x_host_header", FieldParser::Bytes),
+    ("cs_protocol", FieldParser::Bytes),
+    ("cs_bytes", FieldParser::Integer),
+    ("time_taken", FieldParser::Float),
+    ("x_forwarded_for", FieldParser::Bytes),
+    ("ssl_protocol", FieldParser::Bytes),
+    ("ssl_cipher", FieldParser::Bytes),
+    ("x_edge_response_result_type", FieldParser::Bytes),
+    ("cs_protocol_version", FieldParser::Bytes),
+    ("fle_status", FieldParser::Bytes),
+    ("fle_encrypted_fields", FieldParser::Integer),
+    ("c_port", FieldParser::Integer),
+    ("time_to_first_byte", FieldParser::Float),
+    ("x_edge_detailed_result_type", FieldParser::Bytes),
+    ("sc_content_type", FieldParser::Bytes),
+    ("sc_content_len", FieldParser::Integer),
+    ("sc_range_start", FieldParser::Integer),
+    ("sc_range_end", FieldParser::Integer),
+];
+
+type ParseResult<T> = std::result::Result<T, String>;
+
+fn parse_timestamp(date: &str, time: &str) -> ParseResult<Value> {
+    if date == "-" || time == "-" {
+        return Ok(Value::Null);
+    }
+
+    NaiveDateTime::parse_from_str(&format!("{} {}", date, time), "%Y-%m-%d %H:%M:%S")
+        .map(|timestamp| DateTime::<Utc>::from_utc(timestamp, Utc).into())
+        .map_err(|_| format!("failed to parse timestamp: `{} {}`", date, time))
+}
+
+fn parse_field(key: &str, value: &str, parser: FieldParser) -> ParseResult<Value> {
+    if value == "-" {
+        return Ok(Value::Null);
+    }
+
+    match parser {
+        FieldParser::Bytes => Ok(value.into()),
+        FieldParser::Integer => value
+            .parse::<i64>()
+            .map(Into::into)
+            .map_err(|_| format!("failed to parse value as i64 (key: `{}`): `{}`", key, value)),
+        FieldParser::Float => value
+            .parse::<NotNan<f64>>()
+            .map(Into::into)
+            .map_err(|_| format!("failed to parse value as f64 (key: `{}`): `{}`", key, value)),
+    }
+}
+
+fn parse_log(input: &str) -> ParseResult<Value> {
+    if input.starts_with('#') {
+        return Err("input is a log file header, not a log entry".to_owned());
+    }
+
+    let values = input
+        .trim_end_matches(&['\r', '\n'][..])
+        .split('\t')
+        .collect::<Vec<_>>();
+    if values.len() != FIELDS.len() + 2 {
+        return Err(format!(
+            "expected {} tab-separated fields, found {}",
+            FIELDS.len() + 2,
+            values.len()
+        ));
+    }
+
+    let mut log = BTreeMap::new();
+    log.insert(
+        "timestamp".to_owned(),
+        parse_timestamp(values[0], values[1])?,
+    );
+
+    for ((key, parser), value) in FIELDS.iter().zip(&values[2..]) {
+        log.insert((*key).to_owned(), parse_field(key, value, *parser)?);
+    }
+
+    Ok(log.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOG: &str = "2019-12-04\t21:02:31\tLAX1\t392\t192.0.2.100\tGET\td111111abcdef8.cloudfront.net\t/index.html\t200\t-\tMozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)\t-\t-\tHit\tSOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==\td111111abcdef8.cloudfront.net\thttps\t23\t0.001\t-\tTLSv1.2\tECDHE-RSA-AES128-GCM-SHA256\tHit\tHTTP/2.0\t-\t-\t11040\t0.001\tHit\ttext/html\t78\t-\t-";
+
+    #[test]
+    fn parse_aws_cloudfront_log() {
+        let log = parse_log(LOG).unwrap();
+        let log = log.as_object().unwrap();
+
+        assert_eq!(
+            log.get("timestamp").unwrap(),
+            &Value::from(
+                DateTime::parse_from_rfc3339("2019-12-04T21:02:31Z")
+                    .unwrap()
+                    .with_timezone(&Utc)
+            )
+        );
+        assert_eq!(log.get("sc_status").unwrap(), &Value::from(200));
+        assert_eq!(log.get("time_taken").unwrap(), &Value::from(0.001));
+        assert_eq!(log.get("c_ip").unwrap(), &Value::from("192.0.2.100"));
+        assert_eq!(log.get("cs_referer").unwrap(), &Value::Null);
+        assert_eq!(log.get("sc_range_end").unwrap(), &Value::Null);
+    }
+
+    #[test]
+    fn parse_aws_cloudfront_log_trailing_newline() {
+        assert_eq!(
+            parse_log(&format!("{}\n", LOG)).unwrap(),
+            parse_log(LOG).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_aws_cloudfront_log_errors() {
+        assert_eq!(
+            parse_log("#Version: 1.0").unwrap_err(),
+            "input is a log file header, not a log entry"
+        );
+        assert_eq!(
+            parse_log("2019-12-04\t21:02:31\tLAX1").unwrap_err(),
+            "expected 33 tab-separated fields, found 3"
+        );
+        assert_eq!(
+            parse_log(&LOG.replacen("\t392\t", "\tmany\t", 1)).unwrap_err(),
+            "failed to parse value as i64 (key: `sc_bytes`): `many`"
+        );
+        assert_eq!(
+            parse_log(&LOG.replacen("21:02:31", "noon", 1)).unwrap_err(),
+            "failed to parse timestamp: `2019-12-04 noon`"
+        );
+    }
+
+    #[test]
+    fn inner_kind_covers_all_fields() {
+        let log = parse_log(LOG).unwrap();
+        let log = log.as_object().unwrap();
+
+        assert_eq!(
+            log.keys().map(String::as_str).collect::<Vec<_>>(),
+            inner_kind().keys().map(Field::as_str).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn parse_csv_line(csv_string: Value, delimiter: Value, quote: Value) -> Resolved {
+    let csv_string = csv_string.try_bytes()?;
+    let reader = util::csv_reader(&csv_string, &delimiter.try_bytes()?, &quote.try_bytes()?)?;
+    reader
+        .into_byte_records()
+        .next()
+        .transpose()
+        .map_err(|err| format!("invalid csv record: {}", err).into()) // shouldn't really happen
+        .map(|record| {
+            record
+                .map(|record| {
+                    record
+                        .iter()
+                        .map(|x| Bytes::copy_from_slice(x).into())
+                        .collect::<Vec<Value>>()
+                })
+                .unwrap_or_default()
+                .into()
+        })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseCsvLine;
+
+impl Function for ParseCsvLine {
+    fn identifier(&self) -> &'static str {
+        "parse_csv_line"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "parse a single CSV formatted row",
+                source: r#"parse_csv_line!(s'foo,bar,"foo "", bar"')"#,
+                result: Ok(r#"["foo", "bar", "foo \", bar"]"#),
+            },
+            Example {
+                title: "custom quote character",
+                source: r#"parse_csv_line!(s"foo;'bar;baz'", delimiter: ";", quote: "'")"#,
+                result: Ok(r#"["foo", "bar;baz"]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let delimiter = arguments.optional("delimiter").unwrap_or(expr!(","));
+        let quote = arguments.optional("quote").unwrap_or(expr!("\""));
+        Ok(ParseCsvLineFn {
+            value,
+            delimiter,
+            quote,
+        }
+        .as_expr())
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "delimiter",
+                kind: kind::BYTES,
+                required: false,
+            },
+            Parameter {
+                keyword: "quote",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseCsvLineFn {
+    value: Box<dyn Expression>,
+    delimiter: Box<dyn Expression>,
+    quote: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ParseCsvLineFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let csv_string = self.value.resolve(ctx)?;
+        let delimiter = self.delimiter.resolve(ctx)?;
+        let quote = self.quote.resolve(ctx)?;
+
+        parse_csv_line(csv_string, delimiter, quote)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::array(inner_kind()).fallible()
+    }
+}
+
+#[inline]
+fn inner_kind() -> Collection<Index> {
+    let mut v = Collection::any();
+    v.set_unknown(Kind::bytes());
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_csv_line => ParseCsvLine;
+
+        valid {
+            args: func_args![value: value!("foo,bar,\"foo \"\", bar\"")],
+            want: Ok(value!(["foo", "bar", "foo \", bar"])),
+            tdef: TypeDef::array(inner_kind()).fallible(),
+        }
+
+        invalid_utf8 {
+            args: func_args![value: value!(Bytes::copy_from_slice(&b"foo,b\xFFar"[..]))],
+            want: Ok(value!(vec!["foo".into(), value!(Bytes::copy_from_slice(&b"b\xFFar"[..]))])),
+            tdef: TypeDef::array(inner_kind()).fallible(),
+        }
+
+        custom_delimiter {
+            args: func_args![value: value!("foo bar"), delimiter: value!(" ")],
+            want: Ok(value!(["foo", "bar"])),
+            tdef: TypeDef::array(inner_kind()).fallible(),
+        }
+
+        invalid_delimiter {
+            args: func_args![value: value!("foo bar"), delimiter: value!(",,")],
+            want: Err("delimiter must be a single character"),
+            tdef: TypeDef::array(inner_kind()).fallible(),
+        }
+
+        custom_quote {
+            args: func_args![value: value!("foo;'bar;baz'"), delimiter: value!(";"), quote: value!("'")],
+            want: Ok(value!(["foo", "bar;baz"])),
+            tdef: TypeDef::array(inner_kind()).fallible(),
+        }
+
+        invalid_quote {
+            args: func_args![value: value!("foo bar"), quote: value!("")],
+            want: Err("quote must be a single character"),
+            tdef: TypeDef::array(inner_kind()).fallible(),
+        }
+
+        single_value {
+            args: func_args![value: value!("foo")],
+            want: Ok(value!(["foo"])),
+            tdef: TypeDef::array(inner_kind()).fallible(),
+        }
+
+        empty_string {
+            args: func_args![value: value!("")],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(inner_kind()).fallible(),
+        }
+
+        multiple_lines {
+            args: func_args![value: value!("first,line\nsecond,line,with,more,fields")],
+            want: Ok(value!(["first", "line"])),
+            tdef: TypeDef::array(inner_kind()).fallible(),
+        }
+    ];
+}
@@ -0,0 +1,231 @@
+use std::collections::BTreeMap;
+
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn parse_csv_rows(csv_string: Value, delimiter: Value, quote: Value, headers: Value) -> Resolved {
+    let csv_string = csv_string.try_bytes()?;
+    let headers = headers.try_boolean()?;
+    let reader = util::csv_reader(&csv_string, &delimiter.try_bytes()?, &quote.try_bytes()?)?;
+    let mut records = reader.into_byte_records();
+
+    let invalid_record = |err: csv::Error| format!("invalid csv record: {}", err);
+    let to_value = |field: &[u8]| Value::from(Bytes::copy_from_slice(field));
+
+    if !headers {
+        return records
+            .map(|record| {
+                let record = record.map_err(invalid_record)?;
+                Ok(record.iter().map(to_value).collect::<Vec<_>>().into())
+            })
+            .collect::<Result<Vec<Value>>>()
+            .map(Into::into);
+    }
+
+    let keys = match records.next() {
+        Some(record) => record
+            .map_err(invalid_record)?
+            .iter()
+            .map(|key| String::from_utf8_lossy(key).into_owned())
+            .collect::<Vec<_>>(),
+        None => return Ok(Value::Array(vec![])),
+    };
+
+    records
+        .enumerate()
+        .map(|(index, record)| {
+            let record = record.map_err(invalid_record)?;
+            if record.len() > keys.len() {
+                return Err(format!(
+                    "row {} has {} fields, but there are only {} headers",
+                    index + 1,
+                    record.len(),
+                    keys.len()
+                )
+                .into());
+            }
+
+            // Rows with fewer fields than there are headers leave out the remaining keys.
+            Ok(keys
+                .iter()
+                .cloned()
+                .zip(record.iter().map(to_value))
+                .collect::<BTreeMap<_, _>>()
+                .into())
+        })
+        .collect::<Result<Vec<Value>>>()
+        .map(Into::into)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseCsvRows;
+
+impl Function for ParseCsvRows {
+    fn identifier(&self) -> &'static str {
+        "parse_csv_rows"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "parse CSV formatted rows",
+                source: r#"parse_csv_rows!("foo,bar\n\"foo \"\", bar\",baz")"#,
+                result: Ok(r#"[["foo", "bar"], ["foo \", bar", "baz"]]"#),
+            },
+            Example {
+                title: "parse CSV formatted rows with headers",
+                source: r#"parse_csv_rows!("name,age\nalice,42\nbob,39", headers: true)"#,
+                result: Ok(r#"[{ "name": "alice", "age": "42" }, { "name": "bob", "age": "39" }]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let delimiter = arguments.optional("delimiter").unwrap_or(expr!(","));
+        let quote = arguments.optional("quote").unwrap_or(expr!("\""));
+        let headers = arguments.optional("headers").unwrap_or(expr!(false));
+
+        Ok(ParseCsvRowsFn {
+            value,
+            delimiter,
+            quote,
+            headers,
+        }
+        .as_expr())
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "delimiter",
+                kind: kind::BYTES,
+                required: false,
+            },
+            Parameter {
+                keyword: "quote",
+                kind: kind::BYTES,
+                required: false,
+            },
+            Parameter {
+                keyword: "headers",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseCsvRowsFn {
+    value: Box<dyn Expression>,
+    delimiter: Box<dyn Expression>,
+    quote: Box<dyn Expression>,
+    headers: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ParseCsvRowsFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let csv_string = self.value.resolve(ctx)?;
+        let delimiter = self.delimiter.resolve(ctx)?;
+        let quote = self.quote.resolve(ctx)?;
+        let headers = self.headers.resolve(ctx)?;
+
+        parse_csv_rows(csv_string, delimiter, quote, headers)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        let row = match self.headers.as_value() {
+            Some(Value::Boolean(true)) => object_row_kind(),
+            Some(Value::Boolean(false)) => array_row_kind(),
+            _ => array_row_kind() | object_row_kind(),
+        };
+
+        TypeDef::array(Collection::from_unknown(row)).fallible()
+    }
+}
+
+fn array_row_kind() -> Kind {
+    Kind::array(Collection::from_unknown(Kind::bytes()))
+}
+
+fn object_row_kind() -> Kind {
+    Kind::object(Collection::from_unknown(Kind::bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn array_rows() -> TypeDef {
+        TypeDef::array(Collection::from_unknown(array_row_kind())).fallible()
+    }
+
+    fn object_rows() -> TypeDef {
+        TypeDef::array(Collection::from_unknown(object_row_kind())).fallible()
+    }
+
+    test_function![
+        parse_csv_rows => ParseCsvRows;
+
+        multiple_rows {
+            args: func_args![value: value!("foo,bar\n\"foo \"\", bar\",baz,qux\n")],
+            want: Ok(value!([["foo", "bar"], ["foo \", bar", "baz", "qux"]])),
+            tdef: array_rows(),
+        }
+
+        quoted_newline {
+            args: func_args![value: value!("\"foo\nbar\",baz\nqux,quux")],
+            want: Ok(value!([["foo\nbar", "baz"], ["qux", "quux"]])),
+            tdef: array_rows(),
+        }
+
+        custom_delimiter_and_quote {
+            args: func_args![value: value!("foo;'bar;baz'\nqux;quux"), delimiter: ";", quote: "'"],
+            want: Ok(value!([["foo", "bar;baz"], ["qux", "quux"]])),
+            tdef: array_rows(),
+        }
+
+        with_headers {
+            args: func_args![value: value!("name,age\nalice,42\nbob\n"), headers: true],
+            want: Ok(value!([{ "name": "alice", "age": "42" }, { "name": "bob" }])),
+            tdef: object_rows(),
+        }
+
+        headers_only {
+            args: func_args![value: value!("name,age"), headers: true],
+            want: Ok(value!([])),
+            tdef: object_rows(),
+        }
+
+        too_many_fields {
+            args: func_args![value: value!("name,age\nalice,42,extra"), headers: true],
+            want: Err("row 1 has 3 fields, but there are only 2 headers"),
+            tdef: object_rows(),
+        }
+
+        invalid_delimiter {
+            args: func_args![value: value!("foo bar"), delimiter: value!(",,")],
+            want: Err("delimiter must be a single character"),
+            tdef: array_rows(),
+        }
+
+        empty_string {
+            args: func_args![value: value!(""), headers: true],
+            want: Ok(value!([])),
+            tdef: object_rows(),
+        }
+    ];
+}
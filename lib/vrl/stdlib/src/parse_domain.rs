@@ -0,0 +1,232 @@
+use std::{collections::BTreeMap, net::IpAddr};
+
+use ::value::Value;
+use vrl::prelude::*;
+
+fn parse_domain(value: Value) -> Resolved {
+    let bytes = value.try_bytes()?;
+    let host = String::from_utf8_lossy(&bytes).to_lowercase();
+    let host = host.strip_suffix('.').unwrap_or(&host);
+
+    if host.parse::<IpAddr>().is_ok() {
+        return Err(format!(r#"unable to parse domain: "{}" is an IP address"#, host).into());
+    }
+    if !is_hostname(host) {
+        return Err(format!(r#"unable to parse domain: "{}" isn't a hostname"#, host).into());
+    }
+
+    // The list's wildcard rule treats unlisted top-level domains as public suffixes, so any
+    // hostname has a suffix of at least one label.
+    let suffix_len = psl::suffix(host.as_bytes())
+        .map(|suffix| suffix.as_bytes().len())
+        .ok_or_else(|| format!(r#"unable to parse domain: "{}" has no public suffix"#, host))?;
+    let (rest, suffix) = host.split_at(host.len() - suffix_len);
+
+    let (subdomain, domain) = match rest.strip_suffix('.') {
+        None => (None, None),
+        Some(rest) => match rest.rsplit_once('.') {
+            Some((subdomain, domain)) => (Some(subdomain), Some(domain)),
+            None => (None, Some(rest)),
+        },
+    };
+    let registrable_domain = domain.map(|domain| format!("{}.{}", domain, suffix));
+
+    Ok(BTreeMap::from([
+        ("subdomain".to_owned(), subdomain.into()),
+        ("domain".to_owned(), domain.into()),
+        ("suffix".to_owned(), suffix.into()),
+        ("registrable_domain".to_owned(), registrable_domain.into()),
+    ])
+    .into())
+}
+
+/// Checks that `host` is made up of valid DNS labels. Underscores are accepted as they're common
+/// in service records, as are non-ASCII characters of internationalized domain names.
+fn is_hostname(host: &str) -> bool {
+    host.len() <= 253
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseDomain;
+
+impl Function for ParseDomain {
+    fn identifier(&self) -> &'static str {
+        "parse_domain"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "multi-label suffix",
+                source: r#"parse_domain!("www.example.co.uk")"#,
+                result: Ok(indoc! {r#"
+                {
+                    "domain": "example",
+                    "registrable_domain": "example.co.uk",
+                    "subdomain": "www",
+                    "suffix": "co.uk"
+                }
+            "#}),
+            },
+            Example {
+                title: "nested subdomain",
+                source: r#"parse_domain!("api.eu.vector.dev")"#,
+                result: Ok(indoc! {r#"
+                {
+                    "domain": "vector",
+                    "registrable_domain": "vector.dev",
+                    "subdomain": "api.eu",
+                    "suffix": "dev"
+                }
+            "#}),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ParseDomainFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseDomainFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ParseDomainFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        parse_domain(value)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::object(inner_kind()).fallible()
+    }
+}
+
+fn inner_kind() -> BTreeMap<Field, Kind> {
+    BTreeMap::from([
+        ("subdomain".into(), Kind::bytes().or_null()),
+        ("domain".into(), Kind::bytes().or_null()),
+        ("suffix".into(), Kind::bytes()),
+        ("registrable_domain".into(), Kind::bytes().or_null()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_domain => ParseDomain;
+
+        single_label_suffix {
+            args: func_args![value: "www.vector.dev"],
+            want: Ok(value!({
+                subdomain: "www",
+                domain: "vector",
+                suffix: "dev",
+                registrable_domain: "vector.dev",
+            })),
+            tdef: TypeDef::object(inner_kind()).fallible(),
+        }
+
+        multi_label_suffix {
+            args: func_args![value: "a.b.example.co.uk"],
+            want: Ok(value!({
+                subdomain: "a.b",
+                domain: "example",
+                suffix: "co.uk",
+                registrable_domain: "example.co.uk",
+            })),
+            tdef: TypeDef::object(inner_kind()).fallible(),
+        }
+
+        no_subdomain {
+            args: func_args![value: "example.com"],
+            want: Ok(value!({
+                subdomain: null,
+                domain: "example",
+                suffix: "com",
+                registrable_domain: "example.com",
+            })),
+            tdef: TypeDef::object(inner_kind()).fallible(),
+        }
+
+        only_suffix {
+            args: func_args![value: "co.uk"],
+            want: Ok(value!({
+                subdomain: null,
+                domain: null,
+                suffix: "co.uk",
+                registrable_domain: null,
+            })),
+            tdef: TypeDef::object(inner_kind()).fallible(),
+        }
+
+        uppercase_fqdn {
+            args: func_args![value: "WWW.Example.COM."],
+            want: Ok(value!({
+                subdomain: "www",
+                domain: "example",
+                suffix: "com",
+                registrable_domain: "example.com",
+            })),
+            tdef: TypeDef::object(inner_kind()).fallible(),
+        }
+
+        unlisted_suffix {
+            args: func_args![value: "db.cluster.internalzone"],
+            want: Ok(value!({
+                subdomain: "db",
+                domain: "cluster",
+                suffix: "internalzone",
+                registrable_domain: "cluster.internalzone",
+            })),
+            tdef: TypeDef::object(inner_kind()).fallible(),
+        }
+
+        ip_address {
+            args: func_args![value: "192.168.0.1"],
+            want: Err(r#"unable to parse domain: "192.168.0.1" is an IP address"#),
+            tdef: TypeDef::object(inner_kind()).fallible(),
+        }
+
+        not_a_hostname {
+            args: func_args![value: "https://vector.dev/"],
+            want: Err(r#"unable to parse domain: "https://vector.dev/" isn't a hostname"#),
+            tdef: TypeDef::object(inner_kind()).fallible(),
+        }
+
+        empty_label {
+            args: func_args![value: "www..vector.dev"],
+            want: Err(r#"unable to parse domain: "www..vector.dev" isn't a hostname"#),
+            tdef: TypeDef::object(inner_kind()).fallible(),
+        }
+    ];
+}
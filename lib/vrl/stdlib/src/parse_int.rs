@@ -3,36 +3,57 @@ use vrl::prelude::*;
 
 fn parse_int(value: Value, base: Option<Value>) -> Resolved {
     let string = value.try_bytes_utf8_lossy()?;
-    let (base, index) = match base {
-        Some(base) => {
-            let base = base.try_integer()?;
-
-            if !(2..=36).contains(&base) {
-                return Err(format!(
-                    "invalid base {}: must be be between 2 and 36 (inclusive)",
-                    value
-                )
-                .into());
-            }
-            (base as u32, 0)
-        }
-        None => match string.chars().next() {
-            Some('0') => match string.chars().nth(1) {
-                Some('b') => (2, 2),
-                Some('o') => (8, 2),
-                Some('x') => (16, 2),
-                _ => (8, 0),
-            },
-            Some(_) => (10u32, 0),
-            None => return Err("value is empty".into()),
-        },
+    let base = match base {
+        Some(base) => validate_base(base.try_integer()?)?,
+        None => 0,
+    };
+
+    let (sign, unsigned) = match string.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", string.strip_prefix('+').unwrap_or(&string)),
+    };
+    if unsigned.is_empty() {
+        return Err("value is empty".into());
+    }
+
+    let prefixed = match unsigned.get(..2) {
+        Some("0b") => Some(2),
+        Some("0o") => Some(8),
+        Some("0x") => Some(16),
+        _ => None,
+    };
+    let (base, digits) = match (base, prefixed) {
+        // A prefix is only accepted if it agrees with an explicit base.
+        (0, Some(prefixed)) => (prefixed, &unsigned[2..]),
+        (base, Some(prefixed)) if base == prefixed => (base, &unsigned[2..]),
+        (0, None) if unsigned.len() > 1 && unsigned.starts_with('0') => (8, &unsigned[1..]),
+        (0, None) => (10, unsigned),
+        (base, _) => (base, unsigned),
     };
-    let converted = i64::from_str_radix(&string[index..], base)
+
+    // `from_str_radix` accepts a sign of its own, which must not follow the one stripped above.
+    if digits.starts_with(['+', '-']) {
+        return Err("could not parse integer: invalid digit found in string".into());
+    }
+
+    let converted = i64::from_str_radix(&format!("{}{}", sign, digits), base)
         .map_err(|err| format!("could not parse integer: {}", err))?;
 
     Ok(converted.into())
 }
 
+/// A base of `0` detects the base from the prefix of the value.
+fn validate_base(base: i64) -> std::result::Result<u32, String> {
+    if base == 0 || (2..=36).contains(&base) {
+        Ok(base as u32)
+    } else {
+        Err(format!(
+            "invalid base {}: must be 0, or between 2 and 36 (inclusive)",
+            base
+        ))
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct ParseInt;
 
@@ -73,6 +94,11 @@ impl Function for ParseInt {
                 source: r#"parse_int!("2a", base: 16)"#,
                 result: Ok("42"),
             },
+            Example {
+                title: "detect base",
+                source: r#"parse_int!("-0o755", base: 0)"#,
+                result: Ok("-493"),
+            },
         ]
     }
 
@@ -85,6 +111,17 @@ impl Function for ParseInt {
         let value = arguments.required("value");
         let base = arguments.optional("base");
 
+        if let Some(literal) = base.as_ref().and_then(|base| base.as_value()) {
+            if !matches!(literal, Value::Integer(base) if validate_base(base).is_ok()) {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "base",
+                    value: literal,
+                    error: "must be 0, or between 2 and 36 (inclusive)",
+                }
+                .into());
+            }
+        }
+
         Ok(ParseIntFn { value, base }.as_expr())
     }
 }
@@ -154,5 +191,59 @@ mod tests {
             want: Ok(42),
             tdef: TypeDef::integer().fallible(),
         }
+
+        explicit_prefixed {
+            args: func_args![value: "0x1F", base: 16],
+            want: Ok(31),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        explicit_radix_36 {
+            args: func_args![value: "zz", base: 36],
+            want: Ok(1295),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        detected {
+            args: func_args![value: "0o755", base: 0],
+            want: Ok(493),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        negative_prefixed {
+            args: func_args![value: "-0x1F"],
+            want: Ok(-31),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        mismatched_prefix {
+            args: func_args![value: "0x1F", base: 8],
+            want: Err("could not parse integer: invalid digit found in string"),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        invalid_digit {
+            args: func_args![value: "102", base: 2],
+            want: Err("could not parse integer: invalid digit found in string"),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        double_sign {
+            args: func_args![value: "+-5"],
+            want: Err("could not parse integer: invalid digit found in string"),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        empty {
+            args: func_args![value: "-"],
+            want: Err("value is empty"),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        invalid_base {
+            args: func_args![value: "42", base: 37],
+            want: Err("invalid argument"),
+            tdef: TypeDef::integer().fallible(),
+        }
     ];
 }
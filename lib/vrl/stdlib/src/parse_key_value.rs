@@ -23,6 +23,7 @@ pub(crate) fn parse_key_value(
     field_delimiter: Value,
     standalone_key: Value,
     whitespace: Whitespace,
+    values: Values,
 ) -> Resolved {
     let bytes = bytes.try_bytes_utf8_lossy()?;
     let key_value_delimiter = key_value_delimiter.try_bytes_utf8_lossy()?;
@@ -34,6 +35,7 @@ pub(crate) fn parse_key_value(
         &field_delimiter,
         whitespace,
         standalone_key,
+        values,
     )?;
 
     // Construct Value::Object by grouping values with the same key into an array.
@@ -220,6 +222,91 @@ impl FromStr for Whitespace {
     }
 }
 
+/// Determines how parsed values are turned into VRL values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Values {
+    /// Values are returned as strings, exactly as they appear between the delimiters.
+    Verbatim,
+    /// Escape sequences in quoted values are resolved, as is done by logfmt.
+    Unescaped,
+    /// Like `Unescaped`, but unquoted integers, floats and booleans are returned as such.
+    Typed,
+}
+
+impl Values {
+    fn quoted(self, value: &str) -> Value {
+        match self {
+            Values::Verbatim => value.into(),
+            Values::Unescaped | Values::Typed => unescape(value).into(),
+        }
+    }
+
+    fn unquoted(self, value: &str) -> Value {
+        match self {
+            Values::Verbatim | Values::Unescaped => value.into(),
+            Values::Typed => infer_type(value),
+        }
+    }
+}
+
+/// Resolves the `\"`, `\\`, `\n`, `\r` and `\t` escape sequences. Any other backslash is kept
+/// as is.
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('"') => unescaped.push('"'),
+            Some('\\') => unescaped.push('\\'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('t') => unescaped.push('\t'),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+/// Converts the value to an integer, float or boolean if it unambiguously is one, otherwise it's
+/// returned as a string. Values such as `007`, `+1` or `NaN` stay strings, since converting them
+/// would lose how they were written.
+fn infer_type(value: &str) -> Value {
+    match value {
+        "true" => return Value::Boolean(true),
+        "false" => return Value::Boolean(false),
+        _ => {}
+    }
+
+    if let Ok(integer) = value.parse::<i64>() {
+        if integer.to_string() == value {
+            return integer.into();
+        }
+    }
+
+    let looks_like_float = value.contains(['.', 'e', 'E'])
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '-' | '.' | 'e' | 'E'));
+    if looks_like_float {
+        if let Ok(float) = value.parse::<f64>() {
+            if let Ok(float) = NotNan::new(float) {
+                return Value::Float(float);
+            }
+        }
+    }
+
+    value.into()
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct ParseKeyValueFn {
     pub(crate) value: Box<dyn Expression>,
@@ -243,6 +330,7 @@ impl FunctionExpression for ParseKeyValueFn {
             field_delimiter,
             standalone_key,
             whitespace,
+            Values::Verbatim,
         )
     }
 
@@ -257,6 +345,7 @@ fn parse<'a>(
     field_delimiter: &'a str,
     whitespace: Whitespace,
     standalone_key: bool,
+    values: Values,
 ) -> Result<Vec<(String, Value)>> {
     let (rest, result) = parse_line(
         input,
@@ -264,6 +353,7 @@ fn parse<'a>(
         field_delimiter,
         whitespace,
         standalone_key,
+        values,
     )
     .map_err(|e| match e {
         nom::Err::Error(e) | nom::Err::Failure(e) => {
@@ -287,6 +377,7 @@ fn parse_line<'a>(
     field_delimiter: &'a str,
     whitespace: Whitespace,
     standalone_key: bool,
+    values: Values,
 ) -> IResult<&'a str, Vec<(String, Value)>, VerboseError<&'a str>> {
     separated_list1(
         parse_field_delimiter(field_delimiter),
@@ -295,6 +386,7 @@ fn parse_line<'a>(
             field_delimiter,
             whitespace,
             standalone_key,
+            values,
         ),
     )(input)
 }
@@ -322,6 +414,7 @@ fn parse_key_value_<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     field_delimiter: &'a str,
     whitespace: Whitespace,
     standalone_key: bool,
+    values: Values,
 ) -> impl Fn(&'a str) -> IResult<&'a str, (String, Value), E> {
     move |input| {
         map(
@@ -332,7 +425,7 @@ fn parse_key_value_<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
                         parse_key(key_value_delimiter, field_delimiter, standalone_key),
                     ),
                     many_m_n(usize::from(!standalone_key), 1, tag(key_value_delimiter)),
-                    parse_value(field_delimiter),
+                    parse_value(field_delimiter, values),
                 ))(input),
                 Whitespace::Lenient => tuple((
                     preceded(
@@ -344,7 +437,7 @@ fn parse_key_value_<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
                         1,
                         delimited(space0, tag(key_value_delimiter), space0),
                     ),
-                    parse_value(field_delimiter),
+                    parse_value(field_delimiter, values),
                 ))(input),
             },
             |(field, sep, value): (&str, Vec<&str>, Value)| {
@@ -414,17 +507,20 @@ fn parse_undelimited<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
 /// 2. If it does not start with one of the trim values, it is not a delimited field and we parse up to
 ///    the next `field_delimiter` or the eof.
 ///
+/// `values` determines how the parsed text is converted into a `Value`.
 fn parse_value<'a, E: ParseError<&'a str> + ContextError<&'a str>>(
     field_delimiter: &'a str,
+    values: Values,
 ) -> impl Fn(&'a str) -> IResult<&'a str, Value, E> {
     move |input| {
-        map(
-            alt((
-                parse_delimited('"', field_delimiter),
-                parse_undelimited(field_delimiter),
-            )),
-            Into::into,
-        )(input)
+        alt((
+            map(parse_delimited('"', field_delimiter), |value| {
+                values.quoted(value)
+            }),
+            map(parse_undelimited(field_delimiter), |value| {
+                values.unquoted(value)
+            }),
+        ))(input)
     }
 }
 
@@ -472,12 +568,26 @@ mod test {
     fn test_quote_and_escape_char() {
         assert_eq!(
             Ok(vec![("key".to_string(), r#"a\a"#.into()),]),
-            parse(r#"key="a\a""#, "=", " ", Whitespace::Strict, true,)
+            parse(
+                r#"key="a\a""#,
+                "=",
+                " ",
+                Whitespace::Strict,
+                true,
+                Values::Verbatim
+            )
         );
 
         assert_eq!(
             Ok(vec![(r#"a\ a"#.to_string(), r#"val"#.into()),]),
-            parse(r#""a\ a"=val"#, "=", " ", Whitespace::Strict, true,)
+            parse(
+                r#""a\ a"=val"#,
+                "=",
+                " ",
+                Whitespace::Strict,
+                true,
+                Values::Verbatim
+            )
         );
     }
 
@@ -500,6 +610,7 @@ mod test {
                 " ",
                 Whitespace::Lenient,
                 false,
+                Values::Verbatim,
             )
         );
     }
@@ -508,14 +619,24 @@ mod test {
     fn test_parse_key_value() {
         assert_eq!(
             Ok(("", ("ook".to_string(), "pook".into()))),
-            parse_key_value_::<VerboseError<&str>>("=", " ", Whitespace::Lenient, false)(
-                "ook=pook"
-            )
+            parse_key_value_::<VerboseError<&str>>(
+                "=",
+                " ",
+                Whitespace::Lenient,
+                false,
+                Values::Verbatim
+            )("ook=pook")
         );
 
         assert_eq!(
             Ok(("", ("key".to_string(), "".into()))),
-            parse_key_value_::<VerboseError<&str>>("=", " ", Whitespace::Strict, false)("key=")
+            parse_key_value_::<VerboseError<&str>>(
+                "=",
+                " ",
+                Whitespace::Strict,
+                false,
+                Values::Verbatim
+            )("key=")
         );
     }
 
@@ -526,7 +647,14 @@ mod test {
                 ("ook".to_string(), "pook".into()),
                 ("onk".to_string(), "ponk".into())
             ]),
-            parse("ook=pook onk=ponk", "=", " ", Whitespace::Lenient, false)
+            parse(
+                "ook=pook onk=ponk",
+                "=",
+                " ",
+                Whitespace::Lenient,
+                false,
+                Values::Verbatim
+            )
         );
     }
 
@@ -537,7 +665,14 @@ mod test {
                 ("ook".to_string(), "".into()),
                 ("onk".to_string(), "ponk".into())
             ]),
-            parse("ook= onk=ponk", "=", " ", Whitespace::Strict, false)
+            parse(
+                "ook= onk=ponk",
+                "=",
+                " ",
+                Whitespace::Strict,
+                false,
+                Values::Verbatim
+            )
         );
     }
 
@@ -548,7 +683,14 @@ mod test {
                 ("foo".to_string(), "bar".into()),
                 ("foobar".to_string(), value!(true))
             ]),
-            parse("foo:bar ,   foobar   ", ":", ",", Whitespace::Lenient, true)
+            parse(
+                "foo:bar ,   foobar   ",
+                ":",
+                ",",
+                Whitespace::Lenient,
+                true,
+                Values::Verbatim
+            )
         );
     }
 
@@ -566,7 +708,8 @@ mod test {
                 "=",
                 " ",
                 Whitespace::Lenient,
-                true
+                true,
+                Values::Verbatim,
             )
         );
     }
@@ -586,7 +729,8 @@ mod test {
                 "=",
                 " ",
                 Whitespace::Lenient,
-                true
+                true,
+                Values::Verbatim,
             )
         );
     }
@@ -595,7 +739,14 @@ mod test {
     fn test_parse_single_standalone_key() {
         assert_eq!(
             Ok(vec![("foobar".to_string(), value!(true))]),
-            parse("foobar", ":", ",", Whitespace::Lenient, true)
+            parse(
+                "foobar",
+                ":",
+                ",",
+                Whitespace::Lenient,
+                true,
+                Values::Verbatim
+            )
         );
     }
 
@@ -606,7 +757,14 @@ mod test {
                 ("foo".to_string(), "bar".into()),
                 ("foobar".to_string(), value!(true))
             ]),
-            parse("foo:bar ,   foobar   ", ":", ",", Whitespace::Strict, true)
+            parse(
+                "foo:bar ,   foobar   ",
+                ":",
+                ",",
+                Whitespace::Strict,
+                true,
+                Values::Verbatim
+            )
         );
     }
 
@@ -660,25 +818,25 @@ mod test {
         // delimited
         assert_eq!(
             Ok(("", "noog".into())),
-            parse_value::<VerboseError<&str>>(" ")(r#""noog""#)
+            parse_value::<VerboseError<&str>>(" ", Values::Verbatim)(r#""noog""#)
         );
 
         // undelimited
         assert_eq!(
             Ok(("", "noog".into())),
-            parse_value::<VerboseError<&str>>(" ")("noog")
+            parse_value::<VerboseError<&str>>(" ", Values::Verbatim)("noog")
         );
 
         // empty delimited
         assert_eq!(
             Ok(("", "".into())),
-            parse_value::<VerboseError<&str>>(" ")(r#""""#)
+            parse_value::<VerboseError<&str>>(" ", Values::Verbatim)(r#""""#)
         );
 
         // empty undelimited
         assert_eq!(
             Ok(("", "".into())),
-            parse_value::<VerboseError<&str>>(" ")("")
+            parse_value::<VerboseError<&str>>(" ", Values::Verbatim)("")
         );
     }
 
@@ -1,6 +1,7 @@
+use ::value::Value;
 use vrl::prelude::*;
 
-use crate::parse_key_value::{ParseKeyValueFn, Whitespace};
+use crate::parse_key_value::{parse_key_value, Values, Whitespace};
 
 #[derive(Clone, Copy, Debug)]
 pub struct ParseLogFmt;
@@ -11,11 +12,18 @@ impl Function for ParseLogFmt {
     }
 
     fn parameters(&self) -> &'static [Parameter] {
-        &[Parameter {
-            keyword: "value",
-            kind: kind::BYTES,
-            required: true,
-        }]
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "infer_types",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
     }
 
     fn examples(&self) -> &'static [Example] {
@@ -30,6 +38,16 @@ impl Function for ParseLogFmt {
                 source: r#"parse_logfmt!("zork=zook plonk zonk=nork")"#,
                 result: Ok(r#"{"plonk": true, "zork": "zook", "zonk": "nork"}"#),
             },
+            Example {
+                title: "escaped quotes",
+                source: r#"parse_logfmt!(s'msg="say \"hi\""')"#,
+                result: Ok(r#"{"msg": "say \"hi\""}"#),
+            },
+            Example {
+                title: "infer types",
+                source: r#"parse_logfmt!("status=200 took=0.5 ok=false id=\"42\"", infer_types: true)"#,
+                result: Ok(r#"{"status": 200, "took": 0.5, "ok": false, "id": "42"}"#),
+            },
         ]
     }
 
@@ -40,21 +58,108 @@ impl Function for ParseLogFmt {
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
+        let infer_types = arguments.optional("infer_types");
 
-        // The parse_logfmt function is just an alias for `parse_key_value` with the following
-        // parameters for the delimiters.
-        let key_value_delimiter = expr!("=");
-        let field_delimiter = expr!(" ");
-        let whitespace = Whitespace::Lenient;
-        let standalone_key = expr!(true);
-
-        Ok(ParseKeyValueFn {
-            value,
-            key_value_delimiter,
-            field_delimiter,
-            whitespace,
-            standalone_key,
-        }
-        .as_expr())
+        Ok(ParseLogFmtFn { value, infer_types }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseLogFmtFn {
+    value: Box<dyn Expression>,
+    infer_types: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for ParseLogFmtFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let bytes = self.value.resolve(ctx)?;
+        let infer_types = match &self.infer_types {
+            Some(expr) => expr.resolve(ctx)?.try_boolean()?,
+            None => false,
+        };
+        let values = if infer_types {
+            Values::Typed
+        } else {
+            Values::Unescaped
+        };
+
+        // logfmt is `parse_key_value` with fixed delimiters, standalone keys, and escapes in quoted
+        // values resolved.
+        parse_key_value(
+            bytes,
+            value!("="),
+            value!(" "),
+            value!(true),
+            Whitespace::Lenient,
+            values,
+        )
     }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Standalone keys are always `true`, and only with inferred types can values be numbers as
+        // well. Repeated keys are grouped into an array of any of these.
+        let element = if self.infer_types.is_some() {
+            Kind::bytes() | Kind::boolean() | Kind::integer() | Kind::float()
+        } else {
+            Kind::bytes() | Kind::boolean()
+        };
+
+        TypeDef::object(Collection::from_unknown(
+            element.clone() | Kind::array(Collection::from_unknown(element)),
+        ))
+        .fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        parse_logfmt => ParseLogFmt;
+
+        standalone_key {
+            args: func_args![value: "zork=zook plonk zonk=nork"],
+            want: Ok(value!({plonk: true, zork: "zook", zonk: "nork"})),
+            tdef: TypeDef::object(Collection::from_unknown(
+                Kind::boolean() | Kind::bytes() | Kind::array(Collection::from_unknown(Kind::boolean() | Kind::bytes())),
+            )).fallible(),
+        }
+
+        repeated_standalone_key {
+            args: func_args![value: "a a"],
+            want: Ok(value!({a: [true, true]})),
+            tdef: TypeDef::object(Collection::from_unknown(
+                Kind::boolean() | Kind::bytes() | Kind::array(Collection::from_unknown(Kind::boolean() | Kind::bytes())),
+            )).fallible(),
+        }
+
+        escaped_quoted_value {
+            args: func_args![value: r#"msg="say \"hi\" now\n" path="C:\dir\\" raw=a\"b"#],
+            want: Ok(value!({msg: "say \"hi\" now\n", path: "C:\\dir\\", raw: "a\\\"b"})),
+            tdef: TypeDef::object(Collection::from_unknown(
+                Kind::boolean() | Kind::bytes() | Kind::array(Collection::from_unknown(Kind::boolean() | Kind::bytes())),
+            )).fallible(),
+        }
+
+        keeps_strings_by_default {
+            args: func_args![value: "status=200 ok=false"],
+            want: Ok(value!({status: "200", ok: "false"})),
+            tdef: TypeDef::object(Collection::from_unknown(
+                Kind::boolean() | Kind::bytes() | Kind::array(Collection::from_unknown(Kind::boolean() | Kind::bytes())),
+            )).fallible(),
+        }
+
+        infers_types {
+            args: func_args![
+                value: r#"status=200 took=0.5 neg=-3 ok=false id="42" zip=007 big=1e3 word=nan"#,
+                infer_types: true,
+            ],
+            want: Ok(value!({status: 200, took: 0.5, neg: -3, ok: false, id: "42", zip: "007", big: 1000.0, word: "nan"})),
+            tdef: TypeDef::object(Collection::from_unknown(
+                Kind::boolean() | Kind::bytes() | Kind::integer() | Kind::float()
+                    | Kind::array(Collection::from_unknown(Kind::bytes() | Kind::integer() | Kind::float() | Kind::boolean())),
+            )).fallible(),
+        }
+    ];
 }
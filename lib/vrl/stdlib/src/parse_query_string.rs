@@ -141,6 +141,15 @@ mod tests {
             tdef: TypeDef::object(inner_kind()),
         }
 
+        key_without_value {
+            args: func_args![value: value!("flag&x=1+2")],
+            want: Ok(value!({
+                flag: "",
+                x: "1 2",
+            })),
+            tdef: TypeDef::object(inner_kind()),
+        }
+
         empty {
             args: func_args![value: value!("")],
             want: Ok(value!({})),
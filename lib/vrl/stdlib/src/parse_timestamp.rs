@@ -35,12 +35,20 @@ impl Function for ParseTimestamp {
     fn compile(
         &self,
         _state: &state::TypeState,
-        _ctx: &mut FunctionCompileContext,
+        ctx: &mut FunctionCompileContext,
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
         let format = arguments.required("format");
 
+        if let Some(format) = format.as_value() {
+            if let Some(format) = format.as_bytes() {
+                let span = arguments.span("format").unwrap_or_else(|| ctx.span());
+                crate::util::validate_strftime(&String::from_utf8_lossy(format), span)
+                    .map_err(|error| Box::new(error) as Box<dyn DiagnosticMessage>)?;
+            }
+        }
+
         Ok(ParseTimestampFn { value, format }.as_expr())
     }
 
@@ -130,5 +138,15 @@ mod tests {
             tdef: TypeDef::timestamp().fallible(),
             tz: vector_common::TimeZone::Named(chrono_tz::Europe::Paris),
         }
+
+        invalid_format {
+            args: func_args![
+                value: "16/10/2019",
+                format: "%d/%m/%Q"
+            ],
+            want: Err(r#"invalid strftime directive "%Q" at offset 6"#),
+            tdef: TypeDef::timestamp().fallible(),
+            tz: vector_common::TimeZone::default(),
+        }
     ];
 }
@@ -0,0 +1,140 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::json_path::{matches, parse_literal_path, parse_path, Segment};
+
+#[derive(Clone, Copy, Debug)]
+pub struct PathExists;
+
+impl Function for PathExists {
+    fn identifier(&self) -> &'static str {
+        "path_exists"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "path",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "wildcard match",
+                source: r#"path_exists({ "items": [{ "id": 1 }, { "id": 2, "error": "timeout" }] }, ".items[*].error")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "no match",
+                source: r#"path_exists({ "items": [{ "id": 1 }] }, ".items[*].error")"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let path = arguments.required("path");
+
+        let segments = parse_literal_path(&*path)?;
+
+        Ok(PathExistsFn {
+            value,
+            path,
+            segments,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PathExistsFn {
+    value: Box<dyn Expression>,
+    path: Box<dyn Expression>,
+    segments: Option<Vec<Segment>>,
+}
+
+impl FunctionExpression for PathExistsFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        let exists = match &self.segments {
+            Some(segments) => !matches(&value, segments).is_empty(),
+            None => {
+                let path = self.path.resolve(ctx)?.try_bytes()?;
+                !matches(&value, &parse_path(&path)?).is_empty()
+            }
+        };
+
+        Ok(exists.into())
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::boolean().with_fallibility(self.segments.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        path_exists => PathExists;
+
+        field {
+            args: func_args![value: value!({ "a": { "b": null } }), path: ".a.b"],
+            want: Ok(true),
+            tdef: TypeDef::boolean(),
+        }
+
+        missing_field {
+            args: func_args![value: value!({ "a": { "b": 1 } }), path: ".a.c"],
+            want: Ok(false),
+            tdef: TypeDef::boolean(),
+        }
+
+        array_wildcard {
+            args: func_args![value: value!({ "items": [{ "id": 1 }, { "id": 2, "error": "timeout" }] }), path: ".items[*].error"],
+            want: Ok(true),
+            tdef: TypeDef::boolean(),
+        }
+
+        array_wildcard_no_match {
+            args: func_args![value: value!({ "items": [{ "id": 1 }, { "id": 2 }] }), path: ".items[*].error"],
+            want: Ok(false),
+            tdef: TypeDef::boolean(),
+        }
+
+        nested_wildcards {
+            args: func_args![value: value!({ "a": [{ "b": [] }, { "b": [{ "c": 1 }] }] }), path: ".a[*].b[*].c"],
+            want: Ok(true),
+            tdef: TypeDef::boolean(),
+        }
+
+        empty_array {
+            args: func_args![value: value!({ "a": [] }), path: ".a[*]"],
+            want: Ok(false),
+            tdef: TypeDef::boolean(),
+        }
+
+        invalid_path {
+            args: func_args![value: value!({ "a": 1 }), path: ".a[*"],
+            want: Err("invalid argument"),
+            tdef: TypeDef::boolean(),
+        }
+    ];
+}
@@ -0,0 +1,135 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn percentage(part: Value, whole: Value, default: Value) -> Resolved {
+    let whole = whole.try_into_f64()?;
+    if whole == 0.0 {
+        return Ok(default);
+    }
+
+    Ok(Value::from_f64_or_zero(
+        part.try_into_f64()? / whole * 100.0,
+    ))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Percentage;
+
+impl Function for Percentage {
+    fn identifier(&self) -> &'static str {
+        "percentage"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "part",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "whole",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "default",
+                kind: kind::ANY,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "percentage",
+                source: r#"percentage(1, 4)"#,
+                result: Ok("25.0"),
+            },
+            Example {
+                title: "zero whole",
+                source: r#"percentage(1, 0, default: 0.0)"#,
+                result: Ok("0.0"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let part = arguments.required("part");
+        let whole = arguments.required("whole");
+        let default = arguments.optional("default").unwrap_or(expr!(null));
+
+        Ok(PercentageFn {
+            part,
+            whole,
+            default,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PercentageFn {
+    part: Box<dyn Expression>,
+    whole: Box<dyn Expression>,
+    default: Box<dyn Expression>,
+}
+
+impl FunctionExpression for PercentageFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let part = self.part.resolve(ctx)?;
+        let whole = self.whole.resolve(ctx)?;
+        let default = self.default.resolve(ctx)?;
+
+        percentage(part, whole, default)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        TypeDef::float().union(self.default.type_def(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        percentage => Percentage;
+
+        integers {
+            args: func_args![part: 1, whole: 4],
+            want: Ok(value!(25.0)),
+            tdef: TypeDef::float().or_null(),
+        }
+
+        floats {
+            args: func_args![part: 0.3, whole: 0.6],
+            want: Ok(value!(50.0)),
+            tdef: TypeDef::float().or_null(),
+        }
+
+        above_whole {
+            args: func_args![part: 3, whole: 2],
+            want: Ok(value!(150.0)),
+            tdef: TypeDef::float().or_null(),
+        }
+
+        zero_whole {
+            args: func_args![part: 1, whole: 0],
+            want: Ok(value!(null)),
+            tdef: TypeDef::float().or_null(),
+        }
+
+        zero_whole_with_default {
+            args: func_args![part: 1, whole: 0, default: 0.0],
+            want: Ok(value!(0.0)),
+            tdef: TypeDef::float(),
+        }
+    ];
+}
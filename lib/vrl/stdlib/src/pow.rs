@@ -0,0 +1,166 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::util::promoted_numeric_kind;
+
+fn pow(base: Value, exponent: Value) -> Resolved {
+    if let (Value::Integer(base), Value::Integer(exponent)) = (&base, &exponent) {
+        if *exponent < 0 {
+            return Err("integer base can't be raised to a negative exponent, use a float".into());
+        }
+
+        // Exponents this large overflow for any base other than -1, 0 and 1, for which only
+        // whether the exponent is even matters.
+        let exponent = u32::try_from(*exponent).unwrap_or(u32::MAX - u32::from(exponent % 2 == 0));
+
+        return base
+            .checked_pow(exponent)
+            .map(Into::into)
+            .ok_or_else(|| "result doesn't fit in an integer".into());
+    }
+
+    let result = base.try_into_f64()?.powf(exponent.try_into_f64()?);
+    if !result.is_finite() {
+        return Err("result isn't a finite number".into());
+    }
+
+    Ok(Value::from_f64_or_zero(result))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Pow;
+
+impl Function for Pow {
+    fn identifier(&self) -> &'static str {
+        "pow"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "base",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "exponent",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "integers",
+                source: r#"pow!(2, 10)"#,
+                result: Ok("1024"),
+            },
+            Example {
+                title: "float exponent",
+                source: r#"pow!(9, 0.5)"#,
+                result: Ok("3.0"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let base = arguments.required("base");
+        let exponent = arguments.required("exponent");
+
+        Ok(PowFn { base, exponent }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PowFn {
+    base: Box<dyn Expression>,
+    exponent: Box<dyn Expression>,
+}
+
+impl FunctionExpression for PowFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let base = self.base.resolve(ctx)?;
+        let exponent = self.exponent.resolve(ctx)?;
+
+        pow(base, exponent)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let kind = promoted_numeric_kind(&[
+            self.base.type_def(state).kind().clone(),
+            self.exponent.type_def(state).kind().clone(),
+        ]);
+
+        TypeDef::from(kind).fallible(/* overflow */)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        pow => Pow;
+
+        integers {
+            args: func_args![base: 2, exponent: 10],
+            want: Ok(value!(1024)),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        negative_base {
+            args: func_args![base: -3, exponent: 3],
+            want: Ok(value!(-27)),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        huge_exponent {
+            args: func_args![base: -1, exponent: i64::MAX],
+            want: Ok(value!(-1)),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        integer_overflow {
+            args: func_args![base: 10, exponent: 19],
+            want: Err("result doesn't fit in an integer"),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        negative_integer_exponent {
+            args: func_args![base: 2, exponent: -1],
+            want: Err("integer base can't be raised to a negative exponent, use a float"),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        float_exponent {
+            args: func_args![base: 9, exponent: 0.5],
+            want: Ok(value!(3.0)),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        negative_float_exponent {
+            args: func_args![base: 2.0, exponent: -1],
+            want: Ok(value!(0.5)),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        float_overflow {
+            args: func_args![base: 10.0, exponent: 400],
+            want: Err("result isn't a finite number"),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        not_a_number {
+            args: func_args![base: -8.0, exponent: 0.5],
+            want: Err("result isn't a finite number"),
+            tdef: TypeDef::float().fallible(),
+        }
+    ];
+}
@@ -0,0 +1,169 @@
+use ::value::{kind::Collection, Value};
+use vrl::prelude::*;
+
+use crate::json_path::{matches, parse_literal_path, parse_path, Segment};
+
+fn query(value: &Value, segments: &[Segment]) -> Value {
+    matches(value, segments)
+        .into_iter()
+        .cloned()
+        .collect::<Vec<_>>()
+        .into()
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Query;
+
+impl Function for Query {
+    fn identifier(&self) -> &'static str {
+        "query"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "path",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "nested field",
+                source: r#"query({ "a": { "b": [1, 2, 3] } }, ".a.b[0]")"#,
+                result: Ok("[1]"),
+            },
+            Example {
+                title: "wildcard",
+                source: r#"query({ "hosts": [{ "name": "a" }, { "name": "b" }, { "ip": "c" }] }, ".hosts[*].name")"#,
+                result: Ok(r#"["a", "b"]"#),
+            },
+            Example {
+                title: "no matches",
+                source: r#"query({ "a": 1 }, "$.b.*")"#,
+                result: Ok("[]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let path = arguments.required("path");
+
+        let segments = parse_literal_path(&*path)?;
+
+        Ok(QueryFn {
+            value,
+            path,
+            segments,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct QueryFn {
+    value: Box<dyn Expression>,
+    path: Box<dyn Expression>,
+    segments: Option<Vec<Segment>>,
+}
+
+impl FunctionExpression for QueryFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        match &self.segments {
+            Some(segments) => Ok(query(&value, segments)),
+            None => {
+                let path = self.path.resolve(ctx)?.try_bytes()?;
+                Ok(query(&value, &parse_path(&path)?))
+            }
+        }
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::array(Collection::any()).with_fallibility(self.segments.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        query => Query;
+
+        field {
+            args: func_args![value: value!({ "a": { "b": "c" } }), path: ".a.b"],
+            want: Ok(value!(["c"])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        root {
+            args: func_args![value: value!({ "a": 1 }), path: "$"],
+            want: Ok(value!([{ "a": 1 }])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        index {
+            args: func_args![value: value!({ "a": [1, 2, 3] }), path: ".a[1]"],
+            want: Ok(value!([2])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        negative_index {
+            args: func_args![value: value!({ "a": [1, 2, 3] }), path: ".a[-1]"],
+            want: Ok(value!([3])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        out_of_bounds {
+            args: func_args![value: value!({ "a": [1, 2, 3] }), path: ".a[-4]"],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        array_wildcard {
+            args: func_args![value: value!({ "a": [{ "b": 1 }, { "c": 2 }, { "b": 3 }] }), path: ".a[*].b"],
+            want: Ok(value!([1, 3])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        object_wildcard {
+            args: func_args![value: value!({ "x": { "n": 1 }, "y": { "n": 2 }, "z": "n" }), path: "$.*.n"],
+            want: Ok(value!([1, 2])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        quoted_field {
+            args: func_args![value: value!({ "a.b": { "c d": true } }), path: r#"["a.b"]."c d""#],
+            want: Ok(value!([true])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        type_mismatch {
+            args: func_args![value: value!({ "a": "b" }), path: ".a[0].c"],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::any()),
+        }
+
+        invalid_path {
+            args: func_args![value: value!({ "a": "b" }), path: ".a["],
+            want: Err("invalid argument"),
+            tdef: TypeDef::array(Collection::any()),
+        }
+    ];
+}
@@ -0,0 +1,173 @@
+use ::value::Value;
+use rand::{rngs::StdRng, thread_rng, Rng, SeedableRng};
+use vrl::prelude::*;
+
+use crate::util::seed_hash;
+
+const PROBABILITY_OUT_OF_RANGE_ERR: &str = "probability must be between 0.0 and 1.0";
+
+fn random_bool(probability: Value, seed: Option<Value>) -> Resolved {
+    let probability = get_probability(probability.try_into_f64()?)?;
+
+    let result = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed_hash(&seed)).gen_bool(probability),
+        None => thread_rng().gen_bool(probability),
+    };
+
+    Ok(result.into())
+}
+
+fn get_probability(probability: f64) -> std::result::Result<f64, &'static str> {
+    if (0.0..=1.0).contains(&probability) {
+        Ok(probability)
+    } else {
+        Err(PROBABILITY_OUT_OF_RANGE_ERR)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct RandomBool;
+
+impl Function for RandomBool {
+    fn identifier(&self) -> &'static str {
+        "random_bool"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "probability",
+                kind: kind::FLOAT | kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "seed",
+                kind: kind::ANY,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "always true",
+                source: r#"random_bool(1.0)"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "seeded",
+                source: r#"random_bool(0.0, seed: "abc123")"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let probability = arguments.required("probability");
+        let seed = arguments.optional("seed");
+
+        if let Some(literal) = probability.as_value() {
+            // check if probability is valid
+            let probability = literal
+                .try_into_f64()
+                .expect("probability must be an integer or float");
+            let _ = get_probability(probability).map_err(|err| {
+                vrl::function::Error::InvalidArgument {
+                    keyword: "probability",
+                    value: literal,
+                    error: err,
+                }
+            })?;
+        }
+
+        Ok(RandomBoolFn { probability, seed }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct RandomBoolFn {
+    probability: Box<dyn Expression>,
+    seed: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for RandomBoolFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let probability = self.probability.resolve(ctx)?;
+        let seed = self
+            .seed
+            .as_ref()
+            .map(|expr| expr.resolve(ctx))
+            .transpose()?;
+
+        random_bool(probability, seed)
+    }
+
+    fn type_def(&self, _state: &state::TypeState) -> TypeDef {
+        let valid_literal = self
+            .probability
+            .as_value()
+            .and_then(|value| value.try_into_f64().ok())
+            .map_or(false, |probability| get_probability(probability).is_ok());
+
+        TypeDef::boolean().with_fallibility(!valid_literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        random_bool => RandomBool;
+
+        always {
+            args: func_args![probability: 1.0],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        never {
+            args: func_args![probability: 0],
+            want: Ok(false),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        seeded {
+            args: func_args![probability: 1.0, seed: "abc123"],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        out_of_range {
+            args: func_args![probability: 1.5],
+            want: Err("invalid argument"),
+            tdef: TypeDef::boolean().fallible(),
+        }
+    ];
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let seed = Value::from("abc123");
+        let first = random_bool(Value::from(0.5), Some(seed.clone())).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(
+                random_bool(Value::from(0.5), Some(seed.clone())).unwrap(),
+                first
+            );
+        }
+    }
+
+    #[test]
+    fn wrong_kind() {
+        let error = random_bool(Value::Null, None).unwrap_err();
+
+        assert_eq!(error.to_string(), "can't coerce null into float");
+    }
+}
@@ -7,7 +7,7 @@ const LENGTH_TOO_LARGE_ERR: &str = "Length is too large. Maximum is 64k";
 const LENGTH_TOO_SMALL_ERR: &str = "Length cannot be negative";
 
 fn random_bytes(length: Value) -> Resolved {
-    let mut output = vec![0_u8; get_length(length)?];
+    let mut output = vec![0_u8; get_length(length.try_integer()?)?];
 
     // ThreadRng is a cryptographically secure generator
     thread_rng().fill_bytes(&mut output);
@@ -49,12 +49,14 @@ impl Function for RandomBytes {
 
         if let Some(literal) = length.as_value() {
             // check if length is valid
-            let _ = get_length(literal.clone()).map_err(|err| {
-                vrl::function::Error::InvalidArgument {
-                    keyword: "length",
-                    value: literal,
-                    error: err,
-                }
+            let length = literal
+                .clone()
+                .try_integer()
+                .expect("length must be an integer");
+            let _ = get_length(length).map_err(|err| vrl::function::Error::InvalidArgument {
+                keyword: "length",
+                value: literal,
+                error: err,
             })?;
         }
 
@@ -62,8 +64,7 @@ impl Function for RandomBytes {
     }
 }
 
-fn get_length(value: Value) -> std::result::Result<usize, &'static str> {
-    let length = value.try_integer().expect("length must be an integer");
+fn get_length(length: i64) -> std::result::Result<usize, &'static str> {
     if length < 0 {
         return Err(LENGTH_TOO_SMALL_ERR);
     }
@@ -88,7 +89,10 @@ impl FunctionExpression for RandomBytesFn {
         match self.length.as_value() {
             None => TypeDef::bytes().fallible(),
             Some(value) => {
-                if get_length(value).is_ok() {
+                if value
+                    .try_integer()
+                    .map_or(false, |length| get_length(length).is_ok())
+                {
                     TypeDef::bytes()
                 } else {
                     TypeDef::bytes().fallible()
@@ -0,0 +1,159 @@
+use ::value::Value;
+use regex::Regex;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn replace_with<T>(
+    value: Value,
+    pattern: &Regex,
+    count: Value,
+    ctx: &mut Context,
+    runner: closure::Runner<T>,
+) -> Resolved
+where
+    T: Fn(&mut Context) -> Resolved,
+{
+    let value = value.try_bytes_utf8_lossy()?;
+
+    // A negative count replaces all matches.
+    let count = usize::try_from(count.try_integer()?).unwrap_or(usize::MAX);
+
+    let mut replaced = String::with_capacity(value.len());
+    let mut last_end = 0;
+    for captures in pattern.captures_iter(&value).take(count) {
+        let matched = captures.get(0).expect("the overall match always exists");
+        let mut replacement: Value = util::capture_regex_to_map(pattern, &captures, true).into();
+        runner.map_value(ctx, &mut replacement)?;
+
+        replaced.push_str(&value[last_end..matched.start()]);
+        replaced.push_str(&replacement.try_bytes_utf8_lossy()?);
+        last_end = matched.end();
+    }
+    replaced.push_str(&value[last_end..]);
+
+    Ok(replaced.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ReplaceWith;
+
+impl Function for ReplaceWith {
+    fn identifier(&self) -> &'static str {
+        "replace_with"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "pattern",
+                kind: kind::REGEX,
+                required: true,
+            },
+            Parameter {
+                keyword: "count",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "replace all",
+                source: r#"replace_with("foo bar", r'\w+') -> |captures| { upcase(captures."0") }"#,
+                result: Ok(r#""FOO BAR""#),
+            },
+            Example {
+                title: "replace count",
+                source: r#"replace_with("foo bar", r'\w+', count: 1) -> |captures| { upcase(captures."0") }"#,
+                result: Ok(r#""FOO bar""#),
+            },
+            Example {
+                title: "replace with capture group",
+                source: r#"replace_with("id=12 id=345", r'id=(?P<id>\d+)') -> |captures| { "id=" + to_string(to_int!(captures.id) * 2) }"#,
+                result: Ok(r#""id=24 id=690""#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let pattern = arguments.required_regex("pattern")?;
+        let count = arguments.optional("count").unwrap_or_else(|| expr!(-1));
+        let closure = arguments.required_closure()?;
+
+        Ok(ReplaceWithFn {
+            value,
+            pattern,
+            count,
+            closure,
+        }
+        .as_expr())
+    }
+
+    fn closure(&self) -> Option<closure::Definition> {
+        use closure::{Definition, Input, Output, Variable, VariableKind};
+
+        // The closure receives the same object `parse_regex` returns with `numeric_groups` set,
+        // where `"0"` is the overall match.
+        let mut captures: Collection<Field> = Collection::from_unknown(Kind::bytes().or_null());
+        captures.known_mut().insert("0".into(), Kind::bytes());
+
+        Some(Definition {
+            inputs: vec![Input {
+                parameter_keyword: "value",
+                kind: Kind::bytes(),
+                variables: vec![Variable {
+                    kind: VariableKind::Exact(Kind::object(captures)),
+                }],
+                output: Output::Kind(Kind::bytes()),
+                example: Example {
+                    title: "replace all",
+                    source: r#"replace_with("foo bar", r'\w+') -> |captures| { upcase(captures."0") }"#,
+                    result: Ok(r#""FOO BAR""#),
+                },
+            }],
+            is_iterator: true,
+            is_optional: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ReplaceWithFn {
+    value: Box<dyn Expression>,
+    pattern: Regex,
+    count: Box<dyn Expression>,
+    closure: FunctionClosure,
+}
+
+impl FunctionExpression for ReplaceWithFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let count = self.count.resolve(ctx)?;
+        let FunctionClosure {
+            variables,
+            block,
+            block_type_def: _,
+        } = &self.closure;
+        let runner = closure::Runner::new(variables, |ctx| block.resolve(ctx));
+
+        replace_with(value, &self.pattern, count, ctx, runner)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::bytes().infallible()
+    }
+}
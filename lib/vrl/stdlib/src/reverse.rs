@@ -0,0 +1,126 @@
+use ::value::Value;
+use unicode_segmentation::UnicodeSegmentation;
+use vrl::prelude::*;
+
+fn reverse(value: Value) -> Resolved {
+    match value {
+        Value::Bytes(bytes) => Ok(String::from_utf8_lossy(&bytes)
+            .graphemes(true)
+            .rev()
+            .collect::<String>()
+            .into()),
+        Value::Array(mut array) => {
+            array.reverse();
+            Ok(array.into())
+        }
+        value => Err(value::Error::Expected {
+            got: value.kind(),
+            expected: Kind::bytes() | Kind::array(Collection::any()),
+        }
+        .into()),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Reverse;
+
+impl Function for Reverse {
+    fn identifier(&self) -> &'static str {
+        "reverse"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES | kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "array",
+                source: r#"reverse([1, 2, 3])"#,
+                result: Ok("[3, 2, 1]"),
+            },
+            Example {
+                title: "string",
+                source: r#"reverse("ñandú")"#,
+                result: Ok("údnañ"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(ReverseFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ReverseFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ReverseFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        reverse(value)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        match self.value.type_def(state) {
+            v if v.is_bytes() => TypeDef::bytes(),
+            v if v.is_array() => v.infallible().collect_subtypes(),
+            _ => TypeDef::bytes().or_array(Collection::any()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        reverse => Reverse;
+
+        array {
+            args: func_args![value: value!([1, "foo", true])],
+            want: Ok(value!([true, "foo", 1])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer().or_bytes().or_boolean())),
+        }
+
+        empty_array {
+            args: func_args![value: value!([])],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::empty()),
+        }
+
+        string {
+            args: func_args![value: "foobar"],
+            want: Ok("raboof"),
+            tdef: TypeDef::bytes(),
+        }
+
+        combining_mark {
+            // "e" followed by a combining acute accent stays a single character.
+            args: func_args![value: "cafe\u{301}s"],
+            want: Ok("se\u{301}fac"),
+            tdef: TypeDef::bytes(),
+        }
+
+        emoji_zwj_sequence {
+            args: func_args![value: "a👩‍👩‍👧b"],
+            want: Ok("b👩‍👩‍👧a"),
+            tdef: TypeDef::bytes(),
+        }
+    ];
+}
@@ -1,17 +1,24 @@
-use std::net::IpAddr;
+use std::{io, net::IpAddr};
 
 use ::value::Value;
 use dns_lookup::lookup_addr;
 use vrl::prelude::*;
 
-fn reverse_dns(value: Value) -> Resolved {
+use crate::dns_resolution::Resolver;
+
+type LookupAddr = fn(&IpAddr) -> io::Result<String>;
+
+fn reverse_dns(value: Value, resolver: &Resolver, lookup_addr: LookupAddr) -> Resolved {
     let ip: IpAddr = value
         .try_bytes_utf8_lossy()?
         .parse()
         .map_err(|err| format!("unable to parse IP address: {}", err))?;
-    let host = lookup_addr(&ip).map_err(|err| format!("unable to perform a lookup : {}", err))?;
 
-    Ok(host.into())
+    resolver.resolve(format!("PTR {}", ip), move || {
+        lookup_addr(&ip)
+            .map(Into::into)
+            .map_err(|err| format!("unable to perform a lookup : {}", err))
+    })
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -41,24 +48,26 @@ impl Function for ReverseDns {
     fn compile(
         &self,
         _state: &state::TypeState,
-        _ctx: &mut FunctionCompileContext,
+        ctx: &mut FunctionCompileContext,
         arguments: ArgumentList,
     ) -> Compiled {
+        let resolver = Resolver::new(self.identifier(), ctx)?;
         let value = arguments.required("value");
 
-        Ok(ReverseDnsFn { value }.as_expr())
+        Ok(ReverseDnsFn { value, resolver }.as_expr())
     }
 }
 
 #[derive(Debug, Clone)]
 struct ReverseDnsFn {
     value: Box<dyn Expression>,
+    resolver: Resolver,
 }
 
 impl FunctionExpression for ReverseDnsFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
-        reverse_dns(value)
+        reverse_dns(value, &self.resolver, lookup_addr)
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -68,8 +77,36 @@ impl FunctionExpression for ReverseDnsFn {
 
 #[cfg(test)]
 mod tests {
+    use vrl::{diagnostic::Span, CompileConfig};
+
     use super::*;
 
+    fn resolver() -> Resolver {
+        let ctx = FunctionCompileContext::new(Span::default(), CompileConfig::default());
+
+        Resolver::new("reverse_dns", &ctx).unwrap()
+    }
+
+    fn mock_lookup_addr(ip: &IpAddr) -> io::Result<String> {
+        match ip.to_string().as_str() {
+            "127.0.0.1" | "::1" => Ok("localhost".to_owned()),
+            _ => Err(io::Error::new(io::ErrorKind::NotFound, "no such address")),
+        }
+    }
+
+    #[test]
+    fn mocked_lookups() {
+        let resolver = resolver();
+        let lookup = |ip: &str| reverse_dns(ip.into(), &resolver, mock_lookup_addr);
+
+        assert_eq!(lookup("127.0.0.1").unwrap(), value!("localhost"));
+        assert_eq!(lookup("::1").unwrap(), value!("localhost"));
+        assert_eq!(
+            lookup("10.0.0.1").unwrap_err().to_string(),
+            "unable to perform a lookup : no such address"
+        );
+    }
+
     test_function![
         reverse_dns => ReverseDns;
 
@@ -3,13 +3,56 @@ use vrl::prelude::*;
 
 use crate::util::round_to_precision;
 
-fn round(precision: Value, value: Value) -> Resolved {
+#[derive(Clone, Copy, Debug)]
+enum Mode {
+    HalfUp,
+    HalfEven,
+    Floor,
+    Ceil,
+    Trunc,
+}
+
+impl Mode {
+    fn from_value(value: &Value) -> std::result::Result<Self, &'static str> {
+        let mode = value.as_bytes().map(Bytes::as_ref);
+        match mode {
+            Some(b"half_up") => Ok(Self::HalfUp),
+            Some(b"half_even") => Ok(Self::HalfEven),
+            Some(b"floor") => Ok(Self::Floor),
+            Some(b"ceil") => Ok(Self::Ceil),
+            Some(b"trunc") => Ok(Self::Trunc),
+            _ => Err(r#"mode must be one of "half_up", "half_even", "floor", "ceil" or "trunc""#),
+        }
+    }
+
+    fn apply(self, num: f64) -> f64 {
+        match self {
+            // Halves are rounded away from zero.
+            Self::HalfUp => num.round(),
+            Self::HalfEven => round_half_even(num),
+            Self::Floor => num.floor(),
+            Self::Ceil => num.ceil(),
+            Self::Trunc => num.trunc(),
+        }
+    }
+}
+
+fn round_half_even(num: f64) -> f64 {
+    if (num - num.trunc()).abs() == 0.5 {
+        2.0 * (num / 2.0).round()
+    } else {
+        num.round()
+    }
+}
+
+fn round(precision: Value, mode: Value, value: Value) -> Resolved {
     let precision = precision.try_integer()?;
+    let mode = Mode::from_value(&mode)?;
     match value {
         Value::Float(f) => Ok(Value::from_f64_or_zero(round_to_precision(
             f.into_inner(),
             precision,
-            f64::round,
+            |num| mode.apply(num),
         ))),
         value @ Value::Integer(_) => Ok(value),
         value => Err(value::Error::Expected {
@@ -40,6 +83,11 @@ impl Function for Round {
                 kind: kind::INTEGER,
                 required: false,
             },
+            Parameter {
+                keyword: "mode",
+                kind: kind::BYTES,
+                required: false,
+            },
         ]
     }
 
@@ -60,6 +108,16 @@ impl Function for Round {
                 source: r#"round(5.45, 1)"#,
                 result: Ok("5.5"),
             },
+            Example {
+                title: "half even",
+                source: r#"round(2.5, mode: "half_even")"#,
+                result: Ok("2.0"),
+            },
+            Example {
+                title: "truncate",
+                source: r#"round(-5.67, 1, mode: "trunc")"#,
+                result: Ok("-5.6"),
+            },
         ]
     }
 
@@ -71,8 +129,24 @@ impl Function for Round {
     ) -> Compiled {
         let value = arguments.required("value");
         let precision = arguments.optional("precision").unwrap_or(expr!(0));
+        let mode = arguments.optional("mode").unwrap_or(expr!("half_up"));
 
-        Ok(RoundFn { value, precision }.as_expr())
+        if let Some(literal) = mode.as_value() {
+            let _ = Mode::from_value(&literal).map_err(|err| {
+                vrl::function::Error::InvalidArgument {
+                    keyword: "mode",
+                    value: literal,
+                    error: err,
+                }
+            })?;
+        }
+
+        Ok(RoundFn {
+            value,
+            precision,
+            mode,
+        }
+        .as_expr())
     }
 }
 
@@ -80,18 +154,21 @@ impl Function for Round {
 struct RoundFn {
     value: Box<dyn Expression>,
     precision: Box<dyn Expression>,
+    mode: Box<dyn Expression>,
 }
 
 impl FunctionExpression for RoundFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let precision = self.precision.resolve(ctx)?;
+        let mode = self.mode.resolve(ctx)?;
         let value = self.value.resolve(ctx)?;
 
-        round(precision, value)
+        round(precision, mode, value)
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
-        TypeDef::integer().infallible()
+        // Literal modes are checked at compile time.
+        TypeDef::integer().with_fallibility(self.mode.as_value().is_none())
     }
 }
 
@@ -143,5 +220,65 @@ mod tests {
              want: Ok(9_876_543_210_123_456_789_098_765_432_101_234_567_890_987_654_321.987_65),
              tdef: TypeDef::integer().infallible(),
          }
+
+        half_up {
+            args: func_args![value: -2.5, mode: "half_up"],
+            want: Ok(-3.0),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        half_even_down {
+            args: func_args![value: 2.5, mode: "half_even"],
+            want: Ok(2.0),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        half_even_up {
+            args: func_args![value: 3.5, mode: "half_even"],
+            want: Ok(4.0),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        half_even_negative {
+            args: func_args![value: -2.5, mode: "half_even"],
+            want: Ok(-2.0),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        half_even_precision {
+            args: func_args![value: 0.125, precision: 2, mode: "half_even"],
+            want: Ok(0.12),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        half_even_not_a_half {
+            args: func_args![value: 2.6, mode: "half_even"],
+            want: Ok(3.0),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        floor {
+            args: func_args![value: -1234.39, precision: 1, mode: "floor"],
+            want: Ok(-1234.4),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        ceil {
+            args: func_args![value: 1234.31, precision: 1, mode: "ceil"],
+            want: Ok(1234.4),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        trunc {
+            args: func_args![value: -1234.39, precision: 1, mode: "trunc"],
+            want: Ok(-1234.3),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        invalid_mode {
+            args: func_args![value: 1234.5, mode: "sideways"],
+            want: Err("invalid argument"),
+            tdef: TypeDef::integer().infallible(),
+        }
     ];
 }
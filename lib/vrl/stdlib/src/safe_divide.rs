@@ -0,0 +1,137 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn safe_divide(numerator: Value, denominator: Value, default: Value) -> Resolved {
+    if denominator.try_into_f64()? == 0.0 {
+        return Ok(default);
+    }
+
+    Ok(numerator.try_div(denominator)?)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SafeDivide;
+
+impl Function for SafeDivide {
+    fn identifier(&self) -> &'static str {
+        "safe_divide"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "numerator",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "denominator",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "default",
+                kind: kind::ANY,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "division",
+                source: r#"safe_divide(3, 2)"#,
+                result: Ok("1.5"),
+            },
+            Example {
+                title: "division by zero",
+                source: r#"safe_divide(3, 0)"#,
+                result: Ok("null"),
+            },
+            Example {
+                title: "division by zero with default",
+                source: r#"safe_divide(3, 0, default: 0)"#,
+                result: Ok("0"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let numerator = arguments.required("numerator");
+        let denominator = arguments.required("denominator");
+        let default = arguments.optional("default").unwrap_or(expr!(null));
+
+        Ok(SafeDivideFn {
+            numerator,
+            denominator,
+            default,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SafeDivideFn {
+    numerator: Box<dyn Expression>,
+    denominator: Box<dyn Expression>,
+    default: Box<dyn Expression>,
+}
+
+impl FunctionExpression for SafeDivideFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let numerator = self.numerator.resolve(ctx)?;
+        let denominator = self.denominator.resolve(ctx)?;
+        let default = self.default.resolve(ctx)?;
+
+        safe_divide(numerator, denominator, default)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        TypeDef::float().union(self.default.type_def(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        safe_divide => SafeDivide;
+
+        integers {
+            args: func_args![numerator: 3, denominator: 2],
+            want: Ok(value!(1.5)),
+            tdef: TypeDef::float().or_null(),
+        }
+
+        floats {
+            args: func_args![numerator: 1.0, denominator: 0.5],
+            want: Ok(value!(2.0)),
+            tdef: TypeDef::float().or_null(),
+        }
+
+        zero {
+            args: func_args![numerator: 3, denominator: 0],
+            want: Ok(value!(null)),
+            tdef: TypeDef::float().or_null(),
+        }
+
+        zero_float {
+            args: func_args![numerator: 3, denominator: 0.0],
+            want: Ok(value!(null)),
+            tdef: TypeDef::float().or_null(),
+        }
+
+        zero_with_default {
+            args: func_args![numerator: 3, denominator: 0, default: 0],
+            want: Ok(value!(0)),
+            tdef: TypeDef::float().or_integer(),
+        }
+    ];
+}
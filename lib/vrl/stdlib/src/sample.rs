@@ -0,0 +1,168 @@
+use ::value::Value;
+use rand::{thread_rng, Rng};
+use vrl::prelude::*;
+
+use crate::util::seed_hash;
+
+const RATE_TOO_SMALL_ERR: &str = "rate must be at least 1";
+
+fn sample(rate: Value, seed: Option<Value>) -> Resolved {
+    let rate = get_rate(rate.try_integer()?)?;
+
+    // Mirrors the `sample` transform: a seeded decision keeps the same events
+    // across runs, while an unseeded one samples `1/rate` of events at random.
+    let keep = match seed {
+        Some(seed) => seed_hash(&seed) % rate == 0,
+        None => thread_rng().gen_range(0..rate) == 0,
+    };
+
+    Ok(keep.into())
+}
+
+fn get_rate(rate: i64) -> std::result::Result<u64, &'static str> {
+    if rate < 1 {
+        return Err(RATE_TOO_SMALL_ERR);
+    }
+
+    Ok(rate as u64)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sample;
+
+impl Function for Sample {
+    fn identifier(&self) -> &'static str {
+        "sample"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "rate",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "seed",
+                kind: kind::ANY,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "keep every event",
+                source: r#"sample(1)"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "seeded",
+                source: r#"sample(1, seed: "abc123")"#,
+                result: Ok("true"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let rate = arguments.required("rate");
+        let seed = arguments.optional("seed");
+
+        if let Some(literal) = rate.as_value() {
+            // check if rate is valid
+            let rate = literal
+                .clone()
+                .try_integer()
+                .expect("rate must be an integer");
+            let _ = get_rate(rate).map_err(|err| vrl::function::Error::InvalidArgument {
+                keyword: "rate",
+                value: literal,
+                error: err,
+            })?;
+        }
+
+        Ok(SampleFn { rate, seed }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SampleFn {
+    rate: Box<dyn Expression>,
+    seed: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for SampleFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let rate = self.rate.resolve(ctx)?;
+        let seed = self
+            .seed
+            .as_ref()
+            .map(|expr| expr.resolve(ctx))
+            .transpose()?;
+
+        sample(rate, seed)
+    }
+
+    fn type_def(&self, _state: &state::TypeState) -> TypeDef {
+        let valid_literal = self
+            .rate
+            .as_value()
+            .and_then(|value| value.try_integer().ok())
+            .map_or(false, |rate| get_rate(rate).is_ok());
+
+        TypeDef::boolean().with_fallibility(!valid_literal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        sample => Sample;
+
+        keep_all {
+            args: func_args![rate: 1],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        keep_all_seeded {
+            args: func_args![rate: 1, seed: "abc123"],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        zero_rate {
+            args: func_args![rate: 0],
+            want: Err("invalid argument"),
+            tdef: TypeDef::boolean().fallible(),
+        }
+    ];
+
+    #[test]
+    fn seeded_is_deterministic() {
+        let rate = Value::from(2);
+
+        for seed in ["a", "b", "c", "d"] {
+            let first = sample(rate.clone(), Some(seed.into())).unwrap();
+
+            for _ in 0..10 {
+                assert_eq!(sample(rate.clone(), Some(seed.into())).unwrap(), first);
+            }
+        }
+    }
+
+    #[test]
+    fn wrong_kind() {
+        let error = sample(Value::Null, None).unwrap_err();
+
+        assert_eq!(error.to_string(), "expected integer, got null");
+    }
+}
@@ -0,0 +1,112 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn signum(value: Value) -> Resolved {
+    match value {
+        Value::Integer(i) => Ok(i.signum().into()),
+        // Unlike `f64::signum`, zero has no sign.
+        Value::Float(f) if f.into_inner() == 0.0 => Ok(Value::from_f64_or_zero(0.0)),
+        Value::Float(f) => Ok(Value::from_f64_or_zero(f.into_inner().signum())),
+        value => Err(value::Error::Expected {
+            got: value.kind(),
+            expected: Kind::float() | Kind::integer(),
+        }
+        .into()),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Signum;
+
+impl Function for Signum {
+    fn identifier(&self) -> &'static str {
+        "signum"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::INTEGER | kind::FLOAT,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "negative integer",
+                source: r#"signum(-42)"#,
+                result: Ok("-1"),
+            },
+            Example {
+                title: "float",
+                source: r#"signum(4.2)"#,
+                result: Ok("1.0"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(SignumFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SignumFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for SignumFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        signum(value)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        match Kind::from(self.value.type_def(state)) {
+            v if v.is_float() || v.is_integer() => v.into(),
+            _ => Kind::integer().or_float().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        signum => Signum;
+
+        negative_integer {
+            args: func_args![value: -42],
+            want: Ok(value!(-1)),
+            tdef: TypeDef::integer(),
+        }
+
+        zero_integer {
+            args: func_args![value: 0],
+            want: Ok(value!(0)),
+            tdef: TypeDef::integer(),
+        }
+
+        positive_float {
+            args: func_args![value: 4.2],
+            want: Ok(value!(1.0)),
+            tdef: TypeDef::float(),
+        }
+
+        negative_zero_float {
+            args: func_args![value: -0.0],
+            want: Ok(value!(0.0)),
+            tdef: TypeDef::float(),
+        }
+    ];
+}
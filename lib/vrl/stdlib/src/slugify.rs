@@ -0,0 +1,214 @@
+use ::value::Value;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+use vrl::prelude::*;
+
+/// Replaces characters with their closest ASCII equivalent, where one is known.
+///
+/// Accents and other diacritics are removed by decomposing characters and dropping the combining
+/// marks, letters that don't decompose are spelled out.
+fn transliterate(value: &str) -> String {
+    let mut ascii = String::with_capacity(value.len());
+
+    for c in value.nfd().filter(|c| !is_combining_mark(*c)) {
+        match c {
+            'ß' => ascii.push_str("ss"),
+            'Æ' | 'æ' => ascii.push_str("ae"),
+            'Œ' | 'œ' => ascii.push_str("oe"),
+            'Þ' | 'þ' => ascii.push_str("th"),
+            'Ø' | 'ø' => ascii.push('o'),
+            'Đ' | 'đ' | 'Ð' | 'ð' => ascii.push('d'),
+            'Ł' | 'ł' => ascii.push('l'),
+            'ı' => ascii.push('i'),
+            c => ascii.push(c),
+        }
+    }
+
+    ascii
+}
+
+fn slugify(value: &str, separator: &str, transliterate_chars: bool) -> String {
+    let value = if transliterate_chars {
+        transliterate(value)
+    } else {
+        value.to_owned()
+    };
+
+    let mut slug = String::with_capacity(value.len());
+    let mut separate = false;
+
+    for c in value.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            // Runs of other characters become a single separator, and leading or trailing ones
+            // are dropped entirely.
+            if separate && !slug.is_empty() {
+                slug.push_str(separator);
+            }
+            separate = false;
+            slug.push(c);
+        } else {
+            separate = true;
+        }
+    }
+
+    slug
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Slugify;
+
+impl Function for Slugify {
+    fn identifier(&self) -> &'static str {
+        "slugify"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "separator",
+                kind: kind::BYTES,
+                required: false,
+            },
+            Parameter {
+                keyword: "transliterate",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "slugify",
+                source: r#"slugify("Crème Brûlée: A Recipe!")"#,
+                result: Ok("creme-brulee-a-recipe"),
+            },
+            Example {
+                title: "custom separator",
+                source: r#"slugify("Hello,  World", separator: "_")"#,
+                result: Ok("hello_world"),
+            },
+            Example {
+                title: "without transliteration",
+                source: r#"slugify("Straße 5", transliterate: false)"#,
+                result: Ok("straße-5"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let separator = arguments
+            .optional("separator")
+            .unwrap_or_else(|| expr!("-"));
+        let transliterate = arguments
+            .optional("transliterate")
+            .unwrap_or_else(|| expr!(true));
+
+        Ok(SlugifyFn {
+            value,
+            separator,
+            transliterate,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SlugifyFn {
+    value: Box<dyn Expression>,
+    separator: Box<dyn Expression>,
+    transliterate: Box<dyn Expression>,
+}
+
+impl FunctionExpression for SlugifyFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let separator = self.separator.resolve(ctx)?;
+        let transliterate = self.transliterate.resolve(ctx)?.try_boolean()?;
+
+        Ok(slugify(
+            &value.try_bytes_utf8_lossy()?,
+            &separator.try_bytes_utf8_lossy()?,
+            transliterate,
+        )
+        .into())
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::bytes().infallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        slugify => Slugify;
+
+        simple {
+            args: func_args![value: "Hello World"],
+            want: Ok("hello-world"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        punctuation {
+            args: func_args![value: "  --Hello,   World!?-- "],
+            want: Ok("hello-world"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        accents {
+            args: func_args![value: "Crème Brûlée à la Ñandú"],
+            want: Ok("creme-brulee-a-la-nandu"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        special_letters {
+            args: func_args![value: "Große Æsir Øresund Łódź"],
+            want: Ok("grosse-aesir-oresund-lodz"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        no_transliteration {
+            args: func_args![value: "Crème Brûlée", transliterate: false],
+            want: Ok("crème-brûlée"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        separator {
+            args: func_args![value: "a b_c", separator: "_"],
+            want: Ok("a_b_c"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        empty_separator {
+            args: func_args![value: "Hello World", separator: ""],
+            want: Ok("helloworld"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        numbers {
+            args: func_args![value: "Release 2.0.1"],
+            want: Ok("release-2-0-1"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        empty {
+            args: func_args![value: "!!!"],
+            want: Ok(""),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+}
@@ -0,0 +1,188 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+use crate::util;
+
+fn sort(value: Value, descending: Value, numeric: Value) -> Resolved {
+    let array = value.try_array()?;
+    let descending = descending.try_boolean()?;
+    let numeric = numeric.try_boolean()?;
+
+    let order = util::sort_order(&array, descending, numeric)?;
+
+    Ok(util::apply_sort_order(array, order).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sort;
+
+impl Function for Sort {
+    fn identifier(&self) -> &'static str {
+        "sort"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "descending",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "numeric",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "ascending",
+                source: r#"sort([3, 1.5, 2])"#,
+                result: Ok("[1.5, 2, 3]"),
+            },
+            Example {
+                title: "descending",
+                source: r#"sort(["b", "c", "a"], descending: true)"#,
+                result: Ok(r#"["c", "b", "a"]"#),
+            },
+            Example {
+                title: "numeric",
+                source: r#"sort!(["10", "9", "100"], numeric: true)"#,
+                result: Ok(r#"["9", "10", "100"]"#),
+            },
+            Example {
+                title: "mixed types",
+                source: r#"sort!([1, "a"])"#,
+                result: Err(
+                    r#"function call error for "sort" at (0:15): unable to compare integer with string"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let descending = arguments
+            .optional("descending")
+            .unwrap_or_else(|| expr!(false));
+        let numeric = arguments.optional("numeric");
+
+        Ok(SortFn {
+            value,
+            descending,
+            numeric,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SortFn {
+    value: Box<dyn Expression>,
+    descending: Box<dyn Expression>,
+    numeric: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for SortFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let descending = self.descending.resolve(ctx)?;
+        let numeric = self
+            .numeric
+            .as_ref()
+            .map(|numeric| numeric.resolve(ctx))
+            .transpose()?
+            .unwrap_or(Value::Boolean(false));
+
+        sort(value, descending, numeric)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let elements = self
+            .value
+            .type_def(state)
+            .kind()
+            .as_array()
+            .map_or_else(Kind::any, Collection::reduced_kind);
+        let fallible = util::sort_is_fallible(&elements, self.numeric.is_some());
+
+        // The elements keep their types, but not their positions.
+        TypeDef::array(Collection::from_unknown(elements)).with_fallibility(fallible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        sort => Sort;
+
+        integers {
+            args: func_args![value: value!([3, 1, 2])],
+            want: Ok(value!([1, 2, 3])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())).infallible(),
+        }
+
+        mixed_numbers {
+            args: func_args![value: value!([3, 1.5, -2])],
+            want: Ok(value!([-2, 1.5, 3])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer() | Kind::float())).infallible(),
+        }
+
+        strings_descending {
+            args: func_args![value: value!(["b", "c", "a"]), descending: true],
+            want: Ok(value!(["c", "b", "a"])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())).infallible(),
+        }
+
+        strings_lexical {
+            args: func_args![value: value!(["10", "9", "100"])],
+            want: Ok(value!(["10", "100", "9"])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())).infallible(),
+        }
+
+        strings_numeric {
+            args: func_args![value: value!(["10", "9", "100", "1.5"]), numeric: true],
+            want: Ok(value!(["1.5", "9", "10", "100"])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())).fallible(),
+        }
+
+        non_numeric {
+            args: func_args![value: value!(["10", "foo"]), numeric: true],
+            want: Err(r#"unable to sort non-numeric value "foo" numerically"#),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())).fallible(),
+        }
+
+        mixed_types {
+            args: func_args![value: value!([1, "a", true])],
+            want: Err("unable to compare integer with string"),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer() | Kind::bytes() | Kind::boolean())).fallible(),
+        }
+
+        unsortable {
+            args: func_args![value: value!([[], []])],
+            want: Err("unable to sort values of type array"),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::empty()))).fallible(),
+        }
+
+        empty {
+            args: func_args![value: value!([])],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::never())).infallible(),
+        }
+    ];
+}
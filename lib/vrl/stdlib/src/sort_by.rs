@@ -0,0 +1,169 @@
+use ::value::{kind::Collection, Value};
+use vrl::prelude::*;
+
+use crate::util;
+
+fn sort_by<T>(
+    value: Value,
+    descending: Value,
+    numeric: Value,
+    ctx: &mut Context,
+    runner: closure::Runner<T>,
+) -> Resolved
+where
+    T: Fn(&mut Context) -> Resolved,
+{
+    let array = value.try_array()?;
+    let descending = descending.try_boolean()?;
+    let numeric = numeric.try_boolean()?;
+
+    let keys = array
+        .iter()
+        .enumerate()
+        .map(|(index, value)| runner.run_index_value(ctx, index, value))
+        .collect::<Result<Vec<_>, _>>()?;
+    let order = util::sort_order(&keys, descending, numeric)?;
+
+    Ok(util::apply_sort_order(array, order).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SortBy;
+
+impl Function for SortBy {
+    fn identifier(&self) -> &'static str {
+        "sort_by"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "descending",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+            Parameter {
+                keyword: "numeric",
+                kind: kind::BOOLEAN,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "sort by field",
+                source: r#"sort_by([{ "n": 2 }, { "n": 1 }, { "n": 3 }]) -> |_index, value| { value.n }"#,
+                result: Ok(r#"[{ "n": 1 }, { "n": 2 }, { "n": 3 }]"#),
+            },
+            Example {
+                title: "top values",
+                source: r#"sort_by([{ "n": 2, "v": "a" }, { "n": 3, "v": "b" }, { "n": 2, "v": "c" }], descending: true) -> |_index, value| { value.n }"#,
+                result: Ok(r#"[{ "n": 3, "v": "b" }, { "n": 2, "v": "a" }, { "n": 2, "v": "c" }]"#),
+            },
+            Example {
+                title: "sort by numeric string",
+                source: r#"sort_by!([{ "size": "10" }, { "size": "9" }], numeric: true) -> |_index, value| { value.size }"#,
+                result: Ok(r#"[{ "size": "9" }, { "size": "10" }]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let descending = arguments
+            .optional("descending")
+            .unwrap_or_else(|| expr!(false));
+        let numeric = arguments.optional("numeric");
+        let closure = arguments.required_closure()?;
+
+        Ok(SortByFn {
+            value,
+            descending,
+            numeric,
+            closure,
+        }
+        .as_expr())
+    }
+
+    fn closure(&self) -> Option<closure::Definition> {
+        use closure::{Definition, Input, Output, Variable, VariableKind};
+
+        Some(Definition {
+            inputs: vec![Input {
+                parameter_keyword: "value",
+                kind: Kind::array(Collection::any()),
+                variables: vec![
+                    Variable {
+                        kind: VariableKind::TargetInnerKey,
+                    },
+                    Variable {
+                        kind: VariableKind::TargetInnerValue,
+                    },
+                ],
+                output: Output::Kind(Kind::any()),
+                example: Example {
+                    title: "sort by field",
+                    source: r#"sort_by([{ "n": 2 }, { "n": 1 }]) -> |_index, value| { value.n }"#,
+                    result: Ok(r#"[{ "n": 1 }, { "n": 2 }]"#),
+                },
+            }],
+            is_iterator: true,
+            is_optional: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SortByFn {
+    value: Box<dyn Expression>,
+    descending: Box<dyn Expression>,
+    numeric: Option<Box<dyn Expression>>,
+    closure: FunctionClosure,
+}
+
+impl FunctionExpression for SortByFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let descending = self.descending.resolve(ctx)?;
+        let numeric = self
+            .numeric
+            .as_ref()
+            .map(|numeric| numeric.resolve(ctx))
+            .transpose()?
+            .unwrap_or(Value::Boolean(false));
+        let FunctionClosure {
+            variables,
+            block,
+            block_type_def: _,
+        } = &self.closure;
+        let runner = closure::Runner::new(variables, |ctx| block.resolve(ctx));
+
+        sort_by(value, descending, numeric, ctx, runner)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let elements = self
+            .value
+            .type_def(state)
+            .kind()
+            .as_array()
+            .map_or_else(Kind::any, Collection::reduced_kind);
+        let keys = self.closure.block_type_def.kind();
+        let fallible = util::sort_is_fallible(keys, self.numeric.is_some());
+
+        // The elements keep their types, but not their positions.
+        TypeDef::array(Collection::from_unknown(elements)).with_fallibility(fallible)
+    }
+}
@@ -0,0 +1,97 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn sqrt(value: Value) -> Resolved {
+    let value = value.try_into_f64()?;
+    if value < 0.0 {
+        return Err("can't take the square root of a negative number".into());
+    }
+
+    Ok(Value::from_f64_or_zero(value.sqrt()))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sqrt;
+
+impl Function for Sqrt {
+    fn identifier(&self) -> &'static str {
+        "sqrt"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::INTEGER | kind::FLOAT,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "integer",
+            source: r#"sqrt!(16)"#,
+            result: Ok("4.0"),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        Ok(SqrtFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SqrtFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for SqrtFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        sqrt(value)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::float().fallible(/* negative value */)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        sqrt => Sqrt;
+
+        integer {
+            args: func_args![value: 16],
+            want: Ok(value!(4.0)),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        float {
+            args: func_args![value: 2.25],
+            want: Ok(value!(1.5)),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        zero {
+            args: func_args![value: 0],
+            want: Ok(value!(0.0)),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        negative {
+            args: func_args![value: -1],
+            want: Err("can't take the square root of a negative number"),
+            tdef: TypeDef::float().fallible(),
+        }
+    ];
+}
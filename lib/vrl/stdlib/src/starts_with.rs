@@ -1,3 +1,4 @@
+use ::value::Value;
 use vrl::prelude::*;
 
 struct Chars<'a> {
@@ -39,6 +40,7 @@ impl<'a> Iterator for Chars<'a> {
     }
 }
 
+#[derive(Clone, Copy)]
 enum Case {
     Sensitive,
     Insensitive,
@@ -68,6 +70,21 @@ fn starts_with(bytes: &Bytes, starts: &Bytes, case: Case) -> bool {
     }
 }
 
+/// Checks the value against either a single prefix, or an array of candidate prefixes.
+fn starts_with_any(value: &Bytes, substring: Value, case: Case) -> Resolved {
+    match substring {
+        Value::Array(candidates) => {
+            for candidate in candidates {
+                if starts_with(value, &candidate.try_bytes()?, case) {
+                    return Ok(true.into());
+                }
+            }
+            Ok(false.into())
+        }
+        substring => Ok(starts_with(value, &substring.try_bytes()?, case).into()),
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct StartsWith;
 
@@ -85,7 +102,7 @@ impl Function for StartsWith {
             },
             Parameter {
                 keyword: "substring",
-                kind: kind::BYTES,
+                kind: kind::BYTES | kind::ARRAY,
                 required: true,
             },
             Parameter {
@@ -113,6 +130,11 @@ impl Function for StartsWith {
                 source: r#"starts_with("foobar", "bar")"#,
                 result: Ok("false"),
             },
+            Example {
+                title: "any of multiple prefixes",
+                source: r#"starts_with("foobar", ["bar", "foo"])"#,
+                result: Ok("true"),
+            },
         ]
     }
 
@@ -151,16 +173,22 @@ impl FunctionExpression for StartsWithFn {
         };
 
         let substring = self.substring.resolve(ctx)?;
-        let substring = substring.try_bytes()?;
 
         let value = self.value.resolve(ctx)?;
         let value = value.try_bytes()?;
 
-        Ok(starts_with(&value, &substring, case_sensitive).into())
+        starts_with_any(&value, substring, case_sensitive)
     }
 
-    fn type_def(&self, _: &state::TypeState) -> TypeDef {
-        TypeDef::boolean().infallible()
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        // Candidate prefixes given as an array fail at runtime if any of them isn't a string.
+        let fallible = self
+            .substring
+            .type_def(state)
+            .as_array()
+            .map_or(false, |array| !array.reduced_kind().is_bytes());
+
+        TypeDef::boolean().with_fallibility(fallible)
     }
 }
 
@@ -245,6 +273,47 @@ mod tests {
             tdef: TypeDef::boolean().infallible(),
         }
 
+        array_match {
+            args: func_args![value: "foobar",
+                             substring: value!(["baz", "foo"])
+            ],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        array_no_match {
+            args: func_args![value: "foobar",
+                             substring: value!(["baz", "bar"])
+            ],
+            want: Ok(false),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        array_case_insensitive {
+            args: func_args![value: "foobar",
+                             substring: value!(["baz", "FOO"]),
+                             case_sensitive: false
+            ],
+            want: Ok(true),
+            tdef: TypeDef::boolean().infallible(),
+        }
+
+        array_empty {
+            args: func_args![value: "foobar",
+                             substring: value!([])
+            ],
+            want: Ok(false),
+            tdef: TypeDef::boolean().fallible(),
+        }
+
+        array_not_string {
+            args: func_args![value: "foobar",
+                             substring: value!([1, "foo"])
+            ],
+            want: Err("expected string, got integer"),
+            tdef: TypeDef::boolean().fallible(),
+        }
+
         unicode_same_case {
             args: func_args![value: "𛋙ၺ㚺𛋙Zonkکᤊᰙ𛋙Ꮺ믚㋫𐠘𒃪𖾛𞺘ᰙꢝⶺ觨⨙ઉzook",
                              substring: "𛋙ၺ㚺𛋙Zonkکᤊᰙ𛋙",
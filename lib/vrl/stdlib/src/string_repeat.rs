@@ -0,0 +1,174 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+/// The maximum number of bytes a repeated string may take up, to keep a large count from using
+/// up all memory.
+const MAX_LENGTH: usize = 1024 * 1024;
+
+fn string_repeat(value: Value, count: Value) -> Resolved {
+    let value = value.try_bytes()?;
+    let count = repeat_count(Some(value.len()), count.try_integer()?)?;
+
+    Ok(Bytes::from(value.repeat(count)).into())
+}
+
+/// Validates `count`, given the length of the string to repeat if it's known. If it isn't, the
+/// string is assumed to be a single byte long, the shortest string that can exceed the limit.
+fn repeat_count(len: Option<usize>, count: i64) -> std::result::Result<usize, &'static str> {
+    let count = usize::try_from(count).map_err(|_| "count can't be negative")?;
+
+    match count.checked_mul(len.unwrap_or(1)) {
+        Some(total) if total <= MAX_LENGTH => Ok(count),
+        _ => Err("repeated string would be larger than the maximum of 1MiB"),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct StringRepeat;
+
+impl Function for StringRepeat {
+    fn identifier(&self) -> &'static str {
+        "string_repeat"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "count",
+                kind: kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "separator",
+                source: r#"string_repeat("=", 10)"#,
+                result: Ok("=========="),
+            },
+            Example {
+                title: "multi-character string",
+                source: r#"string_repeat("ab", 3)"#,
+                result: Ok("ababab"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let count = arguments.required("count");
+
+        if let Some(literal) = count.as_value() {
+            let len = value
+                .as_value()
+                .and_then(|value| value.as_bytes().map(Bytes::len));
+            let count = literal.try_integer().expect("count must be an integer");
+
+            if let Err(error) = repeat_count(len, count) {
+                return Err(vrl::function::Error::InvalidArgument {
+                    keyword: "count",
+                    value: literal,
+                    error,
+                }
+                .into());
+            }
+        }
+
+        Ok(StringRepeatFn { value, count }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct StringRepeatFn {
+    value: Box<dyn Expression>,
+    count: Box<dyn Expression>,
+}
+
+impl FunctionExpression for StringRepeatFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let count = self.count.resolve(ctx)?;
+
+        string_repeat(value, count)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Only a constant string repeated a constant number of times is checked at compile time.
+        let checked = self.value.as_value().is_some() && self.count.as_value().is_some();
+
+        TypeDef::bytes().with_fallibility(!checked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        string_repeat => StringRepeat;
+
+        repeat {
+            args: func_args![value: "ab", count: 3],
+            want: Ok("ababab"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        zero {
+            args: func_args![value: "ab", count: 0],
+            want: Ok(""),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        empty {
+            args: func_args![value: "", count: 1_000_000_000],
+            want: Ok(""),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        at_limit {
+            args: func_args![value: "abcd", count: 256 * 1024],
+            want: Ok("abcd".repeat(256 * 1024)),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        too_large {
+            args: func_args![value: "abcd", count: 256 * 1024 + 1],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        overflow {
+            args: func_args![value: "abcd", count: i64::MAX],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+
+        negative {
+            args: func_args![value: "ab", count: -1],
+            want: Err("invalid argument"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+
+    #[test]
+    fn runtime_limit() {
+        let error = string_repeat("ab".into(), Value::from(512 * 1024 + 1)).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "repeated string would be larger than the maximum of 1MiB"
+        );
+    }
+}
@@ -1,24 +1,23 @@
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 
 use ::value::Value;
 use vrl::prelude::*;
 
+use crate::util::tally_key;
+
 fn tally(value: Value) -> Resolved {
-    let value = value.try_array()?;
-    #[allow(clippy::mutable_key_type)] // false positive due to bytes::Bytes
-    let mut map: HashMap<Bytes, usize> = HashMap::new();
-    for value in value {
-        if let Value::Bytes(value) = value {
-            *map.entry(value).or_insert(0) += 1;
-        } else {
-            return Err(format!("all values must be strings, found: {:?}", value).into());
-        }
+    let array = value.try_array()?;
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+
+    for (index, value) in array.iter().enumerate() {
+        *counts.entry(tally_key(index, value)?).or_default() += 1;
     }
-    let map: BTreeMap<_, _> = map
+
+    Ok(counts
         .into_iter()
-        .map(|(k, v)| (String::from_utf8_lossy(&k).into_owned(), Value::from(v)))
-        .collect();
-    Ok(map.into())
+        .map(|(key, count)| (key, Value::from(count)))
+        .collect::<BTreeMap<_, _>>()
+        .into())
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -30,11 +29,18 @@ impl Function for Tally {
     }
 
     fn examples(&self) -> &'static [Example] {
-        &[Example {
-            title: "tally",
-            source: r#"tally!(["foo", "bar", "foo", "baz"])"#,
-            result: Ok(r#"{"foo": 2, "bar": 1, "baz": 1}"#),
-        }]
+        &[
+            Example {
+                title: "tally",
+                source: r#"tally!(["foo", "bar", "foo", "baz"])"#,
+                result: Ok(r#"{"foo": 2, "bar": 1, "baz": 1}"#),
+            },
+            Example {
+                title: "tally status codes",
+                source: r#"tally!([200, 404, 200, 500])"#,
+                result: Ok(r#"{"200": 2, "404": 1, "500": 1}"#),
+            },
+        ]
     }
 
     fn compile(
@@ -69,6 +75,7 @@ impl FunctionExpression for TallyFn {
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        // Always fallible, so that existing programs calling `tally!` keep compiling.
         TypeDef::object(Collection::from_unknown(Kind::integer())).fallible()
     }
 }
@@ -88,11 +95,35 @@ mod tests {
             tdef: TypeDef::object(Collection::from_unknown(Kind::integer())).fallible(),
         }
 
-        non_string_values {
+        scalar_values {
+            args: func_args![
+                value: value!([200, 404, 200, true, 1.5, "200"]),
+            ],
+            want: Ok(value!({"200": 3, "404": 1, "true": 1, "1.5": 1})),
+            tdef: TypeDef::object(Collection::from_unknown(Kind::integer())).fallible(),
+        }
+
+        empty {
+            args: func_args![
+                value: value!([]),
+            ],
+            want: Ok(value!({})),
+            tdef: TypeDef::object(Collection::from_unknown(Kind::integer())).fallible(),
+        }
+
+        non_scalar_values {
             args: func_args![
                 value: value!(["foo", [1,2,3], "123abc", 1, true, [1,2,3], "foo", true, 1]),
             ],
-            want: Err("all values must be strings, found: Array([Integer(1), Integer(2), Integer(3)])"),
+            want: Err("cannot tally array at index 1, expected a string, integer, float, boolean or timestamp"),
+            tdef: TypeDef::object(Collection::from_unknown(Kind::integer())).fallible(),
+        }
+
+        null_value {
+            args: func_args![
+                value: value!(["foo", null]),
+            ],
+            want: Err("cannot tally null at index 1, expected a string, integer, float, boolean or timestamp"),
             tdef: TypeDef::object(Collection::from_unknown(Kind::integer())).fallible(),
         }
     ];
@@ -0,0 +1,123 @@
+use std::collections::BTreeMap;
+
+use ::value::{kind::Collection, Value};
+use vrl::prelude::*;
+
+use crate::util::{tally_key, tally_key_kind};
+
+fn tally_by<T>(value: Value, ctx: &mut Context, runner: closure::Runner<T>) -> Resolved
+where
+    T: Fn(&mut Context) -> Resolved,
+{
+    let array = value.try_array()?;
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+
+    for (index, value) in array.into_iter().enumerate() {
+        let key = runner.run_index_value(ctx, index, &value)?;
+
+        *counts.entry(tally_key(index, &key)?).or_default() += 1;
+    }
+
+    Ok(counts
+        .into_iter()
+        .map(|(key, count)| (key, Value::from(count)))
+        .collect::<BTreeMap<_, _>>()
+        .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TallyBy;
+
+impl Function for TallyBy {
+    fn identifier(&self) -> &'static str {
+        "tally_by"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "tally by field",
+                source: r#"tally_by([{ "status": 200 }, { "status": 404 }, { "status": 200 }]) -> |_index, value| { value.status }"#,
+                result: Ok(r#"{ "200": 2, "404": 1 }"#),
+            },
+            Example {
+                title: "tally by derived key",
+                source: r#"tally_by(["foo", "bar", "FOO"]) -> |_index, value| { downcase(string!(value)) }"#,
+                result: Ok(r#"{ "bar": 1, "foo": 2 }"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let closure = arguments.required_closure()?;
+
+        Ok(TallyByFn { value, closure }.as_expr())
+    }
+
+    fn closure(&self) -> Option<closure::Definition> {
+        use closure::{Definition, Input, Output, Variable, VariableKind};
+
+        Some(Definition {
+            inputs: vec![Input {
+                parameter_keyword: "value",
+                kind: Kind::array(Collection::any()),
+                variables: vec![
+                    Variable {
+                        kind: VariableKind::TargetInnerKey,
+                    },
+                    Variable {
+                        kind: VariableKind::TargetInnerValue,
+                    },
+                ],
+                output: Output::Kind(Kind::any()),
+                example: Example {
+                    title: "tally by field",
+                    source: r#"tally_by([{ "id": "a" }, { "id": "a" }]) -> |_index, value| { value.id }"#,
+                    result: Ok(r#"{ "a": 2 }"#),
+                },
+            }],
+            is_iterator: true,
+            is_optional: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TallyByFn {
+    value: Box<dyn Expression>,
+    closure: FunctionClosure,
+}
+
+impl FunctionExpression for TallyByFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let FunctionClosure {
+            variables,
+            block,
+            block_type_def: _,
+        } = &self.closure;
+        let runner = closure::Runner::new(variables, |ctx| block.resolve(ctx));
+
+        tally_by(value, ctx, runner)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        let fallible = !tally_key_kind().is_superset(self.closure.block_type_def.kind());
+
+        TypeDef::object(Collection::from_unknown(Kind::integer())).with_fallibility(fallible)
+    }
+}
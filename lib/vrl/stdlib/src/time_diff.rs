@@ -0,0 +1,230 @@
+use std::str::FromStr;
+
+use ::value::Value;
+use vrl::prelude::*;
+
+fn time_diff(value: Value, other: Value, unit: Unit) -> Resolved {
+    let diff = value.try_timestamp()? - other.try_timestamp()?;
+    let diff = match unit {
+        Unit::Days => Some(diff.num_days()),
+        Unit::Hours => Some(diff.num_hours()),
+        Unit::Minutes => Some(diff.num_minutes()),
+        Unit::Seconds => Some(diff.num_seconds()),
+        Unit::Milliseconds => Some(diff.num_milliseconds()),
+        Unit::Microseconds => diff.num_microseconds(),
+        Unit::Nanoseconds => diff.num_nanoseconds(),
+    };
+
+    diff.map(Into::into).ok_or_else(|| {
+        format!(
+            "difference is too large to be represented in {}",
+            unit.as_str()
+        )
+        .into()
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TimeDiff;
+
+impl Function for TimeDiff {
+    fn identifier(&self) -> &'static str {
+        "time_diff"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "default (seconds)",
+                source: "time_diff(t'2021-01-01T00:01:30Z', t'2021-01-01T00:00:00Z')",
+                result: Ok("90"),
+            },
+            Example {
+                title: "hours",
+                source: r#"time_diff(t'2021-01-01T00:00:00Z', t'2021-01-02T12:00:00Z', unit: "hours")"#,
+                result: Ok("-36"),
+            },
+        ]
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+            Parameter {
+                keyword: "other",
+                kind: kind::TIMESTAMP,
+                required: true,
+            },
+            Parameter {
+                keyword: "unit",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let other = arguments.required("other");
+
+        let unit = arguments
+            .optional_enum("unit", Unit::all_value().as_slice())?
+            .map(|s| {
+                Unit::from_str(&s.try_bytes_utf8_lossy().expect("unit not bytes"))
+                    .expect("validated enum")
+            })
+            .unwrap_or_default();
+
+        Ok(TimeDiffFn { value, other, unit }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl Unit {
+    fn all_value() -> Vec<Value> {
+        use Unit::{Days, Hours, Microseconds, Milliseconds, Minutes, Nanoseconds, Seconds};
+
+        vec![
+            Days,
+            Hours,
+            Minutes,
+            Seconds,
+            Milliseconds,
+            Microseconds,
+            Nanoseconds,
+        ]
+        .into_iter()
+        .map(|u| u.as_str().into())
+        .collect::<Vec<_>>()
+    }
+
+    const fn as_str(self) -> &'static str {
+        use Unit::{Days, Hours, Microseconds, Milliseconds, Minutes, Nanoseconds, Seconds};
+
+        match self {
+            Days => "days",
+            Hours => "hours",
+            Minutes => "minutes",
+            Seconds => "seconds",
+            Milliseconds => "milliseconds",
+            Microseconds => "microseconds",
+            Nanoseconds => "nanoseconds",
+        }
+    }
+
+    /// Differences in the smallest units can overflow an `i64` for timestamps far apart.
+    const fn can_overflow(self) -> bool {
+        matches!(self, Unit::Microseconds | Unit::Nanoseconds)
+    }
+}
+
+impl Default for Unit {
+    fn default() -> Self {
+        Unit::Seconds
+    }
+}
+
+impl FromStr for Unit {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        use Unit::{Days, Hours, Microseconds, Milliseconds, Minutes, Nanoseconds, Seconds};
+
+        match s {
+            "days" => Ok(Days),
+            "hours" => Ok(Hours),
+            "minutes" => Ok(Minutes),
+            "seconds" => Ok(Seconds),
+            "milliseconds" => Ok(Milliseconds),
+            "microseconds" => Ok(Microseconds),
+            "nanoseconds" => Ok(Nanoseconds),
+            _ => Err("unit not recognized"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TimeDiffFn {
+    value: Box<dyn Expression>,
+    other: Box<dyn Expression>,
+    unit: Unit,
+}
+
+impl FunctionExpression for TimeDiffFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let other = self.other.resolve(ctx)?;
+
+        time_diff(value, other, self.unit)
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::integer().with_fallibility(self.unit.can_overflow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    test_function![
+        time_diff => TimeDiff;
+
+        seconds {
+            args: func_args![value: Utc.ymd(2021, 1, 1).and_hms(0, 1, 30), other: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0)],
+            want: Ok(90),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        negative {
+            args: func_args![value: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0), other: Utc.ymd(2021, 1, 1).and_hms(0, 1, 30)],
+            want: Ok(-90),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        truncated {
+            args: func_args![value: Utc.ymd(2021, 1, 2).and_hms(23, 0, 0), other: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0), unit: "days"],
+            want: Ok(1),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        milliseconds {
+            args: func_args![value: Utc.ymd(2021, 1, 1).and_hms_milli(0, 0, 1, 500), other: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0), unit: "milliseconds"],
+            want: Ok(1500),
+            tdef: TypeDef::integer().infallible(),
+        }
+
+        nanoseconds {
+            args: func_args![value: Utc.ymd(2021, 1, 1).and_hms(0, 0, 1), other: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0), unit: "nanoseconds"],
+            want: Ok(1_000_000_000),
+            tdef: TypeDef::integer().fallible(),
+        }
+
+        nanoseconds_overflow {
+            args: func_args![value: Utc.ymd(2500, 1, 1).and_hms(0, 0, 0), other: Utc.ymd(1970, 1, 1).and_hms(0, 0, 0), unit: "nanoseconds"],
+            want: Err("difference is too large to be represented in nanoseconds"),
+            tdef: TypeDef::integer().fallible(),
+        }
+    ];
+}
@@ -1,7 +1,32 @@
 use ::value::Value;
-use vector_common::conversion::Conversion;
 use vrl::prelude::*;
 
+/// Strings coerced to `true`, compared case-insensitively.
+const TRUTHY: &[&str] = &["true", "t", "yes", "y", "on", "enabled", "1"];
+
+/// Strings coerced to `false`, compared case-insensitively.
+const FALSY: &[&str] = &["false", "f", "no", "n", "off", "disabled", "0"];
+
+fn parse_bool(bytes: &[u8]) -> Result<bool, ExpressionError> {
+    let in_table = |table: &[&str]| {
+        table
+            .iter()
+            .any(|candidate| candidate.as_bytes().eq_ignore_ascii_case(bytes))
+    };
+
+    if in_table(TRUTHY) {
+        Ok(true)
+    } else if in_table(FALSY) {
+        Ok(false)
+    } else {
+        Err(format!(
+            r#"Invalid boolean value "{}""#,
+            String::from_utf8_lossy(bytes)
+        )
+        .into())
+    }
+}
+
 fn to_bool(value: Value) -> Resolved {
     use Value::{Boolean, Bytes, Float, Integer, Null};
 
@@ -10,9 +35,7 @@ fn to_bool(value: Value) -> Resolved {
         Integer(v) => Ok(Boolean(v != 0)),
         Float(v) => Ok(Boolean(v != 0.0)),
         Null => Ok(Boolean(false)),
-        Bytes(v) => Conversion::Boolean
-            .convert(v)
-            .map_err(|e| e.to_string().into()),
+        Bytes(v) => parse_bool(&v).map(Boolean),
         v => Err(format!("unable to coerce {} into boolean", v.kind()).into()),
     }
 }
@@ -86,7 +109,12 @@ impl Function for ToBool {
                 result: Ok("true"),
             },
             Example {
-                title: "non-zero integer string",
+                title: "on string",
+                source: "to_bool!(s'ON')",
+                result: Ok("true"),
+            },
+            Example {
+                title: "one string",
                 source: "to_bool!(s'1')",
                 result: Ok("true"),
             },
@@ -106,7 +134,12 @@ impl Function for ToBool {
                 result: Ok("false"),
             },
             Example {
-                title: "zero integer string",
+                title: "disabled string",
+                source: "to_bool!(s'Disabled')",
+                result: Ok("false"),
+            },
+            Example {
+                title: "zero string",
                 source: "to_bool!(s'0')",
                 result: Ok("false"),
             },
@@ -204,6 +237,30 @@ mod tests {
             tdef: TypeDef::boolean().fallible(),
         }
 
+        string_case_insensitive {
+            args: func_args![value: "Enabled"],
+            want: Ok(true),
+            tdef: TypeDef::boolean().fallible(),
+        }
+
+        string_off {
+            args: func_args![value: "OFF"],
+            want: Ok(false),
+            tdef: TypeDef::boolean().fallible(),
+        }
+
+        string_one {
+            args: func_args![value: "1"],
+            want: Ok(true),
+            tdef: TypeDef::boolean().fallible(),
+        }
+
+        string_other_integer {
+            args: func_args![value: "2"],
+            want: Err(r#"Invalid boolean value "2""#),
+            tdef: TypeDef::boolean().fallible(),
+        }
+
         string_error {
             args: func_args![value: "cabbage"],
             want: Err(r#"Invalid boolean value "cabbage""#),
@@ -222,4 +279,21 @@ mod tests {
             tdef: TypeDef::boolean().infallible(),
         }
     ];
+
+    #[test]
+    fn tables() {
+        for value in TRUTHY {
+            assert!(parse_bool(value.as_bytes()).unwrap());
+            assert!(parse_bool(value.to_uppercase().as_bytes()).unwrap());
+        }
+
+        for value in FALSY {
+            assert!(!parse_bool(value.as_bytes()).unwrap());
+            assert!(!parse_bool(value.to_uppercase().as_bytes()).unwrap());
+        }
+
+        for value in ["", " true", "yes please", "-1", "10"] {
+            assert!(parse_bool(value.as_bytes()).is_err());
+        }
+    }
 }
@@ -2,7 +2,9 @@ use ::value::Value;
 use vector_common::conversion::Conversion;
 use vrl::prelude::*;
 
-fn to_float(value: Value) -> Resolved {
+use crate::util;
+
+fn to_float(value: Value, grouping: Option<Value>, decimal: Option<Value>) -> Resolved {
     use Value::{Boolean, Bytes, Float, Integer, Null, Timestamp};
     match value {
         Float(_) => Ok(value),
@@ -12,6 +14,21 @@ fn to_float(value: Value) -> Resolved {
         Timestamp(v) => Ok(Value::from_f64_or_zero(
             v.timestamp_nanos() as f64 / 1_000_000_000_f64,
         )),
+        Bytes(v) if grouping.is_some() || decimal.is_some() => {
+            let grouping = grouping
+                .as_ref()
+                .map(Value::try_bytes_utf8_lossy)
+                .transpose()?;
+            let decimal = decimal
+                .as_ref()
+                .map(Value::try_bytes_utf8_lossy)
+                .transpose()?;
+            let number = util::delocalize_number(&v, grouping.as_deref(), decimal.as_deref())?;
+
+            Conversion::Float
+                .convert(number.into())
+                .map_err(|e| e.to_string().into())
+        }
         Bytes(v) => Conversion::Float
             .convert(v)
             .map_err(|e| e.to_string().into()),
@@ -28,11 +45,23 @@ impl Function for ToFloat {
     }
 
     fn parameters(&self) -> &'static [Parameter] {
-        &[Parameter {
-            keyword: "value",
-            kind: kind::ANY,
-            required: true,
-        }]
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "grouping",
+                kind: kind::BYTES,
+                required: false,
+            },
+            Parameter {
+                keyword: "decimal",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
     }
 
     fn examples(&self) -> &'static [Example] {
@@ -74,6 +103,11 @@ impl Function for ToFloat {
                     r#"function call error for "to_float" at (0:20): Invalid floating point number "foobar": invalid float literal"#,
                 ),
             },
+            Example {
+                title: "localized string",
+                source: r#"to_float!("1.234,56", grouping: ".", decimal: ",")"#,
+                result: Ok("1234.56"),
+            },
             Example {
                 title: "timestamp",
                 source: "to_float(t'2020-01-01T00:00:00.100Z')",
@@ -110,21 +144,40 @@ impl Function for ToFloat {
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
+        let grouping = arguments.optional("grouping");
+        let decimal = arguments.optional("decimal");
 
-        Ok(ToFloatFn { value }.as_expr())
+        Ok(ToFloatFn {
+            value,
+            grouping,
+            decimal,
+        }
+        .as_expr())
     }
 }
 
 #[derive(Debug, Clone)]
 struct ToFloatFn {
     value: Box<dyn Expression>,
+    grouping: Option<Box<dyn Expression>>,
+    decimal: Option<Box<dyn Expression>>,
 }
 
 impl FunctionExpression for ToFloatFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
-
-        to_float(value)
+        let grouping = self
+            .grouping
+            .as_ref()
+            .map(|expr| expr.resolve(ctx))
+            .transpose()?;
+        let decimal = self
+            .decimal
+            .as_ref()
+            .map(|expr| expr.resolve(ctx))
+            .transpose()?;
+
+        to_float(value, grouping, decimal)
     }
 
     fn type_def(&self, state: &state::TypeState) -> TypeDef {
@@ -160,6 +213,36 @@ mod tests {
             tdef: TypeDef::float().infallible(),
         }
 
+        grouping {
+            args: func_args![value: "1,234,567.5", grouping: ","],
+            want: Ok(1_234_567.5),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        grouping_and_decimal {
+            args: func_args![value: "1.234,56", grouping: ".", decimal: ","],
+            want: Ok(1234.56),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        grouping_space {
+            args: func_args![value: "-1 234,5", grouping: " ", decimal: ","],
+            want: Ok(-1234.5),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        unexpected_dot {
+            args: func_args![value: "1.234,56", decimal: ","],
+            want: Err(r#"unexpected "." in number with decimal separator ",""#),
+            tdef: TypeDef::float().fallible(),
+        }
+
+        same_separators {
+            args: func_args![value: "1,234", grouping: ",", decimal: ","],
+            want: Err("grouping and decimal separators must differ"),
+            tdef: TypeDef::float().fallible(),
+        }
+
         timestamp {
              args: func_args![value: Utc.ymd(2014, 7, 8).and_hms_milli(9, 10, 11, 12)],
              want: Ok(1_404_810_611.012),
@@ -2,7 +2,9 @@ use ::value::Value;
 use vector_common::conversion::Conversion;
 use vrl::prelude::*;
 
-fn to_int(value: Value) -> Resolved {
+use crate::util;
+
+fn to_int(value: Value, grouping: Option<Value>) -> Resolved {
     use Value::{Boolean, Bytes, Float, Integer, Null, Timestamp};
 
     match value {
@@ -10,6 +12,17 @@ fn to_int(value: Value) -> Resolved {
         Float(v) => Ok(Integer(v.into_inner() as i64)),
         Boolean(v) => Ok(Integer(if v { 1 } else { 0 })),
         Null => Ok(0.into()),
+        Bytes(v) if grouping.is_some() => {
+            let grouping = grouping
+                .as_ref()
+                .map(Value::try_bytes_utf8_lossy)
+                .transpose()?;
+            let number = util::delocalize_number(&v, grouping.as_deref(), None)?;
+
+            Conversion::Integer
+                .convert(number.into())
+                .map_err(|e| e.to_string().into())
+        }
         Bytes(v) => Conversion::Integer
             .convert(v)
             .map_err(|e| e.to_string().into()),
@@ -27,11 +40,18 @@ impl Function for ToInt {
     }
 
     fn parameters(&self) -> &'static [Parameter] {
-        &[Parameter {
-            keyword: "value",
-            kind: kind::ANY,
-            required: true,
-        }]
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "grouping",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
     }
 
     fn examples(&self) -> &'static [Example] {
@@ -61,6 +81,11 @@ impl Function for ToInt {
                 source: "to_int(null)",
                 result: Ok("0"),
             },
+            Example {
+                title: "localized string",
+                source: r#"to_int!("1,234,567", grouping: ",")"#,
+                result: Ok("1234567"),
+            },
             Example {
                 title: "timestamp",
                 source: "to_int(t'2020-01-01T00:00:00Z')",
@@ -109,21 +134,28 @@ impl Function for ToInt {
         arguments: ArgumentList,
     ) -> Compiled {
         let value = arguments.required("value");
+        let grouping = arguments.optional("grouping");
 
-        Ok(ToIntFn { value }.as_expr())
+        Ok(ToIntFn { value, grouping }.as_expr())
     }
 }
 
 #[derive(Debug, Clone)]
 struct ToIntFn {
     value: Box<dyn Expression>,
+    grouping: Option<Box<dyn Expression>>,
 }
 
 impl FunctionExpression for ToIntFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
+        let grouping = self
+            .grouping
+            .as_ref()
+            .map(|expr| expr.resolve(ctx))
+            .transpose()?;
 
-        to_int(value)
+        to_int(value, grouping)
     }
 
     fn type_def(&self, state: &state::TypeState) -> TypeDef {
@@ -153,6 +185,18 @@ mod tests {
              tdef: TypeDef::integer().fallible(),
         }
 
+        grouping {
+             args: func_args![value: "1,234,567", grouping: ","],
+             want: Ok(1_234_567),
+             tdef: TypeDef::integer().fallible(),
+        }
+
+        grouping_dot {
+             args: func_args![value: "-1.234", grouping: "."],
+             want: Ok(-1234),
+             tdef: TypeDef::integer().fallible(),
+        }
+
         float {
              args: func_args![value: 20.5],
              want: Ok(20),
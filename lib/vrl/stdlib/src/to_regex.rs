@@ -1,13 +1,38 @@
+use std::sync::Mutex;
+
 use ::value::Value;
 use tracing::warn;
 use vrl::prelude::*;
 
-fn to_regex(value: Value) -> Resolved {
+fn to_regex(value: Value, cache: &RegexCache) -> Resolved {
     let string = value.try_bytes_utf8_lossy()?;
+
+    let mut cached = cache.0.lock().expect("regex cache lock poisoned");
+    if let Some((pattern, regex)) = cached.as_ref() {
+        if pattern == string.as_ref() {
+            return Ok(regex.clone().into());
+        }
+    }
+
     let regex = regex::Regex::new(string.as_ref())
-        .map_err(|err| format!("could not create regex: {}", err))
-        .map(Into::into)?;
-    Ok(regex)
+        .map_err(|err| format!("could not create regex: {}", err))?;
+    *cached = Some((string.into_owned(), regex.clone()));
+
+    Ok(regex.into())
+}
+
+/// Holds the most recently compiled pattern, so that resolving the same pattern for
+/// consecutive events doesn't recompile it.
+///
+/// Each clone of the program gets its own, empty cache, so that concurrent copies of a
+/// program don't contend on the lock.
+#[derive(Debug, Default)]
+struct RegexCache(Mutex<Option<(String, regex::Regex)>>);
+
+impl Clone for RegexCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -27,11 +52,18 @@ impl Function for ToRegex {
     }
 
     fn examples(&self) -> &'static [Example] {
-        &[Example {
-            title: "regex",
-            source: "to_regex(s'^foobar$') ?? r''",
-            result: Ok("r'^foobar$'"),
-        }]
+        &[
+            Example {
+                title: "regex",
+                source: "to_regex(s'^foobar$') ?? r''",
+                result: Ok("r'^foobar$'"),
+            },
+            Example {
+                title: "match",
+                source: r#"match("foobar", to_regex("^foo") ?? r'')"#,
+                result: Ok("true"),
+            },
+        ]
     }
 
     fn compile(
@@ -42,19 +74,24 @@ impl Function for ToRegex {
     ) -> Compiled {
         warn!("`to_regex` is an expensive function that could impact throughput.");
         let value = arguments.required("value");
-        Ok(ToRegexFn { value }.as_expr())
+        Ok(ToRegexFn {
+            value,
+            cache: RegexCache::default(),
+        }
+        .as_expr())
     }
 }
 
 #[derive(Debug, Clone)]
 struct ToRegexFn {
     value: Box<dyn Expression>,
+    cache: RegexCache,
 }
 
 impl FunctionExpression for ToRegexFn {
     fn resolve(&self, ctx: &mut Context) -> Resolved {
         let value = self.value.resolve(ctx)?;
-        to_regex(value)
+        to_regex(value, &self.cache)
     }
 
     fn type_def(&self, _: &state::TypeState) -> TypeDef {
@@ -81,4 +118,20 @@ mod tests {
             tdef: TypeDef::regex().fallible(),
         }
     ];
+
+    #[test]
+    fn cache_tracks_latest_pattern() {
+        let cache = RegexCache::default();
+
+        for pattern in ["^foo$", "^foo$", "^bar$"] {
+            let regex = to_regex(pattern.into(), &cache).unwrap();
+            assert_eq!(regex.as_regex().unwrap().as_str(), pattern);
+        }
+
+        let cached = cache.0.lock().unwrap();
+        assert_eq!(
+            cached.as_ref().map(|(pattern, _)| pattern.as_str()),
+            Some("^bar$")
+        );
+    }
 }
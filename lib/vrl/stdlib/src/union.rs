@@ -0,0 +1,126 @@
+use ::value::{kind::Collection, Value};
+use vrl::prelude::*;
+
+use crate::util::{dedup_values, set_operand_kinds, set_operands};
+
+fn union(value: Value, other: Option<Value>) -> Resolved {
+    let arrays = set_operands(value, other)?;
+
+    Ok(dedup_values(arrays.into_iter().flatten()).into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Union;
+
+impl Function for Union {
+    fn identifier(&self) -> &'static str {
+        "union"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "other",
+                kind: kind::ARRAY,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "two arrays",
+                source: r#"union(["a", "b", "a"], ["c", "b"])"#,
+                result: Ok(r#"["a", "b", "c"]"#),
+            },
+            Example {
+                title: "many arrays",
+                source: r#"union([[1, 2], [2, 3], [3, 4]])"#,
+                result: Ok("[1, 2, 3, 4]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let other = arguments.optional("other");
+
+        Ok(UnionFn { value, other }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct UnionFn {
+    value: Box<dyn Expression>,
+    other: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for UnionFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let other = self
+            .other
+            .as_ref()
+            .map(|expr| expr.resolve(ctx))
+            .transpose()?;
+
+        union(value, other)
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let (kinds, fallible) = set_operand_kinds(
+            self.value.type_def(state),
+            self.other.as_ref().map(|expr| expr.type_def(state)),
+        );
+        let kind = kinds
+            .into_iter()
+            .reduce(|acc, kind| acc.union(kind))
+            .expect("at least one operand");
+
+        TypeDef::array(Collection::from_unknown(kind)).with_fallibility(fallible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        union => Union;
+
+        two_arrays {
+            args: func_args![value: value!(["a", "b", "a"]), other: value!(["c", "b"])],
+            want: Ok(value!(["a", "b", "c"])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::bytes())),
+        }
+
+        mixed_types {
+            args: func_args![value: value!([1, "1"]), other: value!([1.0, 1])],
+            want: Ok(value!([1, "1", 1.0])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer().or_bytes().or_float())),
+        }
+
+        many_arrays {
+            args: func_args![value: value!([[1, 2], [2, 3], [3, 4]])],
+            want: Ok(value!([1, 2, 3, 4])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::integer())),
+        }
+
+        not_arrays {
+            args: func_args![value: value!([[1, 2], 3])],
+            want: Err("expected array, got integer"),
+            tdef: TypeDef::array(Collection::any()).fallible(),
+        }
+    ];
+}
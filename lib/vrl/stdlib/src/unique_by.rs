@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use ::value::{kind::Collection, Value};
+use vrl::prelude::*;
+
+fn unique_by<T>(value: Value, ctx: &mut Context, runner: closure::Runner<T>) -> Resolved
+where
+    T: Fn(&mut Context) -> Resolved,
+{
+    let array = value.try_array()?;
+    let mut seen = HashSet::with_capacity(array.len());
+    let mut unique = Vec::with_capacity(array.len());
+
+    for (index, value) in array.into_iter().enumerate() {
+        let key = runner.run_index_value(ctx, index, &value)?;
+
+        if seen.insert(key) {
+            unique.push(value);
+        }
+    }
+
+    Ok(unique.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct UniqueBy;
+
+impl Function for UniqueBy {
+    fn identifier(&self) -> &'static str {
+        "unique_by"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "unique by field",
+                source: r#"unique_by([{ "id": 1, "v": "a" }, { "id": 2, "v": "b" }, { "id": 1, "v": "c" }]) -> |_index, value| { value.id }"#,
+                result: Ok(r#"[{ "id": 1, "v": "a" }, { "id": 2, "v": "b" }]"#),
+            },
+            Example {
+                title: "unique by derived value",
+                source: r#"unique_by(["foo", "FOO", "bar"]) -> |_index, value| { downcase(string!(value)) }"#,
+                result: Ok(r#"["foo", "bar"]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let closure = arguments.required_closure()?;
+
+        Ok(UniqueByFn { value, closure }.as_expr())
+    }
+
+    fn closure(&self) -> Option<closure::Definition> {
+        use closure::{Definition, Input, Output, Variable, VariableKind};
+
+        Some(Definition {
+            inputs: vec![Input {
+                parameter_keyword: "value",
+                kind: Kind::array(Collection::any()),
+                variables: vec![
+                    Variable {
+                        kind: VariableKind::TargetInnerKey,
+                    },
+                    Variable {
+                        kind: VariableKind::TargetInnerValue,
+                    },
+                ],
+                output: Output::Kind(Kind::any()),
+                example: Example {
+                    title: "unique by field",
+                    source: r#"unique_by([{ "id": 1 }, { "id": 1 }]) -> |_index, value| { value.id }"#,
+                    result: Ok(r#"[{ "id": 1 }]"#),
+                },
+            }],
+            is_iterator: true,
+            is_optional: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct UniqueByFn {
+    value: Box<dyn Expression>,
+    closure: FunctionClosure,
+}
+
+impl FunctionExpression for UniqueByFn {
+    fn resolve(&self, ctx: &mut Context) -> Result<Value> {
+        let value = self.value.resolve(ctx)?;
+        let FunctionClosure {
+            variables,
+            block,
+            block_type_def: _,
+        } = &self.closure;
+        let runner = closure::Runner::new(variables, |ctx| block.resolve(ctx));
+
+        unique_by(value, ctx, runner)
+    }
+
+    fn type_def(&self, ctx: &state::TypeState) -> TypeDef {
+        let mut type_def = self.value.type_def(ctx);
+
+        // Erase any type information from the array, as we can't know which elements are removed
+        // at runtime.
+        type_def.kind_mut().add_array(Collection::any());
+
+        type_def
+    }
+}
@@ -0,0 +1,122 @@
+use vrl::prelude::*;
+
+fn unwrap_or(value: Resolved, default: impl FnOnce() -> Resolved) -> Resolved {
+    match value {
+        Err(ExpressionError::Error { .. }) => default(),
+        // Aborts terminate the program, they are not errors of the expression.
+        value => value,
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct UnwrapOr;
+
+impl Function for UnwrapOr {
+    fn identifier(&self) -> &'static str {
+        "unwrap_or"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "default",
+                kind: kind::ANY,
+                required: true,
+            },
+        ]
+    }
+
+    fn accepts_fallible_argument(&self, keyword: &'static str) -> bool {
+        keyword == "value"
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "success",
+                source: r#"unwrap_or(parse_json("{}"), null)"#,
+                result: Ok("{}"),
+            },
+            Example {
+                title: "error",
+                source: r#"unwrap_or(parse_json("{"), {})"#,
+                result: Ok("{}"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let default = arguments.required("default");
+
+        Ok(UnwrapOrFn { value, default }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct UnwrapOrFn {
+    value: Box<dyn Expression>,
+    default: Box<dyn Expression>,
+}
+
+impl FunctionExpression for UnwrapOrFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx);
+
+        unwrap_or(value, || {
+            ctx.state_mut()
+                .count(|counters| counters.handled_errors += 1);
+            self.default.resolve(ctx)
+        })
+    }
+
+    fn type_def(&self, state: &state::TypeState) -> TypeDef {
+        let value = self.value.type_def(state);
+        let default = self.default.type_def(state);
+
+        if value.is_fallible() {
+            value.union(default).infallible()
+        } else {
+            value
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        unwrap_or => UnwrapOr;
+
+        value {
+            args: func_args![value: "foo", default: 1],
+            want: Ok("foo"),
+            tdef: TypeDef::bytes().infallible(),
+        }
+    ];
+
+    #[test]
+    fn error_resolves_to_default() {
+        let error = ExpressionError::from("oops");
+
+        assert_eq!(unwrap_or(Err(error), || Ok(value!(1))).unwrap(), value!(1));
+    }
+
+    #[test]
+    fn memory_limit_is_not_handled() {
+        let error = ExpressionError::MemoryLimitExceeded { limit: 1 };
+
+        assert!(unwrap_or(Err(error), || Ok(value!(1))).is_err());
+    }
+}
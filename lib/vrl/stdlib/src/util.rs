@@ -18,7 +18,11 @@ where
 /// "0" is the overall match.
 /// Any named captures are also added to the Map with the key as the name.
 ///
-#[cfg(any(feature = "parse_regex", feature = "parse_regex_all"))]
+#[cfg(any(
+    feature = "parse_regex",
+    feature = "parse_regex_all",
+    feature = "replace_with"
+))]
 pub(crate) fn capture_regex_to_map(
     regex: &regex::Regex,
     capture: &regex::Captures,
@@ -66,6 +70,31 @@ pub(crate) fn regex_kind(
     inner_type
 }
 
+/// Returns the static capture `group` argument of a regex matching function,
+/// defaulting to the whole match. The group is checked against the number of
+/// capture groups in `pattern` so that invalid indices fail at compile time.
+#[cfg(any(feature = "find_all", feature = "find_first"))]
+pub(crate) fn regex_capture_group(
+    arguments: &vrl::function::ArgumentList,
+    pattern: &regex::Regex,
+) -> Result<usize, vrl::function::Error> {
+    let value = match arguments.optional_value("group")? {
+        Some(value) => value,
+        None => return Ok(0),
+    };
+
+    match value.as_integer() {
+        Some(group) if group >= 0 && (group as usize) < pattern.captures_len() => {
+            Ok(group as usize)
+        }
+        _ => Err(vrl::function::Error::InvalidArgument {
+            keyword: "group",
+            value,
+            error: "capture group doesn't exist in pattern",
+        }),
+    }
+}
+
 #[cfg(any(feature = "is_nullish", feature = "compact"))]
 pub(crate) fn is_nullish(value: &::value::Value) -> bool {
     match value {
@@ -82,6 +111,215 @@ pub(crate) fn is_nullish(value: &::value::Value) -> bool {
     }
 }
 
+/// Returns whether the value is null, or an empty string, array or object. All other values, such as
+/// `0` or `false`, aren't considered empty.
+#[cfg(any(feature = "is_empty", feature = "compact"))]
+pub(crate) fn is_empty(value: &::value::Value) -> bool {
+    match value {
+        ::value::Value::Bytes(v) => v.is_empty(),
+        ::value::Value::Object(v) => v.is_empty(),
+        ::value::Value::Array(v) => v.is_empty(),
+        ::value::Value::Null => true,
+        _ => false,
+    }
+}
+
+/// The maximum number of characters a value may be padded to, to keep a large length from using
+/// up all memory.
+#[cfg(any(feature = "center", feature = "pad_start", feature = "pad_end"))]
+const MAX_PAD_LENGTH: i64 = 1024 * 1024;
+
+/// Validates the length to pad a value to. Negative lengths leave the value unchanged, like any
+/// other length shorter than the value.
+#[cfg(any(feature = "center", feature = "pad_start", feature = "pad_end"))]
+pub(crate) fn pad_length(length: i64) -> Result<usize, &'static str> {
+    if length > MAX_PAD_LENGTH {
+        return Err("can't pad to more than the maximum of 1Mi characters");
+    }
+    Ok(usize::try_from(length).unwrap_or(0))
+}
+
+/// Pads `value` to `length` characters by repeating `pad` at its start or end, cutting the last
+/// repetition short if needed. Values that are already at least `length` characters long are
+/// returned unchanged.
+#[cfg(any(feature = "center", feature = "pad_start", feature = "pad_end"))]
+pub(crate) fn pad(value: &str, length: usize, pad: &str, at_start: bool) -> String {
+    let fill = length.saturating_sub(value.chars().count());
+    if fill == 0 {
+        return value.to_owned();
+    }
+
+    let padding = pad.chars().cycle().take(fill);
+    if at_start {
+        padding.chain(value.chars()).collect()
+    } else {
+        value.chars().chain(padding).collect()
+    }
+}
+
+/// The maximum number of bytes a value may be decompressed to, to keep a small compressed value
+/// from using up all memory.
+#[cfg(any(
+    feature = "decode_gzip",
+    feature = "decode_zlib",
+    feature = "decode_zstd"
+))]
+const MAX_DECOMPRESSED_SIZE: u64 = 1024 * 1024;
+
+/// Reads all of the data out of `decoder`, failing once it holds more than the maximum number of
+/// bytes a value may be decompressed to. `name` is the name of the compression format.
+#[cfg(any(
+    feature = "decode_gzip",
+    feature = "decode_zlib",
+    feature = "decode_zstd"
+))]
+pub(crate) fn decompress(decoder: impl std::io::Read, name: &str) -> Result<Vec<u8>, String> {
+    use std::io::Read;
+
+    let mut decoded = Vec::new();
+    decoder
+        .take(MAX_DECOMPRESSED_SIZE + 1)
+        .read_to_end(&mut decoded)
+        .map_err(|_| format!("unable to decode value with {} decoder", name))?;
+
+    if decoded.len() as u64 > MAX_DECOMPRESSED_SIZE {
+        return Err("decoded value is larger than the maximum of 1Mi bytes".to_owned());
+    }
+    Ok(decoded)
+}
+
+/// Parses an [IANA timezone](https://www.iana.org/time-zones) name, such as `America/New_York`.
+#[cfg(any(feature = "convert_timezone", feature = "format_timestamp"))]
+pub(crate) fn parse_timezone(name: &[u8]) -> Result<chrono_tz::Tz, String> {
+    let name = String::from_utf8_lossy(name);
+    name.parse()
+        .map_err(|_| format!("unknown timezone \"{}\"", name))
+}
+
+/// Looks up the encoding for a [WHATWG charset label](https://encoding.spec.whatwg.org/#names-and-labels),
+/// such as `latin1`, `shift_jis` or `windows-1252`.
+#[cfg(any(feature = "decode_charset", feature = "encode_charset"))]
+pub(crate) fn parse_charset(label: &[u8]) -> Result<&'static encoding_rs::Encoding, String> {
+    encoding_rs::Encoding::for_label_no_replacement(label)
+        .ok_or_else(|| format!("unknown charset \"{}\"", String::from_utf8_lossy(label)))
+}
+
+/// An invalid directive in a literal strftime format string, found at compile time.
+#[cfg(any(feature = "format_timestamp", feature = "parse_timestamp"))]
+#[derive(Debug)]
+pub(crate) struct InvalidStrftimeDirective {
+    directive: String,
+    offset: usize,
+    span: vrl::diagnostic::Span,
+}
+
+#[cfg(any(feature = "format_timestamp", feature = "parse_timestamp"))]
+impl std::fmt::Display for InvalidStrftimeDirective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"invalid strftime directive "{}" at offset {}"#,
+            self.directive, self.offset
+        )
+    }
+}
+
+#[cfg(any(feature = "format_timestamp", feature = "parse_timestamp"))]
+impl std::error::Error for InvalidStrftimeDirective {}
+
+#[cfg(any(feature = "format_timestamp", feature = "parse_timestamp"))]
+impl vrl::diagnostic::DiagnosticMessage for InvalidStrftimeDirective {
+    fn code(&self) -> usize {
+        113
+    }
+
+    fn labels(&self) -> Vec<vrl::diagnostic::Label> {
+        vec![vrl::diagnostic::Label::primary(
+            format!(
+                r#"the format string has an invalid directive "{}" at offset {}"#,
+                self.directive, self.offset
+            ),
+            self.span,
+        )]
+    }
+}
+
+/// Checks that every directive in a strftime `format` string is known, returning the first one
+/// that isn't. The error points at `span`, the span of the format argument.
+#[cfg(any(feature = "format_timestamp", feature = "parse_timestamp"))]
+pub(crate) fn validate_strftime(
+    format: &str,
+    span: vrl::diagnostic::Span,
+) -> Result<(), InvalidStrftimeDirective> {
+    use chrono::format::{strftime::StrftimeItems, Item};
+
+    let mut chars = format.char_indices();
+    while let Some((offset, c)) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+
+        if let Some(Item::Error) = StrftimeItems::new(&format[offset..]).next() {
+            // Include any padding or precision modifiers in the reported directive.
+            let mut directive = String::from('%');
+            for c in format[offset + 1..].chars() {
+                directive.push(c);
+                if !matches!(c, '-' | '_' | '0'..='9' | '.' | ':' | '#') {
+                    break;
+                }
+            }
+
+            return Err(InvalidStrftimeDirective {
+                directive,
+                offset,
+                span,
+            });
+        }
+
+        // `%%` is an escaped percent sign, rather than the start of another directive.
+        if format[offset + 1..].starts_with('%') {
+            chars.next();
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds a reader for the CSV records in `value`, which may have a varying number of fields.
+///
+/// The `delimiter` and `quote` must both be a single byte.
+#[cfg(any(feature = "parse_csv_line", feature = "parse_csv_rows"))]
+pub(crate) fn csv_reader<'a>(
+    value: &'a [u8],
+    delimiter: &[u8],
+    quote: &[u8],
+) -> Result<csv::Reader<&'a [u8]>, &'static str> {
+    let delimiter = match delimiter {
+        [delimiter] => *delimiter,
+        _ => return Err("delimiter must be a single character"),
+    };
+    let quote = match quote {
+        [quote] => *quote,
+        _ => return Err("quote must be a single character"),
+    };
+
+    Ok(csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .quote(quote)
+        .from_reader(value))
+}
+
+/// Hashes a seed into a stable `u64`.
+///
+/// The seed's string representation is hashed so that the same field value
+/// always produces the same result, regardless of the process or run.
+#[cfg(any(feature = "random_bool", feature = "sample"))]
+pub(crate) fn seed_hash(seed: &::value::Value) -> u64 {
+    seahash::hash(seed.to_string_lossy().as_bytes())
+}
+
 #[cfg(any(feature = "decode_base64", feature = "encode_base64"))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Base64Charset {
@@ -122,3 +360,410 @@ impl std::str::FromStr for Base64Charset {
         }
     }
 }
+
+/// A value that `sort` and `sort_by` know how to order.
+#[cfg(any(feature = "sort", feature = "sort_by"))]
+#[derive(Debug)]
+enum SortKey<'a> {
+    Null,
+    Boolean(bool),
+    Integer(i64),
+    Float(f64),
+    Bytes(&'a [u8]),
+    Timestamp((i64, u32)),
+}
+
+#[cfg(any(feature = "sort", feature = "sort_by"))]
+impl<'a> SortKey<'a> {
+    fn new(value: &'a ::value::Value, numeric: bool) -> Result<Self, String> {
+        use ::value::Value;
+
+        let key = match value {
+            Value::Integer(v) => Self::Integer(*v),
+            Value::Float(v) => Self::Float(**v),
+            Value::Bytes(v) if numeric => std::str::from_utf8(v)
+                .ok()
+                .map(str::trim)
+                .and_then(|s| {
+                    s.parse()
+                        .map(Self::Integer)
+                        .or_else(|_| s.parse().map(Self::Float))
+                        .ok()
+                })
+                .filter(|key| !matches!(key, Self::Float(v) if v.is_nan()))
+                .ok_or_else(|| format!("unable to sort non-numeric value {} numerically", value))?,
+            value if numeric => {
+                return Err(format!(
+                    "unable to sort non-numeric value {} numerically",
+                    value
+                ))
+            }
+            Value::Null => Self::Null,
+            Value::Boolean(v) => Self::Boolean(*v),
+            Value::Bytes(v) => Self::Bytes(v),
+            Value::Timestamp(v) => Self::Timestamp((v.timestamp(), v.timestamp_subsec_nanos())),
+            value => {
+                return Err(format!(
+                    "unable to sort values of type {}",
+                    value.kind_str()
+                ))
+            }
+        };
+
+        Ok(key)
+    }
+
+    /// Integers and floats are compared with each other, all other keys only with keys of the
+    /// same type.
+    fn compare(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (Self::Null, Self::Null) => Some(std::cmp::Ordering::Equal),
+            (Self::Boolean(lhs), Self::Boolean(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Integer(lhs), Self::Integer(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Integer(lhs), Self::Float(rhs)) => Some((*lhs as f64).total_cmp(rhs)),
+            (Self::Float(lhs), Self::Integer(rhs)) => Some(lhs.total_cmp(&(*rhs as f64))),
+            (Self::Float(lhs), Self::Float(rhs)) => Some(lhs.total_cmp(rhs)),
+            (Self::Bytes(lhs), Self::Bytes(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Timestamp(lhs), Self::Timestamp(rhs)) => Some(lhs.cmp(rhs)),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the indices of `keys` in sorted order.
+///
+/// All keys need to be of the same type, except for integers and floats, which are compared by
+/// their numeric value. Strings are compared byte-wise. With `numeric` set, strings are parsed as
+/// numbers instead, and any non-numeric key is an error.
+///
+/// The sort is stable, keys that compare equal keep their original order, also when sorting in
+/// descending order.
+#[cfg(any(feature = "sort", feature = "sort_by"))]
+pub(crate) fn sort_order(
+    keys: &[::value::Value],
+    descending: bool,
+    numeric: bool,
+) -> Result<Vec<usize>, String> {
+    let mut indexed = keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| SortKey::new(key, numeric).map(|key| (index, key)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Check all keys against the first one up front, so that the comparison used while sorting is
+    // guaranteed to be total.
+    if let Some((first, key)) = indexed.first() {
+        if let Some((index, _)) = indexed
+            .iter()
+            .find(|(_, other)| key.compare(other).is_none())
+        {
+            return Err(format!(
+                "unable to compare {} with {}",
+                keys[*first].kind_str(),
+                keys[*index].kind_str()
+            ));
+        }
+    }
+
+    indexed.sort_by(|(_, lhs), (_, rhs)| {
+        let ordering = lhs.compare(rhs).expect("keys are comparable");
+
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    Ok(indexed.into_iter().map(|(index, _)| index).collect())
+}
+
+/// Moves `values` into the order returned by `sort_order`.
+#[cfg(any(feature = "sort", feature = "sort_by"))]
+pub(crate) fn apply_sort_order(
+    values: Vec<::value::Value>,
+    order: Vec<usize>,
+) -> Vec<::value::Value> {
+    let mut values = values.into_iter().map(Some).collect::<Vec<_>>();
+
+    order
+        .into_iter()
+        .map(|index| values[index].take().expect("index is unique"))
+        .collect()
+}
+
+/// Returns whether `sort_order` can fail for keys of the given kind.
+///
+/// Numeric keys can always be sorted, other keys only if they all have the same type and
+/// `numeric` can't be set.
+#[cfg(any(feature = "sort", feature = "sort_by"))]
+pub(crate) fn sort_is_fallible(keys: &::value::Kind, numeric: bool) -> bool {
+    use ::value::Kind;
+
+    if (Kind::integer() | Kind::float()).is_superset(keys) {
+        return false;
+    }
+
+    numeric
+        || ![
+            Kind::null(),
+            Kind::boolean(),
+            Kind::bytes(),
+            Kind::timestamp(),
+        ]
+        .iter()
+        .any(|kind| kind.is_superset(keys))
+}
+
+/// Rewrites a localized number into the format `Conversion` parses, by removing all `grouping`
+/// separators and replacing the `decimal` separator with a `.`.
+#[cfg(any(feature = "to_float", feature = "to_int"))]
+pub(crate) fn delocalize_number(
+    value: &[u8],
+    grouping: Option<&str>,
+    decimal: Option<&str>,
+) -> Result<String, String> {
+    if grouping.map_or(false, str::is_empty) || decimal.map_or(false, str::is_empty) {
+        return Err("separators can't be empty".to_owned());
+    }
+    if grouping.is_some() && grouping == decimal {
+        return Err("grouping and decimal separators must differ".to_owned());
+    }
+
+    let mut number = String::from_utf8_lossy(value).into_owned();
+    if let Some(grouping) = grouping {
+        number = number.replace(grouping, "");
+    }
+    if let Some(decimal) = decimal.filter(|&decimal| decimal != ".") {
+        // A `.` that isn't the decimal separator would otherwise be parsed as one.
+        if number.contains('.') {
+            return Err(format!(
+                "unexpected \".\" in number with decimal separator \"{}\"",
+                decimal
+            ));
+        }
+        number = number.replace(decimal, ".");
+    }
+
+    Ok(number)
+}
+
+/// Removes duplicate values, keeping the first occurrence of each in order.
+#[cfg(any(feature = "difference", feature = "intersection", feature = "union"))]
+pub(crate) fn dedup_values(
+    values: impl IntoIterator<Item = ::value::Value>,
+) -> Vec<::value::Value> {
+    let mut unique = Vec::new();
+    for value in values {
+        if !unique.contains(&value) {
+            unique.push(value);
+        }
+    }
+    unique
+}
+
+/// The arrays a set operation applies to: `value` and `other`, or the elements of `value` if
+/// `other` is omitted.
+#[cfg(any(feature = "intersection", feature = "union"))]
+pub(crate) fn set_operands(
+    value: ::value::Value,
+    other: Option<::value::Value>,
+) -> Result<Vec<Vec<::value::Value>>, vrl::prelude::ExpressionError> {
+    use vrl::prelude::VrlValueConvert;
+
+    match other {
+        Some(other) => Ok(vec![value.try_array()?, other.try_array()?]),
+        None => value
+            .try_array()?
+            .into_iter()
+            .map(|array| array.try_array().map_err(Into::into))
+            .collect(),
+    }
+}
+
+/// The kind of the elements of each array a set operation applies to, and whether the operation
+/// can fail because `value` isn't known to only contain arrays when `other` is omitted.
+#[cfg(any(feature = "intersection", feature = "union"))]
+pub(crate) fn set_operand_kinds(
+    value: vrl::prelude::TypeDef,
+    other: Option<vrl::prelude::TypeDef>,
+) -> (Vec<vrl::prelude::Kind>, bool) {
+    let elements = |type_def: vrl::prelude::TypeDef| {
+        type_def
+            .restrict_array()
+            .as_array()
+            .expect("must be an array")
+            .reduced_kind()
+    };
+
+    match other {
+        Some(other) => (vec![elements(value), elements(other)], false),
+        None => {
+            let arrays = elements(value);
+            match arrays.as_array() {
+                Some(array) if arrays.is_array() => (vec![array.reduced_kind()], false),
+                _ => (vec![vrl::prelude::Kind::any()], true),
+            }
+        }
+    }
+}
+
+/// The kinds of values that can be counted by the tally functions.
+#[cfg(feature = "tally_by")]
+pub(crate) fn tally_key_kind() -> vrl::prelude::Kind {
+    vrl::prelude::Kind::bytes()
+        .or_integer()
+        .or_float()
+        .or_boolean()
+        .or_timestamp()
+}
+
+/// The object key the element at `index` is counted under.
+///
+/// Values are counted by their string representation, so e.g. `1` and `"1"` share a key.
+#[cfg(any(feature = "tally", feature = "tally_by"))]
+pub(crate) fn tally_key(
+    index: usize,
+    value: &::value::Value,
+) -> Result<String, vrl::prelude::ExpressionError> {
+    use ::value::Value;
+
+    match value {
+        Value::Bytes(bytes) => Ok(String::from_utf8_lossy(bytes).into_owned()),
+        Value::Integer(integer) => Ok(integer.to_string()),
+        Value::Float(float) => Ok(float.to_string()),
+        Value::Boolean(boolean) => Ok(boolean.to_string()),
+        Value::Timestamp(timestamp) => {
+            Ok(timestamp.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+        }
+        value => Err(format!(
+            "cannot tally {} at index {}, expected a string, integer, float, boolean or timestamp",
+            value.kind_str(),
+            index
+        )
+        .into()),
+    }
+}
+
+/// Parses the field paths taken by the field projection functions.
+#[cfg(any(feature = "only_fields", feature = "without_fields"))]
+pub(crate) fn parse_field_paths(
+    paths: &::value::Value,
+) -> Result<Vec<lookup_lib::lookup_v2::OwnedValuePath>, String> {
+    use ::value::Value;
+    use lookup_lib::lookup_v2::parse_value_path;
+
+    let paths = match paths {
+        Value::Array(paths) => paths,
+        value => return Err(format!("paths must be an array, got {}", value.kind_str())),
+    };
+
+    paths
+        .iter()
+        .map(|path| match path {
+            Value::Bytes(path) => {
+                parse_value_path(&String::from_utf8_lossy(path)).map_err(|error| error.to_string())
+            }
+            value => Err(format!("path must be a string, got {}", value.kind_str())),
+        })
+        .collect()
+}
+
+/// Returns the smallest of the numeric `values`, or the largest with `largest` set.
+///
+/// Integers and floats can be mixed. If any of the values is a float, all values are compared as
+/// floats and the result is a float, otherwise the result is an integer.
+#[cfg(any(feature = "min", feature = "max"))]
+pub(crate) fn numeric_extremum(
+    values: &[::value::Value],
+    largest: bool,
+) -> Result<::value::Value, String> {
+    use ::value::Value;
+
+    if values.iter().all(Value::is_integer) {
+        let integers = values.iter().filter_map(Value::as_integer);
+        let result = if largest {
+            integers.max()
+        } else {
+            integers.min()
+        };
+
+        return result
+            .map(Value::from)
+            .ok_or_else(|| "expected at least one number".to_owned());
+    }
+
+    values
+        .iter()
+        .try_fold(None, |result: Option<f64>, value| {
+            let value = match value {
+                Value::Integer(v) => *v as f64,
+                Value::Float(v) => v.into_inner(),
+                value => {
+                    return Err(format!(
+                        "expected integer or float, got {}",
+                        value.kind_str()
+                    ))
+                }
+            };
+
+            Ok(Some(match result {
+                Some(result) if largest => result.max(value),
+                Some(result) => result.min(value),
+                None => value,
+            }))
+        })?
+        .map(Value::from_f64_or_zero)
+        .ok_or_else(|| "expected at least one number".to_owned())
+}
+
+/// Returns the type definition of `min` and `max`.
+///
+/// An array `value` can be empty or contain non-numeric elements, unless it's known to only
+/// contain numbers and `other` is given.
+#[cfg(any(feature = "min", feature = "max"))]
+pub(crate) fn numeric_extremum_type_def(
+    value: &dyn vrl::Expression,
+    other: Option<&dyn vrl::Expression>,
+    state: &vrl::state::TypeState,
+) -> vrl::TypeDef {
+    use ::value::{kind::Collection, Kind};
+
+    let value = value.type_def(state).kind().clone();
+    let mut numbers = vec![];
+    let mut fallible = false;
+
+    if value.contains_array() {
+        let elements = value
+            .as_array()
+            .map_or_else(Kind::any, Collection::reduced_kind);
+
+        fallible = other.is_none() || !(Kind::integer() | Kind::float()).is_superset(&elements);
+        numbers.push(elements);
+    }
+    if !value.is_array() {
+        let mut scalar = value;
+        scalar.remove_array();
+        numbers.push(scalar);
+    }
+    if let Some(other) = other {
+        numbers.push(other.type_def(state).kind().clone());
+    }
+
+    vrl::TypeDef::from(promoted_numeric_kind(&numbers)).with_fallibility(fallible)
+}
+
+/// Returns the kind of a number computed from numbers of the given kinds, where mixing integers
+/// and floats promotes the result to a float.
+#[cfg(any(feature = "clamp", feature = "min", feature = "max", feature = "pow"))]
+pub(crate) fn promoted_numeric_kind(numbers: &[::value::Kind]) -> ::value::Kind {
+    use ::value::Kind;
+
+    if numbers.iter().all(Kind::is_integer) {
+        Kind::integer()
+    } else if numbers.iter().any(Kind::is_float) {
+        Kind::float()
+    } else {
+        Kind::integer().or_float()
+    }
+}
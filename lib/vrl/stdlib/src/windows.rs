@@ -0,0 +1,229 @@
+use ::value::Value;
+use vrl::prelude::*;
+use vrl::state::TypeState;
+
+/// The maximum number of elements across all the windows, as overlapping windows copy each
+/// element of the array up to `size` times.
+const MAX_ELEMENTS: usize = 1024 * 1024;
+
+fn windows(value: Value, size: Value, step: Value) -> Resolved {
+    let array = value.try_array()?;
+    let size = size.try_integer()?;
+    let step = step.try_integer()?;
+
+    if size < 1 {
+        return Err(r#""size" must be at least 1"#.into());
+    }
+    if step < 1 {
+        return Err(r#""step" must be at least 1"#.into());
+    }
+
+    // Both are positive, and a `size` that doesn't fit a `usize` is larger than any array.
+    let size = usize::try_from(size).unwrap_or(usize::MAX);
+    let step = usize::try_from(step).unwrap_or(usize::MAX);
+
+    let count = match array.len().checked_sub(size) {
+        Some(remaining) => remaining / step + 1,
+        None => 0,
+    };
+    if count.saturating_mul(size) > MAX_ELEMENTS {
+        return Err("windows would contain more than the maximum of 1Mi elements".into());
+    }
+
+    Ok((0..array.len())
+        .step_by(step)
+        .take_while(|start| array.len() - start >= size)
+        .map(|start| Value::from(array[start..start + size].to_vec()))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Windows;
+
+impl Function for Windows {
+    fn identifier(&self) -> &'static str {
+        "windows"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "size",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "step",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "sliding windows",
+                source: r#"windows!([1, 2, 3, 4], 2)"#,
+                result: Ok(r#"[[1, 2], [2, 3], [3, 4]]"#),
+            },
+            Example {
+                title: "windows with step",
+                source: r#"windows!([1, 2, 3, 4, 5], 2, step: 2)"#,
+                result: Ok(r#"[[1, 2], [3, 4]]"#),
+            },
+            Example {
+                title: "size larger than the array",
+                source: r#"windows!([1, 2], 3)"#,
+                result: Ok(r#"[]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let size = arguments.required("size");
+        let step = arguments.optional("step").unwrap_or(expr!(1));
+
+        for (keyword, argument, error) in [
+            ("size", &size, r#""size" must be at least 1"#),
+            ("step", &step, r#""step" must be at least 1"#),
+        ] {
+            if let Some(literal) = argument.as_value() {
+                if matches!(literal.as_integer(), Some(integer) if integer < 1) {
+                    return Err(vrl::function::Error::InvalidArgument {
+                        keyword,
+                        value: literal,
+                        error,
+                    }
+                    .into());
+                }
+            }
+        }
+
+        Ok(WindowsFn { value, size, step }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WindowsFn {
+    value: Box<dyn Expression>,
+    size: Box<dyn Expression>,
+    step: Box<dyn Expression>,
+}
+
+impl FunctionExpression for WindowsFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let size = self.size.resolve(ctx)?;
+        let step = self.step.resolve(ctx)?;
+
+        windows(value, size, step)
+    }
+
+    fn type_def(&self, state: &TypeState) -> TypeDef {
+        let elements = self
+            .value
+            .type_def(state)
+            .kind()
+            .as_array()
+            .map_or_else(Kind::any, Collection::reduced_kind);
+
+        // Always fallible, as the size of the result depends on the length of the array.
+        TypeDef::array(Collection::from_unknown(Kind::array(
+            Collection::from_unknown(elements),
+        )))
+        .fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn windows_of(elements: Kind) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::array(
+            Collection::from_unknown(elements),
+        )))
+        .fallible()
+    }
+
+    test_function![
+        windows => Windows;
+
+        overlapping {
+            args: func_args![value: value!([1, 2, 3, 4]), size: 3],
+            want: Ok(value!([[1, 2, 3], [2, 3, 4]])),
+            tdef: windows_of(Kind::integer()),
+        }
+
+        step {
+            args: func_args![value: value!([1, 2, 3, 4, 5, 6]), size: 2, step: 3],
+            want: Ok(value!([[1, 2], [4, 5]])),
+            tdef: windows_of(Kind::integer()),
+        }
+
+        short_window_dropped {
+            args: func_args![value: value!([1, 2, 3, 4, 5]), size: 2, step: 2],
+            want: Ok(value!([[1, 2], [3, 4]])),
+            tdef: windows_of(Kind::integer()),
+        }
+
+        size_larger_than_array {
+            args: func_args![value: value!(["a", "b"]), size: 3],
+            want: Ok(value!([])),
+            tdef: windows_of(Kind::bytes()),
+        }
+
+        empty_array {
+            args: func_args![value: value!([]), size: 1],
+            want: Ok(value!([])),
+            tdef: windows_of(Kind::never()),
+        }
+
+        zero_size {
+            args: func_args![value: value!([1, 2]), size: 0],
+            want: Err("invalid argument"),
+            tdef: windows_of(Kind::integer()),
+        }
+
+        zero_step {
+            args: func_args![value: value!([1, 2]), size: 1, step: 0],
+            want: Err("invalid argument"),
+            tdef: windows_of(Kind::integer()),
+        }
+    ];
+
+    #[test]
+    fn invalid_runtime_arguments() {
+        assert!(windows(value!([1]), value!(-1), value!(1)).is_err());
+        assert!(windows(value!([1]), value!(1), value!(0)).is_err());
+    }
+
+    #[test]
+    fn output_limit() {
+        // 1024 windows of 1024 elements each.
+        let array = Value::from(vec![Value::Null; 2047]);
+        let result = windows(array, value!(1024), value!(1)).unwrap();
+        assert_eq!(result.as_array().unwrap().len(), 1024);
+
+        // 1025 windows of 1024 elements each.
+        let array = Value::from(vec![Value::Null; 2048]);
+        let error = windows(array, value!(1024), value!(1)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "windows would contain more than the maximum of 1Mi elements"
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use ::value::Value;
+use lookup_lib::lookup_v2::OwnedValuePath;
+use vrl::prelude::*;
+
+use crate::util::parse_field_paths;
+
+fn without_fields(mut value: Value, paths: &[OwnedValuePath]) -> Value {
+    for path in paths {
+        value.remove(path, false);
+    }
+
+    value
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct WithoutFields;
+
+impl Function for WithoutFields {
+    fn identifier(&self) -> &'static str {
+        "without_fields"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "paths",
+                kind: kind::ARRAY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "top-level fields",
+                source: r#"without_fields({ "message": "hi", "host": "a", "pid": 1 }, ["message", "host"])"#,
+                result: Ok(r#"{ "pid": 1 }"#),
+            },
+            Example {
+                title: "nested fields",
+                source: r#"without_fields({ "user": { "id": 1, "email": "a@b.c" }, "pid": 1 }, ["user.id", "missing"])"#,
+                result: Ok(r#"{ "pid": 1, "user": { "email": "a@b.c" } }"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let paths = arguments.required("paths");
+
+        // Literal paths are parsed once here, rather than for every event.
+        let parsed = match paths.as_value() {
+            Some(literal) => match parse_field_paths(&literal) {
+                Ok(parsed) => Some(parsed),
+                Err(_) => {
+                    return Err(vrl::function::Error::InvalidArgument {
+                        keyword: "paths",
+                        value: literal,
+                        error: "must be an array of valid field paths",
+                    }
+                    .into())
+                }
+            },
+            None => None,
+        };
+
+        Ok(WithoutFieldsFn {
+            value,
+            paths,
+            parsed,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct WithoutFieldsFn {
+    value: Box<dyn Expression>,
+    paths: Box<dyn Expression>,
+    parsed: Option<Vec<OwnedValuePath>>,
+}
+
+impl FunctionExpression for WithoutFieldsFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        match &self.parsed {
+            Some(paths) => Ok(without_fields(value, paths)),
+            None => {
+                let paths = parse_field_paths(&self.paths.resolve(ctx)?)?;
+                Ok(without_fields(value, &paths))
+            }
+        }
+    }
+
+    fn type_def(&self, _: &state::TypeState) -> TypeDef {
+        TypeDef::object(Collection::any()).with_fallibility(self.parsed.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        without_fields => WithoutFields;
+
+        top_level {
+            args: func_args![
+                value: value!({ "message": "hi", "host": "a", "pid": 1 }),
+                paths: value!(["message", "host"]),
+            ],
+            want: Ok(value!({ "pid": 1 })),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        nested {
+            args: func_args![
+                value: value!({ "user": { "id": 1, "email": "a@b.c" }, "tags": ["a", "b"] }),
+                paths: value!(["user.id", "tags", "missing.field"]),
+            ],
+            want: Ok(value!({ "user": { "email": "a@b.c" } })),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        quoted {
+            args: func_args![
+                value: value!({ "a.b": 1, "a": { "b": 2 } }),
+                paths: value!([r#""a.b""#]),
+            ],
+            want: Ok(value!({ "a": { "b": 2 } })),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        invalid_path {
+            args: func_args![
+                value: value!({}),
+                paths: value!(["foo$"]),
+            ],
+            want: Err("invalid argument"),
+            tdef: TypeDef::object(Collection::any()),
+        }
+
+        non_string_path {
+            args: func_args![
+                value: value!({}),
+                paths: value!([1]),
+            ],
+            want: Err("invalid argument"),
+            tdef: TypeDef::object(Collection::any()),
+        }
+    ];
+}
@@ -0,0 +1,180 @@
+use ::value::Value;
+use vrl::prelude::*;
+
+fn zip2(value0: Value, value1: Value) -> Resolved {
+    Ok(value0
+        .try_array()?
+        .into_iter()
+        .zip(value1.try_array()?)
+        .map(|(v0, v1)| Value::Array(vec![v0, v1]))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+fn zip_all(value: Value) -> Resolved {
+    let mut iters = value
+        .try_array()?
+        .into_iter()
+        .map(|value| value.try_array().map(Vec::into_iter))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    // The result is truncated to the length of the shortest input array.
+    let mut result = Vec::new();
+    if iters.is_empty() {
+        return Ok(result.into());
+    }
+
+    'outer: loop {
+        let mut row = Vec::with_capacity(iters.len());
+        for iter in &mut iters {
+            match iter.next() {
+                Some(value) => row.push(value),
+                None => break 'outer,
+            }
+        }
+        result.push(Value::Array(row));
+    }
+
+    Ok(result.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Zip;
+
+impl Function for Zip {
+    fn identifier(&self) -> &'static str {
+        "zip"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "array_0",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "array_1",
+                kind: kind::ARRAY,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "two arrays",
+                source: r#"zip(["a", "b", "c"], [1, 2, 3])"#,
+                result: Ok(r#"[["a", 1], ["b", 2], ["c", 3]]"#),
+            },
+            Example {
+                title: "array of arrays",
+                source: r#"zip!([["a", "b", "c"], [1, 2, 3], [true, false, true]])"#,
+                result: Ok(r#"[["a", 1, true], ["b", 2, false], ["c", 3, true]]"#),
+            },
+            Example {
+                title: "truncates to the shortest array",
+                source: r#"zip(["a", "b", "c"], [1, 2])"#,
+                result: Ok(r#"[["a", 1], ["b", 2]]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &state::TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let array_0 = arguments.required("array_0");
+        let array_1 = arguments.optional("array_1");
+
+        match array_1 {
+            Some(array_1) => Ok(ZipFn { array_0, array_1 }.as_expr()),
+            None => Ok(ZipAllFn { value: array_0 }.as_expr()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ZipFn {
+    array_0: Box<dyn Expression>,
+    array_1: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ZipFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let array_0 = self.array_0.resolve(ctx)?;
+        let array_1 = self.array_1.resolve(ctx)?;
+
+        zip2(array_0, array_1)
+    }
+
+    fn type_def(&self, _state: &state::TypeState) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::array(Collection::any())))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ZipAllFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ZipAllFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+
+        zip_all(value)
+    }
+
+    fn type_def(&self, _state: &state::TypeState) -> TypeDef {
+        // Fails at runtime if any of the elements isn't an array.
+        TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))).fallible()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    test_function![
+        zip => Zip;
+
+        two_arrays {
+            args: func_args![array_0: value!(["a", "b"]), array_1: value!([1, 2])],
+            want: Ok(value!([["a", 1], ["b", 2]])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))),
+        }
+
+        truncates_to_shortest {
+            args: func_args![array_0: value!(["a", "b", "c"]), array_1: value!([1])],
+            want: Ok(value!([["a", 1]])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))),
+        }
+
+        array_of_arrays {
+            args: func_args![array_0: value!([["a", "b"], [1, 2], [true, false]])],
+            want: Ok(value!([["a", 1, true], ["b", 2, false]])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))).fallible(),
+        }
+
+        array_of_arrays_truncates_to_shortest {
+            args: func_args![array_0: value!([["a", "b"], [1, 2, 3]])],
+            want: Ok(value!([["a", 1], ["b", 2]])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))).fallible(),
+        }
+
+        empty {
+            args: func_args![array_0: value!([])],
+            want: Ok(value!([])),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))).fallible(),
+        }
+
+        not_an_array_of_arrays {
+            args: func_args![array_0: value!([["a"], 1])],
+            want: Err("expected array, got integer"),
+            tdef: TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))).fallible(),
+        }
+    ];
+}
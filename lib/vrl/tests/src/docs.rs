@@ -14,7 +14,9 @@ use crate::Test;
 /// This mostly consists of functions that have a non-deterministic result.
 const SKIP_FUNCTION_EXAMPLES: &[&str] = &[
     "type_def", // Not supported on VM runtime
+    "random_bool",
     "random_bytes",
+    "sample",
     "uuid_v4",
     "strip_ansi_escape_codes",
     "get_hostname",
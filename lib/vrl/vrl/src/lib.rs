@@ -12,14 +12,19 @@ mod runtime;
 
 pub use compiler::{
     function, state, value, CompilationResult, CompileConfig, Compiler, Context, Expression,
-    Function, Program, ProgramInfo, SecretTarget, Target, TargetValue, TargetValueRef, VrlRuntime,
+    Function, FunctionCallStats, Program, ProgramInfo, ReadOnlyPath, SecretTarget, Target,
+    TargetValue, TargetValueRef, VrlRuntime,
 };
 pub use diagnostic;
-pub use runtime::{Runtime, RuntimeResult, Terminate};
+pub use runtime::{Runtime, RuntimeResult, Terminate, TraceFn};
 pub use vector_common::TimeZone;
 
+use std::collections::BTreeMap;
+
 use crate::state::TypeState;
+use ::value::{Secrets, Value};
 pub use compiler::expression::query;
+use diagnostic::{DiagnosticList, DiagnosticMessage};
 
 /// Compile a given source into the final [`Program`].
 pub fn compile(source: &str, fns: &[Box<dyn Function>]) -> compiler::Result {
@@ -54,3 +59,264 @@ pub fn compile_with_state(
 
     Compiler::compile(fns, ast, state, config)
 }
+
+/// Compiles the given source, and runs the resulting program against `sample`.
+///
+/// This is useful to show the effect of a program on a representative event, for example while
+/// validating user input. The program is compiled without any external type information, and
+/// runs with empty metadata in the local timezone.
+///
+/// Returns the event after it was modified by the program, along with any compilation
+/// warnings. Compilation errors, as well as errors raised while running the program (including
+/// calls to `abort`), are returned as diagnostics.
+pub fn compile_and_run(
+    source: &str,
+    fns: &[Box<dyn Function>],
+    sample: Value,
+) -> Result<(Value, DiagnosticList), DiagnosticList> {
+    let CompilationResult {
+        program, warnings, ..
+    } = compile(source, fns)?;
+
+    let mut target = TargetValue {
+        value: sample,
+        metadata: Value::Object(BTreeMap::new()),
+        secrets: Secrets::new(),
+    };
+
+    Runtime::default()
+        .resolve(&mut target, &program, &TimeZone::default())
+        .map_err(|err| {
+            let (Terminate::Abort(error) | Terminate::Error(error)) = err;
+            DiagnosticList::from(vec![Box::new(error) as Box<dyn DiagnosticMessage>])
+        })?;
+
+    Ok((target.value, warnings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warnings(source: &str, kind_change_warnings: bool) -> Vec<String> {
+        let mut config = CompileConfig::default();
+        config.set_kind_change_warnings(kind_change_warnings);
+
+        compile_with_state(source, &vrl_stdlib::all(), &TypeState::default(), config)
+            .unwrap()
+            .warnings
+            .warnings()
+            .into_iter()
+            .map(|warning| warning.message().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn kind_change_warning() {
+        assert_eq!(
+            warnings(r#"x = 1; x = "foo""#, true),
+            vec!["variable `x` changes type".to_owned()]
+        );
+    }
+
+    #[test]
+    fn kind_change_warning_disabled_by_default() {
+        assert!(warnings(r#"x = 1; x = "foo""#, false).is_empty());
+    }
+
+    #[test]
+    fn kind_change_warning_compatible_kinds() {
+        assert!(warnings(r#"x = null; x = 1; x = 2"#, true).is_empty());
+    }
+
+    fn shadowed_function_warnings(source: &str) -> Vec<String> {
+        let mut config = CompileConfig::default();
+        config.set_shadowed_function_warnings(true);
+
+        compile_with_state(source, &vrl_stdlib::all(), &TypeState::default(), config)
+            .unwrap()
+            .warnings
+            .warnings()
+            .into_iter()
+            .map(|warning| warning.message().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn shadowed_function_warning() {
+        assert_eq!(
+            shadowed_function_warnings("length = 5; length = length + 1; count = 1"),
+            vec!["variable `length` shares its name with the `length` function".to_owned()]
+        );
+    }
+
+    #[test]
+    fn shadowed_function_warning_disabled_by_default() {
+        assert!(warnings("length = 5", false).is_empty());
+    }
+
+    #[test]
+    fn fallible_assignment_points_at_fallible_expression() {
+        let source = ".a = 1 + to_int(.foo)";
+        let errors = compile(source, &vrl_stdlib::all()).unwrap_err();
+        let error = &errors[0];
+
+        assert_eq!(error.message(), "unhandled fallible assignment");
+        assert!(error.labels().iter().any(|label| {
+            label.message == "because this expression can fail"
+                && label.span == diagnostic::Span::new(9, 21)
+        }));
+
+        let notes = error
+            .notes()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert!(notes.contains(&"    .a = 1 + to_int(.foo) ?? 0".to_owned()));
+        assert!(!notes
+            .iter()
+            .any(|note| note.contains("aborting the program")));
+    }
+
+    #[test]
+    fn invalid_strftime_directive_points_at_format_argument() {
+        let source = r#"format_timestamp!(now(), "%Y %Q")"#;
+        let errors = compile(source, &vrl_stdlib::all()).unwrap_err();
+
+        assert!(errors[0].labels().iter().any(|label| {
+            label.message == r#"the format string has an invalid directive "%Q" at offset 3"#
+                && label.span == diagnostic::Span::new(25, 32)
+        }));
+    }
+
+    #[test]
+    fn fallible_assignment_suggests_raising_function_call() {
+        let errors = compile(".a = parse_json(.foo)", &vrl_stdlib::all()).unwrap_err();
+        let notes = errors[0]
+            .notes()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        assert!(notes.contains(&"    .a = parse_json(.foo) ?? null".to_owned()));
+        assert!(notes.contains(&"    .a = parse_json!(.foo)".to_owned()));
+    }
+
+    #[test]
+    fn function_call_stats() {
+        let source = r#"
+            .a = upcase!(.a)
+            .b = map_values(object!(.b)) -> |value| {
+                parse_json(upcase!(value)) ?? null
+            }
+            .c = upcase!(.c)
+        "#;
+        let program = compile(source, &vrl_stdlib::all()).unwrap().program;
+        let stats = program.info().function_call_stats();
+
+        assert_eq!(
+            stats["upcase"],
+            FunctionCallStats {
+                calls: 3,
+                calls_in_iteration: 1,
+            }
+        );
+        assert_eq!(
+            stats["parse_json"],
+            FunctionCallStats {
+                calls: 1,
+                calls_in_iteration: 1,
+            }
+        );
+        assert_eq!(
+            stats["object"],
+            FunctionCallStats {
+                calls: 1,
+                calls_in_iteration: 0,
+            }
+        );
+        assert!(!stats.contains_key("downcase"));
+    }
+
+    #[test]
+    fn read_and_write_paths() {
+        let source = r#"
+            .a = .b
+            .c.d = 1
+            del(.e)
+            .a = .b
+            x = .f
+            x
+        "#;
+        let program = compile(source, &vrl_stdlib::all()).unwrap().program;
+        let paths = |paths: &[&str]| {
+            paths
+                .iter()
+                .map(|path| {
+                    lookup::OwnedTargetPath::event(
+                        lookup::lookup_v2::parse_value_path(path).unwrap(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(program.info().read_paths(), paths(&["b", "e", "f"]));
+        assert_eq!(program.info().write_paths(), paths(&["a", "c.d", "e"]));
+    }
+
+    #[test]
+    fn reads_entire_event() {
+        let reads_entire_event = |source| {
+            compile(source, &vrl_stdlib::all())
+                .unwrap()
+                .program
+                .info()
+                .reads_entire_event()
+        };
+
+        assert!(!reads_entire_event(".a = .b; .c.d = 1"));
+        assert!(!reads_entire_event(". = {}"));
+        assert!(reads_entire_event(".a = ."));
+        assert!(reads_entire_event("for_each(.) -> |_key, _value| { null }"));
+        assert!(reads_entire_event(r#"get!(., ["a"])"#));
+    }
+
+    #[test]
+    fn compile_and_run_returns_modified_sample() {
+        let (value, warnings) = compile_and_run(
+            r#".message = upcase!(.message); .processed = true"#,
+            &vrl_stdlib::all(),
+            vrl_core::value!({ "message": "foo" }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            value,
+            vrl_core::value!({ "message": "FOO", "processed": true })
+        );
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn compile_and_run_compile_error() {
+        let errors =
+            compile_and_run(".foo = upcase(.bar)", &vrl_stdlib::all(), Value::Null).unwrap_err();
+
+        assert!(errors.has_errors());
+    }
+
+    #[test]
+    fn compile_and_run_runtime_error() {
+        let errors = compile_and_run(
+            r#".foo = to_int!(.bar)"#,
+            &vrl_stdlib::all(),
+            vrl_core::value!({ "bar": "baz" }),
+        )
+        .unwrap_err();
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0]
+            .message()
+            .starts_with("function call error for \"to_int\""));
+    }
+}
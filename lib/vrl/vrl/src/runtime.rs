@@ -1,16 +1,30 @@
-use std::{error::Error, fmt};
+use std::{collections::BTreeMap, error::Error, fmt};
 
 use compiler::ExpressionError;
+use diagnostic::Span;
 use lookup::OwnedTargetPath;
-use value::Value;
+use value::{Secrets, Value};
 
-use crate::{state, Context, Program, Target, TimeZone};
+use crate::{state, Context, Program, Target, TargetValue, TimeZone};
 
 pub type RuntimeResult = Result<Value, Terminate>;
 
-#[derive(Debug, Default)]
+/// A callback invoked after each top-level expression of a program, see [`Runtime::with_trace`].
+pub type TraceFn = Box<dyn FnMut(Span, &Value) + Send + Sync>;
+
+#[derive(Default)]
 pub struct Runtime {
     state: state::Runtime,
+    trace: Option<TraceFn>,
+}
+
+impl fmt::Debug for Runtime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Runtime")
+            .field("state", &self.state)
+            .field("trace", &self.trace.is_some())
+            .finish()
+    }
 }
 
 /// The error raised if the runtime is terminated.
@@ -44,7 +58,59 @@ impl Error for Terminate {
 
 impl Runtime {
     pub fn new(state: state::Runtime) -> Self {
-        Self { state }
+        Self { state, trace: None }
+    }
+
+    /// Limits the approximate number of bytes the values stored in variables by a program may
+    /// take up. Programs that exceed it are terminated with
+    /// [`ExpressionError::MemoryLimitExceeded`].
+    ///
+    /// Values assigned to the target, and the results of function calls, count against the limit
+    /// along with the variables at the time they are produced. Other intermediate values, such as
+    /// the results of operators like `+` that aren't stored anywhere, are not accounted for.
+    ///
+    /// This currently only applies to the AST runtime.
+    #[must_use]
+    pub fn with_memory_limit(mut self, limit: usize) -> Self {
+        self.state.set_memory_limit(Some(limit));
+        self
+    }
+
+    /// Calls `trace` after each top-level expression of a resolved program, with the span of the
+    /// expression in the program source and the event as it is after the expression ran. This is
+    /// meant for debugging, programs resolve without any overhead if no trace is set.
+    ///
+    /// Expressions that raise an error aren't traced.
+    ///
+    /// This currently only applies to the AST runtime.
+    #[must_use]
+    pub fn with_trace(mut self, trace: impl FnMut(Span, &Value) + Send + Sync + 'static) -> Self {
+        self.trace = Some(Box::new(trace));
+        self
+    }
+
+    /// Counts the function calls, handled errors, and path lookups of the programs resolved by
+    /// this runtime, see [`Runtime::counters`]. This is meant for profiling, programs only pay
+    /// for a check of whether counting is enabled if it isn't.
+    ///
+    /// This currently only applies to the AST runtime.
+    #[must_use]
+    pub fn with_counters(mut self) -> Self {
+        self.state.set_counters_enabled(true);
+        self
+    }
+
+    /// The counts accumulated by all programs resolved since counting was enabled, or since the
+    /// last [`Runtime::reset_counters`]. Returns `None` if counting isn't enabled.
+    pub fn counters(&self) -> Option<&state::Counters> {
+        self.state.counters()
+    }
+
+    /// Resets the counts to zero, if counting is enabled.
+    pub fn reset_counters(&mut self) {
+        if self.state.counters().is_some() {
+            self.state.set_counters_enabled(true);
+        }
     }
 
     pub fn is_empty(&self) -> bool {
@@ -63,27 +129,372 @@ impl Runtime {
         program: &Program,
         timezone: &TimeZone,
     ) -> RuntimeResult {
-        // Validate that the path is a value.
-        match target.target_get(&OwnedTargetPath::event_root()) {
-            Ok(Some(_)) => {}
-            Ok(None) => {
-                return Err(Terminate::Error(
-                    "expected target object, got nothing".to_owned().into(),
-                ))
-            }
-            Err(err) => {
-                return Err(Terminate::Error(
-                    format!("error querying target object: {}", err).into(),
-                ))
-            }
+        validate_target(target)?;
+
+        let mut ctx = Context::new(target, &mut self.state, timezone);
+
+        let result = match &mut self.trace {
+            None => program.resolve(&mut ctx),
+            Some(trace) => program.resolve_traced(&mut ctx, trace),
         };
 
+        result.map_err(terminate)
+    }
+
+    /// Resolves the provided [`Program`] in a best-effort mode, for when partial results are
+    /// preferred over losing the event altogether.
+    ///
+    /// This changes VRL's normal semantics: a runtime error no longer terminates the program.
+    /// Instead, the top-level expression that raised it is abandoned, the error is collected, and
+    /// the program continues with the next top-level expression. Changes made by the abandoned
+    /// expression before the error was raised are kept, and variables it would have assigned are
+    /// left unassigned. Explicit `abort` calls, and exceeding the memory limit, still terminate
+    /// the program.
+    ///
+    /// The type guarantees VRL makes at compile time don't hold once an error is collected. An
+    /// unassigned variable reads as `null` whatever type it was assigned, so later expressions can
+    /// see values of a kind they weren't compiled for. Functions raise an error for such values,
+    /// which is collected like any other, even when they are otherwise infallible.
+    ///
+    /// Returns the program's result, or `null` if its last expression raised an error, along with
+    /// the collected errors. It's up to the caller to attach those to the event, if desired.
+    ///
+    /// Tracing is not supported in this mode.
+    pub fn resolve_lenient(
+        &mut self,
+        target: &mut dyn Target,
+        program: &Program,
+        timezone: &TimeZone,
+    ) -> Result<(Value, Vec<ExpressionError>), Terminate> {
+        validate_target(target)?;
+
         let mut ctx = Context::new(target, &mut self.state, timezone);
+        let mut errors = vec![];
+
+        let value = program
+            .resolve_lenient(&mut ctx, &mut errors)
+            .map_err(terminate)?;
+
+        Ok((value, errors))
+    }
+
+    /// Resolves the provided [`Program`] against a copy of `target`, leaving `target` itself
+    /// untouched. This is useful to preview the effect of a program on an event.
+    ///
+    /// Returns the event as the program left it, followed by the program's result. The copy
+    /// starts out without any metadata or secrets, and changes the program makes to those are
+    /// discarded.
+    pub fn resolve_cloned(
+        &mut self,
+        target: &Value,
+        program: &Program,
+        timezone: &TimeZone,
+    ) -> Result<(Value, Value), Terminate> {
+        let mut target = TargetValue {
+            value: target.clone(),
+            metadata: Value::Object(BTreeMap::new()),
+            secrets: Secrets::new(),
+        };
+
+        let output = self.resolve(&mut target, program, timezone)?;
+
+        Ok((target.value, output))
+    }
+}
+
+/// Validates that the target holds an event for the program to run against.
+fn validate_target(target: &dyn Target) -> Result<(), Terminate> {
+    match target.target_get(&OwnedTargetPath::event_root()) {
+        Ok(Some(_)) => Ok(()),
+        Ok(None) => Err(Terminate::Error(
+            "expected target object, got nothing".to_owned().into(),
+        )),
+        Err(err) => Err(Terminate::Error(
+            format!("error querying target object: {}", err).into(),
+        )),
+    }
+}
+
+fn terminate(err: ExpressionError) -> Terminate {
+    match err {
+        #[cfg(feature = "expr-abort")]
+        ExpressionError::Abort { .. } => Terminate::Abort(err),
+        err @ (ExpressionError::Error { .. } | ExpressionError::MemoryLimitExceeded { .. }) => {
+            Terminate::Error(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diagnostic::DiagnosticMessage;
+
+    use super::*;
+    use crate::compile;
+
+    fn resolve_with_limit(source: &str, limit: usize) -> RuntimeResult {
+        let program = compile(source, &vrl_stdlib::all()).unwrap().program;
+        let mut target = TargetValue {
+            value: Value::Object(BTreeMap::new()),
+            metadata: Value::Object(BTreeMap::new()),
+            secrets: Secrets::new(),
+        };
+
+        let mut runtime = Runtime::default().with_memory_limit(limit);
+        runtime.resolve(&mut target, &program, &TimeZone::default())
+    }
+
+    #[test]
+    fn trace_top_level_expressions() {
+        use std::sync::{Arc, Mutex};
+
+        let source = ".a = 1\n.b = .a + 1";
+        let program = compile(source, &vrl_stdlib::all()).unwrap().program;
+        let mut target = TargetValue {
+            value: Value::Object(BTreeMap::new()),
+            metadata: Value::Object(BTreeMap::new()),
+            secrets: Secrets::new(),
+        };
+
+        let steps = Arc::new(Mutex::new(vec![]));
+        let trace_steps = Arc::clone(&steps);
+        let mut runtime = Runtime::default().with_trace(move |span, event| {
+            trace_steps
+                .lock()
+                .unwrap()
+                .push((source[span.range()].to_owned(), event.clone()));
+        });
+
+        assert_eq!(
+            runtime.resolve(&mut target, &program, &TimeZone::default()),
+            Ok(Value::from(2))
+        );
+        assert_eq!(
+            *steps.lock().unwrap(),
+            vec![
+                (".a = 1".to_owned(), vrl_core::value!({"a": 1})),
+                (".b = .a + 1".to_owned(), vrl_core::value!({"a": 1, "b": 2})),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_lenient_continues_after_errors() {
+        let program = compile(
+            r#"
+                .a = 1
+                .b = parse_json!(.raw)
+                .c = .a + 1
+                .d = parse_int!("x")
+            "#,
+            &vrl_stdlib::all(),
+        )
+        .unwrap()
+        .program;
+        let mut target = TargetValue {
+            value: vrl_core::value!({"raw": "{"}),
+            metadata: Value::Object(BTreeMap::new()),
+            secrets: Secrets::new(),
+        };
+
+        let mut runtime = Runtime::default();
+        let (value, errors) = runtime
+            .resolve_lenient(&mut target, &program, &TimeZone::default())
+            .unwrap();
+
+        assert_eq!(value, Value::Null);
+        assert_eq!(target.value, vrl_core::value!({"raw": "{", "a": 1, "c": 2}));
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].message().contains("unable to parse json"));
+        assert!(errors[1].message().contains("could not parse integer"));
+    }
+
+    #[test]
+    fn resolve_lenient_reads_unassigned_variable() {
+        let program = compile(
+            r#"
+                p = to_float!(.p)
+                .sampled = random_bool!(p)
+                .unassigned = is_null(p)
+            "#,
+            &vrl_stdlib::all(),
+        )
+        .unwrap()
+        .program;
+        let mut target = TargetValue {
+            value: vrl_core::value!({"p": "x"}),
+            metadata: Value::Object(BTreeMap::new()),
+            secrets: Secrets::new(),
+        };
+
+        let mut runtime = Runtime::default();
+        let (_, errors) = runtime
+            .resolve_lenient(&mut target, &program, &TimeZone::default())
+            .unwrap();
+
+        assert_eq!(
+            target.value,
+            vrl_core::value!({"p": "x", "unassigned": true})
+        );
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[1].message(), "can't coerce null into float");
+    }
+
+    #[test]
+    fn resolve_lenient_still_aborts() {
+        let program = compile(".a = parse_json!(\"{\")\nabort\n.b = 1", &vrl_stdlib::all())
+            .unwrap()
+            .program;
+        let mut target = TargetValue {
+            value: Value::Object(BTreeMap::new()),
+            metadata: Value::Object(BTreeMap::new()),
+            secrets: Secrets::new(),
+        };
+
+        let mut runtime = Runtime::default();
+        assert!(matches!(
+            runtime.resolve_lenient(&mut target, &program, &TimeZone::default()),
+            Err(Terminate::Abort(_))
+        ));
+        assert_eq!(target.value, Value::Object(BTreeMap::new()));
+    }
+
+    #[test]
+    fn resolve_cloned_leaves_target_untouched() {
+        let program = compile(".a = 2\ndel(.b)\n.a + 1", &vrl_stdlib::all())
+            .unwrap()
+            .program;
+        let target = vrl_core::value!({"a": 1, "b": true});
+
+        let mut runtime = Runtime::default();
+        assert_eq!(
+            runtime.resolve_cloned(&target, &program, &TimeZone::default()),
+            Ok((vrl_core::value!({"a": 2}), Value::from(3)))
+        );
+        assert_eq!(target, vrl_core::value!({"a": 1, "b": true}));
+    }
+
+    #[test]
+    fn counters() {
+        let source = r#"
+            .a = upcase(.num) ?? "none"
+            x, err = parse_int(.raw)
+            .b = length(.msg) ?? 0
+        "#;
+        let program = compile(source, &vrl_stdlib::all()).unwrap().program;
+        let event = vrl_core::value!({"num": 1, "raw": "x", "msg": "foo"});
+
+        let mut runtime = Runtime::default().with_counters();
+        for _ in 0..2 {
+            runtime
+                .resolve_cloned(&event, &program, &TimeZone::default())
+                .unwrap();
+            runtime.clear();
+        }
+
+        assert_eq!(
+            runtime.counters(),
+            Some(&state::Counters {
+                function_calls: 6,
+                handled_errors: 4,
+                path_lookups: 6,
+            })
+        );
+
+        runtime.reset_counters();
+        assert_eq!(runtime.counters(), Some(&state::Counters::default()));
+    }
+
+    #[test]
+    fn counters_disabled_by_default() {
+        let program = compile("upcase(.a) ?? null", &vrl_stdlib::all())
+            .unwrap()
+            .program;
+
+        let mut runtime = Runtime::default();
+        runtime
+            .resolve_cloned(
+                &Value::Object(BTreeMap::new()),
+                &program,
+                &TimeZone::default(),
+            )
+            .unwrap();
+        runtime.reset_counters();
+
+        assert_eq!(runtime.counters(), None);
+    }
+
+    #[test]
+    fn memory_limit_not_exceeded() {
+        assert_eq!(
+            resolve_with_limit(r#"x = "foo"; x"#, 1024),
+            Ok(Value::from("foo"))
+        );
+    }
+
+    #[test]
+    fn memory_limit_exceeded() {
+        let source = r#"
+            x = "0123456789"
+            x = x + x + x + x + x + x + x + x
+            x = x + x + x + x + x + x + x + x
+            x
+        "#;
+
+        assert_eq!(
+            resolve_with_limit(source, 256),
+            Err(Terminate::Error(ExpressionError::MemoryLimitExceeded {
+                limit: 256
+            }))
+        );
+    }
+
+    #[test]
+    fn memory_limit_exceeded_by_function_result() {
+        let source = r#"
+            x = "0123456789"
+            length(strip_whitespace(x + x + x + x + x + x + x + x + x + x + x + x + x + x + x + x))
+        "#;
+
+        assert_eq!(
+            resolve_with_limit(source, 128),
+            Err(Terminate::Error(ExpressionError::MemoryLimitExceeded {
+                limit: 128
+            }))
+        );
+    }
+
+    #[test]
+    fn memory_limit_not_coalesced() {
+        let source = r#"
+            x = "0123456789"
+            {
+                x = x + x + x + x + x + x + x + x
+                x = x + x + x + x + x + x + x + x
+                parse_int!(x)
+            } ?? 0
+        "#;
+
+        assert_eq!(
+            resolve_with_limit(source, 256),
+            Err(Terminate::Error(ExpressionError::MemoryLimitExceeded {
+                limit: 256
+            }))
+        );
+    }
+
+    #[test]
+    fn memory_limit_exceeded_by_error_assignment() {
+        let source = r#"
+            x, err = parse_json(s'"0123456789012345678901234567890123456789"')
+            x
+        "#;
 
-        program.resolve(&mut ctx).map_err(|err| match err {
-            #[cfg(feature = "expr-abort")]
-            ExpressionError::Abort { .. } => Terminate::Abort(err),
-            err @ ExpressionError::Error { .. } => Terminate::Error(err),
-        })
+        assert_eq!(
+            resolve_with_limit(source, 16),
+            Err(Terminate::Error(ExpressionError::MemoryLimitExceeded {
+                limit: 16
+            }))
+        );
     }
 }
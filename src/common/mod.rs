@@ -18,3 +18,6 @@ pub(crate) mod sqs;
 
 #[cfg(any(feature = "sources-aws_s3", feature = "sinks-aws_s3"))]
 pub(crate) mod s3;
+
+#[cfg(any(feature = "sources-redis", feature = "sinks-redis"))]
+pub(crate) mod redis;
@@ -0,0 +1,49 @@
+//! Connection handling shared between the `redis` source and sink.
+
+use redis::{aio::ConnectionManager, IntoConnectionInfo, RedisResult};
+
+/// The protocol and endpoint of a Redis connection, as reported in internal events.
+pub(crate) struct ConnectionInfo {
+    pub(crate) protocol: &'static str,
+    pub(crate) endpoint: String,
+}
+
+impl ConnectionInfo {
+    /// Parses the connection details out of a Redis URL.
+    pub(crate) fn from_url(url: &str) -> RedisResult<Self> {
+        url.into_connection_info().map(|info| Self::from(&info))
+    }
+}
+
+impl From<&redis::ConnectionInfo> for ConnectionInfo {
+    fn from(redis_conn_info: &redis::ConnectionInfo) -> Self {
+        let (protocol, endpoint) = match &redis_conn_info.addr {
+            redis::ConnectionAddr::Tcp(host, port)
+            | redis::ConnectionAddr::TcpTls { host, port, .. } => {
+                ("tcp", format!("{}:{}", host, port))
+            }
+            redis::ConnectionAddr::Unix(path) => ("uds", path.to_string_lossy().to_string()),
+        };
+
+        Self { protocol, endpoint }
+    }
+}
+
+/// Opens a Redis client for the given URL.
+///
+/// This doesn't connect yet, it only validates the URL.
+pub(crate) fn build_client(url: &str) -> RedisResult<redis::Client> {
+    trace!("Open Redis client.");
+    let client = redis::Client::open(url)?;
+    trace!("Open Redis client success.");
+    Ok(client)
+}
+
+/// Creates a connection manager for the given client, which transparently reconnects to the same
+/// endpoint when the connection is dropped.
+pub(crate) async fn connection_manager(client: redis::Client) -> RedisResult<ConnectionManager> {
+    trace!("Get Redis connection.");
+    let conn = client.get_tokio_connection_manager().await?;
+    trace!("Get Redis connection success.");
+    Ok(conn)
+}
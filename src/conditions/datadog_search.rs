@@ -18,7 +18,7 @@ use crate::conditions::{Condition, Conditional, ConditionalConfig};
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct DatadogSearchConfig {
     /// The query string.
-    source: String,
+    pub(crate) source: String,
 }
 
 impl_generate_config_from_default!(DatadogSearchConfig);
@@ -123,6 +123,18 @@ impl ConditionConfig {
     }
 }
 
+impl std::fmt::Display for ConditionConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConditionConfig::IsLog => f.write_str("is_log"),
+            ConditionConfig::IsMetric => f.write_str("is_metric"),
+            ConditionConfig::IsTrace => f.write_str("is_trace"),
+            ConditionConfig::Vrl(x) => f.write_str(&x.source),
+            ConditionConfig::DatadogSearch(x) => write!(f, "datadog_search: {}", x.source),
+        }
+    }
+}
+
 pub trait Conditional: std::fmt::Debug {
     /// Checks if a condition is true.
     ///
@@ -194,6 +206,15 @@ impl AnyCondition {
     }
 }
 
+impl std::fmt::Display for AnyCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyCondition::String(s) => f.write_str(s),
+            AnyCondition::Map(m) => m.fmt(f),
+        }
+    }
+}
+
 impl From<ConditionConfig> for AnyCondition {
     fn from(config: ConditionConfig) -> Self {
         Self::Map(config)
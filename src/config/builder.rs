@@ -196,6 +196,7 @@ impl From<Config> for ConfigBuilder {
             secret,
             hash: _,
             expansions: _,
+            mock_time: _,
         } = config;
 
         let transforms = transforms
@@ -1,7 +1,13 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
 
 use indexmap::{IndexMap, IndexSet};
 
+#[cfg(feature = "transforms-alias")]
+use crate::transforms::Transforms;
+
 use super::{
     builder::ConfigBuilder, graph::Graph, id::Inputs, schema, validation, ComponentKey, Config,
     OutputId, SourceConfig, TransformConfig,
@@ -22,40 +28,105 @@ pub(crate) fn to_string_expansions(
         .collect::<IndexMap<_, _>>()
 }
 
-pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<String>> {
+pub fn compile(builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<String>> {
+    compile_inner(builder, true)
+}
+
+/// Runs the same validation as [`compile`], including graph construction and cycle detection,
+/// and discards the resulting config.
+///
+/// The config is still assembled, as the sink schema checks and the warnings are computed from
+/// it, so this is not meaningfully faster than [`compile`]. Only computing the config's hash and
+/// propagating acknowledgements are skipped.
+///
+/// Returns the warnings if the config is valid, and the errors otherwise.
+pub fn compile_validate(builder: ConfigBuilder) -> Result<Vec<String>, Vec<String>> {
+    compile_inner(builder, false).map(|(_, warnings)| warnings)
+}
+
+/// Compiles the config like [`compile`], but treats warnings as errors, for callers that only
+/// accept configs without any warnings.
+///
+/// Returns the errors if the config is invalid, and the warnings otherwise, if there are any.
+pub fn compile_strict(builder: ConfigBuilder) -> Result<Config, Vec<String>> {
+    let (config, warnings) = compile(builder)?;
+
+    if warnings.is_empty() {
+        Ok(config)
+    } else {
+        Err(warnings)
+    }
+}
+
+fn compile_inner(
+    builder: ConfigBuilder,
+    finalize: bool,
+) -> Result<(Config, Vec<String>), Vec<String>> {
+    let mut timings = PhaseTimings::new();
+    let result = compile_phases(builder, finalize, &mut timings);
+    timings.log();
+    result
+}
+
+/// Runs the phases of [`compile`], recording how long each one takes.
+///
+/// Kept apart from `compile_inner` so that the timings are logged however compilation returns,
+/// including when a phase bails out early.
+fn compile_phases(
+    mut builder: ConfigBuilder,
+    finalize: bool,
+    timings: &mut PhaseTimings,
+) -> Result<(Config, Vec<String>), Vec<String>> {
     let mut errors = Vec::new();
 
     // component names should not have dots in the configuration file
     // but components can expand (like route) to have components with a dot
     // so this check should be done before expanding components
-    if let Err(name_errors) = validation::check_names(
-        builder
-            .transforms
-            .keys()
-            .chain(builder.sources.keys())
-            .chain(builder.sinks.keys()),
-    ) {
+    if let Err(name_errors) = timings.phase("check_names", || {
+        validation::check_names(
+            builder
+                .transforms
+                .keys()
+                .chain(builder.sources.keys())
+                .chain(builder.sinks.keys()),
+        )
+    }) {
         errors.extend(name_errors);
     }
 
-    let expansions = expand_macros(&mut builder)?;
+    if let Err(id_errors) = timings.phase("check_unique_ids", || {
+        validation::check_unique_ids(&builder)
+    }) {
+        errors.extend(id_errors);
+    }
+
+    if let Err(disabled_errors) = timings.phase("remove_disabled", || remove_disabled(&mut builder))
+    {
+        errors.extend(disabled_errors);
+    }
+
+    let expansions = timings.phase("expand_macros", || expand_macros(&mut builder))?;
 
-    expand_globs(&mut builder);
+    timings.phase("expand_globs", || expand_globs(&mut builder));
 
-    if let Err(type_errors) = validation::check_shape(&builder) {
+    if let Err(type_errors) = timings.phase("check_shape", || validation::check_shape(&builder)) {
         errors.extend(type_errors);
     }
 
-    if let Err(type_errors) = validation::check_resources(&builder) {
+    if let Err(type_errors) =
+        timings.phase("check_resources", || validation::check_resources(&builder))
+    {
         errors.extend(type_errors);
     }
 
-    if let Err(output_errors) = validation::check_outputs(&builder) {
+    if let Err(output_errors) =
+        timings.phase("check_outputs", || validation::check_outputs(&builder))
+    {
         errors.extend(output_errors);
     }
 
     #[cfg(feature = "enterprise")]
-    let hash = Some(builder.sha256_hash());
+    let hash = finalize.then(|| builder.sha256_hash());
 
     #[cfg(not(feature = "enterprise"))]
     let hash = None;
@@ -78,7 +149,9 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
     } = builder;
 
     let str_expansions = to_string_expansions(&expansions);
-    let graph = match Graph::new(&sources, &transforms, &sinks, &str_expansions, schema) {
+    let graph = match timings.phase("build_graph", || {
+        Graph::new(&sources, &transforms, &sinks, &str_expansions, schema)
+    }) {
         Ok(graph) => graph,
         Err(graph_errors) => {
             errors.extend(graph_errors);
@@ -86,11 +159,11 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
         }
     };
 
-    if let Err(type_errors) = graph.typecheck() {
+    if let Err(type_errors) = timings.phase("typecheck", || graph.typecheck()) {
         errors.extend(type_errors);
     }
 
-    if let Err(e) = graph.check_for_cycles() {
+    if let Err(e) = timings.phase("check_for_cycles", || graph.check_for_cycles()) {
         errors.push(e);
     }
 
@@ -132,11 +205,22 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
             tests,
             expansions,
             secret,
+            mock_time: None,
         };
 
-        config.propagate_acknowledgements()?;
+        if config.schema.validation {
+            if let Err(schema_errors) = timings.phase("check_sink_schemas", || {
+                validation::check_sink_schemas(&config)
+            }) {
+                return Err(schema_errors);
+            }
+        }
+
+        if finalize {
+            config.propagate_acknowledgements()?;
+        }
 
-        let warnings = validation::warnings(&config);
+        let warnings = timings.phase("warnings", || validation::warnings(&config));
 
         Ok((config, warnings))
     } else {
@@ -144,11 +228,132 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
     }
 }
 
+/// Records how long each phase of [`compile`] takes.
+///
+/// Every phase runs within its own `debug` span. Timings are only measured, and the final
+/// breakdown only logged, when `debug` events are enabled for this module.
+struct PhaseTimings {
+    enabled: bool,
+    phases: Vec<(&'static str, Duration)>,
+}
+
+impl PhaseTimings {
+    fn new() -> Self {
+        Self {
+            enabled: tracing::enabled!(tracing::Level::DEBUG),
+            phases: Vec::new(),
+        }
+    }
+
+    fn phase<T>(&mut self, name: &'static str, f: impl FnOnce() -> T) -> T {
+        let _span = debug_span!("compile_phase", phase = name).entered();
+
+        if !self.enabled {
+            return f();
+        }
+
+        let start = Instant::now();
+        let result = f();
+        self.phases.push((name, start.elapsed()));
+        result
+    }
+
+    fn log(&self) {
+        if !self.enabled {
+            return;
+        }
+
+        let total: Duration = self.phases.iter().map(|(_, elapsed)| *elapsed).sum();
+        let breakdown = self
+            .phases
+            .iter()
+            .map(|(name, elapsed)| format!("{}={:?}", name, elapsed))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        debug!(message = "Compiled config.", ?total, %breakdown);
+    }
+}
+
+/// Removes the components whose `enabled` option is turned off, as if they weren't part of the
+/// config. Enabled components using any of them as an input are reported as errors, rather than
+/// silently losing that input.
+pub(super) fn remove_disabled(config: &mut ConfigBuilder) -> Result<(), Vec<String>> {
+    let disabled = config
+        .sources
+        .iter()
+        .filter(|(_, source)| !source.enabled)
+        .map(|(key, _)| key.clone())
+        .chain(
+            config
+                .transforms
+                .iter()
+                .filter(|(_, transform)| !transform.enabled)
+                .map(|(key, _)| key.clone()),
+        )
+        .chain(
+            config
+                .sinks
+                .iter()
+                .filter(|(_, sink)| !sink.enabled)
+                .map(|(key, _)| key.clone()),
+        )
+        .collect::<Vec<_>>();
+    if disabled.is_empty() {
+        return Ok(());
+    }
+
+    config.sources.retain(|_, source| source.enabled);
+    config.transforms.retain(|_, transform| transform.enabled);
+    config.sinks.retain(|_, sink| sink.enabled);
+
+    // An input refers to a component either by its ID, or to one of its named outputs or expanded
+    // components by a dotted path starting with its ID. Those inputs are removed as well, so that
+    // later phases don't report them again as missing components.
+    let find_disabled = |input: &str| {
+        disabled.iter().find(|key| {
+            input
+                .strip_prefix(key.id())
+                .map_or(false, |rest| rest.is_empty() || rest.starts_with('.'))
+        })
+    };
+
+    let mut errors = Vec::new();
+    let mut remove_disabled_inputs =
+        |kind: &str, key: &ComponentKey, inputs: &mut Inputs<String>| {
+            let raw_inputs = std::mem::take(inputs);
+            for input in raw_inputs {
+                match find_disabled(&input) {
+                    Some(disabled_key) => errors.push(format!(
+                        "Input \"{}\" for {} \"{}\" refers to disabled component \"{}\".",
+                        input, kind, key, disabled_key
+                    )),
+                    None => inputs.extend(Some(input)),
+                }
+            }
+        };
+    for (key, transform) in config.transforms.iter_mut() {
+        remove_disabled_inputs("transform", key, &mut transform.inputs);
+    }
+    for (key, sink) in config.sinks.iter_mut() {
+        remove_disabled_inputs("sink", key, &mut sink.inputs);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 /// Some component configs can act like macros and expand themselves into multiple replacement
 /// configs. Performs those expansions and records the relevant metadata.
 pub(super) fn expand_macros(
     config: &mut ConfigBuilder,
 ) -> Result<IndexMap<ComponentKey, Vec<ComponentKey>>, Vec<String>> {
+    #[cfg(feature = "transforms-alias")]
+    let aliases = resolve_aliases(config)?;
+
     let mut expanded_transforms = IndexMap::new();
     let mut expansions = IndexMap::new();
     let mut errors = Vec::new();
@@ -166,6 +371,13 @@ pub(super) fn expand_macros(
     }
     config.transforms = expanded_transforms;
 
+    // An alias of a transform that isn't a macro expands to just itself. It's still recorded, so
+    // that the expansions account for every alias.
+    #[cfg(feature = "transforms-alias")]
+    for key in aliases {
+        expansions.entry(key.clone()).or_insert_with(|| vec![key]);
+    }
+
     if !errors.is_empty() {
         Err(errors)
     } else {
@@ -173,6 +385,72 @@ pub(super) fn expand_macros(
     }
 }
 
+/// Replaces every `alias` transform with a copy of the transform it refers to, keeping the key,
+/// inputs and enabled state of the alias. This runs before macros are expanded, so an alias of a
+/// macro transform is expanded under its own key, and gets its own entry in the expansions.
+///
+/// Returns the keys of the resolved aliases.
+#[cfg(feature = "transforms-alias")]
+fn resolve_aliases(config: &mut ConfigBuilder) -> Result<Vec<ComponentKey>, Vec<String>> {
+    let mut resolved = Vec::new();
+    let mut errors = Vec::new();
+
+    for (key, transform) in &config.transforms {
+        let mut alias_of = match &transform.inner {
+            Transforms::Alias(alias) => &alias.alias_of,
+            _ => continue,
+        };
+        let mut chain = vec![key.id()];
+
+        loop {
+            if chain.contains(&alias_of.as_str()) {
+                chain.push(alias_of.as_str());
+                errors.push(format!(
+                    "Transform \"{}\" is part of an alias cycle: {}.",
+                    key,
+                    chain.join(" -> ")
+                ));
+                break;
+            }
+
+            let target = ComponentKey::from(alias_of.as_str());
+            match config.transforms.get(&target) {
+                Some(target) => match &target.inner {
+                    Transforms::Alias(alias) => {
+                        chain.push(alias_of.as_str());
+                        alias_of = &alias.alias_of;
+                    }
+                    inner => {
+                        resolved.push((key.clone(), inner.clone()));
+                        break;
+                    }
+                },
+                None => {
+                    errors.push(format!(
+                        "Transform \"{}\" is an alias of \"{}\", which doesn't match any transforms.",
+                        key, alias_of
+                    ));
+                    break;
+                }
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let mut aliases = Vec::with_capacity(resolved.len());
+    for (key, inner) in resolved {
+        if let Some(transform) = config.transforms.get_mut(&key) {
+            transform.inner = inner;
+        }
+        aliases.push(key);
+    }
+
+    Ok(aliases)
+}
+
 /// Expand globs in input lists
 pub(crate) fn expand_globs(config: &mut ConfigBuilder) {
     let candidates = config
@@ -250,6 +528,8 @@ fn expand_globs_inner(inputs: &mut Inputs<String>, id: &str, candidates: &IndexS
 
 #[cfg(test)]
 mod test {
+    use vector_config::NamedComponent;
+
     use super::*;
     use crate::test_util::mock::{basic_sink, basic_source, basic_transform};
 
@@ -309,6 +589,208 @@ mod test {
         );
     }
 
+    #[cfg(feature = "transforms-alias")]
+    fn alias(alias_of: &str) -> crate::transforms::alias::AliasConfig {
+        crate::transforms::alias::AliasConfig {
+            alias_of: alias_of.to_string(),
+        }
+    }
+
+    #[cfg(feature = "transforms-alias")]
+    #[test]
+    fn aliases_are_resolved() {
+        let mut builder = ConfigBuilder::default();
+        builder.add_source("in1", basic_source().1);
+        builder.add_source("in2", basic_source().1);
+        builder.add_transform("original", &["in1"], basic_transform("foo", 1.0));
+        builder.add_transform("copy", &["in2"], alias("original"));
+        builder.add_transform("copy_of_copy", &["copy"], alias("copy"));
+        builder.add_sink("out", &["original", "copy_of_copy"], basic_sink(1).1);
+
+        let config = builder.build().expect("build should succeed");
+
+        for (key, input) in [
+            ("original", "in1"),
+            ("copy", "in2"),
+            ("copy_of_copy", "copy"),
+        ] {
+            let transform = config
+                .transforms
+                .get(&ComponentKey::from(key))
+                .expect("transform should exist");
+            assert_eq!(transform.inner.get_component_name(), "test_basic");
+            assert_eq!(
+                without_ports(transform.inputs.clone()),
+                vec![ComponentKey::from(input)]
+            );
+        }
+
+        for key in ["copy", "copy_of_copy"] {
+            assert_eq!(
+                config.expansions.get(&ComponentKey::from(key)),
+                Some(&vec![ComponentKey::from(key)])
+            );
+        }
+        assert!(!config
+            .expansions
+            .contains_key(&ComponentKey::from("original")));
+    }
+
+    #[cfg(feature = "transforms-alias")]
+    #[test]
+    fn alias_errors() {
+        let mut builder = ConfigBuilder::default();
+        builder.add_source("in", basic_source().1);
+        builder.add_transform("a", &["in"], alias("b"));
+        builder.add_transform("b", &["in"], alias("a"));
+        builder.add_transform("c", &["in"], alias("missing"));
+        builder.add_sink("out", &["a", "b", "c"], basic_sink(1).1);
+
+        assert_eq!(
+            builder.build().map(|_| ()),
+            Err(vec![
+                r#"Transform "a" is part of an alias cycle: a -> b -> a."#.to_owned(),
+                r#"Transform "b" is part of an alias cycle: b -> a -> b."#.to_owned(),
+                r#"Transform "c" is an alias of "missing", which doesn't match any transforms."#
+                    .to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn compile_validate_matches_compile() {
+        let mut builder = ConfigBuilder::default();
+        builder.add_source("in", basic_source().1);
+        builder.add_source("unused", basic_source().1);
+        builder.add_sink("out", &["in"], basic_sink(1).1);
+
+        let (_, warnings) = compile(builder.clone()).expect("compile should succeed");
+        assert_eq!(compile_validate(builder), Ok(warnings));
+
+        let mut builder = ConfigBuilder::default();
+        builder.add_source("in", basic_source().1);
+        builder.add_transform("one", &["in", "two"], basic_transform("", 1.0));
+        builder.add_transform("two", &["one"], basic_transform("", 1.0));
+        builder.add_sink("out", &["two"], basic_sink(1).1);
+
+        let errors = compile(builder.clone()).map(|_| ()).unwrap_err();
+        assert_eq!(compile_validate(builder), Err(errors));
+    }
+
+    #[test]
+    fn compile_strict_rejects_warnings() {
+        let mut builder = ConfigBuilder::default();
+        builder.add_source("in", basic_source().1);
+        builder.add_sink("out", &["in"], basic_sink(1).1);
+
+        assert!(compile_strict(builder.clone()).is_ok());
+
+        builder.add_source("unused", basic_source().1);
+
+        let (_, warnings) = compile(builder.clone()).expect("compile should succeed");
+        assert!(!warnings.is_empty());
+        assert_eq!(compile_strict(builder).map(|_| ()), Err(warnings));
+    }
+
+    #[test]
+    fn disabled_components_are_removed() {
+        let mut builder = ConfigBuilder::default();
+        builder.add_source("in", basic_source().1);
+        builder.add_source("staging_in", basic_source().1);
+        builder.add_transform("staging", &["staging_in"], basic_transform("", 1.0));
+        builder.add_sink("out", &["*in"], basic_sink(1).1);
+        builder.add_sink("staging_out", &["staging"], basic_sink(1).1);
+        builder.sources[&ComponentKey::from("staging_in")].enabled = false;
+        builder.transforms[&ComponentKey::from("staging")].enabled = false;
+        builder.sinks[&ComponentKey::from("staging_out")].enabled = false;
+
+        let config = builder.build().expect("build should succeed");
+
+        assert_eq!(
+            config.sources.keys().collect::<Vec<_>>(),
+            vec![&ComponentKey::from("in")]
+        );
+        assert!(config.transforms.is_empty());
+        assert_eq!(
+            config.sinks.keys().collect::<Vec<_>>(),
+            vec![&ComponentKey::from("out")]
+        );
+        assert_eq!(
+            without_ports(config.sinks[&ComponentKey::from("out")].inputs.clone()),
+            vec![ComponentKey::from("in")]
+        );
+    }
+
+    #[test]
+    fn disabled_components_used_as_inputs_are_errors() {
+        let mut builder = ConfigBuilder::default();
+        builder.add_source("in", basic_source().1);
+        builder.add_transform("parse", &["in"], basic_transform("", 1.0));
+        builder.add_sink("out", &["parse", "in"], basic_sink(1).1);
+        builder.add_sink("parsed_only", &["parse.errors"], basic_sink(1).1);
+        builder.transforms[&ComponentKey::from("parse")].enabled = false;
+
+        let errors = builder.build().map(|_| ()).unwrap_err();
+
+        for error in [
+            r#"Input "parse" for sink "out" refers to disabled component "parse"."#,
+            r#"Input "parse.errors" for sink "parsed_only" refers to disabled component "parse"."#,
+        ] {
+            assert!(errors.contains(&error.to_owned()), "{:?}", errors);
+        }
+        assert!(!errors.iter().any(|error| error.contains("doesn't match")));
+    }
+
+    #[test]
+    fn duplicate_ids_include_disabled_components() {
+        let mut builder = ConfigBuilder::default();
+        builder.add_source("in", basic_source().1);
+        builder.add_transform("in", &["in"], basic_transform("", 1.0));
+        builder.add_sink("out", &["in"], basic_sink(1).1);
+        builder.transforms[&ComponentKey::from("in")].enabled = false;
+
+        let errors = builder.build().map(|_| ()).unwrap_err();
+
+        assert_eq!(
+            errors[0],
+            r#"More than one component with name "in" (source, transform)."#
+        );
+    }
+
+    #[test]
+    fn sink_schema_requirements_are_checked() {
+        let requirement = vector_core::schema::Requirement::empty()
+            .required_meaning("timestamp", value::Kind::timestamp());
+
+        let mut builder = ConfigBuilder::default();
+        builder.schema.validation = true;
+        builder.add_source("in1", basic_source().1);
+        builder.add_source("in2", basic_source().1);
+        builder.add_sink(
+            "out",
+            &["in*"],
+            basic_sink(1).1.with_schema_requirement(requirement.clone()),
+        );
+
+        let errors = builder.build().map(|_| ()).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                r#"schema error in sink "out" for input "in1": missing semantic meaning: timestamp"#,
+                r#"schema error in sink "out" for input "in2": missing semantic meaning: timestamp"#,
+            ]
+        );
+
+        let mut builder = ConfigBuilder::default();
+        builder.add_source("in", basic_source().1);
+        builder.add_sink(
+            "out",
+            &["in"],
+            basic_sink(1).1.with_schema_requirement(requirement),
+        );
+        assert!(builder.build().is_ok(), "validation is disabled by default");
+    }
+
     fn without_ports(outputs: Inputs<OutputId>) -> Vec<ComponentKey> {
         outputs
             .into_iter()
@@ -1,12 +1,12 @@
 use indexmap::{set::IndexSet, IndexMap};
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 
 use super::{
     schema, ComponentKey, DataType, Output, OutputId, SinkConfig, SinkOuter, SourceConfig,
     SourceOuter, TransformConfig, TransformOuter,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Node {
     Source {
         outputs: Vec<Output>,
@@ -24,6 +24,8 @@ pub enum Node {
 struct Edge {
     from: OutputId,
     to: ComponentKey,
+    /// The condition guarding the output this edge starts from, if any.
+    condition: Option<String>,
 }
 
 #[derive(Default)]
@@ -32,6 +34,27 @@ pub struct Graph {
     edges: Vec<Edge>,
 }
 
+/// The components and edges that differ between two graphs, as computed by [`Graph::diff`].
+#[derive(Debug, Default, PartialEq)]
+pub struct GraphDiff {
+    pub added: HashSet<ComponentKey>,
+    pub removed: HashSet<ComponentKey>,
+    /// Components present in both graphs whose node or set of inputs differs.
+    pub changed: HashSet<ComponentKey>,
+    pub added_edges: HashSet<(OutputId, ComponentKey)>,
+    pub removed_edges: HashSet<(OutputId, ComponentKey)>,
+}
+
+impl GraphDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+    }
+}
+
 impl Graph {
     pub fn new(
         sources: &IndexMap<ComponentKey, SourceOuter>,
@@ -94,13 +117,15 @@ impl Graph {
             );
         }
 
+        // Inputs are resolved by parsing them, which settles whether a dotted input names a
+        // component or a port, but outputs that share a string form are still rejected.
+        graph.input_map()?;
+
         // With all of the nodes added, go through inputs and add edges, resolving strings into
         // actual `OutputId`s along the way.
-        let available_inputs = graph.input_map()?;
-
         for (id, config) in transforms.iter() {
             for input in config.inputs.iter() {
-                if let Err(e) = graph.add_input(input, id, &available_inputs, expansions) {
+                if let Err(e) = graph.add_input(input, id, expansions) {
                     errors.push(e);
                 }
             }
@@ -108,7 +133,7 @@ impl Graph {
 
         for (id, config) in sinks.iter() {
             for input in config.inputs.iter() {
-                if let Err(e) = graph.add_input(input, id, &available_inputs, expansions) {
+                if let Err(e) = graph.add_input(input, id, expansions) {
                     errors.push(e);
                 }
             }
@@ -125,18 +150,24 @@ impl Graph {
         &mut self,
         from: &str,
         to: &ComponentKey,
-        available_inputs: &HashMap<String, OutputId>,
         expansions: &IndexMap<String, Vec<String>>,
     ) -> Result<(), String> {
-        if let Some(output_id) = available_inputs.get(from) {
+        if let Some(output_id) = self.resolve_input(from) {
+            // Reported here rather than left to `check_for_cycles`, as a one-node cycle is almost
+            // always a typo, and the generic cycle message doesn't make that obvious.
+            if &output_id.component == to {
+                return Err(format!("Component \"{}\" cannot take itself as input.", to));
+            }
+            let condition = self.get_output_condition(&output_id);
             self.edges.push(Edge {
-                from: output_id.clone(),
+                from: output_id,
                 to: to.clone(),
+                condition,
             });
             Ok(())
         } else if let Some(expanded) = expansions.get(from) {
             for item in expanded {
-                self.add_input(item, to, available_inputs, expansions)?;
+                self.add_input(item, to, expansions)?;
             }
             Ok(())
         } else {
@@ -183,6 +214,17 @@ impl Graph {
         }
     }
 
+    /// Return the condition guarding the output with the given `OutputId`, if any.
+    fn get_output_condition(&self, id: &OutputId) -> Option<String> {
+        match self.nodes.get(&id.component)? {
+            Node::Source { outputs } | Node::Transform { outputs, .. } => outputs
+                .iter()
+                .find(|output| output.port == id.port)
+                .and_then(|output| output.condition.clone()),
+            Node::Sink { .. } => None,
+        }
+    }
+
     pub fn typecheck(&self) -> Result<(), Vec<String>> {
         let mut errors = Vec::new();
 
@@ -307,6 +349,23 @@ impl Graph {
         }
     }
 
+    /// Resolves an input specifier, such as `component` or `component.port`, to an output of a
+    /// source or transform in the graph.
+    ///
+    /// See [`OutputId::parse`] for how component ids containing dots are told apart from ports.
+    pub fn resolve_input(&self, input: &str) -> Option<OutputId> {
+        let outputs = |key: &ComponentKey| match self.nodes.get(key) {
+            Some(Node::Source { outputs } | Node::Transform { outputs, .. }) => Some(outputs),
+            _ => None,
+        };
+
+        OutputId::parse(input, |key| outputs(key).is_some()).filter(|id| {
+            outputs(&id.component).map_or(false, |outputs| {
+                outputs.iter().any(|output| output.port == id.port)
+            })
+        })
+    }
+
     pub fn inputs_for(&self, node: &ComponentKey) -> Vec<OutputId> {
         self.edges
             .iter()
@@ -315,6 +374,153 @@ impl Graph {
             .collect()
     }
 
+    /// Returns the human-readable condition an event must match to travel along the edge from
+    /// `from` to `to`, such as the condition of a `route` transform's named output.
+    ///
+    /// Returns `None` if there is no such edge, or the edge is unconditional.
+    pub fn edge_condition(&self, from: &OutputId, to: &ComponentKey) -> Option<&str> {
+        self.edges
+            .iter()
+            .find(|edge| &edge.from == from && &edge.to == to)
+            .and_then(|edge| edge.condition.as_deref())
+    }
+
+    /// Computes the minimal set of components and edges that differ between `old` and `new`.
+    ///
+    /// A component present in both graphs is considered changed if its kind, input type or outputs
+    /// differ, or if it consumes a different set of outputs. The graph doesn't hold component
+    /// options, so changes to those have to be detected from the configs themselves, see
+    /// `ConfigDiff`.
+    pub fn diff(old: &Graph, new: &Graph) -> GraphDiff {
+        let mut diff = GraphDiff::default();
+
+        for (key, node) in &new.nodes {
+            match old.nodes.get(key) {
+                None => {
+                    diff.added.insert(key.clone());
+                }
+                Some(old_node) => {
+                    if old_node != node || old.input_set(key) != new.input_set(key) {
+                        diff.changed.insert(key.clone());
+                    }
+                }
+            }
+        }
+
+        diff.removed = old
+            .nodes
+            .keys()
+            .filter(|key| !new.nodes.contains_key(key))
+            .cloned()
+            .collect();
+
+        let old_edges = old.edge_set();
+        let new_edges = new.edge_set();
+        diff.added_edges = new_edges.difference(&old_edges).cloned().collect();
+        diff.removed_edges = old_edges.difference(&new_edges).cloned().collect();
+
+        diff
+    }
+
+    fn input_set(&self, node: &ComponentKey) -> HashSet<OutputId> {
+        self.inputs_for(node).into_iter().collect()
+    }
+
+    fn edge_set(&self) -> HashSet<(OutputId, ComponentKey)> {
+        self.edges
+            .iter()
+            .map(|edge| (edge.from.clone(), edge.to.clone()))
+            .collect()
+    }
+
+    /// Returns every component that `key` feeds, directly or through other components.
+    ///
+    /// The result doesn't include `key` itself and is in topological order: a component always
+    /// comes before the components it feeds. Components that don't depend on each other are
+    /// ordered by key. Components on or downstream of a cycle are ordered by key after all others.
+    pub fn downstream_of(&self, key: &ComponentKey) -> Vec<ComponentKey> {
+        let reachable = self.reachable_from(key, |edge| (&edge.from.component, &edge.to));
+        self.topological_order(reachable)
+    }
+
+    /// Returns every component that feeds `key`, directly or through other components.
+    ///
+    /// The result doesn't include `key` itself and is ordered the same way as
+    /// [`Graph::downstream_of`], so the components furthest upstream come first.
+    pub fn upstream_of(&self, key: &ComponentKey) -> Vec<ComponentKey> {
+        let reachable = self.reachable_from(key, |edge| (&edge.to, &edge.from.component));
+        self.topological_order(reachable)
+    }
+
+    /// Collects the components reachable from `root` by following edges, where `direction` maps an
+    /// edge to the component it's followed from and the component it leads to.
+    fn reachable_from<'a>(
+        &'a self,
+        root: &ComponentKey,
+        direction: impl Fn(&'a Edge) -> (&'a ComponentKey, &'a ComponentKey),
+    ) -> HashSet<ComponentKey> {
+        let mut reachable = HashSet::new();
+        let mut traversal = VecDeque::from([root.clone()]);
+
+        while let Some(n) = traversal.pop_front() {
+            for (from, to) in self.edges.iter().map(&direction) {
+                if from == &n && to != root && reachable.insert(to.clone()) {
+                    traversal.push_back(to.clone());
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Orders `keys` so that each component comes before the components it feeds, only
+    /// considering the edges between the given components.
+    ///
+    /// Components on a cycle can't be ordered, and neither can any of the components they feed,
+    /// even if those aren't on a cycle themselves. All of them are appended at the end, ordered
+    /// by key.
+    fn topological_order(&self, mut keys: HashSet<ComponentKey>) -> Vec<ComponentKey> {
+        let edges = self
+            .edges
+            .iter()
+            .filter(|edge| keys.contains(&edge.from.component) && keys.contains(&edge.to))
+            .map(|edge| (&edge.from.component, &edge.to))
+            .collect::<HashSet<_>>();
+
+        let mut in_degree: HashMap<&ComponentKey, usize> = keys.iter().map(|k| (k, 0)).collect();
+        for (_, to) in &edges {
+            *in_degree.get_mut(to).expect("edge ends are in keys") += 1;
+        }
+
+        let mut ready = in_degree
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(key, _)| *key)
+            .collect::<BTreeSet<_>>();
+        let mut ordered = Vec::with_capacity(keys.len());
+
+        while let Some(n) = ready.iter().next().copied() {
+            ready.remove(n);
+            ordered.push(n.clone());
+            for (_, to) in edges.iter().filter(|(from, _)| *from == n) {
+                let degree = in_degree.get_mut(to).expect("edge ends are in keys");
+                *degree -= 1;
+                if *degree == 0 {
+                    ready.insert(*to);
+                }
+            }
+        }
+
+        for key in &ordered {
+            keys.remove(key);
+        }
+        let mut cyclic = keys.into_iter().collect::<Vec<_>>();
+        cyclic.sort();
+        ordered.extend(cyclic);
+
+        ordered
+    }
+
     /// From a given root node, get all paths from the root node to leaf nodes
     /// where the leaf node must be a sink. This is useful for determining which
     /// components are relevant in a Vector unit test.
@@ -394,6 +600,7 @@ mod test {
                 self.edges.push(Edge {
                     from,
                     to: id.clone(),
+                    condition: None,
                 });
             }
         }
@@ -416,14 +623,14 @@ mod test {
                 self.edges.push(Edge {
                     from,
                     to: id.clone(),
+                    condition: None,
                 });
             }
         }
 
         fn test_add_input(&mut self, node: &str, input: &str) -> Result<(), String> {
-            let available_inputs = self.input_map().unwrap();
             let expansions = IndexMap::new();
-            self.add_input(input, &node.into(), &available_inputs, &expansions)
+            self.add_input(input, &node.into(), &expansions)
         }
     }
 
@@ -615,6 +822,57 @@ mod test {
         );
     }
 
+    #[test]
+    fn disallows_self_as_input() {
+        let mut graph = Graph::default();
+        graph.add_source("in", DataType::Log);
+        graph.add_transform("myself", DataType::Log, DataType::Log, vec!["in"]);
+        graph.add_transform_output("myself", "errors", DataType::Log);
+
+        let expected = "Component \"myself\" cannot take itself as input.".to_string();
+        assert_eq!(
+            Err(expected.clone()),
+            graph.test_add_input("myself", "myself")
+        );
+        assert_eq!(
+            Err(expected),
+            graph.test_add_input("myself", "myself.errors")
+        );
+    }
+
+    #[test]
+    fn records_output_conditions_on_edges() {
+        let mut graph = Graph::default();
+        graph.add_source("log_source", DataType::Log);
+        graph.add_transform("router", DataType::Log, DataType::Log, vec!["log_source"]);
+        match graph.nodes.get_mut(&ComponentKey::from("router")) {
+            Some(Node::Transform { outputs, .. }) => outputs.push(
+                Output::default(DataType::Log)
+                    .with_port("errors")
+                    .with_condition(".level == \"error\""),
+            ),
+            _ => panic!("invalid transform"),
+        }
+        graph.add_sink("default_sink", DataType::Log, vec![]);
+        graph.add_sink("errors_sink", DataType::Log, vec![]);
+
+        assert_eq!(Ok(()), graph.test_add_input("default_sink", "router"));
+        assert_eq!(Ok(()), graph.test_add_input("errors_sink", "router.errors"));
+
+        assert_eq!(
+            Some(".level == \"error\""),
+            graph.edge_condition(&"router.errors".into(), &"errors_sink".into())
+        );
+        assert_eq!(
+            None,
+            graph.edge_condition(&"router".into(), &"default_sink".into())
+        );
+        assert_eq!(
+            None,
+            graph.edge_condition(&"router.errors".into(), &"default_sink".into())
+        );
+    }
+
     #[test]
     fn disallows_ambiguous_inputs() {
         let mut graph = Graph::default();
@@ -671,6 +929,97 @@ mod test {
         );
     }
 
+    #[test]
+    fn diff_reports_changed_components_and_edges() {
+        let mut old = Graph::default();
+        old.add_source("in", DataType::Log);
+        old.add_source("removed", DataType::Log);
+        old.add_transform("parse", DataType::Log, DataType::Log, vec!["in"]);
+        old.add_sink("out", DataType::Log, vec!["parse"]);
+        old.add_sink("untouched", DataType::Log, vec!["in"]);
+
+        let mut new = Graph::default();
+        new.add_source("in", DataType::Log);
+        new.add_source("added", DataType::Log);
+        new.add_transform("parse", DataType::Log, DataType::Log, vec!["in", "added"]);
+        new.add_sink("out", DataType::Metric, vec!["parse"]);
+        new.add_sink("untouched", DataType::Log, vec!["in"]);
+
+        let diff = Graph::diff(&old, &new);
+
+        let keys = |keys: &[&str]| keys.iter().copied().map(ComponentKey::from).collect();
+        assert_eq!(diff.added, keys(&["added"]));
+        assert_eq!(diff.removed, keys(&["removed"]));
+        assert_eq!(diff.changed, keys(&["parse", "out"]));
+        assert_eq!(
+            diff.added_edges,
+            HashSet::from([("added".into(), "parse".into())])
+        );
+        assert!(diff.removed_edges.is_empty());
+
+        assert!(Graph::diff(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn downstream_and_upstream_are_transitive_and_ordered() {
+        let mut graph = Graph::default();
+        graph.add_source("in1", DataType::Log);
+        graph.add_source("in2", DataType::Log);
+        graph.add_source("unrelated", DataType::Log);
+        graph.add_transform("t1", DataType::Log, DataType::Log, vec!["in1"]);
+        graph.add_transform("t2", DataType::Log, DataType::Log, vec!["t1", "in2"]);
+        graph.add_transform_output("t2", "errors", DataType::Log);
+        graph.add_sink("out", DataType::Log, vec!["t2"]);
+        assert_eq!(Ok(()), graph.test_add_input("out", "t2.errors"));
+        graph.add_sink("out2", DataType::Log, vec!["t1"]);
+        graph.add_sink("out3", DataType::Log, vec!["unrelated"]);
+
+        let keys = |keys: &[&str]| -> Vec<ComponentKey> {
+            keys.iter().copied().map(ComponentKey::from).collect()
+        };
+
+        assert_eq!(
+            graph.downstream_of(&"in1".into()),
+            keys(&["t1", "out2", "t2", "out"])
+        );
+        assert_eq!(graph.downstream_of(&"in2".into()), keys(&["t2", "out"]));
+        assert_eq!(
+            graph.upstream_of(&"out".into()),
+            keys(&["in1", "in2", "t1", "t2"])
+        );
+        assert_eq!(graph.upstream_of(&"t1".into()), keys(&["in1"]));
+        assert!(graph.downstream_of(&"out".into()).is_empty());
+        assert!(graph.upstream_of(&"in1".into()).is_empty());
+        assert!(graph.downstream_of(&"missing".into()).is_empty());
+    }
+
+    #[test]
+    fn downstream_of_tolerates_cycles() {
+        let mut graph = Graph::default();
+        graph.add_source("in", DataType::Log);
+        graph.add_transform("one", DataType::Log, DataType::Log, vec!["in", "two"]);
+        graph.add_transform("two", DataType::Log, DataType::Log, vec!["one"]);
+        graph.add_sink("out", DataType::Log, vec!["two"]);
+        graph.add_transform("three", DataType::Log, DataType::Log, vec!["two"]);
+        graph.add_sink("out3", DataType::Log, vec!["three"]);
+
+        // Everything `in` feeds is on or downstream of the cycle, so nothing can be ordered
+        // topologically, and `out3` comes before `three` even though `three` feeds it.
+        let downstream: Vec<_> = graph
+            .downstream_of(&"in".into())
+            .into_iter()
+            .map(|key| key.to_string())
+            .collect();
+        assert_eq!(downstream, vec!["one", "out", "out3", "three", "two"]);
+
+        let downstream: Vec<_> = graph
+            .downstream_of(&"one".into())
+            .into_iter()
+            .map(|key| key.to_string())
+            .collect();
+        assert_eq!(downstream, vec!["two", "out", "three", "out3"]);
+    }
+
     #[test]
     fn paths_to_sink_simple() {
         let mut graph = Graph::default();
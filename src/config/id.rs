@@ -106,6 +106,35 @@ pub struct OutputId {
     pub port: Option<String>,
 }
 
+impl OutputId {
+    /// Parses an input specifier such as `component` or `component.port`.
+    ///
+    /// Component ids can themselves contain dots, for example the `route.branch` ids created by
+    /// route expansion, so the string can't be split on its own. The whole string is first tried
+    /// as a component id, and only if that doesn't name a known component is the segment after
+    /// the last `.` treated as a port of the component named by the rest. Returns `None` if
+    /// neither form names a known component.
+    ///
+    /// This is the inverse of the `Display` implementation for any id whose string form isn't
+    /// shared with another id, a case that `Graph::input_map` reports as ambiguous.
+    pub fn parse<F>(input: &str, is_component: F) -> Option<Self>
+    where
+        F: Fn(&ComponentKey) -> bool,
+    {
+        let component = ComponentKey::from(input);
+        if is_component(&component) {
+            return Some(component.into());
+        }
+
+        let (component, port) = input.rsplit_once('.')?;
+        let component = ComponentKey::from(component);
+        is_component(&component).then(|| Self {
+            component,
+            port: Some(port.to_string()),
+        })
+    }
+}
+
 impl fmt::Display for OutputId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.port {
@@ -152,3 +181,61 @@ impl From<&str> for OutputId {
         component.into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str, components: &[&str]) -> Option<OutputId> {
+        OutputId::parse(input, |key| components.contains(&key.id()))
+    }
+
+    #[test]
+    fn parse_plain_component() {
+        assert_eq!(
+            parse("in", &["in"]),
+            Some(OutputId::from(ComponentKey::from("in")))
+        );
+        assert_eq!(parse("missing", &["in"]), None);
+    }
+
+    #[test]
+    fn parse_component_port() {
+        let id = OutputId::from((&ComponentKey::from("remap"), "dropped".to_string()));
+        assert_eq!(parse("remap.dropped", &["remap"]), Some(id));
+        assert_eq!(parse("remap.dropped", &["other"]), None);
+    }
+
+    #[test]
+    fn parse_route_expanded_ids() {
+        let components = ["route", "route.errors", "route._unmatched"];
+
+        // The expanded component is preferred over a port of the route it was expanded from.
+        assert_eq!(
+            parse("route.errors", &components),
+            Some(OutputId::from(ComponentKey::from("route.errors")))
+        );
+
+        // Ports of expanded components are split at the last dot.
+        let id = OutputId::from((&ComponentKey::from("route.errors"), "dropped".to_string()));
+        assert_eq!(parse("route.errors.dropped", &components), Some(id));
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let components = ["in", "my.route.branch", "remap"];
+        let ids = vec![
+            OutputId::from(ComponentKey::from("in")),
+            OutputId::from(ComponentKey::from("my.route.branch")),
+            OutputId::from((&ComponentKey::from("remap"), "dropped".to_string())),
+            OutputId::from((
+                &ComponentKey::from("my.route.branch"),
+                "dropped".to_string(),
+            )),
+        ];
+
+        for id in ids {
+            assert_eq!(parse(&id.to_string(), &components), Some(id));
+        }
+    }
+}
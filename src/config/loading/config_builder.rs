@@ -3,11 +3,14 @@ use std::{collections::HashMap, io::Read};
 use indexmap::IndexMap;
 use toml::value::Table;
 
-use super::{deserialize_table, loader, prepare_input, secret};
+use super::{
+    check_missing_env_vars, deserialize_table, loader, prepare_config_input, secret,
+    with_missing_env_vars,
+};
 use super::{ComponentHint, Process};
 use crate::config::{
-    ComponentKey, ConfigBuilder, EnrichmentTableOuter, SinkOuter, SourceOuter, TestDefinition,
-    TransformOuter,
+    format, ComponentKey, ConfigBuilder, EnrichmentTableOuter, Format, SinkOuter, SourceOuter,
+    TestDefinition, TransformOuter,
 };
 
 pub struct ConfigBuilderLoader {
@@ -32,19 +35,41 @@ impl ConfigBuilderLoader {
 }
 
 impl Process for ConfigBuilderLoader {
-    /// Prepares input for a `ConfigBuilder` by interpolating environment variables.
+    /// Prepares input for a `ConfigBuilder` by interpolating environment variables. Unset
+    /// variables are reported by `merge`, once it's known where they're used.
     fn prepare<R: Read>(&mut self, input: R) -> Result<(String, Vec<String>), Vec<String>> {
-        let (prepared_input, warnings) = prepare_input(input)?;
+        let prepared_input = prepare_config_input(input)?;
         let prepared_input = self
             .secrets
             .as_ref()
             .map(|s| secret::interpolate(&prepared_input, s))
             .unwrap_or(Ok(prepared_input))?;
-        Ok((prepared_input, warnings))
+        Ok((prepared_input, vec![]))
+    }
+
+    /// Deserializes prepared input, blaming unset environment variables if that fails.
+    fn load<R: Read, T>(
+        &mut self,
+        input: R,
+        format: Format,
+    ) -> Result<(T, Vec<String>), Vec<String>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let (value, warnings) = self.prepare(input)?;
+
+        format::deserialize(&value, format)
+            .map(|builder| (builder, warnings))
+            .map_err(|errors| with_missing_env_vars(&value, errors))
     }
 
     /// Merge a TOML `Table` with a `ConfigBuilder`. Component types extend specific keys.
     fn merge(&mut self, table: Table, hint: Option<ComponentHint>) -> Result<(), Vec<String>> {
+        check_missing_env_vars(
+            &table,
+            hint.as_ref().map_or("", ComponentHint::as_component_field),
+        )?;
+
         match hint {
             Some(ComponentHint::Source) => {
                 self.builder.sources.extend(deserialize_table::<
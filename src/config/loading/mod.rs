@@ -20,6 +20,7 @@ use loader::process::Process;
 pub use loader::*;
 pub use secret::*;
 pub use source::*;
+use toml::value::{Table, Value};
 use vector_config::NamedComponent;
 
 use super::{
@@ -242,7 +243,7 @@ pub fn load_builder_from_paths_with_secrets(
 /// Uses `SourceLoader` to process `ConfigPaths`, deserializing to a toml `SourceMap`.
 pub fn load_source_from_paths(
     config_paths: &[ConfigPath],
-) -> Result<(toml::value::Table, Vec<String>), Vec<String>> {
+) -> Result<(Table, Vec<String>), Vec<String>> {
     loader_from_paths(SourceLoader::new(), config_paths)
 }
 
@@ -288,7 +289,10 @@ fn load_from_inputs(
     }
 }
 
-pub fn prepare_input<R: std::io::Read>(mut input: R) -> Result<(String, Vec<String>), Vec<String>> {
+/// Reads `input`, returning it along with the environment variables to interpolate into it.
+fn read_input<R: std::io::Read>(
+    mut input: R,
+) -> Result<(String, HashMap<String, String>), Vec<String>> {
     let mut source_string = String::new();
     input
         .read_to_string(&mut source_string)
@@ -300,16 +304,99 @@ pub fn prepare_input<R: std::io::Read>(mut input: R) -> Result<(String, Vec<Stri
             vars.insert("HOSTNAME".into(), hostname);
         }
     }
+    Ok((source_string, vars))
+}
+
+pub fn prepare_input<R: std::io::Read>(input: R) -> Result<(String, Vec<String>), Vec<String>> {
+    let (source_string, vars) = read_input(input)?;
     vars::interpolate(&source_string, &vars)
 }
 
+/// Interpolates environment variables into a config. Unlike `prepare_input`, references to unset
+/// variables are marked rather than dropped, so that `check_missing_env_vars` can report where
+/// each of them is used once the config is parsed.
+pub fn prepare_config_input<R: std::io::Read>(input: R) -> Result<String, Vec<String>> {
+    let (source_string, vars) = read_input(input)?;
+    vars::interpolate_marking_missing(&source_string, &vars)
+}
+
+/// Check that no unset environment variables are used in a config prepared by
+/// `prepare_config_input`, listing the field each of them is used in. `path` is the location of
+/// `table` within the config.
+fn check_missing_env_vars(table: &Table, path: &str) -> Result<(), Vec<String>> {
+    fn walk(value: &Value, path: &str, errors: &mut Vec<String>) {
+        match value {
+            Value::String(s) => errors.extend(vars::missing(s).map(|name| {
+                format!(
+                    "Environment variable \"{}\" used in \"{}\" is not set.",
+                    name, path
+                )
+            })),
+            Value::Array(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    walk(value, &format!("{}[{}]", path, i), errors);
+                }
+            }
+            Value::Table(table) => walk_table(table, path, errors),
+            _ => {}
+        }
+    }
+
+    fn walk_table(table: &Table, path: &str, errors: &mut Vec<String>) {
+        for (key, value) in table {
+            errors.extend(vars::missing(key).map(|name| {
+                format!(
+                    "Environment variable \"{}\" used in a key of \"{}\" is not set.",
+                    name, path
+                )
+            }));
+
+            let path = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+            walk(value, &path, errors);
+        }
+    }
+
+    let mut errors = Vec::new();
+    walk_table(table, path, &mut errors);
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Adds the unset environment variables used in a config prepared by `prepare_config_input` to
+/// the errors from parsing it, as they are the likely cause, but can't be located any further.
+fn with_missing_env_vars(input: &str, errors: Vec<String>) -> Vec<String> {
+    let mut names = vars::missing(input).collect::<Vec<_>>();
+    names.sort_unstable();
+    names.dedup();
+
+    names
+        .into_iter()
+        .map(|name| format!("Environment variable \"{}\" is not set.", name))
+        .chain(errors)
+        .collect()
+}
+
 pub fn load<R: std::io::Read, T>(input: R, format: Format) -> Result<(T, Vec<String>), Vec<String>>
 where
     T: serde::de::DeserializeOwned,
 {
-    let (with_vars, warnings) = prepare_input(input)?;
+    let with_vars = prepare_config_input(input)?;
+
+    if vars::missing(&with_vars).next().is_some() {
+        let table = format::deserialize::<Table>(&with_vars, format)
+            .map_err(|errors| with_missing_env_vars(&with_vars, errors))?;
+        check_missing_env_vars(&table, "")?;
+    }
 
-    format::deserialize(&with_vars, format).map(|builder| (builder, warnings))
+    format::deserialize(&with_vars, format).map(|builder| (builder, Vec::new()))
 }
 
 #[cfg(not(windows))]
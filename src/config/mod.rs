@@ -6,6 +6,7 @@ use std::{
     path::PathBuf,
 };
 
+use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 pub use vector_config::component::{GenerateConfig, SinkDescription, TransformDescription};
 use vector_config::configurable_component;
@@ -41,9 +42,11 @@ pub mod watcher;
 
 pub use builder::ConfigBuilder;
 pub use cmd::{cmd, Opts};
+pub use compiler::{compile_strict, compile_validate};
 pub use diff::ConfigDiff;
 pub use enrichment_table::{EnrichmentTableConfig, EnrichmentTableOuter};
 pub use format::{Format, FormatHint};
+pub use graph::{Graph, GraphDiff};
 pub use id::{ComponentKey, Inputs, OutputId};
 pub use loading::{
     load, load_builder_from_paths, load_from_paths, load_from_paths_with_provider_and_secrets,
@@ -115,6 +118,8 @@ pub struct Config {
     tests: Vec<TestDefinition>,
     expansions: IndexMap<ComponentKey, Vec<ComponentKey>>,
     secret: IndexMap<ComponentKey, SecretBackends>,
+    /// A fixed time for VRL's `now()` function to return, set when running unit tests.
+    pub mock_time: Option<DateTime<Utc>>,
 }
 
 impl Config {
@@ -187,14 +192,10 @@ impl Config {
         for (sink, input) in sink_inputs {
             let component = &input.component;
             if let Some(source) = self.sources.get_mut(component) {
+                // Sources that can't acknowledge events are reported by the config validation
+                // warnings instead.
                 if source.inner.can_acknowledge() {
                     source.sink_acknowledgements = true;
-                } else {
-                    warn!(
-                        message = "Source has acknowledgements enabled by a sink, but acknowledgements are not supported by this source. Silent data loss could occur.",
-                        source = component.id(),
-                        sink = sink.id(),
-                    );
                 }
             } else if let Some(transform) = self.transforms.get(component) {
                 let inputs = transform
@@ -367,6 +368,12 @@ pub struct TestDefinition<T = OutputId> {
     /// A set of component outputs that should not have emitted any events.
     #[serde(default)]
     pub no_outputs_from: Vec<T>,
+
+    /// A fixed time for the VRL `now()` function to return while running this test.
+    ///
+    /// This makes the outputs of programs that add the current time to events predictable. It
+    /// only affects `now()`.
+    pub now: Option<DateTime<Utc>>,
 }
 
 impl TestDefinition<String> {
@@ -381,11 +388,10 @@ impl TestDefinition<String> {
             inputs,
             outputs,
             no_outputs_from,
+            now,
         } = self;
         let mut errors = Vec::new();
 
-        let output_map = graph.input_map().expect("ambiguous outputs");
-
         let outputs = outputs
             .into_iter()
             .map(|old| {
@@ -411,8 +417,8 @@ impl TestDefinition<String> {
             .filter_map(|(extract_from, conditions)| {
                 let mut outputs = Vec::new();
                 for from in extract_from {
-                    if let Some(output_id) = output_map.get(&from) {
-                        outputs.push(output_id.clone());
+                    if let Some(output_id) = graph.resolve_input(&from) {
+                        outputs.push(output_id);
                     } else {
                         errors.push(format!(
                             r#"Invalid extract_from target in test '{}': '{}' does not exist"#,
@@ -434,8 +440,8 @@ impl TestDefinition<String> {
         let no_outputs_from = no_outputs_from
             .into_iter()
             .filter_map(|o| {
-                if let Some(output_id) = output_map.get(&o) {
-                    Some(output_id.clone())
+                if let Some(output_id) = graph.resolve_input(&o) {
+                    Some(output_id)
                 } else {
                     errors.push(format!(
                         r#"Invalid no_outputs_from target in test '{}': '{}' does not exist"#,
@@ -453,6 +459,7 @@ impl TestDefinition<String> {
                 inputs,
                 outputs,
                 no_outputs_from,
+                now,
             })
         } else {
             Err(errors)
@@ -468,6 +475,7 @@ impl TestDefinition<OutputId> {
             inputs,
             outputs,
             no_outputs_from,
+            now,
         } = self;
 
         let outputs = outputs
@@ -492,6 +500,7 @@ impl TestDefinition<OutputId> {
             inputs,
             outputs,
             no_outputs_from,
+            now,
         }
     }
 }
@@ -663,6 +672,59 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn unset_env_var() {
+        let err = load(
+            r#"
+            [sources.in]
+            type = "test_basic"
+
+            [transforms.sample]
+            type = "test_basic"
+            inputs = ["in"]
+            suffix = "${VECTOR_TEST_UNSET_ENV_VAR}"
+            increase = 1.25
+
+            [sinks.out]
+            type = "test_basic"
+            inputs = ["sample"]
+            "#,
+            Format::Toml,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            vec![
+                "Environment variable \"VECTOR_TEST_UNSET_ENV_VAR\" used in \"transforms.sample.suffix\" is not set."
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn escaped_env_var() {
+        load(
+            r#"
+            [sources.in]
+            type = "test_basic"
+
+            [transforms.sample]
+            type = "test_basic"
+            inputs = ["in"]
+            suffix = "$${VECTOR_TEST_UNSET_ENV_VAR}"
+            increase = 1.25
+
+            [sinks.out]
+            type = "test_basic"
+            inputs = ["sample"]
+            "#,
+            Format::Toml,
+        )
+        .await
+        .unwrap();
+    }
+
     #[tokio::test]
     #[cfg(unix)]
     async fn conflicting_stdin_and_fd_resources() {
@@ -779,6 +841,175 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn warnings_high_fan_in_sink() {
+        let sources = (0..10)
+            .map(|i| format!("[sources.in{}]\ntype = \"test_basic\"\n", i))
+            .collect::<String>();
+        let inputs = (0..10)
+            .map(|i| format!("\"in{}\"", i))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let warnings = load(
+            &format!(
+                "{}\n[sinks.out]\ntype = \"test_basic\"\ninputs = [{}]\n",
+                sources, inputs
+            ),
+            Format::Toml,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            warnings,
+            vec![
+                "Sink \"out\" has 10 inputs and only in-memory buffers, consider using a disk buffer to avoid memory pressure",
+            ]
+        )
+    }
+
+    #[tokio::test]
+    async fn warnings_high_fan_out() {
+        let sinks = (0..6)
+            .map(|i| {
+                format!(
+                    "[sinks.out{}]\ntype = \"test_basic\"\ninputs = [\"*\"]\n",
+                    i
+                )
+            })
+            .collect::<String>();
+
+        let warnings = load(
+            &format!("[sources.in]\ntype = \"test_basic\"\n{}", sinks),
+            Format::Toml,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            warnings,
+            vec![
+                "Source \"in\" feeds 6 sinks, check that this fan-out is intended or raise `fan_out_warning_threshold`",
+            ]
+        );
+
+        let warnings = load(
+            &format!(
+                "fan_out_warning_threshold = 6\n[sources.in]\ntype = \"test_basic\"\n{}",
+                sinks
+            ),
+            Format::Toml,
+        )
+        .await
+        .unwrap();
+
+        assert!(warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn warnings_mixed_log_namespaces() {
+        let warnings = load(
+            r#"
+            [sources.legacy]
+            type = "test_basic"
+            log_namespace = false
+
+            [sources.vector]
+            type = "test_basic"
+            log_namespace = true
+
+            [transforms.mixed]
+            type = "test_basic"
+            inputs = ["legacy", "vector"]
+            suffix = "foo"
+            increase = 1.25
+
+            [sinks.out]
+            type = "test_basic"
+            inputs = ["mixed"]
+            "#,
+            Format::Toml,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            warnings,
+            vec![
+                "Transform \"mixed\" receives events in the legacy log namespace from \"legacy\" and in the Vector log namespace from \"vector\", fields may be missing depending on which input an event came from",
+            ]
+        )
+    }
+
+    #[tokio::test]
+    async fn warnings_unacknowledged_source() {
+        let warnings = load(
+            r#"
+            acknowledgements.enabled = true
+
+            [sources.in]
+            type = "test_basic"
+
+            [transforms.sample]
+            type = "test_basic"
+            inputs = ["in"]
+            suffix = "foo"
+            increase = 1.25
+
+            [sinks.out]
+            type = "test_basic"
+            inputs = ["sample"]
+            "#,
+            Format::Toml,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            warnings,
+            vec![
+                "Sink \"out\" has acknowledgements enabled, but source \"in\" feeding it can't acknowledge events, so they could be lost silently",
+            ]
+        )
+    }
+
+    #[cfg(feature = "transforms-remap")]
+    #[tokio::test]
+    async fn warnings_unreachable_output() {
+        let warnings = load(
+            r#"
+            [sources.in]
+            type = "test_basic"
+
+            [transforms.remap]
+            type = "remap"
+            inputs = ["in"]
+            source = ".foo = 1"
+            drop_on_error = true
+            reroute_dropped = true
+
+            [sinks.out]
+            type = "test_basic"
+            inputs = ["remap"]
+
+            [sinks.dropped]
+            type = "test_basic"
+            inputs = ["remap.dropped"]
+            "#,
+            Format::Toml,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            warnings,
+            vec![
+                "Output \"dropped\" of transform \"remap\" never emits events, so consumers \"dropped\" won't receive any from it",
+            ]
+        )
+    }
+
     #[tokio::test]
     async fn cycle() {
         let errors = load(
@@ -1476,7 +1707,7 @@ mod resource_tests {
 mod pipelines_tests {
     use indoc::indoc;
 
-    use super::{load_from_str, Format};
+    use super::{load_from_str, ComponentKey, Format, OutputId};
 
     #[test]
     fn forbid_pipeline_nesting() {
@@ -1511,4 +1742,61 @@ mod pipelines_tests {
         );
         assert!(res.is_err(), "should error");
     }
+
+    #[test]
+    fn inputs_to_expanded_components_containing_dots() {
+        let config = load_from_str(
+            indoc! {r#"
+                [sources.in]
+                  type = "stdin"
+
+                [transforms.processing]
+                  inputs = ["in"]
+                  type = "pipelines"
+
+                  [[transforms.processing.logs]]
+                    name = "foo"
+
+                    [[transforms.processing.logs.transforms]]
+                      type = "filter"
+                      condition = ""
+
+                [sinks.out]
+                  type = "console"
+                  inputs = ["processing"]
+                  encoding.codec = "json"
+
+                [sinks.filtered]
+                  type = "console"
+                  inputs = ["processing.logs.0"]
+                  encoding.codec = "json"
+
+                [sinks.unmatched]
+                  type = "console"
+                  inputs = ["processing.type_router._unmatched"]
+                  encoding.codec = "json"
+            "#},
+            Format::Toml,
+        )
+        .unwrap();
+
+        // The ids of expanded components contain dots, which mustn't be mistaken for the
+        // separator between a component and one of its ports.
+        let filtered = OutputId::from(ComponentKey::from("processing.logs.0"));
+        let unmatched = OutputId {
+            component: ComponentKey::from("processing.type_router"),
+            port: Some("_unmatched".to_string()),
+        };
+        let inputs = |sink: &str| {
+            config
+                .sink(&ComponentKey::from(sink))
+                .unwrap()
+                .inputs
+                .to_vec()
+        };
+
+        assert_eq!(inputs("filtered"), vec![filtered.clone()]);
+        assert_eq!(inputs("unmatched"), vec![unmatched.clone()]);
+        assert_eq!(inputs("out"), vec![unmatched, filtered]);
+    }
 }
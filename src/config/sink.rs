@@ -22,6 +22,16 @@ where
     #[configurable(derived)]
     pub inputs: Inputs<T>,
 
+    /// Whether or not the component is enabled.
+    ///
+    /// Disabled components are removed from the configuration when it's loaded, as if they weren't
+    /// defined at all.
+    #[serde(
+        default = "crate::serde::default_true",
+        skip_serializing_if = "crate::serde::is_true"
+    )]
+    pub enabled: bool,
+
     /// The full URI to make HTTP healthcheck requests to.
     ///
     /// This must be a valid URI, which requires at least the scheme and host. All other
@@ -65,6 +75,7 @@ where
     {
         SinkOuter {
             inputs: Inputs::from_iter(inputs),
+            enabled: true,
             buffer: Default::default(),
             healthcheck: SinkHealthcheckOptions::default(),
             healthcheck_uri: None,
@@ -123,6 +134,7 @@ where
     {
         SinkOuter {
             inputs: Inputs::from_iter(inputs),
+            enabled: self.enabled,
             inner: self.inner,
             buffer: self.buffer,
             healthcheck: self.healthcheck,
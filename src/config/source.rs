@@ -18,6 +18,16 @@ use crate::{shutdown::ShutdownSignal, sources::Sources, SourceSender};
 #[configurable(metadata(docs::component_base_type = "source"))]
 #[derive(Clone, Debug)]
 pub struct SourceOuter {
+    /// Whether or not the component is enabled.
+    ///
+    /// Disabled components are removed from the configuration when it's loaded, as if they weren't
+    /// defined at all.
+    #[serde(
+        default = "crate::serde::default_true",
+        skip_serializing_if = "crate::serde::is_true"
+    )]
+    pub enabled: bool,
+
     #[configurable(derived)]
     #[serde(
         default,
@@ -36,6 +46,7 @@ pub struct SourceOuter {
 impl SourceOuter {
     pub(crate) fn new<I: Into<Sources>>(inner: I) -> Self {
         Self {
+            enabled: true,
             proxy: Default::default(),
             sink_acknowledgements: false,
             inner: inner.into(),
@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use enum_dispatch::enum_dispatch;
 use indexmap::IndexMap;
 use serde::Serialize;
@@ -26,6 +27,16 @@ where
     #[configurable(derived)]
     pub inputs: Inputs<T>,
 
+    /// Whether or not the component is enabled.
+    ///
+    /// Disabled components are removed from the configuration when it's loaded, as if they weren't
+    /// defined at all.
+    #[serde(
+        default = "crate::serde::default_true",
+        skip_serializing_if = "crate::serde::is_true"
+    )]
+    pub enabled: bool,
+
     #[configurable(metadata(docs::hidden))]
     #[serde(flatten)]
     pub inner: Transforms,
@@ -42,6 +53,7 @@ where
     {
         TransformOuter {
             inputs: Inputs::from_iter(inputs),
+            enabled: true,
             inner: inner.into(),
         }
     }
@@ -61,6 +73,7 @@ where
     {
         TransformOuter {
             inputs: Inputs::from_iter(inputs),
+            enabled: self.enabled,
             inner: self.inner,
         }
     }
@@ -105,6 +118,7 @@ impl TransformOuter<String> {
             for (inner_name, inner_transform) in inner_topology.inner {
                 let child = TransformOuter {
                     inputs: inner_transform.inputs,
+                    enabled: true,
                     inner: inner_transform.inner,
                 };
                 children.push(inner_name.clone());
@@ -140,6 +154,12 @@ pub struct TransformContext {
     /// information, such as the `remap` transform, which passes this information along to the VRL
     /// compiler such that type coercion becomes less of a need for operators writing VRL programs.
     pub merged_schema_definition: schema::Definition,
+
+    /// A fixed time for VRL's `now()` function to return instead of the current time.
+    ///
+    /// This is only set when running unit tests, so that their expected outputs can include
+    /// timestamps.
+    pub mock_time: Option<DateTime<Utc>>,
 }
 
 impl Default for TransformContext {
@@ -150,6 +170,7 @@ impl Default for TransformContext {
             enrichment_tables: Default::default(),
             schema_definitions: HashMap::from([(None, schema::Definition::any())]),
             merged_schema_definition: schema::Definition::any(),
+            mock_time: None,
         }
     }
 }
@@ -439,7 +439,8 @@ async fn build_unit_test(
             .sinks
             .insert(ComponentKey::from(Uuid::new_v4().to_string()), sink);
     }
-    let config = config_builder.build()?;
+    let mut config = config_builder.build()?;
+    config.mock_time = test.now;
     let diff = config::ConfigDiff::initial(&config);
     let pieces = builder::build_pieces(&config, &diff, HashMap::new()).await?;
 
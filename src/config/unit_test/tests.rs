@@ -713,6 +713,57 @@ async fn test_no_outputs_from() {
     assert!(!tests.remove(0).run().await.errors.is_empty());
 }
 
+#[tokio::test]
+async fn test_mock_time() {
+    let config: ConfigBuilder = toml::from_str(indoc! {r#"
+          [transforms.foo]
+            inputs = [ "ignored" ]
+            type = "remap"
+            source = """
+              .processed_at = now()
+            """
+
+          [[tests]]
+            name = "check_mock_time_succeeds"
+            now = "2022-01-01T00:00:00Z"
+
+            [tests.input]
+              insert_at = "foo"
+              type = "raw"
+              value = "foo"
+
+            [[tests.outputs]]
+              extract_from = "foo"
+              [[tests.outputs.conditions]]
+                type = "vrl"
+                source = """
+                  assert_eq!(.processed_at, t'2022-01-01T00:00:00Z')
+                """
+
+          [[tests]]
+            name = "check_mock_time_fails"
+            now = "2022-01-02T00:00:00Z"
+
+            [tests.input]
+              insert_at = "foo"
+              type = "raw"
+              value = "foo"
+
+            [[tests.outputs]]
+              extract_from = "foo"
+              [[tests.outputs.conditions]]
+                type = "vrl"
+                source = """
+                  assert_eq!(.processed_at, t'2022-01-01T00:00:00Z')
+                """
+      "#})
+    .unwrap();
+
+    let mut tests = build_unit_tests(config).await.unwrap();
+    assert!(tests.remove(0).run().await.errors.is_empty());
+    assert!(!tests.remove(0).run().await.errors.is_empty());
+}
+
 #[tokio::test]
 async fn test_no_outputs_from_chained() {
     let config: ConfigBuilder = toml::from_str(indoc! { r#"
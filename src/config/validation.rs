@@ -1,15 +1,29 @@
 use crate::config::schema;
-use crate::topology::schema::merged_definition;
+use crate::topology::schema::{merged_definition, validate_sink_expectations};
 use futures_util::{stream, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use heim::{disk::Partition, units::information::byte};
 use indexmap::IndexMap;
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::PathBuf,
+};
+use vector_buffers::BufferType;
+use vector_core::config::{DataType, LogNamespace};
 use vector_core::internal_event::DEFAULT_OUTPUT;
 
 use super::{
-    builder::ConfigBuilder, ComponentKey, Config, OutputId, Resource, SourceConfig, TransformConfig,
+    builder::ConfigBuilder, ComponentKey, Config, OutputId, Resource, SourceConfig, SourceOuter,
+    TransformConfig,
 };
 
+/// The number of inputs at which a sink with only in-memory buffers is considered to have a high
+/// enough fan-in to warrant a warning about memory pressure.
+const HIGH_FAN_IN_SINK_INPUTS: usize = 10;
+
+/// The number of sinks a single output can feed before it's considered to have a high enough
+/// fan-out to warrant a warning, unless `fan_out_warning_threshold` is set.
+const DEFAULT_FAN_OUT_WARNING_THRESHOLD: usize = 5;
+
 /// Check that provide + topology config aren't present in the same builder, which is an error.
 pub fn check_provider(config: &ConfigBuilder) -> Result<(), Vec<String>> {
     if config.provider.is_some()
@@ -41,17 +55,13 @@ pub fn check_names<'a, I: Iterator<Item = &'a ComponentKey>>(names: I) -> Result
     }
 }
 
-pub fn check_shape(config: &ConfigBuilder) -> Result<(), Vec<String>> {
-    let mut errors = vec![];
-
-    if config.sources.is_empty() {
-        errors.push("No sources defined in the config.".to_owned());
-    }
-
-    if config.sinks.is_empty() {
-        errors.push("No sinks defined in the config.".to_owned());
-    }
-
+/// Checks that no ID is shared between sources, transforms, and sinks.
+///
+/// Each kind of component is kept in its own map, so a shared ID isn't caught by the maps
+/// themselves, and it would make inputs referring to it ambiguous. This needs to run before
+/// disabled components are removed, and before transforms are expanded, as both can hide a
+/// shared ID while leaving behind inputs that still refer to it.
+pub fn check_unique_ids(config: &ConfigBuilder) -> Result<(), Vec<String>> {
     // Helper for below
     fn tagged<'a>(
         tag: &'static str,
@@ -60,8 +70,7 @@ pub fn check_shape(config: &ConfigBuilder) -> Result<(), Vec<String>> {
         iter.map(move |x| (tag, x))
     }
 
-    // Check for non-unique names across sources, sinks, and transforms
-    let mut used_keys = HashMap::<&ComponentKey, Vec<&'static str>>::new();
+    let mut used_keys = IndexMap::<&ComponentKey, Vec<&'static str>>::new();
     for (ctype, id) in tagged("source", config.sources.keys())
         .chain(tagged("transform", config.transforms.keys()))
         .chain(tagged("sink", config.sinks.keys()))
@@ -70,12 +79,34 @@ pub fn check_shape(config: &ConfigBuilder) -> Result<(), Vec<String>> {
         uses.push(ctype);
     }
 
-    for (id, uses) in used_keys.into_iter().filter(|(_id, uses)| uses.len() > 1) {
-        errors.push(format!(
-            "More than one component with name \"{}\" ({}).",
-            id,
-            uses.join(", ")
-        ));
+    let errors: Vec<_> = used_keys
+        .into_iter()
+        .filter(|(_id, uses)| uses.len() > 1)
+        .map(|(id, uses)| {
+            format!(
+                "More than one component with name \"{}\" ({}).",
+                id,
+                uses.join(", ")
+            )
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+pub fn check_shape(config: &ConfigBuilder) -> Result<(), Vec<String>> {
+    let mut errors = vec![];
+
+    if config.sources.is_empty() {
+        errors.push("No sources defined in the config.".to_owned());
+    }
+
+    if config.sinks.is_empty() {
+        errors.push("No sinks defined in the config.".to_owned());
     }
 
     // Warnings and errors
@@ -323,6 +354,24 @@ async fn process_partitions(partitions: Vec<Partition>) -> heim::Result<IndexMap
         .await
 }
 
+/// Checks that the schema requirement of every sink is satisfied by the schemas of its inputs.
+///
+/// This needs the resolved inputs and output definitions of the complete topology, so unlike the
+/// other checks it runs against the compiled `Config`.
+pub fn check_sink_schemas(config: &Config) -> Result<(), Vec<String>> {
+    let errors = config
+        .sinks()
+        .filter_map(|(key, sink)| validate_sink_expectations(key, sink, config).err())
+        .flatten()
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 pub fn warnings(config: &Config) -> Vec<String> {
     let mut warnings = vec![];
     let mut cache = HashMap::new();
@@ -341,22 +390,48 @@ pub fn warnings(config: &Config) -> Vec<String> {
             })
             .collect::<Vec<_>>()
     });
+    let mut unreachable_ids = Vec::new();
     let transform_ids = config.transforms.iter().flat_map(|(key, transform)| {
         transform
             .inner
             .outputs(&merged_definition(&transform.inputs, config, &mut cache))
             .iter()
             .map(|output| {
-                if let Some(port) = &output.port {
-                    ("transform", OutputId::from((key, port.clone())))
+                let id = if let Some(port) = &output.port {
+                    OutputId::from((key, port.clone()))
                 } else {
-                    ("transform", OutputId::from(key))
+                    OutputId::from(key)
+                };
+                if output.unreachable {
+                    unreachable_ids.push(id.clone());
                 }
+                ("transform", id)
             })
             .collect::<Vec<_>>()
     });
 
+    let fan_out_threshold = config
+        .global
+        .fan_out_warning_threshold
+        .unwrap_or(DEFAULT_FAN_OUT_WARNING_THRESHOLD);
+
     for (input_type, id) in transform_ids.chain(source_ids) {
+        // Sink inputs have already been resolved against the graph at this point, so sinks that
+        // only match the output through a glob are counted as well.
+        let fan_out = config
+            .sinks
+            .iter()
+            .filter(|(_, sink)| sink.inputs.contains(&id))
+            .count();
+        if fan_out > fan_out_threshold {
+            warnings.push(format!(
+                "{} \"{}\" feeds {} sinks, check that this fan-out is intended or raise `fan_out_warning_threshold`",
+                capitalize(input_type),
+                id,
+                fan_out
+            ));
+        }
+
         if !config
             .transforms
             .iter()
@@ -374,9 +449,207 @@ pub fn warnings(config: &Config) -> Vec<String> {
         }
     }
 
+    // Outputs that are never emitted are only worth reporting when something consumes them, unused
+    // ones are already reported above.
+    for id in unreachable_ids {
+        let consumers = config
+            .transforms
+            .iter()
+            .filter(|(_, transform)| transform.inputs.contains(&id))
+            .map(|(key, _)| key)
+            .chain(
+                config
+                    .sinks
+                    .iter()
+                    .filter(|(_, sink)| sink.inputs.contains(&id))
+                    .map(|(key, _)| key),
+            )
+            .map(|key| format!("\"{}\"", key))
+            .collect::<Vec<_>>();
+
+        if !consumers.is_empty() {
+            let output = match &id.port {
+                Some(port) => format!("Output \"{}\" of transform \"{}\"", port, id.component),
+                None => format!("Transform \"{}\"", id.component),
+            };
+            warnings.push(format!(
+                "{} never emits events, so consumers {} won't receive any from it",
+                output,
+                consumers.join(", ")
+            ));
+        }
+    }
+
+    // Sink inputs have already been resolved against the graph at this point, so expanded globs
+    // and macros are counted here.
+    for (key, sink) in &config.sinks {
+        let in_degree = sink.inputs.len();
+        let memory_only = sink
+            .buffer
+            .stages()
+            .iter()
+            .all(|stage| matches!(stage, BufferType::Memory { .. }));
+
+        if in_degree >= HIGH_FAN_IN_SINK_INPUTS && memory_only {
+            warnings.push(format!(
+                "Sink \"{}\" has {} inputs and only in-memory buffers, consider using a disk buffer to avoid memory pressure",
+                key, in_degree
+            ));
+        }
+    }
+
+    // Only the component where inputs from different log namespaces first meet is reported, as
+    // everything downstream of it receives the mixed events too.
+    let mut namespaces = HashMap::new();
+    let consumers = config
+        .transforms
+        .iter()
+        .map(|(key, transform)| ("transform", key, &transform.inputs))
+        .chain(
+            config
+                .sinks
+                .iter()
+                .map(|(key, sink)| ("sink", key, &sink.inputs)),
+        );
+    for (component_type, key, inputs) in consumers {
+        let inputs = inputs
+            .iter()
+            .map(|input| {
+                (
+                    input,
+                    upstream_log_namespaces(config, input, &mut namespaces),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mixed = inputs
+            .iter()
+            .flat_map(|(_, namespaces)| namespaces.iter())
+            .collect::<BTreeSet<_>>()
+            .len()
+            > 1;
+        if mixed && inputs.iter().all(|(_, namespaces)| namespaces.len() < 2) {
+            let inputs_in = |namespace: LogNamespace| {
+                inputs
+                    .iter()
+                    .filter(|(_, namespaces)| namespaces.contains(&namespace))
+                    .map(|(input, _)| format!("\"{}\"", input))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            warnings.push(format!(
+                "{} \"{}\" receives events in the legacy log namespace from {} and in the Vector log namespace from {}, fields may be missing depending on which input an event came from",
+                capitalize(component_type),
+                key,
+                inputs_in(LogNamespace::Legacy),
+                inputs_in(LogNamespace::Vector),
+            ));
+        }
+    }
+
+    // Sources that can't acknowledge events don't wait for the sinks to deliver them, so a sink
+    // requiring acknowledgements only gets at-most-once delivery for the events from them.
+    let mut unacknowledged = HashMap::new();
+    for (key, sink) in &config.sinks {
+        if !sink
+            .inner
+            .acknowledgements()
+            .merge_default(&config.global.acknowledgements)
+            .enabled()
+        {
+            continue;
+        }
+
+        let sources = sink
+            .inputs
+            .iter()
+            .flat_map(|input| upstream_unacknowledged_sources(config, input, &mut unacknowledged))
+            .collect::<BTreeSet<_>>();
+        for source in sources {
+            warnings.push(format!(
+                "Sink \"{}\" has acknowledgements enabled, but source \"{}\" feeding it can't acknowledge events, so they could be lost silently",
+                key, source
+            ));
+        }
+    }
+
     warnings
 }
 
+/// Collects what `from_source` finds for each source output that `id` receives events from,
+/// directly or through transforms. Results are cached per output in `cache`.
+fn upstream_of_sources<T, F>(
+    config: &Config,
+    id: &OutputId,
+    cache: &mut HashMap<OutputId, BTreeSet<T>>,
+    from_source: &F,
+) -> BTreeSet<T>
+where
+    T: Clone + Ord,
+    F: Fn(&SourceOuter, &OutputId) -> BTreeSet<T>,
+{
+    if let Some(found) = cache.get(id) {
+        return found.clone();
+    }
+    // Guards against cycles, which are reported as errors elsewhere.
+    cache.insert(id.clone(), BTreeSet::new());
+
+    let found = if let Some(source) = config.source(&id.component) {
+        from_source(source, id)
+    } else if let Some(transform) = config.transform(&id.component) {
+        transform
+            .inputs
+            .iter()
+            .flat_map(|input| upstream_of_sources(config, input, cache, from_source))
+            .collect()
+    } else {
+        BTreeSet::new()
+    };
+
+    cache.insert(id.clone(), found.clone());
+    found
+}
+
+/// The sources that can't acknowledge events which `id` receives events from, directly or through
+/// transforms.
+fn upstream_unacknowledged_sources(
+    config: &Config,
+    id: &OutputId,
+    cache: &mut HashMap<OutputId, BTreeSet<ComponentKey>>,
+) -> BTreeSet<ComponentKey> {
+    upstream_of_sources(config, id, cache, &|source, id| {
+        if source.inner.can_acknowledge() {
+            BTreeSet::new()
+        } else {
+            BTreeSet::from([id.component.clone()])
+        }
+    })
+}
+
+/// The log namespaces of the sources that `id` receives events from, directly or through
+/// transforms.
+fn upstream_log_namespaces(
+    config: &Config,
+    id: &OutputId,
+    cache: &mut HashMap<OutputId, BTreeSet<LogNamespace>>,
+) -> BTreeSet<LogNamespace> {
+    upstream_of_sources(config, id, cache, &|source, id| {
+        source
+            .inner
+            .outputs(config.schema.log_namespace())
+            .into_iter()
+            .find(|output| output.port == id.port && output.ty.contains(DataType::Log))
+            .map(|output| {
+                // Sources without a schema definition don't support the Vector namespace.
+                output.log_schema_definition.map_or_else(
+                    || BTreeSet::from([LogNamespace::Legacy]),
+                    |definition| definition.log_namespaces().clone(),
+                )
+            })
+            .unwrap_or_default()
+    })
+}
+
 fn capitalize(s: &str) -> String {
     let mut s = s.to_owned();
     if let Some(r) = s.get_mut(0..1) {
@@ -1,15 +1,52 @@
 use std::collections::HashMap;
 
+use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 
+/// Marks a reference to an unset variable, see `interpolate_marking_missing`.
+const MISSING_MARKER: &str = "VECTOR_MISSING_ENV_VAR";
+
 /// (result, warnings)
 pub fn interpolate(
     input: &str,
     vars: &HashMap<String, String>,
 ) -> Result<(String, Vec<String>), Vec<String>> {
-    let mut errors = Vec::new();
     let mut warnings = Vec::new();
 
+    let interpolated = interpolate_with(input, vars, |name| {
+        warnings.push(format!("Unknown env var in config. name = {:?}", name));
+        String::new()
+    })?;
+
+    Ok((interpolated, warnings))
+}
+
+/// Like `interpolate`, but replaces references to unset variables with a marker naming the
+/// variable, rather than an empty string. Once the config is parsed, `missing` finds the markers,
+/// so that each unset variable can be reported along with where it's used.
+pub fn interpolate_marking_missing(
+    input: &str,
+    vars: &HashMap<String, String>,
+) -> Result<String, Vec<String>> {
+    interpolate_with(input, vars, |name| format!("{}({})", MISSING_MARKER, name))
+}
+
+/// Returns the names of the unset variables marked in `input` by `interpolate_marking_missing`.
+pub fn missing(input: &str) -> impl Iterator<Item = &str> {
+    static RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(&format!(r"{}\(([[:word:].]+)\)", MISSING_MARKER)).unwrap());
+
+    RE.captures_iter(input)
+        .map(|caps| caps.get(1).expect("name is always captured").as_str())
+}
+
+fn interpolate_with(
+    input: &str,
+    vars: &HashMap<String, String>,
+    mut unset: impl FnMut(&str) -> String,
+) -> Result<String, Vec<String>> {
+    let mut errors = Vec::new();
+
     // Environment variable names can have any characters from the Portable Character Set other
     // than NUL.  However, for Vector's intepolation, we are closer to what a shell supports which
     // is solely of uppercase letters, digits, and the '_' (that is, the `[:word:]` regex class).
@@ -57,20 +94,19 @@ pub fn interpolate(
                             ));
                             ""
                         }),
-                        _ => val.unwrap_or_else(|| {
-                            warnings
-                                .push(format!("Unknown env var in config. name = {:?}", name));
-                            ""
-                        }),
+                        _ => match val {
+                            Some(v) => v,
+                            None => return unset(name),
+                        },
                     }
+                    .to_string()
                 })
-                .unwrap_or("$")
-                .to_string()
+                .unwrap_or_else(|| "$".to_string())
         })
         .into_owned();
 
     if errors.is_empty() {
-        Ok((interpolated, warnings))
+        Ok(interpolated)
     } else {
         Err(errors)
     }
@@ -78,7 +114,7 @@ pub fn interpolate(
 
 #[cfg(test)]
 mod test {
-    use super::interpolate;
+    use super::{interpolate, interpolate_marking_missing, missing};
     #[test]
     fn interpolation() {
         let vars = vec![
@@ -121,4 +157,26 @@ mod test {
         assert!(interpolate("${NOT?error cats}", &vars).is_err());
         assert!(interpolate("${EMPTY:?error cats}", &vars).is_err());
     }
+    #[test]
+    fn marking_missing() {
+        let vars = vec![("FOO".into(), "dogs".into())].into_iter().collect();
+
+        let interpolated =
+            interpolate_marking_missing("$FOO ${NOT} $NOT.BAR $${NOT} ${NOT:-cats}", &vars)
+                .unwrap();
+        assert_eq!(
+            interpolated,
+            "dogs VECTOR_MISSING_ENV_VAR(NOT) VECTOR_MISSING_ENV_VAR(NOT.BAR) ${NOT} cats"
+        );
+        assert_eq!(
+            missing(&interpolated).collect::<Vec<_>>(),
+            vec!["NOT", "NOT.BAR"]
+        );
+
+        // Values aren't interpolated again, so references in them aren't reported.
+        let vars = vec![("FOO".into(), "${NOT}".into())].into_iter().collect();
+        let interpolated = interpolate_marking_missing("${FOO}", &vars).unwrap();
+        assert_eq!(interpolated, "${NOT}");
+        assert_eq!(missing(&interpolated).count(), 0);
+    }
 }
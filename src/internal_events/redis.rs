@@ -1,7 +1,12 @@
-use metrics::counter;
+use metrics::{counter, register_gauge, Gauge};
 use vector_core::internal_event::InternalEvent;
 
-use vector_common::internal_event::{error_stage, error_type};
+use vector_common::internal_event::{
+    error_stage, error_type, ComponentEventsDropped, InternalEventHandle, RegisterInternalEvent,
+    UNINTENTIONAL,
+};
+
+use crate::emit;
 
 #[derive(Debug)]
 pub struct RedisReceiveEventError {
@@ -34,3 +39,214 @@ impl InternalEvent for RedisReceiveEventError {
         );
     }
 }
+
+#[derive(Debug)]
+pub struct RedisMessageOversizedError {
+    pub size: usize,
+    pub max_bytes: usize,
+}
+
+impl InternalEvent for RedisMessageOversizedError {
+    fn emit(self) {
+        let reason = "Message larger than max_message_bytes.";
+        error!(
+            message = reason,
+            size = %self.size,
+            max_message_bytes = %self.max_bytes,
+            error_code = "oversized",
+            error_type = error_type::CONDITION_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => "oversized",
+            "error_type" => error_type::CONDITION_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+        emit!(ComponentEventsDropped::<UNINTENTIONAL> { count: 1, reason });
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("RedisMessageOversizedError")
+    }
+}
+
+#[derive(Debug)]
+pub struct RedisMessageTruncated {
+    pub size: usize,
+    pub max_bytes: usize,
+}
+
+impl InternalEvent for RedisMessageTruncated {
+    fn emit(self) {
+        warn!(
+            message = "Message larger than max_message_bytes, truncating.",
+            size = %self.size,
+            max_message_bytes = %self.max_bytes,
+            internal_log_rate_limit = true,
+        );
+        counter!("redis_messages_truncated_total", 1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("RedisMessageTruncated")
+    }
+}
+
+#[derive(Debug)]
+pub struct RedisConnectionEstablished {
+    pub endpoint: String,
+}
+
+impl InternalEvent for RedisConnectionEstablished {
+    fn emit(self) {
+        debug!(message = "Connected.", endpoint = %self.endpoint);
+        counter!("connection_established_total", 1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("RedisConnectionEstablished")
+    }
+}
+
+#[derive(Debug)]
+pub struct RedisConnectionFailedError<'a> {
+    pub error: &'a redis::RedisError,
+    pub endpoint: String,
+}
+
+impl<'a> InternalEvent for RedisConnectionFailedError<'a> {
+    fn emit(self) {
+        let error_code = self.error.code().unwrap_or("UNKNOWN").to_string();
+        error!(
+            message = "Unable to connect.",
+            endpoint = %self.endpoint,
+            error = %self.error,
+            error_code = %error_code,
+            error_type = error_type::CONNECTION_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => error_code,
+            "error_type" => error_type::CONNECTION_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("RedisConnectionFailedError")
+    }
+}
+
+#[derive(Debug)]
+pub struct RedisConnectionLost<'a> {
+    pub channel: &'a str,
+}
+
+impl<'a> InternalEvent for RedisConnectionLost<'a> {
+    fn emit(self) {
+        warn!(
+            message = "Connection lost, reconnecting.",
+            channel = %self.channel,
+        );
+        counter!("connection_shutdown_total", 1);
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("RedisConnectionLost")
+    }
+}
+
+#[derive(Debug)]
+pub struct RedisListLengthError {
+    error: redis::RedisError,
+    error_code: String,
+}
+
+impl From<redis::RedisError> for RedisListLengthError {
+    fn from(error: redis::RedisError) -> Self {
+        let error_code = error.code().unwrap_or("UNKNOWN").to_string();
+        Self { error, error_code }
+    }
+}
+
+impl InternalEvent for RedisListLengthError {
+    fn emit(self) {
+        error!(
+            message = "Failed to get the list length.",
+            error = %self.error,
+            error_code = %self.error_code,
+            error_type = error_type::REQUEST_FAILED,
+            stage = error_stage::RECEIVING,
+            internal_log_rate_limit = true,
+        );
+        counter!(
+            "component_errors_total", 1,
+            "error_code" => self.error_code,
+            "error_type" => error_type::REQUEST_FAILED,
+            "stage" => error_stage::RECEIVING,
+        );
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("RedisListLengthError")
+    }
+}
+
+/// The number of messages waiting in the list a `redis` source consumes, which is how far the
+/// source lags behind the producers.
+#[derive(Debug)]
+pub struct RedisListLength;
+
+impl RegisterInternalEvent for RedisListLength {
+    type Handle = RedisListLengthHandle;
+
+    fn register(self) -> Self::Handle {
+        Self::Handle {
+            length: register_gauge!("redis_list_length"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RedisListLengthHandle {
+    length: Gauge,
+}
+
+impl InternalEventHandle for RedisListLengthHandle {
+    type Data = usize;
+
+    fn emit(&self, length: usize) {
+        self.length.set(length as f64);
+    }
+}
+
+/// Whether a `redis` source is currently subscribed to its channel.
+#[derive(Debug)]
+pub struct RedisSubscriptionConnected;
+
+impl RegisterInternalEvent for RedisSubscriptionConnected {
+    type Handle = RedisSubscriptionConnectedHandle;
+
+    fn register(self) -> Self::Handle {
+        Self::Handle {
+            connected: register_gauge!("redis_subscription_connected"),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RedisSubscriptionConnectedHandle {
+    connected: Gauge,
+}
+
+impl InternalEventHandle for RedisSubscriptionConnectedHandle {
+    type Data = bool;
+
+    fn emit(&self, connected: bool) {
+        self.connected.set(if connected { 1.0 } else { 0.0 });
+    }
+}
@@ -15,6 +15,12 @@ pub const fn default_false() -> bool {
     false
 }
 
+/// Skips serializing boolean options that are `true`, for options that default to `true`.
+#[allow(clippy::trivially_copy_pass_by_ref)] // serde passes the value by reference
+pub const fn is_true(value: &bool) -> bool {
+    *value
+}
+
 /// The default max length of the input buffer.
 ///
 /// Any input exceeding this limit will be discarded.
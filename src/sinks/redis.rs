@@ -14,6 +14,7 @@ use vector_core::ByteSizeOf;
 
 use crate::{
     codecs::{Encoder, EncodingConfig, Transformer},
+    common::redis::{build_client, connection_manager, ConnectionInfo},
     config::{self, AcknowledgementsConfig, GenerateConfig, Input, SinkConfig, SinkContext},
     event::Event,
     internal_events::TemplateRenderingError,
@@ -215,10 +216,12 @@ impl RedisSinkConfig {
 
         let buffer = VecBuffer::new(batch.size);
 
+        let connection_info = ConnectionInfo::from_url(&self.url)?;
+
         let redis = RedisSink {
             conn,
             data_type,
-            bytes_sent: register!(BytesSent::from(Protocol::TCP)),
+            bytes_sent: register!(BytesSent::from(Protocol::from(connection_info.protocol))),
         };
 
         let svc = ServiceBuilder::new()
@@ -236,13 +239,7 @@ impl RedisSinkConfig {
     }
 
     async fn build_client(&self) -> RedisResult<ConnectionManager> {
-        trace!("Open Redis client.");
-        let client = redis::Client::open(self.url.as_str())?;
-        trace!("Open Redis client success.");
-        trace!("Get Redis connection.");
-        let conn = client.get_tokio_connection_manager().await;
-        trace!("Get Redis connection success.");
-        conn
+        connection_manager(build_client(&self.url)?).await
     }
 
     async fn healthcheck(mut conn: ConnectionManager) -> crate::Result<()> {
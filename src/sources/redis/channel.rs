@@ -1,71 +1,186 @@
+use std::{fmt, num::NonZeroUsize, sync::Arc};
+
 use futures_util::StreamExt;
+use redis::{aio::PubSub, RedisResult};
 use snafu::{ResultExt, Snafu};
-use vector_common::internal_event::{BytesReceived, Registered};
+use tokio::sync::mpsc;
+use tracing_futures::Instrument;
+use vector_common::internal_event::{BytesReceived, InternalEventHandle as _, Registered};
 
 use crate::{
     codecs,
     config::SourceContext,
-    internal_events::RedisReceiveEventError,
+    internal_events::{RedisConnectionLost, RedisReceiveEventError, RedisSubscriptionConnected},
     sources::{
-        redis::{handle_line, ConnectionInfo},
+        redis::{
+            connect_first, handle_line, join_consumers, reconnect, EventEnricher, MessageSizeLimit,
+        },
         Source,
     },
 };
 
+/// The number of received messages that can be queued up for each decoding worker.
+const WORKER_BUFFER_SIZE: usize = 100;
+
+/// The channels and channel patterns a source subscribes to over a single connection.
+#[derive(Clone, Debug)]
+pub struct Subscription {
+    pub channels: Vec<String>,
+    pub patterns: Vec<String>,
+}
+
+impl Subscription {
+    pub fn is_empty(&self) -> bool {
+        self.channels.is_empty() && self.patterns.is_empty()
+    }
+}
+
+impl fmt::Display for Subscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keys = self.channels.iter().chain(&self.patterns);
+        for (i, key) in keys.enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(key)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Snafu)]
 enum BuildError {
-    #[snafu(display("Failed to create connection: {}", source))]
-    Connection { source: redis::RedisError },
     #[snafu(display("Failed to subscribe to channel: {}", source))]
     Subscribe { source: redis::RedisError },
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn subscribe(
-    client: redis::Client,
-    connection_info: ConnectionInfo,
+    clients: Vec<redis::Client>,
     bytes_received: Registered<BytesReceived>,
-    key: String,
-    redis_key: Option<String>,
+    subscription: Subscription,
+    enricher: EventEnricher,
+    size_limit: MessageSizeLimit,
+    concurrency: NonZeroUsize,
     decoder: codecs::Decoder,
     cx: SourceContext,
 ) -> crate::Result<Source> {
-    let conn = client
-        .get_async_connection()
-        .await
-        .context(ConnectionSnafu {})?;
+    let (pubsub_conn, endpoint) = connect_first(&clients, |client| {
+        subscribe_to(client, subscription.clone())
+    })
+    .await
+    .context(SubscribeSnafu {})?;
+    trace!(channel = %subscription, "Subscribed to channel.");
+
+    Ok(Box::pin(async move {
+        let mut shutdown = cx.shutdown;
+        let mut pubsub_conn = Some((pubsub_conn, endpoint));
+        let subscription_connected = register!(RedisSubscriptionConnected);
 
-    trace!(endpoint = %connection_info.endpoint.as_str(), "Connected.");
+        // Every subscriber receives every message published to the channel, so rather than
+        // subscribing multiple times, messages are handed out to a pool of decoding workers.
+        let (workers, handles): (Vec<_>, Vec<_>) = (0..concurrency.get())
+            .map(|_| {
+                let (sender, mut receiver) =
+                    mpsc::channel::<(Arc<str>, String, String)>(WORKER_BUFFER_SIZE);
+                let enricher = enricher.clone();
+                let decoder = decoder.clone();
+                let mut tx = cx.out.clone();
 
-    let mut pubsub_conn = conn.into_pubsub();
-    pubsub_conn
-        .subscribe(&key)
-        .await
-        .context(SubscribeSnafu {})?;
-    trace!(endpoint = %connection_info.endpoint.as_str(), channel = %key, "Subscribed to channel.");
+                let handle = tokio::spawn(
+                    async move {
+                        while let Some((endpoint, channel, line)) = receiver.recv().await {
+                            if let Err(()) = handle_line(
+                                line,
+                                &channel,
+                                &endpoint,
+                                &enricher,
+                                decoder.clone(),
+                                &mut tx,
+                            )
+                            .await
+                            {
+                                break;
+                            }
+                        }
+                    }
+                    .in_current_span(),
+                );
 
-    Ok(Box::pin(async move {
-        let shutdown = cx.shutdown;
-        let mut tx = cx.out;
-        let mut pubsub_stream = pubsub_conn.on_message().take_until(shutdown);
-        while let Some(msg) = pubsub_stream.next().await {
-            match msg.get_payload::<String>() {
-                Ok(line) => {
-                    if let Err(()) = handle_line(
-                        line,
-                        &key,
-                        redis_key.as_deref(),
-                        decoder.clone(),
-                        &bytes_received,
-                        &mut tx,
-                    )
-                    .await
-                    {
-                        break;
+                (sender, handle)
+            })
+            .unzip();
+        let mut next_worker = workers.iter().cycle();
+
+        'subscription: loop {
+            let (conn, endpoint) = match pubsub_conn.take() {
+                Some(subscription) => subscription,
+                None => match reconnect(
+                    &clients,
+                    |client| subscribe_to(client, subscription.clone()),
+                    &mut shutdown,
+                )
+                .await
+                {
+                    Some(subscription) => subscription,
+                    None => break,
+                },
+            };
+
+            subscription_connected.emit(true);
+
+            let endpoint = Arc::<str>::from(endpoint);
+            let mut pubsub_stream = conn.into_on_message().take_until(shutdown.clone());
+            while let Some(msg) = pubsub_stream.next().await {
+                match msg.get_payload::<String>() {
+                    Ok(line) => {
+                        // Oversized messages are handled before they're queued up for a worker.
+                        let line = match size_limit.apply(line, &bytes_received) {
+                            Some(line) => line,
+                            None => continue,
+                        };
+                        let worker = next_worker.next().expect("at least one worker exists");
+                        let channel = msg.get_channel_name().to_owned();
+                        if worker
+                            .send((Arc::clone(&endpoint), channel, line))
+                            .await
+                            .is_err()
+                        {
+                            // The worker only stops when the output has been closed.
+                            break 'subscription;
+                        }
                     }
+                    Err(error) => emit!(RedisReceiveEventError::from(error)),
                 }
-                Err(error) => emit!(RedisReceiveEventError::from(error)),
             }
+
+            // The message stream ends either because shutdown was signalled, or because the
+            // connection was lost, in which case we re-subscribe on the next healthy endpoint.
+            if pubsub_stream.is_stopped() {
+                break;
+            }
+            subscription_connected.emit(false);
+            emit!(RedisConnectionLost {
+                channel: &subscription.to_string()
+            });
         }
+        subscription_connected.emit(false);
+
+        // Let the workers finish decoding any messages that were already received.
+        drop(workers);
+        join_consumers(handles).await;
+
         Ok(())
     }))
 }
+
+async fn subscribe_to(client: redis::Client, subscription: Subscription) -> RedisResult<PubSub> {
+    let mut pubsub_conn = client.get_async_connection().await?.into_pubsub();
+    for channel in &subscription.channels {
+        pubsub_conn.subscribe(channel).await?;
+    }
+    for pattern in &subscription.patterns {
+        pubsub_conn.psubscribe(pattern).await?;
+    }
+    Ok(pubsub_conn)
+}
@@ -1,56 +1,149 @@
+use std::{num::NonZeroUsize, time::Duration};
+
 use redis::{aio::ConnectionManager, AsyncCommands, RedisResult};
 use snafu::{ResultExt, Snafu};
-use vector_common::internal_event::{BytesReceived, Registered};
+use tracing_futures::Instrument;
+use vector_common::internal_event::{BytesReceived, InternalEventHandle as _, Registered};
 
-use super::{handle_line, Method};
+use super::{
+    connect_first, handle_line, is_connection_lost, join_consumers, reconnect, EventEnricher,
+    MessageSizeLimit, Method,
+};
 use crate::{
-    codecs, config::SourceContext, internal_events::RedisReceiveEventError, sources::Source,
+    codecs,
+    common::redis::connection_manager,
+    config::SourceContext,
+    internal_events::{RedisListLength, RedisListLengthError, RedisReceiveEventError},
+    shutdown::ShutdownSignal,
+    sources::Source,
+    SourceSender,
 };
 
+/// How often the length of the list is reported.
+const LIST_LENGTH_INTERVAL: Duration = Duration::from_secs(10);
+
 #[derive(Debug, Snafu)]
 enum BuildError {
     #[snafu(display("Failed to create connection: {}", source))]
     Connection { source: redis::RedisError },
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn watch(
-    client: redis::Client,
+    clients: Vec<redis::Client>,
     bytes_received: Registered<BytesReceived>,
     key: String,
-    redis_key: Option<String>,
+    enricher: EventEnricher,
+    size_limit: MessageSizeLimit,
     method: Method,
+    concurrency: NonZeroUsize,
     decoder: codecs::Decoder,
     cx: SourceContext,
 ) -> crate::Result<Source> {
-    let mut conn = client
-        .get_tokio_connection_manager()
+    // Blocking pops hold up the whole connection, so every consumer needs a connection of its own.
+    let mut conns = Vec::with_capacity(concurrency.get());
+    for _ in 0..concurrency.get() {
+        let conn = connect_first(&clients, connection_manager)
+            .await
+            .context(ConnectionSnafu {})?;
+        conns.push(conn);
+    }
+    // The length is queried on a connection of its own too, so it isn't held up by the pops.
+    let (length_conn, _) = connect_first(&clients, connection_manager)
         .await
         .context(ConnectionSnafu {})?;
+    let length_clients = clients.clone();
+    let length_key = key.clone();
+
+    let consumer = Consumer {
+        clients,
+        bytes_received,
+        key,
+        enricher,
+        size_limit,
+        method,
+        decoder,
+    };
 
     Ok(Box::pin(async move {
-        let mut shutdown = cx.shutdown;
-        let mut tx = cx.out;
+        let length_reporter = tokio::spawn(
+            report_length(length_clients, length_conn, length_key, cx.shutdown.clone())
+                .in_current_span(),
+        );
+
+        let handles = conns
+            .into_iter()
+            .map(|(conn, endpoint)| {
+                tokio::spawn(
+                    consumer
+                        .clone()
+                        .run(conn, endpoint, cx.out.clone(), cx.shutdown.clone())
+                        .in_current_span(),
+                )
+            })
+            .collect();
+
+        join_consumers(handles).await;
+        // The consumers also stop when the output is closed, without shutdown being signalled.
+        length_reporter.abort();
+        Ok(())
+    }))
+}
+
+#[derive(Clone)]
+struct Consumer {
+    clients: Vec<redis::Client>,
+    bytes_received: Registered<BytesReceived>,
+    key: String,
+    enricher: EventEnricher,
+    size_limit: MessageSizeLimit,
+    method: Method,
+    decoder: codecs::Decoder,
+}
+
+impl Consumer {
+    async fn run(
+        self,
+        mut conn: ConnectionManager,
+        mut endpoint: String,
+        mut tx: SourceSender,
+        mut shutdown: ShutdownSignal,
+    ) {
         loop {
-            let res = match method {
+            let res = match self.method {
                 Method::Rpop => tokio::select! {
-                    res = brpop(&mut conn, &key) => res,
+                    res = brpop(&mut conn, &self.key) => res,
                     _ = &mut shutdown => break
                 },
                 Method::Lpop => tokio::select! {
-                    res = blpop(&mut conn, &key) => res,
+                    res = blpop(&mut conn, &self.key) => res,
                     _ = &mut shutdown => break
                 },
             };
 
             match res {
-                Err(error) => emit!(RedisReceiveEventError::from(error)),
+                Err(error) => {
+                    let connection_lost = is_connection_lost(&error);
+                    emit!(RedisReceiveEventError::from(error));
+
+                    if connection_lost {
+                        match reconnect(&self.clients, connection_manager, &mut shutdown).await {
+                            Some(reconnected) => (conn, endpoint) = reconnected,
+                            None => break,
+                        }
+                    }
+                }
                 Ok(line) => {
+                    let line = match self.size_limit.apply(line, &self.bytes_received) {
+                        Some(line) => line,
+                        None => continue,
+                    };
                     if let Err(()) = handle_line(
                         line,
-                        &key,
-                        redis_key.as_deref(),
-                        decoder.clone(),
-                        &bytes_received,
+                        &self.key,
+                        &endpoint,
+                        &self.enricher,
+                        self.decoder.clone(),
                         &mut tx,
                     )
                     .await
@@ -60,8 +153,43 @@ pub async fn watch(
                 }
             }
         }
-        Ok(())
-    }))
+    }
+}
+
+/// Periodically reports the number of messages waiting in the list, which is the backlog the
+/// consumers have yet to work through.
+///
+/// Like the consumers, this moves on to the next healthy endpoint when the connection is lost.
+async fn report_length(
+    clients: Vec<redis::Client>,
+    mut conn: ConnectionManager,
+    key: String,
+    mut shutdown: ShutdownSignal,
+) {
+    let list_length = register!(RedisListLength);
+    let mut interval = tokio::time::interval(LIST_LENGTH_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {},
+            _ = &mut shutdown => break,
+        }
+
+        match conn.llen::<_, usize>(&key).await {
+            Ok(length) => list_length.emit(length),
+            Err(error) => {
+                let connection_lost = is_connection_lost(&error);
+                emit!(RedisListLengthError::from(error));
+
+                if connection_lost {
+                    match reconnect(&clients, connection_manager, &mut shutdown).await {
+                        Some((reconnected, _)) => conn = reconnected,
+                        None => break,
+                    }
+                }
+            }
+        }
+    }
 }
 
 async fn brpop(conn: &mut ConnectionManager, key: &str) -> RedisResult<String> {
@@ -1,25 +1,34 @@
-use bytes::Bytes;
-use chrono::Utc;
+use std::{future::Future, num::NonZeroUsize, panic, time::Duration};
+
+use chrono::{DateTime, Utc};
 use codecs::{
     decoding::{DeserializerConfig, FramingConfig},
     StreamDecodingError,
 };
 use futures::StreamExt;
 use snafu::{ResultExt, Snafu};
+use tokio::task::JoinHandle;
 use tokio_util::codec::FramedRead;
+use value::Kind;
 use vector_common::internal_event::{
     ByteSize, BytesReceived, InternalEventHandle as _, Protocol, Registered,
 };
-use vector_config::configurable_component;
+use vector_config::{configurable_component, NamedComponent};
 use vector_core::config::LogNamespace;
 use vector_core::ByteSizeOf;
 
 use crate::{
     codecs::{Decoder, DecodingConfig},
+    common::redis::{build_client, ConnectionInfo},
     config::{log_schema, GenerateConfig, Output, SourceConfig, SourceContext},
-    event::Event,
-    internal_events::{EventsReceived, StreamClosedError},
-    serde::{default_decoding, default_framing_message_based},
+    event::{Event, LogEvent},
+    internal_events::{
+        EventsReceived, RedisConnectionEstablished, RedisConnectionFailedError,
+        RedisMessageOversizedError, RedisMessageTruncated, StreamClosedError,
+    },
+    serde::{default_decoding, default_framing_message_based, default_true},
+    shutdown::ShutdownSignal,
+    sinks::util::retries::ExponentialBackoff,
     SourceSender,
 };
 
@@ -57,6 +66,22 @@ pub struct ListOption {
     method: Method,
 }
 
+/// Options for the Redis `channel` data type.
+#[configurable_component]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ChannelOption {
+    /// Additional channels to subscribe to, along with `key`.
+    #[serde(default)]
+    channels: Vec<String>,
+
+    /// Channel patterns to subscribe to.
+    ///
+    /// Patterns use the glob-style syntax of the Redis `PSUBSCRIBE` command, for example `logs.*`.
+    #[serde(default)]
+    patterns: Vec<String>,
+}
+
 /// Method for getting events from the `list` data type.
 #[configurable_component]
 #[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
@@ -71,22 +96,77 @@ pub enum Method {
     Rpop,
 }
 
-pub struct ConnectionInfo {
-    protocol: &'static str,
-    endpoint: String,
+/// What to do with messages larger than `max_message_bytes`.
+#[configurable_component]
+#[derive(Clone, Copy, Debug, Derivative, Eq, PartialEq)]
+#[derivative(Default)]
+#[serde(rename_all = "lowercase")]
+pub enum OversizeAction {
+    /// Drop the message without decoding it.
+    #[derivative(Default)]
+    Drop,
+
+    /// Truncate the message to `max_message_bytes` and decode what is left.
+    ///
+    /// Messages are cut at the last character boundary within the limit, so they stay valid UTF-8.
+    Truncate,
 }
 
-impl From<&redis::ConnectionInfo> for ConnectionInfo {
-    fn from(redis_conn_info: &redis::ConnectionInfo) -> Self {
-        let (protocol, endpoint) = match &redis_conn_info.addr {
-            redis::ConnectionAddr::Tcp(host, port)
-            | redis::ConnectionAddr::TcpTls { host, port, .. } => {
-                ("tcp", format!("{}:{}", host, port))
-            }
-            redis::ConnectionAddr::Unix(path) => ("uds", path.to_string_lossy().to_string()),
-        };
+/// Standard metadata to add to each event.
+///
+/// With the `Vector` log namespace, metadata is added to the event metadata: the source type and ingest timestamp
+/// under `vector`, and the endpoint under `redis`. With the `Legacy` log namespace, it is added to the root of the
+/// event instead, under the configured field names, unless a field with the same name already exists.
+#[configurable_component]
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields)]
+pub struct MetadataConfig {
+    /// Whether to add the source type to each event.
+    ///
+    /// With the `Legacy` log namespace, the field name is set by `source_type_key`.
+    #[serde(default = "default_true")]
+    #[derivative(Default(value = "true"))]
+    source_type: bool,
+
+    /// Sets the name of the log field to use to add the source type to each event, with the `Legacy` log namespace.
+    ///
+    /// By default, the global `log_schema.source_type_key` option is used.
+    source_type_key: Option<String>,
+
+    /// Whether to add the time at which each event was received.
+    ///
+    /// With the `Legacy` log namespace, the field name is set by `ingest_timestamp_key`.
+    #[serde(default = "default_true")]
+    #[derivative(Default(value = "true"))]
+    ingest_timestamp: bool,
+
+    /// Sets the name of the log field to use to add the time at which each event was received, with the `Legacy` log
+    /// namespace.
+    ///
+    /// By default, the global `log_schema.timestamp_key` option is used.
+    ingest_timestamp_key: Option<String>,
 
-        Self { protocol, endpoint }
+    /// Sets the name of the log field to use to add the endpoint to each event.
+    ///
+    /// The value will be the `host:port` (or socket path) of the Redis endpoint that the event was read from, which
+    /// can differ from `url` after failing over to one of the `failover_urls`.
+    ///
+    /// By default, this is not set and the endpoint will not be added.
+    endpoint_key: Option<String>,
+}
+
+impl MetadataConfig {
+    fn source_type_key(&self) -> &str {
+        self.source_type_key
+            .as_deref()
+            .unwrap_or_else(|| log_schema().source_type_key())
+    }
+
+    fn ingest_timestamp_key(&self) -> &str {
+        self.ingest_timestamp_key
+            .as_deref()
+            .unwrap_or_else(|| log_schema().timestamp_key())
     }
 }
 
@@ -102,21 +182,63 @@ pub struct RedisSourceConfig {
     #[configurable(derived)]
     list: Option<ListOption>,
 
+    #[configurable(derived)]
+    channel: Option<ChannelOption>,
+
     /// The Redis URL to connect to.
     ///
     /// The URL must take the form of `protocol://server:port/db` where the `protocol` can either be `redis` or `rediss` for connections secured via TLS.
     url: String,
 
+    /// Additional Redis URLs to fail over to.
+    ///
+    /// When the connection to the current endpoint is lost, the source will try to reconnect to `url` and then each of
+    /// these URLs in order, backing off between full rounds of attempts, until one of them succeeds.
+    #[serde(default)]
+    failover_urls: Vec<String>,
+
     /// The Redis key to read messages from.
+    ///
+    /// For the `channel` data type, this can be left empty if `channel.channels` or `channel.patterns` is set.
+    #[serde(default)]
     key: String,
 
     /// Sets the name of the log field to use to add the key to each event.
     ///
-    /// The value will be the Redis key that the event was read from.
+    /// The value will be the Redis key that the event was read from. For the `channel` data type, this is the channel
+    /// the message was published to, also when it was received through a pattern. With the `Legacy` log namespace, it
+    /// overwrites any existing field of the same name. With the `Vector` log namespace, the key is added to the event
+    /// metadata as `redis.key` instead.
     ///
-    /// By default, this is not set and the field will not be automatically added.
+    /// By default, this is not set and the key will not be added.
     redis_key: Option<String>,
 
+    #[configurable(derived)]
+    #[serde(default)]
+    metadata: MetadataConfig,
+
+    /// The number of consumers reading from Redis concurrently.
+    ///
+    /// For the `list` data type, each consumer pops messages from the list over its own connection. For the `channel`
+    /// data type, a single subscription is shared, and received messages are decoded by this many workers.
+    ///
+    /// When set to more than one, events are not guaranteed to be emitted in the order they were read from Redis.
+    #[serde(default = "default_concurrency")]
+    #[derivative(Default(value = "default_concurrency()"))]
+    concurrency: NonZeroUsize,
+
+    /// The maximum size, in bytes, of a message read from Redis.
+    ///
+    /// Larger messages are handled according to `oversize_action` instead of being decoded, which protects against
+    /// a producer publishing arbitrarily large messages.
+    ///
+    /// By default, messages of any size are decoded.
+    max_message_bytes: Option<usize>,
+
+    #[configurable(derived)]
+    #[serde(default)]
+    oversize_action: OversizeAction,
+
     #[configurable(derived)]
     #[serde(default = "default_framing_message_based")]
     #[derivative(Default(value = "default_framing_message_based()"))]
@@ -126,6 +248,35 @@ pub struct RedisSourceConfig {
     #[serde(default = "default_decoding")]
     #[derivative(Default(value = "default_decoding()"))]
     decoding: DeserializerConfig,
+
+    /// The namespace to use. This overrides the global setting.
+    #[serde(default)]
+    log_namespace: Option<bool>,
+}
+
+impl RedisSourceConfig {
+    /// The channels and patterns to subscribe to for the `channel` data type.
+    fn subscription(&self) -> crate::Result<channel::Subscription> {
+        let channel = self.channel.clone().unwrap_or_default();
+        let subscription = channel::Subscription {
+            channels: std::iter::once(self.key.clone())
+                .filter(|key| !key.is_empty())
+                .chain(channel.channels)
+                .collect(),
+            patterns: channel.patterns,
+        };
+
+        if subscription.is_empty() {
+            return Err(
+                "One of `key`, `channel.channels` or `channel.patterns` must be set.".into(),
+            );
+        }
+        Ok(subscription)
+    }
+}
+
+fn default_concurrency() -> NonZeroUsize {
+    NonZeroUsize::new(1).expect("static non-zero number")
 }
 
 impl GenerateConfig for RedisSourceConfig {
@@ -147,18 +298,27 @@ impl GenerateConfig for RedisSourceConfig {
 impl SourceConfig for RedisSourceConfig {
     async fn build(&self, cx: SourceContext) -> crate::Result<super::Source> {
         // A key must be specified to actually query i.e. the list to pop from, or the channel to subscribe to.
-        if self.key.is_empty() {
+        if matches!(self.data_type, DataTypeConfig::List) && self.key.is_empty() {
             return Err("`key` cannot be empty.".into());
         }
 
-        let client = redis::Client::open(self.url.as_str()).context(ClientSnafu {})?;
-        let connection_info = ConnectionInfo::from(client.get_connection_info());
-        let decoder = DecodingConfig::new(
-            self.framing.clone(),
-            self.decoding.clone(),
-            LogNamespace::Legacy,
-        )
-        .build();
+        let clients = std::iter::once(&self.url)
+            .chain(&self.failover_urls)
+            .map(|url| build_client(url).context(ClientSnafu {}))
+            .collect::<Result<Vec<_>, _>>()?;
+        let connection_info = ConnectionInfo::from(clients[0].get_connection_info());
+        let log_namespace = cx.log_namespace(self.log_namespace);
+        let decoder =
+            DecodingConfig::new(self.framing.clone(), self.decoding.clone(), log_namespace).build();
+        let enricher = EventEnricher {
+            log_namespace,
+            metadata: self.metadata.clone(),
+            redis_key: self.redis_key.clone(),
+        };
+        let size_limit = MessageSizeLimit {
+            max_bytes: self.max_message_bytes,
+            oversize_action: self.oversize_action,
+        };
 
         let bytes_received = register!(BytesReceived::from(Protocol::from(
             connection_info.protocol
@@ -168,11 +328,13 @@ impl SourceConfig for RedisSourceConfig {
             DataTypeConfig::List => {
                 let list = self.list.unwrap_or_default();
                 list::watch(
-                    client,
+                    clients,
                     bytes_received.clone(),
                     self.key.clone(),
-                    self.redis_key.clone(),
+                    enricher,
+                    size_limit,
                     list.method,
+                    self.concurrency,
                     decoder,
                     cx,
                 )
@@ -180,11 +342,12 @@ impl SourceConfig for RedisSourceConfig {
             }
             DataTypeConfig::Channel => {
                 channel::subscribe(
-                    client,
-                    connection_info,
+                    clients,
                     bytes_received.clone(),
-                    self.key.clone(),
-                    self.redis_key.clone(),
+                    self.subscription()?,
+                    enricher,
+                    size_limit,
+                    self.concurrency,
                     decoder,
                     cx,
                 )
@@ -193,8 +356,46 @@ impl SourceConfig for RedisSourceConfig {
         }
     }
 
-    fn outputs(&self, _global_log_namespace: LogNamespace) -> Vec<Output> {
-        vec![Output::default(self.decoding.output_type())]
+    fn outputs(&self, global_log_namespace: LogNamespace) -> Vec<Output> {
+        let log_namespace = global_log_namespace.merge(self.log_namespace);
+        let mut schema_definition = self.decoding.schema_definition(log_namespace);
+
+        if self.metadata.source_type {
+            schema_definition = schema_definition.with_vector_metadata(
+                Some(self.metadata.source_type_key()),
+                "source_type",
+                Kind::bytes(),
+                None,
+            );
+        }
+        if self.metadata.ingest_timestamp {
+            schema_definition = schema_definition.with_vector_metadata(
+                Some(self.metadata.ingest_timestamp_key()),
+                "ingest_timestamp",
+                Kind::timestamp(),
+                None,
+            );
+        }
+        if let Some(redis_key) = &self.redis_key {
+            schema_definition = schema_definition.with_source_metadata(
+                Self::NAME,
+                Some(redis_key.as_str()),
+                "key",
+                Kind::bytes(),
+                None,
+            );
+        }
+        if let Some(endpoint_key) = &self.metadata.endpoint_key {
+            schema_definition = schema_definition.with_source_metadata(
+                Self::NAME,
+                Some(endpoint_key.as_str()),
+                "endpoint",
+                Kind::bytes(),
+                None,
+            );
+        }
+
+        vec![Output::default(self.decoding.output_type()).with_schema_definition(schema_definition)]
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -202,18 +403,199 @@ impl SourceConfig for RedisSourceConfig {
     }
 }
 
+const fn fresh_backoff() -> ExponentialBackoff {
+    ExponentialBackoff::from_millis(2)
+        .factor(250)
+        .max_delay(Duration::from_secs(60))
+}
+
+/// Tries each endpoint once, in order, returning the first successful connection along with its endpoint.
+async fn connect_first<T, F, Fut>(
+    clients: &[redis::Client],
+    mut connect: F,
+) -> redis::RedisResult<(T, String)>
+where
+    F: FnMut(redis::Client) -> Fut,
+    Fut: Future<Output = redis::RedisResult<T>>,
+{
+    let mut last_error = None;
+    for client in clients {
+        let endpoint = ConnectionInfo::from(client.get_connection_info()).endpoint;
+        match connect(client.clone()).await {
+            Ok(conn) => {
+                emit!(RedisConnectionEstablished {
+                    endpoint: endpoint.clone()
+                });
+                return Ok((conn, endpoint));
+            }
+            Err(error) => {
+                emit!(RedisConnectionFailedError {
+                    error: &error,
+                    endpoint,
+                });
+                last_error = Some(error);
+            }
+        }
+    }
+    Err(last_error.expect("at least one endpoint is always configured"))
+}
+
+/// Cycles through the endpoints until a connection succeeds, backing off between full rounds of attempts.
+///
+/// Returns `None` if shutdown is signalled before a connection could be made.
+async fn reconnect<T, F, Fut>(
+    clients: &[redis::Client],
+    mut connect: F,
+    shutdown: &mut ShutdownSignal,
+) -> Option<(T, String)>
+where
+    F: FnMut(redis::Client) -> Fut,
+    Fut: Future<Output = redis::RedisResult<T>>,
+{
+    let mut backoff = fresh_backoff();
+    loop {
+        tokio::select! {
+            res = connect_first(clients, &mut connect) => {
+                if let Ok(conn) = res {
+                    return Some(conn);
+                }
+            },
+            _ = &mut *shutdown => return None,
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(backoff.next().unwrap()) => {},
+            _ = &mut *shutdown => return None,
+        }
+    }
+}
+
+/// Whether an error means the connection to the endpoint was lost, so that it's time to move on to
+/// another endpoint.
+fn is_connection_lost(error: &redis::RedisError) -> bool {
+    error.is_connection_dropped() || error.is_connection_refusal() || error.is_io_error()
+}
+
+/// Waits for all consumer tasks to finish.
+///
+/// If any of them panicked, the panic is resumed here so that Vector shuts down properly.
+async fn join_consumers(handles: Vec<JoinHandle<()>>) {
+    for handle in handles {
+        if let Err(error) = handle.await {
+            if error.is_panic() {
+                panic::resume_unwind(error.into_panic());
+            }
+        }
+    }
+}
+
+/// Adds the configured metadata to events read from Redis.
+#[derive(Clone)]
+pub struct EventEnricher {
+    log_namespace: LogNamespace,
+    metadata: MetadataConfig,
+    redis_key: Option<String>,
+}
+
+impl EventEnricher {
+    fn enrich(&self, log: &mut LogEvent, key: &str, endpoint: &str, now: DateTime<Utc>) {
+        if self.metadata.source_type {
+            self.log_namespace.insert_vector_metadata(
+                log,
+                self.metadata.source_type_key(),
+                "source_type",
+                RedisSourceConfig::NAME,
+            );
+        }
+        if self.metadata.ingest_timestamp {
+            self.log_namespace.insert_vector_metadata(
+                log,
+                self.metadata.ingest_timestamp_key(),
+                "ingest_timestamp",
+                now,
+            );
+        }
+        if let Some(redis_key) = &self.redis_key {
+            match self.log_namespace {
+                // Unlike the other metadata, the key has always overwritten an existing field.
+                LogNamespace::Legacy => {
+                    log.insert(redis_key.as_str(), key);
+                }
+                LogNamespace::Vector => self.log_namespace.insert_source_metadata(
+                    RedisSourceConfig::NAME,
+                    log,
+                    redis_key.as_str(),
+                    "key",
+                    key,
+                ),
+            }
+        }
+        if let Some(endpoint_key) = &self.metadata.endpoint_key {
+            self.log_namespace.insert_source_metadata(
+                RedisSourceConfig::NAME,
+                log,
+                endpoint_key.as_str(),
+                "endpoint",
+                endpoint,
+            );
+        }
+    }
+}
+
+/// Keeps messages that are too large from being decoded.
+#[derive(Clone, Copy)]
+pub struct MessageSizeLimit {
+    max_bytes: Option<usize>,
+    oversize_action: OversizeAction,
+}
+
+impl MessageSizeLimit {
+    /// Returns the message to decode, which is `None` if it was dropped for being too large.
+    ///
+    /// Received bytes are counted here, at the message's original size, so that messages that
+    /// are dropped or truncated are still accounted for.
+    fn apply(self, mut line: String, bytes_received: &Registered<BytesReceived>) -> Option<String> {
+        bytes_received.emit(ByteSize(line.len()));
+
+        let max_bytes = match self.max_bytes {
+            Some(max_bytes) if line.len() > max_bytes => max_bytes,
+            _ => return Some(line),
+        };
+
+        match self.oversize_action {
+            OversizeAction::Drop => {
+                emit!(RedisMessageOversizedError {
+                    size: line.len(),
+                    max_bytes,
+                });
+                None
+            }
+            OversizeAction::Truncate => {
+                emit!(RedisMessageTruncated {
+                    size: line.len(),
+                    max_bytes,
+                });
+                let mut end = max_bytes;
+                while !line.is_char_boundary(end) {
+                    end -= 1;
+                }
+                line.truncate(end);
+                Some(line)
+            }
+        }
+    }
+}
+
 async fn handle_line(
     line: String,
     key: &str,
-    redis_key: Option<&str>,
+    endpoint: &str,
+    enricher: &EventEnricher,
     decoder: Decoder,
-    bytes_received: &Registered<BytesReceived>,
     out: &mut SourceSender,
 ) -> Result<(), ()> {
     let now = Utc::now();
 
-    bytes_received.emit(ByteSize(line.len()));
-
     let mut stream = FramedRead::new(line.as_ref(), decoder.clone());
     while let Some(next) = stream.next().await {
         match next {
@@ -226,11 +608,7 @@ async fn handle_line(
 
                 let events = events.into_iter().map(|mut event| {
                     if let Event::Log(ref mut log) = event {
-                        log.try_insert(log_schema().source_type_key(), Bytes::from("redis"));
-                        log.try_insert(log_schema().timestamp_key(), now);
-                        if let Some(redis_key) = redis_key {
-                            event.as_mut_log().insert(redis_key, key);
-                        }
+                        enricher.enrich(log, key, endpoint, now);
                     }
                     event
                 });
@@ -254,12 +632,328 @@ async fn handle_line(
 
 #[cfg(test)]
 mod test {
+    use codecs::decoding::NewlineDelimitedDecoderOptions;
+
     use super::*;
+    use crate::{
+        event::MetricValue,
+        metrics::{self, Controller},
+        test_util::collect_ready,
+    };
 
     #[test]
     fn generate_config() {
         crate::test_util::test_generate_config::<RedisSourceConfig>();
     }
+
+    #[test]
+    fn channel_subscription_combines_key_channels_and_patterns() {
+        let config: RedisSourceConfig = toml::from_str(
+            r#"
+            url = "redis://127.0.0.1:6379/0"
+            key = "vector"
+            data_type = "channel"
+            channel.channels = ["logs"]
+            channel.patterns = ["metrics.*"]
+            "#,
+        )
+        .unwrap();
+
+        let subscription = config.subscription().unwrap();
+        assert_eq!(subscription.channels, vec!["vector", "logs"]);
+        assert_eq!(subscription.patterns, vec!["metrics.*"]);
+        assert_eq!(subscription.to_string(), "vector, logs, metrics.*");
+    }
+
+    #[test]
+    fn channel_subscription_requires_a_channel() {
+        let config: RedisSourceConfig = toml::from_str(
+            r#"
+            url = "redis://127.0.0.1:6379/0"
+            data_type = "channel"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.subscription().is_err());
+    }
+
+    #[test]
+    fn message_size_limit() {
+        let bytes_received = register!(BytesReceived::from(Protocol::from("redis")));
+        let limit = |max_bytes, oversize_action| MessageSizeLimit {
+            max_bytes,
+            oversize_action,
+        };
+
+        assert_eq!(
+            limit(None, OversizeAction::Drop).apply("hello".to_owned(), &bytes_received),
+            Some("hello".to_owned())
+        );
+        assert_eq!(
+            limit(Some(5), OversizeAction::Drop).apply("hello".to_owned(), &bytes_received),
+            Some("hello".to_owned())
+        );
+        assert_eq!(
+            limit(Some(4), OversizeAction::Drop).apply("hello".to_owned(), &bytes_received),
+            None
+        );
+        assert_eq!(
+            limit(Some(4), OversizeAction::Truncate).apply("hello".to_owned(), &bytes_received),
+            Some("hell".to_owned())
+        );
+        // "é" takes two bytes, and isn't split.
+        assert_eq!(
+            limit(Some(4), OversizeAction::Truncate).apply("heéllo".to_owned(), &bytes_received),
+            Some("heé".to_owned())
+        );
+        assert_eq!(
+            limit(Some(3), OversizeAction::Truncate).apply("heéllo".to_owned(), &bytes_received),
+            Some("he".to_owned())
+        );
+    }
+
+    #[test]
+    fn message_size_limit_counts_original_size() {
+        metrics::init_test();
+
+        // Each action counts under its own protocol, to keep the counters apart.
+        let received_bytes = |protocol: &'static str, oversize_action| {
+            let bytes_received = register!(BytesReceived::from(Protocol::from(protocol)));
+            MessageSizeLimit {
+                max_bytes: Some(4),
+                oversize_action,
+            }
+            .apply("hello".to_owned(), &bytes_received);
+
+            Controller::get()
+                .expect("There must be a controller")
+                .capture_metrics()
+                .into_iter()
+                .find(|metric| {
+                    metric.name() == "component_received_bytes_total"
+                        && metric.tag_value("protocol").as_deref() == Some(protocol)
+                })
+                .map(|metric| metric.value().clone())
+        };
+
+        assert_eq!(
+            received_bytes("redis_oversize_drop", OversizeAction::Drop),
+            Some(MetricValue::Counter { value: 5.0 })
+        );
+        assert_eq!(
+            received_bytes("redis_oversize_truncate", OversizeAction::Truncate),
+            Some(MetricValue::Counter { value: 5.0 })
+        );
+    }
+
+    fn clients(endpoints: &[&str]) -> Vec<redis::Client> {
+        endpoints
+            .iter()
+            .map(|endpoint| build_client(&format!("redis://{}/0", endpoint)).unwrap())
+            .collect()
+    }
+
+    /// Returns a fake connect function that records the endpoints it is called with and only
+    /// succeeds on the `succeed_on`th attempt, if ever.
+    fn fake_connect(
+        attempts: &mut Vec<String>,
+        succeed_on: Option<usize>,
+    ) -> impl FnMut(redis::Client) -> futures::future::Ready<redis::RedisResult<String>> + '_ {
+        move |client| {
+            let endpoint = ConnectionInfo::from(client.get_connection_info()).endpoint;
+            attempts.push(endpoint.clone());
+            futures::future::ready(if Some(attempts.len()) == succeed_on {
+                Ok(endpoint)
+            } else {
+                Err(redis::RedisError::from((
+                    redis::ErrorKind::IoError,
+                    "connection refused",
+                )))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn connect_first_returns_first_successful_endpoint() {
+        let clients = clients(&["redis-a:6379", "redis-b:6379", "redis-c:6379"]);
+        let mut attempts = Vec::new();
+
+        let (conn, endpoint) = connect_first(&clients, fake_connect(&mut attempts, Some(2)))
+            .await
+            .unwrap();
+
+        assert_eq!(conn, "redis-b:6379");
+        assert_eq!(endpoint, "redis-b:6379");
+        assert_eq!(attempts, vec!["redis-a:6379", "redis-b:6379"]);
+    }
+
+    #[tokio::test]
+    async fn connect_first_fails_when_all_endpoints_fail() {
+        let clients = clients(&["redis-a:6379", "redis-b:6379"]);
+        let mut attempts = Vec::new();
+
+        let result = connect_first(&clients, fake_connect(&mut attempts, None)).await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts, vec!["redis-a:6379", "redis-b:6379"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reconnect_retries_endpoints_in_order() {
+        let clients = clients(&["redis-a:6379", "redis-b:6379"]);
+        let mut attempts = Vec::new();
+        let mut shutdown = ShutdownSignal::noop();
+
+        let (conn, endpoint) = reconnect(
+            &clients,
+            fake_connect(&mut attempts, Some(4)),
+            &mut shutdown,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(conn, "redis-b:6379");
+        assert_eq!(endpoint, "redis-b:6379");
+        assert_eq!(
+            attempts,
+            vec![
+                "redis-a:6379",
+                "redis-b:6379",
+                "redis-a:6379",
+                "redis-b:6379"
+            ]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reconnect_stops_on_shutdown() {
+        let clients = clients(&["redis-a:6379"]);
+        let mut attempts = Vec::new();
+        let (trigger, mut shutdown, _) = ShutdownSignal::new_wired();
+        drop(trigger);
+
+        let result = reconnect(&clients, fake_connect(&mut attempts, None), &mut shutdown).await;
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn enrich_legacy_namespace() {
+        let enricher = EventEnricher {
+            log_namespace: LogNamespace::Legacy,
+            metadata: MetadataConfig {
+                source_type_key: Some("type".to_owned()),
+                ingest_timestamp_key: Some("received_at".to_owned()),
+                endpoint_key: Some("endpoint".to_owned()),
+                ..Default::default()
+            },
+            redis_key: Some("redis_key".to_owned()),
+        };
+        let now = Utc::now();
+        let mut log = LogEvent::from("hello");
+        log.insert("redis_key", "existing");
+        log.insert("endpoint", "existing");
+
+        enricher.enrich(&mut log, "vector", "127.0.0.1:6379", now);
+
+        assert_eq!(log["type"], "redis".into());
+        assert_eq!(log["received_at"], now.into());
+        assert_eq!(log["redis_key"], "vector".into());
+        assert_eq!(log["endpoint"], "existing".into());
+        assert!(!log.contains(log_schema().source_type_key()));
+    }
+
+    #[test]
+    fn enrich_vector_namespace() {
+        let enricher = EventEnricher {
+            log_namespace: LogNamespace::Vector,
+            metadata: MetadataConfig {
+                source_type_key: Some("type".to_owned()),
+                ingest_timestamp: false,
+                endpoint_key: Some("endpoint".to_owned()),
+                ..Default::default()
+            },
+            redis_key: Some("redis_key".to_owned()),
+        };
+        let mut log = LogEvent::from(value::Value::from("hello"));
+
+        enricher.enrich(&mut log, "vector", "127.0.0.1:6379", Utc::now());
+
+        let metadata = log.metadata().value();
+        assert_eq!(log.value(), &"hello".into());
+        assert_eq!(metadata.get("vector.source_type"), Some(&"redis".into()));
+        assert_eq!(metadata.get("vector.ingest_timestamp"), None);
+        assert_eq!(metadata.get("redis.key"), Some(&"vector".into()));
+        assert_eq!(
+            metadata.get("redis.endpoint"),
+            Some(&"127.0.0.1:6379".into())
+        );
+    }
+
+    async fn decode_message(
+        message: &str,
+        framing: FramingConfig,
+        decoding: DeserializerConfig,
+    ) -> Vec<Event> {
+        let decoder = DecodingConfig::new(framing, decoding, LogNamespace::Legacy).build();
+        let enricher = EventEnricher {
+            log_namespace: LogNamespace::Legacy,
+            metadata: MetadataConfig::default(),
+            redis_key: None,
+        };
+        let (mut tx, rx) = SourceSender::new_test();
+
+        handle_line(
+            message.to_owned(),
+            "vector",
+            "127.0.0.1:6379",
+            &enricher,
+            decoder,
+            &mut tx,
+        )
+        .await
+        .unwrap();
+        drop(tx);
+
+        collect_ready(rx).await
+    }
+
+    #[tokio::test]
+    async fn message_based_framing_emits_one_event_per_message() {
+        let events = decode_message(
+            "{\"a\":1}\n{\"a\":2}",
+            default_framing_message_based(),
+            default_decoding(),
+        )
+        .await;
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].as_log()[log_schema().message_key()],
+            "{\"a\":1}\n{\"a\":2}".into()
+        );
+    }
+
+    #[tokio::test]
+    async fn newline_delimited_framing_emits_one_event_per_line() {
+        let events = decode_message(
+            "{\"a\":1}\n{\"a\":2}\n",
+            FramingConfig::NewlineDelimited {
+                newline_delimited: NewlineDelimitedDecoderOptions::default(),
+            },
+            DeserializerConfig::Json,
+        )
+        .await;
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].as_log()["a"], 1.into());
+        assert_eq!(events[1].as_log()["a"], 2.into());
+        assert_eq!(
+            events[1].as_log()[log_schema().source_type_key()],
+            "redis".into()
+        );
+    }
 }
 
 #[cfg(all(test, feature = "redis-integration-tests"))]
@@ -295,11 +989,18 @@ mod integration_test {
             list: Some(ListOption {
                 method: Method::Rpop,
             }),
+            channel: None,
             url: REDIS_SERVER.to_owned(),
+            failover_urls: Vec::new(),
             key: key.clone(),
             redis_key: None,
+            metadata: MetadataConfig::default(),
+            concurrency: default_concurrency(),
+            max_message_bytes: None,
+            oversize_action: OversizeAction::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            log_namespace: None,
         };
 
         let events = run_and_assert_source_compliance_n(config, 3, &SOURCE_TAGS).await;
@@ -328,11 +1029,18 @@ mod integration_test {
             list: Some(ListOption {
                 method: Method::Lpop,
             }),
+            channel: None,
             url: REDIS_SERVER.to_owned(),
+            failover_urls: Vec::new(),
             key: key.clone(),
             redis_key: None,
+            metadata: MetadataConfig::default(),
+            concurrency: default_concurrency(),
+            max_message_bytes: None,
+            oversize_action: OversizeAction::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            log_namespace: None,
         };
 
         let events = run_and_assert_source_compliance_n(config, 3, &SOURCE_TAGS).await;
@@ -342,6 +1050,149 @@ mod integration_test {
         assert_eq!(events[2].as_log()[log_schema().message_key()], "3".into());
     }
 
+    #[tokio::test]
+    async fn redis_source_list_vector_namespace_metadata() {
+        let client = redis::Client::open(REDIS_SERVER).unwrap();
+        let mut conn = client.get_tokio_connection_manager().await.unwrap();
+
+        let key = format!("test-key-{}", random_string(10));
+        debug!("Test key name: {}.", key);
+
+        let _: i32 = conn.rpush(&key, "1").await.unwrap();
+
+        let config = RedisSourceConfig {
+            data_type: DataTypeConfig::List,
+            list: None,
+            channel: None,
+            url: REDIS_SERVER.to_owned(),
+            failover_urls: Vec::new(),
+            key: key.clone(),
+            redis_key: Some("redis_key".to_owned()),
+            metadata: MetadataConfig {
+                ingest_timestamp: false,
+                endpoint_key: Some("redis_endpoint".to_owned()),
+                ..Default::default()
+            },
+            concurrency: default_concurrency(),
+            max_message_bytes: None,
+            oversize_action: OversizeAction::default(),
+            framing: default_framing_message_based(),
+            decoding: default_decoding(),
+            log_namespace: Some(true),
+        };
+
+        let events = run_and_assert_source_compliance_n(config, 1, &SOURCE_TAGS).await;
+        let log = events[0].as_log();
+        let metadata = log.metadata().value();
+
+        assert_eq!(log.value(), &"1".into());
+        assert_eq!(metadata.get("vector.source_type"), Some(&"redis".into()));
+        assert_eq!(metadata.get("vector.ingest_timestamp"), None);
+        assert_eq!(metadata.get("redis.key"), Some(&key.as_str().into()));
+        assert_eq!(metadata.get("redis.endpoint"), Some(&"redis:6379".into()));
+    }
+
+    #[tokio::test]
+    async fn redis_source_list_concurrent() {
+        let client = redis::Client::open(REDIS_SERVER).unwrap();
+        let mut conn = client.get_tokio_connection_manager().await.unwrap();
+
+        let key = format!("test-key-{}", random_string(10));
+        debug!("Test key name: {}.", key);
+
+        for i in 0..10 {
+            let _: i32 = conn.rpush(&key, i.to_string()).await.unwrap();
+        }
+
+        let config = RedisSourceConfig {
+            data_type: DataTypeConfig::List,
+            list: None,
+            channel: None,
+            url: REDIS_SERVER.to_owned(),
+            failover_urls: Vec::new(),
+            key: key.clone(),
+            redis_key: None,
+            metadata: MetadataConfig::default(),
+            concurrency: NonZeroUsize::new(3).unwrap(),
+            max_message_bytes: None,
+            oversize_action: OversizeAction::default(),
+            framing: default_framing_message_based(),
+            decoding: default_decoding(),
+            log_namespace: None,
+        };
+
+        let events = run_and_assert_source_compliance_n(config, 10, &SOURCE_TAGS).await;
+
+        // Consumers run concurrently, so events may arrive in any order.
+        let mut messages = events
+            .iter()
+            .map(|event| {
+                event.as_log()[log_schema().message_key()]
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect::<Vec<_>>();
+        messages.sort_by_key(|message| message.parse::<usize>().unwrap());
+        assert_eq!(messages, (0..10).map(|i| i.to_string()).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn redis_source_channel_multiple_channels_and_patterns() {
+        let prefix = format!("test-channel-{}", random_string(10));
+        let channel = format!("{}-channel", prefix);
+        let pattern_channel = format!("{}-pattern.a", prefix);
+
+        let config = RedisSourceConfig {
+            data_type: DataTypeConfig::Channel,
+            list: None,
+            channel: Some(ChannelOption {
+                channels: vec![channel.clone()],
+                patterns: vec![format!("{}-pattern.*", prefix)],
+            }),
+            url: REDIS_SERVER.to_owned(),
+            failover_urls: Vec::new(),
+            key: String::new(),
+            redis_key: Some("redis_key".to_owned()),
+            metadata: MetadataConfig::default(),
+            concurrency: default_concurrency(),
+            max_message_bytes: None,
+            oversize_action: OversizeAction::default(),
+            framing: default_framing_message_based(),
+            decoding: default_decoding(),
+            log_namespace: None,
+        };
+
+        let (tx, rx) = SourceSender::new_test();
+        let context = SourceContext::new_test(tx, None);
+        let source = config
+            .build(context)
+            .await
+            .expect("source should not fail to build");
+
+        tokio::spawn(source);
+
+        // Briefly wait to ensure the source is subscribed.
+        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+
+        let client = redis::Client::open(REDIS_SERVER).unwrap();
+        let mut async_conn = client
+            .get_async_connection()
+            .await
+            .expect("Failed to get redis async connection.");
+
+        let _: i32 = async_conn.publish(&channel, "1").await.unwrap();
+        let _: i32 = async_conn.publish(&pattern_channel, "2").await.unwrap();
+
+        let events = collect_n(rx, 2).await;
+        assert_eq!(events[0].as_log()[log_schema().message_key()], "1".into());
+        assert_eq!(events[0].as_log()["redis_key"], channel.as_str().into());
+        assert_eq!(events[1].as_log()[log_schema().message_key()], "2".into());
+        assert_eq!(
+            events[1].as_log()["redis_key"],
+            pattern_channel.as_str().into()
+        );
+    }
+
     #[tokio::test]
     async fn redis_source_channel_consume_event() {
         let key = format!("test-channel-{}", random_string(10));
@@ -351,11 +1202,18 @@ mod integration_test {
         let config = RedisSourceConfig {
             data_type: DataTypeConfig::Channel,
             list: None,
+            channel: None,
             url: REDIS_SERVER.to_owned(),
+            failover_urls: Vec::new(),
             key: key.clone(),
             redis_key: None,
+            metadata: MetadataConfig::default(),
+            concurrency: default_concurrency(),
+            max_message_bytes: None,
+            oversize_action: OversizeAction::default(),
             framing: default_framing_message_based(),
             decoding: default_decoding(),
+            log_namespace: None,
         };
 
         let (tx, rx) = SourceSender::new_test();
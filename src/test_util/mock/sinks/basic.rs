@@ -7,6 +7,7 @@ use vector_config::configurable_component;
 use vector_core::{
     config::{AcknowledgementsConfig, Input},
     event::Event,
+    schema,
     sink::{StreamSink, VectorSink},
 };
 
@@ -26,6 +27,9 @@ pub struct BasicSinkConfig {
     #[serde(skip)]
     healthy: bool,
 
+    #[serde(skip)]
+    schema_requirement: Option<schema::Requirement>,
+
     /// Dummy field used for generating unique configurations to trigger reloads.
     data: Option<String>,
 }
@@ -49,6 +53,7 @@ impl BasicSinkConfig {
         Self {
             sink: Mode::Normal(sink),
             healthy,
+            schema_requirement: None,
             data: None,
         }
     }
@@ -57,9 +62,15 @@ impl BasicSinkConfig {
         Self {
             sink: Mode::Normal(sink),
             healthy,
+            schema_requirement: None,
             data: Some(data.into()),
         }
     }
+
+    pub fn with_schema_requirement(mut self, requirement: schema::Requirement) -> Self {
+        self.schema_requirement = Some(requirement);
+        self
+    }
 }
 
 #[derive(Debug, Snafu)]
@@ -94,7 +105,10 @@ impl SinkConfig for BasicSinkConfig {
     }
 
     fn input(&self) -> Input {
-        Input::all()
+        match &self.schema_requirement {
+            Some(requirement) => Input::all().with_schema_requirement(requirement.clone()),
+            None => Input::all(),
+        }
     }
 
     fn acknowledgements(&self) -> &AcknowledgementsConfig {
@@ -10,6 +10,7 @@ use vector_core::config::LogNamespace;
 use vector_core::{
     config::{DataType, Output},
     event::{EventArray, EventContainer},
+    schema::Definition,
     source::Source,
 };
 
@@ -34,6 +35,9 @@ pub struct BasicSourceConfig {
 
     /// Meaningless field that only exists for triggering config diffs during topology reloading.
     data: Option<String>,
+
+    /// The log namespace to declare in the output schema, if any.
+    log_namespace: Option<bool>,
 }
 
 impl Default for BasicSourceConfig {
@@ -45,6 +49,7 @@ impl Default for BasicSourceConfig {
             data_type: Some(DataType::all()),
             force_shutdown: false,
             data: None,
+            log_namespace: None,
         }
     }
 }
@@ -59,6 +64,7 @@ impl BasicSourceConfig {
             data_type: Some(DataType::all()),
             force_shutdown: false,
             data: None,
+            log_namespace: None,
         }
     }
 
@@ -69,6 +75,7 @@ impl BasicSourceConfig {
             data_type: Some(DataType::all()),
             force_shutdown: false,
             data: Some(data.into()),
+            log_namespace: None,
         }
     }
 
@@ -82,6 +89,7 @@ impl BasicSourceConfig {
             data_type: Some(DataType::all()),
             force_shutdown: false,
             data: None,
+            log_namespace: None,
         }
     }
 
@@ -132,7 +140,16 @@ impl SourceConfig for BasicSourceConfig {
     }
 
     fn outputs(&self, _global_log_namespace: LogNamespace) -> Vec<Output> {
-        vec![Output::default(self.data_type.unwrap())]
+        let output = Output::default(self.data_type.unwrap());
+
+        match self.log_namespace {
+            Some(log_namespace) => {
+                let definition =
+                    Definition::default_for_namespace(&[LogNamespace::from(log_namespace)].into());
+                vec![output.with_schema_definition(definition)]
+            }
+            None => vec![output],
+        }
     }
 
     fn can_acknowledge(&self) -> bool {
@@ -360,6 +360,7 @@ pub async fn build_pieces(
             enrichment_tables: enrichment_tables.clone(),
             schema_definitions,
             merged_schema_definition: merged_definition.clone(),
+            mock_time: config.mock_time,
         };
 
         let node = TransformNode::from_parts(key.clone(), transform, &merged_definition);
@@ -397,15 +398,6 @@ pub async fn build_pieces(
         let typetag = sink.inner.get_component_name();
         let input_type = sink.inner.input().data_type();
 
-        if config.schema.validation {
-            // At this point, we've validated that all transforms are valid, including any
-            // transform that mutates the schema provided by their sources. We can now validate the
-            // schema expectations of each individual sink.
-            if let Err(mut err) = schema::validate_sink_expectations(key, sink, config) {
-                errors.append(&mut err);
-            };
-        }
-
         let (tx, rx) = if let Some(buffer) = buffers.remove(key) {
             buffer
         } else {
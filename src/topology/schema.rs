@@ -216,7 +216,11 @@ pub(super) fn expanded_definitions(
     definitions
 }
 
-pub(super) fn validate_sink_expectations(
+/// Validates that every pipeline feeding into the given sink satisfies the sink's schema
+/// requirement.
+///
+/// Each input is checked separately, so that errors name the input whose schema falls short.
+pub(crate) fn validate_sink_expectations(
     key: &ComponentKey,
     sink: &SinkOuter<OutputId>,
     config: &topology::Config,
@@ -225,24 +229,28 @@ pub(super) fn validate_sink_expectations(
 
     // Get the schema against which we need to validate the schemas of the components feeding into
     // this sink.
-    let input = sink.inner.input();
-    let requirement = input.schema_requirement();
+    let sink_input = sink.inner.input();
+    let requirement = sink_input.schema_requirement();
 
-    // Get all pipeline definitions feeding into this sink.
     let mut cache = HashMap::default();
-    let definitions = expanded_definitions(&sink.inputs, config, &mut cache);
-
-    // Validate each individual definition against the sink requirement.
-    for definition in definitions {
-        if let Err(err) = requirement.validate(&definition) {
-            errors.append(
-                &mut err
-                    .errors()
-                    .iter()
-                    .cloned()
-                    .map(|err| format!("schema error in component {}: {}", key, err))
-                    .collect(),
-            );
+    for input in &sink.inputs {
+        // Get all pipeline definitions feeding into this sink through this input.
+        let definitions = expanded_definitions(std::slice::from_ref(input), config, &mut cache);
+
+        // Validate each individual definition against the sink requirement.
+        for definition in definitions {
+            if let Err(err) = requirement.validate(&definition) {
+                for err in err.errors() {
+                    let error = format!(
+                        "schema error in sink \"{}\" for input \"{}\": {}",
+                        key, input, err
+                    );
+                    // Multiple pipelines through the same input can fail in the same way.
+                    if !errors.contains(&error) {
+                        errors.push(error);
+                    }
+                }
+            }
         }
     }
 
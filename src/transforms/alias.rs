@@ -0,0 +1,53 @@
+use vector_config::configurable_component;
+
+use crate::{
+    config::{DataType, GenerateConfig, Input, Output, TransformConfig, TransformContext},
+    schema,
+    transforms::Transform,
+};
+
+/// Configuration for the `alias` transform.
+///
+/// An alias is replaced by a copy of the transform it refers to when the configuration is
+/// compiled, keeping its own key and inputs.
+#[configurable_component(transform("alias"))]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct AliasConfig {
+    /// The key of the transform to copy.
+    ///
+    /// The referenced transform can itself be an alias, as long as the aliases don't form a cycle.
+    pub alias_of: String,
+}
+
+impl GenerateConfig for AliasConfig {
+    fn generate_config() -> toml::Value {
+        toml::Value::try_from(Self {
+            alias_of: "my_transform_id".to_string(),
+        })
+        .unwrap()
+    }
+}
+
+#[async_trait::async_trait]
+impl TransformConfig for AliasConfig {
+    async fn build(&self, _context: &TransformContext) -> crate::Result<Transform> {
+        Err("this transform must be resolved to the transform it aliases".into())
+    }
+
+    fn input(&self) -> Input {
+        Input::all()
+    }
+
+    fn outputs(&self, _: &schema::Definition) -> Vec<Output> {
+        vec![Output::default(DataType::all())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn generate_config() {
+        crate::test_util::test_generate_config::<super::AliasConfig>();
+    }
+}
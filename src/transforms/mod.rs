@@ -6,6 +6,8 @@ use snafu::Snafu;
 
 #[cfg(feature = "transforms-aggregate")]
 pub mod aggregate;
+#[cfg(feature = "transforms-alias")]
+pub mod alias;
 #[cfg(feature = "transforms-aws_ec2_metadata")]
 pub mod aws_ec2_metadata;
 #[cfg(feature = "transforms-dedupe")]
@@ -66,6 +68,10 @@ pub enum Transforms {
     #[cfg(feature = "transforms-aggregate")]
     Aggregate(#[configurable(derived)] aggregate::AggregateConfig),
 
+    /// Alias.
+    #[cfg(feature = "transforms-alias")]
+    Alias(#[configurable(derived)] alias::AliasConfig),
+
     /// AWS EC2 metadata.
     #[cfg(feature = "transforms-aws_ec2_metadata")]
     AwsEc2Metadata(#[configurable(derived)] aws_ec2_metadata::Ec2Metadata),
@@ -143,6 +149,8 @@ impl NamedComponent for Transforms {
         match self {
             #[cfg(feature = "transforms-aggregate")]
             Transforms::Aggregate(config) => config.get_component_name(),
+            #[cfg(feature = "transforms-alias")]
+            Transforms::Alias(config) => config.get_component_name(),
             #[cfg(feature = "transforms-aws_ec2_metadata")]
             Transforms::AwsEc2Metadata(config) => config.get_component_name(),
             #[cfg(feature = "transforms-dedupe")]
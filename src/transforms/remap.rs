@@ -6,6 +6,7 @@ use std::{
     path::PathBuf,
 };
 
+use chrono::{DateTime, Utc};
 use lookup::lookup_v2::ValuePath;
 use lookup::{metadata_path, path, PathPrefix};
 use snafu::{ResultExt, Snafu};
@@ -23,6 +24,7 @@ use vrl::{
     prelude::{DiagnosticMessage, ExpressionError},
     CompileConfig, Program, Runtime, Terminate, VrlRuntime,
 };
+use vrl_stdlib::MockTime;
 
 use crate::{
     config::{
@@ -125,6 +127,7 @@ impl RemapConfig {
         &self,
         enrichment_tables: enrichment::TableRegistry,
         merged_schema_definition: schema::Definition,
+        mock_time: Option<DateTime<Utc>>,
     ) -> Result<(
         vrl::Program,
         String,
@@ -161,6 +164,9 @@ impl RemapConfig {
 
         config.set_custom(enrichment_tables);
         config.set_custom(MeaningList::default());
+        if let Some(mock_time) = mock_time {
+            config.set_custom(MockTime(mock_time));
+        }
 
         compile_vrl(&source, &functions, &state, config)
             .map_err(|diagnostics| {
@@ -211,11 +217,20 @@ impl TransformConfig for RemapConfig {
         // We need to compile the VRL program in order to know the schema definition output of this
         // transform. We ignore any compilation errors, as those are caught by the transform build
         // step.
-        let default_definition = self
-            .compile_vrl_program(
-                enrichment::TableRegistry::default(),
-                input_definition.clone(),
-            )
+        let compiled = self.compile_vrl_program(
+            enrichment::TableRegistry::default(),
+            input_definition.clone(),
+            None,
+        );
+
+        // The `dropped` output only receives events if the program can fail or abort while
+        // configured to drop events for that reason.
+        let dropped_unreachable = compiled.as_ref().map_or(false, |(program, _, _, _)| {
+            let info = program.info();
+            !((self.drop_on_error && info.fallible) || (self.drop_on_abort && info.abortable))
+        });
+
+        let default_definition = compiled
             .map(|(program, _, _, external_context)| {
                 let meaning = external_context
                     .get_custom::<MeaningList>()
@@ -289,7 +304,8 @@ impl TransformConfig for RemapConfig {
                 default_output,
                 Output::default(DataType::all())
                     .with_schema_definition(dropped_definition)
-                    .with_port(DROPPED),
+                    .with_port(DROPPED)
+                    .with_unreachable(dropped_unreachable),
             ]
         } else {
             vec![default_output]
@@ -360,6 +376,7 @@ impl Remap<AstRunner> {
         let (program, warnings, _, _) = config.compile_vrl_program(
             context.enrichment_tables.clone(),
             context.merged_schema_definition.clone(),
+            context.mock_time,
         )?;
 
         let runtime = Runtime::default();
@@ -653,6 +670,35 @@ mod tests {
         )
     }
 
+    #[test]
+    fn unreachable_dropped_output() {
+        let unreachable_outputs = |source: &str, drop_on_error, drop_on_abort| {
+            RemapConfig {
+                source: Some(source.to_owned()),
+                drop_on_error,
+                drop_on_abort,
+                reroute_dropped: true,
+                ..Default::default()
+            }
+            .outputs(&schema::Definition::any())
+            .into_iter()
+            .filter(|output| output.unreachable)
+            .filter_map(|output| output.port)
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            unreachable_outputs(".foo = 1", true, true),
+            vec![DROPPED.to_owned()]
+        );
+        assert_eq!(
+            unreachable_outputs(".foo = to_int!(.bar)", false, true),
+            vec![DROPPED.to_owned()]
+        );
+        assert!(unreachable_outputs(".foo = to_int!(.bar)", true, false).is_empty());
+        assert!(unreachable_outputs("abort", false, true).is_empty());
+    }
+
     #[test]
     fn config_both_source_and_file() {
         let config = RemapConfig {
@@ -105,10 +105,18 @@ impl TransformConfig for RouteConfig {
     fn outputs(&self, _: &schema::Definition) -> Vec<Output> {
         let mut result: Vec<Output> = self
             .route
-            .keys()
-            .map(|output_name| Output::default(DataType::all()).with_port(output_name))
+            .iter()
+            .map(|(output_name, condition)| {
+                Output::default(DataType::all())
+                    .with_port(output_name)
+                    .with_condition(condition.to_string())
+            })
             .collect();
-        result.push(Output::default(DataType::all()).with_port(UNMATCHED_ROUTE));
+        result.push(
+            Output::default(DataType::all())
+                .with_port(UNMATCHED_ROUTE)
+                .with_condition("no other route matches"),
+        );
         result
     }
 
@@ -98,6 +98,16 @@ base: components: sinks: configuration: {
 			}
 		}
 	}
+	enabled: {
+		description: """
+			Whether or not the component is enabled.
+
+			Disabled components are removed from the configuration when it's loaded, as if they weren't
+			defined at all.
+			"""
+		required: false
+		type: bool: default: true
+	}
 	healthcheck: {
 		description: "Healthcheck configuration."
 		required:    false
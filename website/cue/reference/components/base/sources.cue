@@ -1,64 +1,76 @@
 package metadata
 
-base: components: sources: configuration: proxy: {
-	description: """
-		Proxy configuration.
+base: components: sources: configuration: {
+	enabled: {
+		description: """
+			Whether or not the component is enabled.
 
-		Vector can be configured to proxy traffic through an HTTP(S) proxy when making external requests. Similar to common
-		proxy configuration convention, users can set different proxies to use based on the type of traffic being proxied,
-		as well as set specific hosts that should not be proxied.
-		"""
-	required: false
-	type: object: options: {
-		enabled: {
-			description: "Enables proxying support."
-			required:    false
-			type: bool: default: true
-		}
-		http: {
-			description: """
-				Proxy endpoint to use when proxying HTTP traffic.
+			Disabled components are removed from the configuration when it's loaded, as if they weren't
+			defined at all.
+			"""
+		required: false
+		type: bool: default: true
+	}
+	proxy: {
+		description: """
+			Proxy configuration.
 
-				Must be a valid URI string.
-				"""
-			required: false
-			type: string: {
-				examples: ["http://foo.bar:3128"]
-				syntax: "literal"
+			Vector can be configured to proxy traffic through an HTTP(S) proxy when making external requests. Similar to common
+			proxy configuration convention, users can set different proxies to use based on the type of traffic being proxied,
+			as well as set specific hosts that should not be proxied.
+			"""
+		required: false
+		type: object: options: {
+			enabled: {
+				description: "Enables proxying support."
+				required:    false
+				type: bool: default: true
 			}
-		}
-		https: {
-			description: """
-				Proxy endpoint to use when proxying HTTPS traffic.
+			http: {
+				description: """
+					Proxy endpoint to use when proxying HTTP traffic.
 
-				Must be a valid URI string.
-				"""
-			required: false
-			type: string: {
-				examples: ["http://foo.bar:3128"]
-				syntax: "literal"
+					Must be a valid URI string.
+					"""
+				required: false
+				type: string: {
+					examples: ["http://foo.bar:3128"]
+					syntax: "literal"
+				}
 			}
-		}
-		no_proxy: {
-			description: """
-				A list of hosts to avoid proxying.
+			https: {
+				description: """
+					Proxy endpoint to use when proxying HTTPS traffic.
+
+					Must be a valid URI string.
+					"""
+				required: false
+				type: string: {
+					examples: ["http://foo.bar:3128"]
+					syntax: "literal"
+				}
+			}
+			no_proxy: {
+				description: """
+					A list of hosts to avoid proxying.
 
-				Multiple patterns are allowed:
+					Multiple patterns are allowed:
 
-				| Pattern             | Example match                                                               |
-				| ------------------- | --------------------------------------------------------------------------- |
-				| Domain names        | `**example.com**` matches requests to `**example.com**`                     |
-				| Wildcard domains    | `**.example.com**` matches requests to `**example.com**` and its subdomains |
-				| IP addresses        | `**127.0.0.1**` matches requests to `**127.0.0.1**`                         |
-				| [CIDR][cidr] blocks | `**192.168.0.0/16**` matches requests to any IP addresses in this range     |
-				| Splat               | `__*__` matches all hosts                                                   |
+					| Pattern             | Example match                                                               |
+					| ------------------- | --------------------------------------------------------------------------- |
+					| Domain names        | `**example.com**` matches requests to `**example.com**`                     |
+					| Wildcard domains    | `**.example.com**` matches requests to `**example.com**` and its subdomains |
+					| IP addresses        | `**127.0.0.1**` matches requests to `**127.0.0.1**`                         |
+					| [CIDR][cidr] blocks | `**192.168.0.0/16**` matches requests to any IP addresses in this range     |
+					| Splat               | `__*__` matches all hosts                                                   |
 
-				[cidr]: https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing
-				"""
-			required: false
-			type: array: {
-				default: []
-				items: type: string: syntax: "literal"
+					[cidr]: https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing
+					"""
+				required: false
+				type: array: {
+					default: []
+					items: type: string: syntax: "literal"
+				}
 			}
 		}
 	}
@@ -1,20 +1,32 @@
 package metadata
 
-base: components: transforms: configuration: inputs: {
-	description: """
-		A list of upstream [source][sources] or [transform][transforms] IDs.
+base: components: transforms: configuration: {
+	enabled: {
+		description: """
+			Whether or not the component is enabled.
 
-		Wildcards (`*`) are supported.
+			Disabled components are removed from the configuration when it's loaded, as if they weren't
+			defined at all.
+			"""
+		required: false
+		type: bool: default: true
+	}
+	inputs: {
+		description: """
+			A list of upstream [source][sources] or [transform][transforms] IDs.
+
+			Wildcards (`*`) are supported.
 
-		See [configuration][configuration] for more info.
+			See [configuration][configuration] for more info.
 
-		[sources]: https://vector.dev/docs/reference/configuration/sources/
-		[transforms]: https://vector.dev/docs/reference/configuration/transforms/
-		[configuration]: https://vector.dev/docs/reference/configuration/
-		"""
-	required: true
-	type: array: items: type: string: {
-		examples: ["my-source-or-transform-id", "prefix-*"]
-		syntax: "literal"
+			[sources]: https://vector.dev/docs/reference/configuration/sources/
+			[transforms]: https://vector.dev/docs/reference/configuration/transforms/
+			[configuration]: https://vector.dev/docs/reference/configuration/
+			"""
+		required: true
+		type: array: items: type: string: {
+			examples: ["my-source-or-transform-id", "prefix-*"]
+			syntax: "literal"
+		}
 	}
 }
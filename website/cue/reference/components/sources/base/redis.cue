@@ -1,6 +1,44 @@
 package metadata
 
 base: components: sources: redis: configuration: {
+	channel: {
+		description: "Options for the Redis `channel` data type."
+		required:    false
+		type: object: options: {
+			channels: {
+				description: "Additional channels to subscribe to, along with `key`."
+				required:    false
+				type: array: {
+					default: []
+					items: type: string: syntax: "literal"
+				}
+			}
+			patterns: {
+				description: """
+					Channel patterns to subscribe to.
+
+					Patterns use the glob-style syntax of the Redis `PSUBSCRIBE` command, for example `logs.*`.
+					"""
+				required: false
+				type: array: {
+					default: []
+					items: type: string: syntax: "literal"
+				}
+			}
+		}
+	}
+	concurrency: {
+		description: """
+			The number of consumers reading from Redis concurrently.
+
+			For the `list` data type, each consumer pops messages from the list over its own connection. For the `channel`
+			data type, a single subscription is shared, and received messages are decoded by this many workers.
+
+			When set to more than one, events are not guaranteed to be emitted in the order they were read from Redis.
+			"""
+		required: false
+		type: uint: default: 1
+	}
 	data_type: {
 		description: "The Redis data type (`list` or `channel`) to use."
 		required:    false
@@ -34,6 +72,19 @@ base: components: sources: redis: configuration: {
 			}
 		}
 	}
+	failover_urls: {
+		description: """
+			Additional Redis URLs to fail over to.
+
+			When the connection to the current endpoint is lost, the source will try to reconnect to `url` and then each of
+			these URLs in order, backing off between full rounds of attempts, until one of them succeeds.
+			"""
+		required: false
+		type: array: {
+			default: []
+			items: type: string: syntax: "literal"
+		}
+	}
 	framing: {
 		description: "Configuration for building a `Framer`."
 		required:    false
@@ -99,9 +150,16 @@ base: components: sources: redis: configuration: {
 		}
 	}
 	key: {
-		description: "The Redis key to read messages from."
-		required:    true
-		type: string: syntax: "literal"
+		description: """
+			The Redis key to read messages from.
+
+			For the `channel` data type, this can be left empty if `channel.channels` or `channel.patterns` is set.
+			"""
+		required: false
+		type: string: {
+			default: ""
+			syntax:  "literal"
+		}
 	}
 	list: {
 		description: "Options for the Redis `list` data type."
@@ -115,13 +173,109 @@ base: components: sources: redis: configuration: {
 			}
 		}
 	}
+	log_namespace: {
+		description: "The namespace to use. This overrides the global setting."
+		required:    false
+		type: bool: {}
+	}
+	max_message_bytes: {
+		description: """
+			The maximum size, in bytes, of a message read from Redis.
+
+			Larger messages are handled according to `oversize_action` instead of being decoded, which protects against
+			a producer publishing arbitrarily large messages.
+
+			By default, messages of any size are decoded.
+			"""
+		required: false
+		type: uint: {}
+	}
+	metadata: {
+		description: """
+			Standard metadata to add to each event.
+
+			With the `Vector` log namespace, metadata is added to the event metadata: the source type and ingest timestamp
+			under `vector`, and the endpoint under `redis`. With the `Legacy` log namespace, it is added to the root of the
+			event instead, under the configured field names, unless a field with the same name already exists.
+			"""
+		required: false
+		type: object: options: {
+			endpoint_key: {
+				description: """
+					Sets the name of the log field to use to add the endpoint to each event.
+
+					The value will be the `host:port` (or socket path) of the Redis endpoint that the event was read from, which
+					can differ from `url` after failing over to one of the `failover_urls`.
+
+					By default, this is not set and the endpoint will not be added.
+					"""
+				required: false
+				type: string: syntax: "literal"
+			}
+			ingest_timestamp: {
+				description: """
+					Whether to add the time at which each event was received.
+
+					With the `Legacy` log namespace, the field name is set by `ingest_timestamp_key`.
+					"""
+				required: false
+				type: bool: default: true
+			}
+			ingest_timestamp_key: {
+				description: """
+					Sets the name of the log field to use to add the time at which each event was received, with the `Legacy` log
+					namespace.
+
+					By default, the global `log_schema.timestamp_key` option is used.
+					"""
+				required: false
+				type: string: syntax: "literal"
+			}
+			source_type: {
+				description: """
+					Whether to add the source type to each event.
+
+					With the `Legacy` log namespace, the field name is set by `source_type_key`.
+					"""
+				required: false
+				type: bool: default: true
+			}
+			source_type_key: {
+				description: """
+					Sets the name of the log field to use to add the source type to each event, with the `Legacy` log namespace.
+
+					By default, the global `log_schema.source_type_key` option is used.
+					"""
+				required: false
+				type: string: syntax: "literal"
+			}
+		}
+	}
+	oversize_action: {
+		description: "What to do with messages larger than `max_message_bytes`."
+		required:    false
+		type: string: {
+			default: "drop"
+			enum: {
+				drop: "Drop the message without decoding it."
+				truncate: """
+					Truncate the message to `max_message_bytes` and decode what is left.
+
+					Messages are cut at the last character boundary within the limit, so they stay valid UTF-8.
+					"""
+			}
+		}
+	}
 	redis_key: {
 		description: """
 			Sets the name of the log field to use to add the key to each event.
 
-			The value will be the Redis key that the event was read from.
+			The value will be the Redis key that the event was read from. For the `channel` data type, this is the channel
+			the message was published to, also when it was received through a pattern. With the `Legacy` log namespace, it
+			overwrites any existing field of the same name. With the `Vector` log namespace, the key is added to the event
+			metadata as `redis.key` instead.
 
-			By default, this is not set and the field will not be automatically added.
+			By default, this is not set and the key will not be added.
 			"""
 		required: false
 		type: string: syntax: "literal"
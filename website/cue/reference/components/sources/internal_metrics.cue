@@ -936,6 +936,24 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		redis_list_length: {
+			description:       "The number of messages waiting in the list the Redis source consumes, reported every 10 seconds."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		redis_messages_truncated_total: {
+			description:       "The total number of messages the Redis source truncated for being larger than `max_message_bytes`."
+			type:              "counter"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
+		redis_subscription_connected: {
+			description:       "Whether the Redis source is subscribed to its channel (`1`) or not (`0`)."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		request_errors_total: {
 			description:       "The total number of requests errors for this component."
 			type:              "counter"
@@ -69,10 +69,14 @@ components: sources: redis: {
 			}
 		}
 		key: {
-			description: "The Redis key to read messages from."
-			required:    true
+			description: """
+				The Redis key to read messages from. For the `channel` data type, this can be left empty if
+				`channel.channels` or `channel.patterns` is set.
+				"""
+			required: false
 			warnings: []
 			type: string: {
+				default: ""
 				examples: ["vector"]
 				syntax: "literal"
 			}
@@ -114,6 +118,82 @@ components: sources: redis: {
 				}
 			}
 		}
+		channel: {
+			common:      false
+			description: "Options for the Redis `channel` data type. All channels and patterns are subscribed to over a single connection."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: []
+				options: {
+					channels: {
+						common:      false
+						description: "Additional channels to subscribe to, along with `key`."
+						required:    false
+						type: array: {
+							default: []
+							items: type: string: {
+								examples: ["logs"]
+								syntax: "literal"
+							}
+						}
+					}
+					patterns: {
+						common:      false
+						description: "Channel patterns to subscribe to, using the glob-style syntax of the Redis `PSUBSCRIBE` command."
+						required:    false
+						type: array: {
+							default: []
+							items: type: string: {
+								examples: ["logs.*"]
+								syntax: "literal"
+							}
+						}
+					}
+				}
+			}
+		}
+		concurrency: {
+			common:      false
+			description: """
+				The number of consumers reading from Redis concurrently. For the `list` data type, each consumer pops
+				messages from the list over its own connection. For the `channel` data type, a single subscription is
+				shared, and received messages are decoded by this many workers.
+				"""
+			required: false
+			warnings: ["When set to more than `1`, events are not guaranteed to be emitted in the order they were read from Redis."]
+			type: uint: {
+				default: 1
+				unit:    null
+			}
+		}
+		max_message_bytes: {
+			common:      false
+			description: """
+				The maximum size, in bytes, of a message read from Redis. Larger messages are handled according to
+				`oversize_action` instead of being decoded. By default, messages of any size are decoded.
+				"""
+			required: false
+			warnings: []
+			type: uint: {
+				default: null
+				examples: [1048576]
+				unit: "bytes"
+			}
+		}
+		oversize_action: {
+			common:      false
+			description: "What to do with messages larger than `max_message_bytes`."
+			required:    false
+			type: string: {
+				default: "drop"
+				enum: {
+					drop:     "Drop the message without decoding it."
+					truncate: "Truncate the message to `max_message_bytes`, at the last character boundary within the limit, and decode what is left."
+				}
+				syntax: "literal"
+			}
+		}
 		redis_key: {
 			common:      false
 			description: "The log field name to use for the redis key. If set to an empty string or null, the key is not added to the log event."
@@ -125,6 +205,39 @@ components: sources: redis: {
 				syntax: "literal"
 			}
 		}
+		metadata: {
+			common:      false
+			description: "Standard metadata to add to each event. With the `Vector` log namespace, metadata is added to the event metadata rather than to the event itself."
+			required:    false
+			warnings: []
+			type: object: {
+				examples: []
+				options: {
+					source_type: {
+						common:      false
+						description: "Whether to add the source type to each event."
+						required:    false
+						type: bool: default: true
+					}
+					ingest_timestamp: {
+						common:      false
+						description: "Whether to add the time at which each event was received."
+						required:    false
+						type: bool: default: true
+					}
+					endpoint_key: {
+						common:      false
+						description: "The log field name to use for the Redis endpoint the event was read from. If not set, the endpoint is not added to the log event."
+						required:    false
+						type: string: {
+							default: null
+							examples: ["redis_endpoint"]
+							syntax: "literal"
+						}
+					}
+				}
+			}
+		}
 	}
 
 	output: logs: record: {
@@ -149,10 +262,44 @@ components: sources: redis: {
 					default: null
 				}
 			}
+			redis_endpoint: {
+				description: "The Redis endpoint the event was read from"
+				required:    false
+				common:      false
+				type: string: {
+					examples: ["127.0.0.1:6379"]
+					default: null
+				}
+			}
 		}
 	}
 
 	how_it_works: {
+		multiple_events_per_message: {
+			title: "Multiple events per message"
+			body:  """
+				By default, each Redis message is decoded into a single event. If producers batch several
+				events into one message, such as newline-delimited JSON documents, set `framing.method` to
+				`newline_delimited` to split each message on newlines before it is decoded, emitting one
+				event per line:
+
+				```toml
+				framing.method = "newline_delimited"
+				decoding.codec = "json"
+				```
+				"""
+		}
+		backlog_metrics: {
+			title: "Backlog metrics"
+			body:  """
+				For the `list` data type, the number of messages waiting in the list is reported every 10 seconds
+				as the `redis_list_length` gauge, which shows how far the source lags behind the producers. For
+				the `channel` data type, messages that aren't received while the subscription is down are lost
+				rather than queued up, so the `redis_subscription_connected` gauge is reported instead.
+
+				Consumer lag for Redis streams isn't reported, as the source doesn't support consuming streams.
+				"""
+		}
 		redis_rs: {
 			title: "redis-rs"
 			body:  """
@@ -164,10 +311,15 @@ components: sources: redis: {
 	}
 
 	telemetry: metrics: {
-		events_in_total:         components.sources.internal_metrics.output.metrics.events_in_total
-		events_out_total:        components.sources.internal_metrics.output.metrics.events_out_total
-		processed_bytes_total:   components.sources.internal_metrics.output.metrics.processed_bytes_total
-		processed_bytes_total:   components.sources.internal_metrics.output.metrics.processed_bytes_total
-		processing_errors_total: components.sources.internal_metrics.output.metrics.processing_errors_total
+		component_discarded_events_total: components.sources.internal_metrics.output.metrics.component_discarded_events_total
+		component_errors_total:           components.sources.internal_metrics.output.metrics.component_errors_total
+		events_in_total:                  components.sources.internal_metrics.output.metrics.events_in_total
+		events_out_total:                 components.sources.internal_metrics.output.metrics.events_out_total
+		processed_bytes_total:            components.sources.internal_metrics.output.metrics.processed_bytes_total
+		processed_bytes_total:            components.sources.internal_metrics.output.metrics.processed_bytes_total
+		processing_errors_total:          components.sources.internal_metrics.output.metrics.processing_errors_total
+		redis_list_length:                components.sources.internal_metrics.output.metrics.redis_list_length
+		redis_messages_truncated_total:   components.sources.internal_metrics.output.metrics.redis_messages_truncated_total
+		redis_subscription_connected:     components.sources.internal_metrics.output.metrics.redis_subscription_connected
 	}
 }
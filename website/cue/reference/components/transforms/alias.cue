@@ -0,0 +1,52 @@
+package metadata
+
+components: transforms: alias: {
+	title: "Alias"
+
+	description: """
+		Reuses the configuration of another transform under a different key. When the configuration
+		is loaded, the alias is replaced by a copy of the referenced transform, with its own inputs.
+		"""
+
+	classes: {
+		commonly_used: false
+		development:   "beta"
+		egress_method: "stream"
+		stateful:      false
+	}
+
+	features: {}
+
+	support: {
+		requirements: []
+		warnings: []
+		notices: []
+	}
+
+	configuration: base.components.transforms.alias.configuration
+
+	input: {
+		logs: true
+		metrics: {
+			counter:      true
+			distribution: true
+			gauge:        true
+			histogram:    true
+			set:          true
+			summary:      true
+		}
+		traces: true
+	}
+
+	how_it_works: {
+		resolution: {
+			title: "Resolution"
+			body: """
+				Aliases are resolved before any other transforms are expanded, so an alias of a `route`
+				transform has its own routes, referenced as `<alias_key>.<route_id>`. The referenced
+				transform can itself be an alias. Aliases that refer to each other in a cycle, or to a
+				transform that doesn't exist, are reported as configuration errors.
+				"""
+		}
+	}
+}
@@ -0,0 +1,11 @@
+package metadata
+
+base: components: transforms: alias: configuration: alias_of: {
+	description: """
+		The key of the transform to copy.
+
+		The referenced transform can itself be an alias, as long as the aliases don't form a cycle.
+		"""
+	required: true
+	type: string: {}
+}
@@ -90,6 +90,21 @@ configuration: {
 			}
 		}
 
+		fan_out_warning_threshold: {
+			common: false
+			description: """
+				The number of sinks a single component output can feed before Vector warns about it at
+				startup. Broad input globs, such as `*`, can make the same events flow into many sinks,
+				which is sometimes intended, but often a mistake.
+				"""
+			required: false
+			type: uint: {
+				default: 5
+				examples: [10]
+				unit: null
+			}
+		}
+
 		enrichment_tables: {
 			common:      false
 			description: """
@@ -536,6 +551,13 @@ configuration: {
 						option = "${ENV_VAR:?err}" # Vector exits with 'err' message if variable is unset or empty
 						option = "${ENV_VAR?err}" # Vector exits with 'err' message only if variable is unset
 						```
+
+						Any other variable that is unset and has no default value is also reported as
+						an error, along with the component and field it is used in:
+
+						```text
+						Environment variable "ENV_VAR" used in "sinks.out.address" is not set.
+						```
 						"""
 				},
 				{
@@ -250,6 +250,14 @@ remap: #Remap & {
 				  │ or change this to an infallible assignment:
 				  │ structured, err = parse_key_value(.message)
 				  │
+				  = try: specifying a default value in case the expression fails
+				  =
+				  =     structured = parse_key_value(.message) ?? {}
+				  =
+				  = try: aborting the program if the expression fails
+				  =
+				  =     structured = parse_key_value!(.message)
+				  =
 				  = see documentation about error handling at https://errors.vrl.dev/#handling
 				  = learn more about error code 103 at https://errors.vrl.dev/103
 				  = see language documentation at https://vrl.dev
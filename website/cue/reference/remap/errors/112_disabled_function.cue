@@ -0,0 +1,18 @@
+package metadata
+
+remap: errors: "112": {
+	title:       "Disabled function"
+	description: """
+		A [function call expression](\(urls.vrl_expressions)#function-call) calls a function that the
+		program's environment doesn't allow, such as `dns_lookup` or `reverse_dns` when DNS lookups are
+		disabled.
+		"""
+	rationale:   """
+		Some functions perform network I/O, which isn't acceptable everywhere VRL runs. Rejecting the
+		program when it's compiled surfaces this before any events are processed.
+		"""
+	resolution:  """
+		Remove the call to the disabled function, or enable the function in the environment running the
+		program.
+		"""
+}
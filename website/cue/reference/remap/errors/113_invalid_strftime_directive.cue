@@ -0,0 +1,29 @@
+package metadata
+
+remap: errors: "113": {
+	title:       "Invalid strftime directive"
+	description: """
+		The literal `format` argument of `format_timestamp` or `parse_timestamp` contains a directive that
+		isn't a valid [strftime](\(urls.chrono_time_formats)) directive.
+		"""
+	rationale:   """
+		An invalid directive would make every call to the function fail at runtime. Checking literal
+		formats when the program is compiled surfaces the mistake before any events are processed.
+		"""
+	resolution:  """
+		Correct the directive, or escape a literal `%` as `%%`.
+		"""
+
+	examples: [
+		{
+			"title": title
+			source: #"""
+				format_timestamp!(now(), format: "%Y-%m-%d %Q")
+				"""#
+			diff: #"""
+				-format_timestamp!(now(), format: "%Y-%m-%d %Q")
+				+format_timestamp!(now(), format: "%Y-%m-%d %H")
+				"""#
+		},
+	]
+}
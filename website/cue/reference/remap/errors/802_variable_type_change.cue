@@ -0,0 +1,34 @@
+package metadata
+
+remap: errors: "802": {
+	title: "Variable type change"
+	description: """
+		A variable that already holds a value of a known type is assigned a value of an unrelated type.
+		This warning is only emitted when explicitly enabled, such as in the VRL REPL.
+		"""
+
+	rationale: """
+		Reusing a variable for a value of a different type is valid, but often points to a logic error and
+		can lead to confusing type errors further down the program.
+		"""
+
+	resolution: """
+		Assign the new value to a differently named variable, or ignore the warning if the type change is
+		intentional.
+		"""
+
+	examples: [
+		{
+			"title": "Reused variable name"
+			source: #"""
+				count = 1
+				count = "one"
+				"""#
+			diff: #"""
+				count = 1
+				-count = "one"
+				+count_label = "one"
+				"""#
+		},
+	]
+}
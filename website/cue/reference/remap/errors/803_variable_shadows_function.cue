@@ -0,0 +1,33 @@
+package metadata
+
+remap: errors: "803": {
+	title: "Variable shadows function"
+	description: """
+		A variable is assigned for the first time under the name of a function, such as `length`.
+		This warning is only emitted when explicitly enabled, such as in the VRL REPL.
+		"""
+
+	rationale: """
+		Function calls always refer to the function, while the bare name refers to the variable. Using the
+		same name for both makes it easy to mix them up, which leads to confusing errors further down the
+		program.
+		"""
+
+	resolution: """
+		Give the variable a name that doesn't match a function, or ignore the warning if the name is
+		intentional.
+		"""
+
+	examples: [
+		{
+			"title": "Variable named after a function"
+			source: #"""
+				length = 5
+				"""#
+			diff: #"""
+				-length = 5
+				+max_length = 5
+				"""#
+		},
+	]
+}
@@ -0,0 +1,69 @@
+package metadata
+
+remap: functions: abbreviate_number: {
+	category: "Number"
+	description: #"""
+		Formats the `value` into a short, human-readable string by scaling it down by powers of 1000 (`K`, `M`, `G`,
+		`T`, `P`, `E`) or 1024 (`Ki`, `Mi`, `Gi`, `Ti`, `Pi`, `Ei`), such as `1.5K` for `1500`.
+		"""#
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The number to abbreviate."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "base"
+			description: "Whether to scale by powers of 1000 (`si`) or powers of 1024 (`binary`)."
+			required:    false
+			type: ["string"]
+			default: "si"
+			enum: {
+				si:     "Scale by powers of 1000, using the `K`, `M`, `G`, `T`, `P`, and `E` suffixes."
+				binary: "Scale by powers of 1024, using the `Ki`, `Mi`, `Gi`, `Ti`, `Pi`, and `Ei` suffixes."
+			}
+		},
+		{
+			name:        "precision"
+			description: "The number of decimal places to display, up to `17`."
+			required:    false
+			type: ["integer"]
+			default: 1
+		},
+		{
+			name:        "unit"
+			description: "A unit to append after the suffix, such as `B` for bytes."
+			required:    false
+			type: ["string"]
+			default: ""
+		},
+	]
+	internal_failure_reasons: [
+		"`precision` is negative or greater than `17`.",
+	]
+	return: {
+		types: ["string"]
+		rules: [
+			"Numbers smaller than the base are not given a suffix.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Abbreviate a number"
+			source: #"""
+				abbreviate_number(1500)
+				"""#
+			return: "1.5K"
+		},
+		{
+			title: "Abbreviate a number of bytes"
+			source: #"""
+				abbreviate_number(123456789, base: "binary", precision: 2, unit: "B")
+				"""#
+			return: "117.74MiB"
+		},
+	]
+}
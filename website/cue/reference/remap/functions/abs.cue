@@ -0,0 +1,42 @@
+package metadata
+
+remap: functions: abs: {
+	category: "Number"
+	description: """
+		Returns the absolute value of the `value`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The number to take the absolute value of."
+			required:    true
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["integer", "float"]
+		rules: [
+			"Returns an integer if `value` is an integer, and a float otherwise.",
+			"The absolute value of the smallest integer, `-9223372036854775808`, doesn't fit in an integer and saturates to the largest integer.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Absolute value of an integer"
+			source: #"""
+				abs(-42)
+				"""#
+			return: 42
+		},
+		{
+			title: "Absolute value of a float"
+			source: #"""
+				abs(-4.2)
+				"""#
+			return: 4.2
+		},
+	]
+}
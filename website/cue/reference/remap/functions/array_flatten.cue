@@ -0,0 +1,56 @@
+package metadata
+
+remap: functions: array_flatten: {
+	category: "Enumerate"
+	description: #"""
+		Flattens nested arrays in `value` up to `depth` levels deep. Elements that aren't arrays are kept as-is,
+		including objects, which are never flattened. Use `flatten` to fully flatten arrays or objects.
+		"""#
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to flatten."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "depth"
+			description: "The number of levels of nesting to flatten. A negative depth flattens all levels."
+			required:    false
+			default:     1
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["array"]
+		rules: [
+			"If `depth` is `0`, `value` is returned unchanged.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Flatten one level"
+			source: #"""
+				array_flatten([1, [2, [3, [4]]]])
+				"""#
+			return: [1, 2, [3, [4]]]
+		},
+		{
+			title: "Flatten two levels"
+			source: #"""
+				array_flatten([1, [2, [3, [4]]]], depth: 2)
+				"""#
+			return: [1, 2, 3, [4]]
+		},
+		{
+			title: "Flatten all levels"
+			source: #"""
+				array_flatten([1, [2, [3, [4]]]], depth: -1)
+				"""#
+			return: [1, 2, 3, 4]
+		},
+	]
+}
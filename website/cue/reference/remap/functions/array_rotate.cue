@@ -0,0 +1,48 @@
+package metadata
+
+remap: functions: array_rotate: {
+	category: "Enumerate"
+	description: #"""
+		Cyclically shifts the elements of `value` by `count` positions. Elements shifted past the end of the
+		array wrap around to the start.
+		"""#
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to rotate."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "count"
+			description: "The number of positions to shift the elements by. A positive count shifts them towards the end, a negative count towards the start."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["array"]
+		rules: [
+			"Shifting by a multiple of the array's length returns `value` unchanged.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Rotate towards the end"
+			source: #"""
+				array_rotate([1, 2, 3, 4], 1)
+				"""#
+			return: [4, 1, 2, 3]
+		},
+		{
+			title: "Rotate towards the start"
+			source: #"""
+				array_rotate([1, 2, 3, 4], -1)
+				"""#
+			return: [2, 3, 4, 1]
+		},
+	]
+}
@@ -0,0 +1,45 @@
+package metadata
+
+remap: functions: byte_size: {
+	category: "Type"
+	description: """
+		Estimates the size of `value` in bytes.
+
+		The estimate is independent of the platform and of how Vector stores values in memory, so it is
+		stable across releases:
+
+		* Strings and regular expressions count their length in bytes.
+		* Integers, floats, and timestamps count 8 bytes.
+		* Booleans and `null` count 1 byte.
+		* Arrays count 8 bytes plus the size of their elements.
+		* Objects count 8 bytes plus the length of their keys and the size of their values.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to estimate the size of."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Estimate the size of a string"
+			source: #"""
+				byte_size("foobar")
+				"""#
+			return: 6
+		},
+		{
+			title: "Estimate the size of an object"
+			source: #"""
+				byte_size({ "foo": "bar", "baz": [1, true] })
+				"""#
+			return: 34
+		},
+	]
+}
@@ -0,0 +1,59 @@
+package metadata
+
+remap: functions: center: {
+	category: "String"
+	description: """
+		Centers the `value` within `width` characters by adding `pad` to both sides, repeating it as
+		often as needed. If the padding can't be split evenly, the extra character goes after `value`.
+		Each side starts with a fresh repetition of `pad`, and its last repetition is cut short if it
+		doesn't fit.
+
+		The width is measured in [unicode scalar values](https://www.unicode.org/glossary/#unicode_scalar_value),
+		like `strlen` does, not in bytes. If `value` is already at least `width` characters long, it's
+		returned unchanged.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to center."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "width"
+			description: "The number of characters to center the string in, up to 1048576 (1Mi)."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "pad"
+			description: "The string to pad with. It can't be empty."
+			required:    false
+			default:     " "
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`pad` is an empty string.",
+		"`width` is larger than 1048576 (1Mi).",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Center with spaces"
+			source: """
+				center("abc", 7)
+				"""
+			return: "  abc  "
+		},
+		{
+			title: "Center a heading"
+			source: """
+				center(" Report ", 16, "=")
+				"""
+			return: "==== Report ===="
+		},
+	]
+}
@@ -0,0 +1,56 @@
+package metadata
+
+remap: functions: clamp: {
+	category: "Number"
+	description: """
+		Restricts the `value` to the range between `min` and `max`, inclusive.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The number to restrict."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "min"
+			description: "The lower bound of the range."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "max"
+			description: "The upper bound of the range."
+			required:    true
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: [
+		"`min` is greater than `max`",
+	]
+	return: {
+		types: ["integer", "float"]
+		rules: [
+			"Returns an integer if `value`, `min`, and `max` are all integers. Returns a float otherwise.",
+			"If `min` and `max` are literals, `min` being greater than `max` is a compile-time error.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Cap a score"
+			source: #"""
+				clamp(150, min: 0, max: 100)
+				"""#
+			return: 100
+		},
+		{
+			title: "Clamp with float bounds"
+			source: #"""
+				clamp(2, min: 0, max: 1.5)
+				"""#
+			return: 1.5
+		},
+	]
+}
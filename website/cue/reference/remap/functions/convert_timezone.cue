@@ -0,0 +1,55 @@
+package metadata
+
+remap: functions: convert_timezone: {
+	category: "Timestamp"
+	description: """
+		Reads the date and time of the `value` as the local time in the given `timezone`, and returns
+		the matching instant in UTC.
+
+		Use this for timestamps that were parsed without an offset but are actually in local time.
+		To render a timestamp in a time zone instead, use the `timezone` argument of
+		[`format_timestamp`](#format_timestamp).
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The timestamp holding the local date and time."
+			required:    true
+			type: ["timestamp"]
+		},
+		{
+			name:        "timezone"
+			description: "The [IANA time zone name](\(urls.iana_time_zones)) the date and time are local to, such as `America/New_York`."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`timezone` isn't a known time zone name.",
+		"The local time doesn't exist in `timezone`, because clocks went forward over it.",
+	]
+	return: {
+		types: ["timestamp"]
+		rules: [
+			"Local times that occur twice, because clocks went back, resolve to the earlier instant.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Convert a local time to UTC"
+			source: #"""
+				convert_timezone!(t'2021-02-10T23:32:00Z', "America/New_York")
+				"""#
+			return: "2021-02-11T04:32:00Z"
+		},
+		{
+			title: "Convert a local time during daylight saving time"
+			source: #"""
+				convert_timezone!(t'2021-07-01T09:00:00Z', "Europe/Paris")
+				"""#
+			return: "2021-07-01T07:00:00Z"
+		},
+	]
+}
@@ -0,0 +1,62 @@
+package metadata
+
+remap: functions: decode_charset: {
+	category:    "Codec"
+	description: """
+		Decodes the `value` from the given `charset` into a UTF-8 string.
+
+		Byte order marks are kept as part of the decoded string.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The data to decode."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name: "charset"
+			description: """
+				The [label](\(urls.encoding_charset_labels)) of the character set the data is encoded
+				with, such as `latin1`, `shift_jis`, or `windows-1252`. Labels are matched
+				case-insensitively. As in web browsers, `latin1` and `iso-8859-1` refer to
+				`windows-1252`.
+				"""
+			required: true
+			type: ["string"]
+		},
+		{
+			name: "lossy"
+			description: """
+				Whether to replace sequences that aren't valid in `charset` with the replacement
+				character (`�`) instead of failing.
+				"""
+			required: false
+			type: ["boolean"]
+			default: false
+		},
+	]
+	internal_failure_reasons: [
+		"`charset` isn't a known character set.",
+		"`value` contains sequences that aren't valid in `charset`, and `lossy` isn't set.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decode Latin-1 data"
+			source: """
+				decode_charset!(decode_base64!("Y2Fm6Q=="), "latin1")
+				"""
+			return: "café"
+		},
+		{
+			title: "Decode invalid Shift-JIS data lossily"
+			source: """
+				decode_charset(decode_base64!("gg=="), "shift_jis", lossy: true)
+				"""
+			return: "�"
+		},
+	]
+}
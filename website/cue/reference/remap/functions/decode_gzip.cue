@@ -0,0 +1,34 @@
+package metadata
+
+remap: functions: decode_gzip: {
+	category:    "Codec"
+	description: """
+		Decodes the `value` (a [Gzip](\(urls.gzip)) string) into its original string.
+		Multiple concatenated Gzip members are decoded as a single stream.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The [Gzip](\(urls.gzip)) data to decode."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid encoded Gzip string.",
+		"The decoded value is larger than 1Mi bytes.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decode Gzip data"
+			source: #"""
+				encoded_text = decode_base64!("H4sIAAAAAAACAyvISU0sTlVISU3OT0lVyE0FAJsZ870QAAAA")
+				decode_gzip!(encoded_text)
+				"""#
+			return: "please decode me"
+		},
+	]
+}
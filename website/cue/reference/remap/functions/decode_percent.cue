@@ -13,8 +13,20 @@ remap: functions: decode_percent: {
 			required:    true
 			type: ["string"]
 		},
+		{
+			name: "strict"
+			description: """
+				Whether to fail on malformed sequences, where a `%` isn't followed by two hexadecimal digits.
+				By default, malformed sequences are left as-is.
+				"""
+			required: false
+			type: ["boolean"]
+			default: false
+		},
+	]
+	internal_failure_reasons: [
+		"`strict` is `true` and `value` contains a malformed percent-encoded sequence.",
 	]
-	internal_failure_reasons: []
 	return: types: ["string"]
 
 	examples: [
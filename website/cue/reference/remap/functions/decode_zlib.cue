@@ -0,0 +1,33 @@
+package metadata
+
+remap: functions: decode_zlib: {
+	category:    "Codec"
+	description: """
+		Decodes the `value` (a [Zlib](\(urls.zlib)) string) into its original string.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The [Zlib](\(urls.zlib)) data to decode."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid encoded Zlib string.",
+		"The decoded value is larger than 1Mi bytes.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decode Zlib data"
+			source: #"""
+				encoded_text = decode_base64!("eJwryElNLE5VSElNzk9JVchNBQAz9AXx")
+				decode_zlib!(encoded_text)
+				"""#
+			return: "please decode me"
+		},
+	]
+}
@@ -0,0 +1,33 @@
+package metadata
+
+remap: functions: decode_zstd: {
+	category:    "Codec"
+	description: """
+		Decodes the `value` (a [Zstandard](\(urls.zstd)) string) into its original string.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The [Zstandard](\(urls.zstd)) data to decode."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid encoded Zstandard string.",
+		"The decoded value is larger than 1Mi bytes.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decode Zstandard data"
+			source: #"""
+				encoded_text = decode_base64!("KLUv/QBYgQAAcGxlYXNlIGRlY29kZSBtZQ==")
+				decode_zstd!(encoded_text)
+				"""#
+			return: "please decode me"
+		},
+	]
+}
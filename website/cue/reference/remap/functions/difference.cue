@@ -0,0 +1,40 @@
+package metadata
+
+remap: functions: difference: {
+	category: "Enumerate"
+	description: #"""
+		Returns the values of `value` that aren't present in `other`, treating both arrays as sets
+		of values.
+
+		Duplicate values are removed, and the first occurrence of each value is kept, in order.
+		"""#
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to remove values from."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "other"
+			description: "The values to remove."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["array"]
+	}
+
+	examples: [
+		{
+			title: "Difference"
+			source: #"""
+				difference(["a", "b", "c", "a"], ["b"])
+				"""#
+			return: ["a", "c"]
+		},
+	]
+}
@@ -0,0 +1,54 @@
+package metadata
+
+remap: functions: dns_lookup: {
+	category: "System"
+	description: """
+		Looks up the IP addresses of the host `value` using the system resolver.
+		"""
+	notices: [
+		"""
+			Lookups block the event being processed until they complete or time out after one second. Successful
+			results are cached for a minute, so repeated lookups of the same host are cheap.
+			""",
+		"""
+			Applications embedding VRL can change the timeout and cache, or disable this function, in which case
+			programs calling it fail to compile.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The host name to look up."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "record_type"
+			description: "The type of address to look up."
+			required:    false
+			default:     "A"
+			enum: {
+				A:    "IPv4 addresses."
+				AAAA: "IPv6 addresses."
+			}
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"The lookup fails, for example because the host doesn't exist.",
+		"The lookup times out.",
+		"Too many lookups are already waiting to run.",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Look up the IPv4 addresses of a host"
+			source: #"""
+				dns_lookup!("localhost")
+				"""#
+			return: ["127.0.0.1"]
+		},
+	]
+}
@@ -0,0 +1,63 @@
+package metadata
+
+remap: functions: encode_charset: {
+	category:    "Codec"
+	description: """
+		Encodes the UTF-8 `value` into the given `charset`.
+
+		UTF-16 can only be decoded, so it isn't accepted as a `charset`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to encode."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name: "charset"
+			description: """
+				The [label](\(urls.encoding_charset_labels)) of the character set to encode the
+				string with, such as `latin1`, `shift_jis`, or `windows-1252`. Labels are matched
+				case-insensitively. As in web browsers, `latin1` and `iso-8859-1` refer to
+				`windows-1252`.
+				"""
+			required: true
+			type: ["string"]
+		},
+		{
+			name: "lossy"
+			description: """
+				Whether to replace characters that can't be represented in `charset` with `?`
+				instead of failing. Invalid UTF-8 in `value` is replaced as well.
+				"""
+			required: false
+			type: ["boolean"]
+			default: false
+		},
+	]
+	internal_failure_reasons: [
+		"`charset` isn't a known character set, or can only be decoded.",
+		"`value` isn't valid UTF-8, and `lossy` isn't set.",
+		"`value` contains characters that can't be represented in `charset`, and `lossy` isn't set.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Encode to Latin-1"
+			source: """
+				encode_base64(encode_charset!("café", "latin1"))
+				"""
+			return: "Y2Fm6Q=="
+		},
+		{
+			title: "Encode to Latin-1 lossily"
+			source: """
+				encode_charset("snowman ☃", "latin1", lossy: true)
+				"""
+			return: "snowman ?"
+		},
+	]
+}
@@ -4,6 +4,8 @@ remap: functions: encode_json: {
 	category: "Codec"
 	description: """
 		Encodes the `value` to JSON.
+
+		Object keys are always encoded in sorted order, so the output is deterministic.
 		"""
 
 	arguments: [
@@ -13,6 +15,13 @@ remap: functions: encode_json: {
 			required:    true
 			type: ["any"]
 		},
+		{
+			name:        "pretty"
+			description: "Whether to format the JSON over multiple lines, indented by two spaces."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
 	]
 	internal_failure_reasons: []
 	return: types: ["string"]
@@ -25,5 +34,16 @@ remap: functions: encode_json: {
 				"""#
 			return: #"{"hello":"world"}"#
 		},
+		{
+			title: "Encode to pretty JSON"
+			source: #"""
+				.payload = encode_json({"hello": "world"}, pretty: true)
+				"""#
+			return: """
+				{
+				  "hello": "world"
+				}
+				"""
+		},
 	]
 }
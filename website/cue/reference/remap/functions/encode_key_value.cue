@@ -10,6 +10,10 @@ remap: functions: encode_key_value: {
 		"""
 			If `fields_ordering` is specified then the function is fallible else it is infallible.
 			""",
+		"""
+			Keys and values containing whitespace, `"`, or the `key_value_delimiter`, and empty values, are
+			quoted.
+			""",
 	]
 
 	arguments: [
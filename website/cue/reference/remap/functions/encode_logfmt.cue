@@ -4,8 +4,16 @@ remap: functions: encode_logfmt: {
 	category:    "Codec"
 	description: """
 		Encodes the `value` to [logfmt](\(urls.logfmt)).
+
+		Values containing whitespace, `"`, or `=`, and empty values, are quoted, with `"` and `\\` escaped
+		inside them.
+		"""
+	notices: [
 		"""
-	notices:     functions.encode_key_value.notices
+			If `fields_ordering` is specified, or `flatten` is `false`, then the function is fallible else it
+			is infallible.
+			""",
+	]
 
 	arguments: [
 		{
@@ -20,9 +28,20 @@ remap: functions: encode_logfmt: {
 			required:    false
 			type: ["array"]
 		},
+		{
+			name:        "flatten"
+			description: """
+				Whether to flatten nested objects and arrays into dotted keys, such as `agent.name`. If `false`,
+				nested values cause an error.
+				"""
+			required: false
+			default:  true
+			type: ["boolean"]
+		},
 	]
 	internal_failure_reasons: [
 		"`fields_ordering` contains a non-string element",
+		"`flatten` is `false` and `value` contains a nested object or array",
 	]
 	return: types: ["string"]
 
@@ -0,0 +1,39 @@
+package metadata
+
+remap: functions: encode_zstd: {
+	category:    "Codec"
+	description: """
+		Encodes the `value` to [Zstandard](\(urls.zstd)).
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to encode."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "compression_level"
+			description: "The compression level to use, between 1 and 22."
+			required:    false
+			type: ["integer"]
+			default: 3
+		},
+	]
+	internal_failure_reasons: [
+		"`compression_level` isn't between 1 and 22.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Encode to Zstandard"
+			source: #"""
+				encoded_text = encode_zstd("please encode me")
+				encode_base64(encoded_text)
+				"""#
+			return: "KLUv/QBYgQAAcGxlYXNlIGVuY29kZSBtZQ=="
+		},
+	]
+}
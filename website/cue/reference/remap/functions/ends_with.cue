@@ -15,9 +15,12 @@ remap: functions: ends_with: {
 		},
 		{
 			name:        "substring"
-			description: "The substring with which `value` must end."
-			required:    true
-			type: ["string"]
+			description: """
+				The substring with which `value` must end. If an array of substrings is given, `value` must
+				end with any of them.
+				"""
+			required: true
+			type: ["string", "array"]
 		},
 		{
 			name:        "case_sensitive"
@@ -27,7 +30,9 @@ remap: functions: ends_with: {
 			default: true
 		},
 	]
-	internal_failure_reasons: []
+	internal_failure_reasons: [
+		"`substring` is an array containing a value that isn't a string",
+	]
 	return: types: ["boolean"]
 
 	examples: [
@@ -45,5 +50,12 @@ remap: functions: ends_with: {
 				"""#
 			return: true
 		},
+		{
+			title: "String ends with any"
+			source: #"""
+				ends_with("The Needle In The Haystack", ["The Field", "The Haystack"])
+				"""#
+			return: true
+		},
 	]
 }
@@ -0,0 +1,29 @@
+package metadata
+
+remap: functions: enumerate: {
+	category: "Array"
+	description: """
+		Returns an array of `[index, element]` pairs for each element of the `value` array.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to enumerate."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Enumerate an array"
+			source: #"""
+				enumerate(["a", "b", "c"])
+				"""#
+			return: [[0, "a"], [1, "b"], [2, "c"]]
+		},
+	]
+}
@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: find_all: {
+	category: "String"
+	description: """
+		Returns all substrings of `value` that match the [Regex](\(urls.regex)) `pattern`, as a flat array of strings.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to search."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "pattern"
+			description: "The regular expression pattern to search for."
+			required:    true
+			type: ["regex"]
+		},
+		{
+			name: "group"
+			description: """
+				The index of the capture group to extract from each match. The 0th group is the whole match.
+				Matches in which the group doesn't participate are skipped.
+				"""
+			required: false
+			default:  0
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Find all numbers"
+			source: #"""
+				find_all("a1 b22 c333", r'\d+')
+				"""#
+			return: ["1", "22", "333"]
+		},
+		{
+			title: "Extract a capture group"
+			source: #"""
+				find_all("user=alice user=bob", r'user=(\w+)', group: 1)
+				"""#
+			return: ["alice", "bob"]
+		},
+	]
+}
@@ -0,0 +1,50 @@
+package metadata
+
+remap: functions: find_first: {
+	category: "String"
+	description: """
+		Returns the first substring of `value` that matches the [Regex](\(urls.regex)) `pattern`, or `null` if
+		there is no match.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to search."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "pattern"
+			description: "The regular expression pattern to search for."
+			required:    true
+			type: ["regex"]
+		},
+		{
+			name:        "group"
+			description: "The index of the capture group to extract from the match. The 0th group is the whole match."
+			required:    false
+			default:     0
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string", "null"]
+
+	examples: [
+		{
+			title: "Find the first number"
+			source: #"""
+				find_first("a1 b22 c333", r'\d+')
+				"""#
+			return: "1"
+		},
+		{
+			title: "Extract a capture group"
+			source: #"""
+				find_first("user=alice user=bob", r'user=(\w+)', group: 1)
+				"""#
+			return: "alice"
+		},
+	]
+}
@@ -19,8 +19,19 @@ remap: functions: format_timestamp: {
 			required:    true
 			type: ["string"]
 		},
+		{
+			name:        "timezone"
+			description: """
+				The [IANA time zone name](\(urls.iana_time_zones)) to render the timestamp in, such as
+				`America/New_York`. Timestamps are rendered in UTC by default.
+				"""
+			required:    false
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`timezone` isn't a known time zone name.",
 	]
-	internal_failure_reasons: []
 	return: types: ["string"]
 
 	examples: [
@@ -38,5 +49,12 @@ remap: functions: format_timestamp: {
 				"""#
 			return: "21-Oct-2020 16:00"
 		},
+		{
+			title: "Format a timestamp in a time zone"
+			source: #"""
+				format_timestamp!(t'2020-10-21T16:00:00Z', format: "%+", timezone: "Asia/Tokyo")
+				"""#
+			return: "2020-10-22T01:00:00+09:00"
+		},
 	]
 }
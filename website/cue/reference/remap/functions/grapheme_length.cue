@@ -0,0 +1,43 @@
+package metadata
+
+remap: functions: grapheme_length: {
+	category: "Enumerate"
+	description: """
+		Returns the number of [grapheme clusters](https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries)
+		in `value`, which is the number of characters as perceived by a reader.
+
+		This differs from `length`, which counts the bytes of a string, and from `strlen`, which counts
+		its unicode scalar values. An emoji with a skin tone modifier or a letter followed by a combining
+		accent is a single grapheme, but is made up of several unicode scalar values and even more bytes.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string"
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["integer"]
+	}
+
+	examples: [
+		{
+			title: "grapheme_length"
+			source: """
+				grapheme_length("ñandú")
+				"""
+			return: 5
+		},
+		{
+			title: "grapheme_length with emoji modifiers"
+			source: """
+				grapheme_length("👍🏽")
+				"""
+			return: 1
+		},
+	]
+}
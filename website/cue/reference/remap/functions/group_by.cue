@@ -0,0 +1,58 @@
+package metadata
+
+remap: functions: group_by: {
+	category:    "Enumerate"
+	description: """
+		Groups the elements of an array into an object, where each key is the string returned by the closure
+		for an element, and each value is the array of elements that produced that key. The order of the
+		elements within each group is preserved.
+
+		The function uses the "function closure syntax" to allow reading
+		the index/value combination for each item in the array.
+
+		The same scoping rules apply to closure blocks as they do for
+		regular blocks, meaning, any variable defined in parent scopes
+		are accessible, and mutations to those variables are preserved,
+		but any new variables instantiated in the closure block are
+		unavailable outside of the block.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to group."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"The closure returns a value that isn't a string.",
+	]
+	return: {
+		types: ["object"]
+	}
+	examples: [
+		{
+			title: "Group events by level"
+			input: log: {
+				events: [
+					{level: "info", message: "first"},
+					{level: "error", message: "second"},
+					{level: "info", message: "third"},
+				]
+			}
+			source: #"""
+				group_by(array!(.events)) -> |_index, value| { string!(value.level) }
+				"""#
+			return: {
+				error: [
+					{level: "error", message: "second"},
+				]
+				info: [
+					{level: "info", message: "first"},
+					{level: "info", message: "third"},
+				]
+			}
+		},
+	]
+}
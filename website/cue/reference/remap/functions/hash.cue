@@ -0,0 +1,78 @@
+package metadata
+
+remap: functions: hash: {
+	category:    "Cryptography"
+	description: """
+		Calculates a hash of the `value` with the given `algorithm`, and encodes it as `encoding`.
+
+		Unlike the `md5`, `sha1` and `sha2` functions, the algorithm doesn't need to be known when
+		writing the program, which allows it to be read from the event or an environment variable.
+		If `algorithm` and `encoding` are constant, they are validated when the program is compiled
+		and the function can't fail.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to calculate the hash for."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "algorithm"
+			description: "The hash algorithm to use."
+			enum: {
+				md5:    "MD5 algorithm"
+				sha1:   "SHA-1 algorithm"
+				sha256: "SHA-256 algorithm"
+				sha512: "SHA-512 algorithm"
+			}
+			required: true
+			type: ["string"]
+		},
+		{
+			name:        "encoding"
+			description: "How the hash is encoded."
+			enum: {
+				hex:    "Lowercase hexadecimal"
+				base64: "Standard base64, with padding"
+			}
+			required: false
+			default:  "hex"
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`algorithm` isn't a supported algorithm.",
+		"`encoding` isn't a supported encoding.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Calculate a sha256 hash"
+			source: #"""
+				hash("foo", "sha256")
+				"""#
+			return: "2c26b46b68ffc68ff99b453c1d30413413422d706483bfa0f98a5e886266e7ae"
+		},
+		{
+			title: "Calculate a base64 encoded md5 hash"
+			source: #"""
+				hash("foo", "md5", encoding: "base64")
+				"""#
+			return: "rL0Y20zC+Fzt72VPzMSk2A=="
+		},
+		{
+			title: "Calculate a hash with an algorithm from the event"
+			input: log: {
+				message:   "foo"
+				algorithm: "sha1"
+			}
+			source: #"""
+				hash!(.message, .algorithm)
+				"""#
+			return: "0beec7b5ea3f0fdbc95d0dd47f3c5bc275da8a33"
+		},
+	]
+}
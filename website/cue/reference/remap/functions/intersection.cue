@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: intersection: {
+	category: "Enumerate"
+	description: #"""
+		Returns the values present in every one of the given arrays, treated as sets of values.
+
+		Duplicate values are removed, and values are kept in the order of their first occurrence
+		in the first array.
+		"""#
+
+	arguments: [
+		{
+			name: "value"
+			description: """
+				The first array. If `other` is omitted, an array of the arrays to intersect.
+				"""
+			required: true
+			type: ["array"]
+		},
+		{
+			name:        "other"
+			description: "The second array."
+			required:    false
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`other` is omitted and `value` contains an element that isn't an array",
+	]
+	return: {
+		types: ["array"]
+	}
+
+	examples: [
+		{
+			title: "Intersection of two arrays"
+			source: #"""
+				intersection(["a", "b", "c", "b"], ["b", "d", "a"])
+				"""#
+			return: ["a", "b"]
+		},
+		{
+			title: "Intersection of many arrays"
+			source: #"""
+				intersection([[1, 2, 3], [2, 3, 4], [3, 4, 5]])
+				"""#
+			return: [3]
+		},
+	]
+}
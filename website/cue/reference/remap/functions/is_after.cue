@@ -0,0 +1,40 @@
+package metadata
+
+remap: functions: is_after: {
+	category: "Timestamp"
+	description: """
+		Determines whether the `value` timestamp is later than the `other` timestamp.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The timestamp to compare."
+			required:    true
+			type: ["timestamp"]
+		},
+		{
+			name:        "other"
+			description: "The timestamp to compare `value` with."
+			required:    true
+			type: ["timestamp"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["boolean"]
+		rules: [
+			"Returns `true` if `value` is strictly later than `other`, and `false` if they're equal.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Compare timestamps"
+			source: #"""
+				is_after(t'2021-01-02T00:00:00Z', t'2021-01-01T00:00:00Z')
+				"""#
+			return: true
+		},
+	]
+}
@@ -3,7 +3,10 @@ package metadata
 remap: functions: is_empty: {
 	category: "Type"
 	description: """
-		Check if the object, array, or string has a length of 0.
+		Check if the `value` is null, or an object, array, or string with a length of 0.
+
+		All other values, including `0`, `false`, and whitespace-only strings, aren't considered
+		empty. Use `is_nullish` to also treat whitespace-only strings and `-` as empty.
 		"""
 
 	arguments: [
@@ -11,15 +14,15 @@ remap: functions: is_empty: {
 			name:        "value"
 			description: #"The value to check."#
 			required:    true
-			type: ["object", "array", "string"]
+			type: ["any"]
 		},
 	]
 	internal_failure_reasons: []
 	return: {
 		types: ["boolean"]
 		rules: [
-			#"Returns `true` if `value` is empty."#,
-			#"Returns `false` if `value` is non-empty."#,
+			#"Returns `true` if `value` is `null`, or an empty string, array, or object."#,
+			#"Returns `false` for non-empty strings, arrays, and objects, and all other types of values."#,
 		]
 	}
 
@@ -38,6 +41,20 @@ remap: functions: is_empty: {
 				"""
 			return: false
 		},
+		{
+			title: "Null"
+			source: """
+				is_empty(null)
+				"""
+			return: true
+		},
+		{
+			title: "Number"
+			source: """
+				is_empty(0)
+				"""
+			return: false
+		},
 		{
 			title: "Non-empty object"
 			source: """
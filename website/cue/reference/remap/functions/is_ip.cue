@@ -0,0 +1,49 @@
+package metadata
+
+remap: functions: is_ip: {
+	category: "IP"
+	description: """
+		Check if the value is a valid IPv4 or IPv6 address.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The IP address to check"
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["boolean"]
+		rules: [
+			#"Returns `true` if `value` is a valid IPv4 or IPv6 address."#,
+			#"Returns `false` if `value` is anything else."#,
+		]
+	}
+
+	examples: [
+		{
+			title: "Valid IPv4 address"
+			source: """
+				is_ip("10.0.102.37")
+				"""
+			return: true
+		},
+		{
+			title: "Valid IPv6 address"
+			source: """
+				is_ip("2001:0db8:85a3:0000:0000:8a2e:0370:7334")
+				"""
+			return: true
+		},
+		{
+			title: "Arbitrary string"
+			source: """
+				is_ip("foobar")
+				"""
+			return: false
+		},
+	]
+}
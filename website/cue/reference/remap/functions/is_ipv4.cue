@@ -15,7 +15,7 @@ remap: functions: is_ipv4: {
 			name:        "value"
 			description: "The IP address to check"
 			required:    true
-			type: ["string"]
+			type: ["any"]
 		},
 	]
 	internal_failure_reasons: []
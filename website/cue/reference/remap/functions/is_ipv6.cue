@@ -11,7 +11,7 @@ remap: functions: is_ipv6: {
 			name:        "value"
 			description: "The IP address to check"
 			required:    true
-			type: ["string"]
+			type: ["any"]
 		},
 	]
 	internal_failure_reasons: []
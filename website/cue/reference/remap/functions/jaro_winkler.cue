@@ -0,0 +1,36 @@
+package metadata
+
+remap: functions: jaro_winkler: {
+	category: "String"
+	description: """
+		Returns the [Jaro-Winkler similarity](\(urls.jaro_winkler_distance)) of `value` and `other`, from `0.0` for
+		strings with nothing in common to `1.0` for identical strings. Strings that share a prefix score higher.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The first string to compare."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "other"
+			description: "The second string to compare."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["float"]
+
+	examples: [
+		{
+			title: "Compare similar strings"
+			source: #"""
+				round(jaro_winkler("martha", "marhta"), precision: 3)
+				"""#
+			return: 0.961
+		},
+	]
+}
@@ -5,6 +5,11 @@ remap: functions: join: {
 	description: #"""
 		Joins each string in the `value` array into a single string, with items optionally separated from one another
 		by a `separator`.
+
+		Integers, floats, booleans, and timestamps are converted to strings, and `null` to an empty string, unless
+		`coerce` is `false`. To join arrays or objects, pass a closure that formats each item as a string. The
+		function uses the "function closure syntax" to allow reading the index/value combination for each item in
+		the array.
 		"""#
 
 	arguments: [
@@ -20,8 +25,18 @@ remap: functions: join: {
 			required:    false
 			type: ["string"]
 		},
+		{
+			name:        "coerce"
+			description: "Whether to convert items that aren't strings to strings. Ignored if a closure is passed."
+			required:    false
+			default:     true
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains arrays or objects, and no closure is passed.",
+		"`value` contains items that aren't strings, and `coerce` is `false`.",
 	]
-	internal_failure_reasons: []
 	return: {
 		types: ["string"]
 	}
@@ -41,5 +56,21 @@ remap: functions: join: {
 				"""#
 			return: "sources, transforms, sinks"
 		},
+		{
+			title: "Join array of mixed scalars"
+			source: #"""
+				join!(["status", 200, true], separator: " ")
+				"""#
+			return: "status 200 true"
+		},
+		{
+			title: "Join formatted objects"
+			source: #"""
+				join!([{"key": "a", "count": 1}, {"key": "b", "count": 2}], separator: ", ") -> |_index, value| {
+					value.key + "=" + to_string(value.count)
+				}
+				"""#
+			return: "a=1, b=2"
+		},
 	]
 }
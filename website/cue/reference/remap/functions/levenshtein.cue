@@ -0,0 +1,36 @@
+package metadata
+
+remap: functions: levenshtein: {
+	category: "String"
+	description: """
+		Returns the [Levenshtein distance](\(urls.levenshtein_distance)) between `value` and `other`: the minimum
+		number of single-character insertions, deletions, or substitutions needed to change one into the other.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The first string to compare."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "other"
+			description: "The second string to compare."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Compute an edit distance"
+			source: #"""
+				levenshtein("kitten", "sitting")
+				"""#
+			return: 3
+		},
+	]
+}
@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: logarithm: {
+	category: "Number"
+	description: """
+		Returns the logarithm of the `value` in the given `base`.
+
+		This function is named `logarithm` because the [`log`](#log) function writes log events.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The number to take the logarithm of."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "base"
+			description: "The base of the logarithm."
+			required:    true
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is zero or negative.",
+		"`base` is zero, negative, or `1`.",
+	]
+	return: {
+		types: ["float"]
+		rules: [
+			"Always returns a float, even if `value` and `base` are integers.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Base 10 logarithm"
+			source: #"""
+				logarithm!(1000, 10)
+				"""#
+			return: 3.0
+		},
+		{
+			title: "Base 2 logarithm"
+			source: #"""
+				logarithm!(0.25, 2)
+				"""#
+			return: -2.0
+		},
+	]
+}
@@ -33,6 +33,9 @@ remap: functions: map_keys: {
 		but any new variables instantiated in the closure block are
 		unavailable outside of the block.
 
+		If the closure maps multiple keys to the same key, the last one wins: keys are
+		visited in lexicographic order, and the value of the last key visited is kept.
+
 		Check out the examples below to learn about the closure syntax.
 		"""#
 
@@ -0,0 +1,67 @@
+package metadata
+
+remap: functions: max: {
+	category: "Number"
+	description: """
+		Returns the largest of the given numbers.
+
+		Pass either two numbers, or an array of numbers as `value`. When `value` is an array and
+		`other` is also given, `other` is compared along with the elements of the array.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "A number, or an array of numbers."
+			required:    true
+			type: ["integer", "float", "array"]
+		},
+		{
+			name:        "other"
+			description: "The number to compare `value` with."
+			required:    false
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is an array that contains a value that isn't an integer or float",
+		"`value` is an empty array and `other` isn't given",
+	]
+	return: {
+		types: ["integer", "float"]
+		rules: [
+			"Returns an integer if all numbers are integers. If any number is a float, all numbers are compared as floats and a float is returned.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Two numbers"
+			source: #"""
+				max(3, 1)
+				"""#
+			return: 3
+		},
+		{
+			title: "Integer and float"
+			source: #"""
+				max(3, 1.5)
+				"""#
+			return: 3.0
+		},
+		{
+			title: "Array of numbers"
+			source: #"""
+				max!([4, 2, 8])
+				"""#
+			return: 8
+		},
+		{
+			title: "Array of mixed numbers"
+			source: #"""
+				max!([4, 2, 8.5])
+				"""#
+			return: 8.5
+		},
+	]
+}
@@ -0,0 +1,53 @@
+package metadata
+
+remap: functions: object_from_entries: {
+	category: "Object"
+	description: """
+		Builds an object from an array of entries. Each entry is either a `[key, value]` array or an object with
+		`key` and `value` fields.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of entries to build the object from."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "on_duplicate"
+			description: "How to handle entries that share the same key."
+			required:    false
+			enum: {
+				error:      "Raise an error."
+				last_wins:  "Keep the value of the last entry with that key."
+				first_wins: "Keep the value of the first entry with that key."
+			}
+			default: "error"
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"An entry isn't a `[key, value]` array or a `{key, value}` object.",
+		"An entry's key isn't a string.",
+		"Two entries share the same key and `on_duplicate` is `error`.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Build an object from pairs"
+			source: #"""
+				object_from_entries([["foo", 1], ["bar", true]])
+				"""#
+			return: {"foo": 1, "bar": true}
+		},
+		{
+			title: "Keep the last duplicate"
+			source: #"""
+				object_from_entries([{"key": "foo", "value": 1}, {"key": "foo", "value": 2}], on_duplicate: "last_wins")
+				"""#
+			return: {"foo": 2}
+		},
+	]
+}
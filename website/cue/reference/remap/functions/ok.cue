@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: ok: {
+	category:    "Type"
+	description: """
+		Evaluates `value` and returns an object describing its outcome, with the result in the `value`
+		field and the error message in the `error` field. Exactly one of the fields is non-null.
+
+		Only errors are captured, an `abort` inside `value` still terminates the program.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The expression to evaluate, which can be fallible."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["object"]
+		rules: [
+			#"Returns `{"value": <result>, "error": null}` if `value` succeeds."#,
+			#"Returns `{"value": null, "error": <message>}` if `value` results in an error."#,
+		]
+	}
+
+	examples: [
+		{
+			title: "Capture an error"
+			source: """
+				ok(assert(1 == 2, "unexpected value"))
+				"""
+			return: {
+				value: null
+				error: "unexpected value"
+			}
+		},
+		{
+			title: "Successful expression"
+			source: """
+				ok(to_int("12"))
+				"""
+			return: {
+				value: 12
+				error: null
+			}
+		},
+	]
+}
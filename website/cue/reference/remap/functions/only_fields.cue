@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: only_fields: {
+	category:    "Object"
+	description: """
+		Returns a new object containing only the fields of `value` at the given `paths`, keeping
+		their nesting. Paths that don't exist in `value` are ignored.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to project."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name: "paths"
+			description: """
+				The paths of the fields to keep, such as `message` or `user.id`. Literal paths are
+				validated when the program is compiled.
+				"""
+			required: true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`paths` contains a value that isn't a valid field path.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Keep a subset of fields"
+			input: log: {
+				message: "login"
+				user: {
+					id:    "u123"
+					email: "user@example.com"
+				}
+				debug: true
+			}
+			source: #"""
+				. = only_fields(., ["message", "user.id"])
+				"""#
+			return: {
+				message: "login"
+				user: id: "u123"
+			}
+		},
+	]
+}
@@ -0,0 +1,57 @@
+package metadata
+
+remap: functions: pad_start: {
+	category: "String"
+	description: """
+		Pads the `value` to `length` characters by adding `pad` to its start, repeating it as often
+		as needed. If the `pad` doesn't fit a whole number of times, its last repetition is cut short.
+
+		The length is measured in [unicode scalar values](https://www.unicode.org/glossary/#unicode_scalar_value),
+		like `strlen` does, not in bytes. If `value` is already at least `length` characters long, it's
+		returned unchanged.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to pad."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "length"
+			description: "The number of characters to pad the string to, up to 1048576 (1Mi)."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "pad"
+			description: "The string to pad with. It can't be empty."
+			required:    false
+			default:     " "
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`pad` is an empty string.",
+		"`length` is larger than 1048576 (1Mi).",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Pad with zeros"
+			source: """
+				pad_start("42", 5, "0")
+				"""
+			return: "00042"
+		},
+		{
+			title: "Pad with spaces"
+			source: """
+				pad_start("foo", 5)
+				"""
+			return: "  foo"
+		},
+	]
+}
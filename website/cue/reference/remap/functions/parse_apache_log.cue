@@ -24,6 +24,9 @@ remap: functions: parse_apache_log: {
 			description: """
 				The [date/time format](https://docs.rs/chrono/latest/chrono/format/strftime/index.html) to use for
 				encoding the timestamp. The time is parsed in local time if the timestamp doesn't specify a timezone.
+
+				For the `error` format, when no format is given, timestamps written by Apache itself, such as
+				`Wed Oct 11 14:32:52 2000`, are also recognized.
 				"""
 			required: false
 			default:  "%d/%b/%Y:%T %z"
@@ -4,6 +4,7 @@ remap: functions: parse_csv: {
 	category: "Parse"
 	description: #"""
 		Parses a single CSV formatted row. Only the first row is parsed in case of multiline input value.
+		Use [`parse_csv_rows`](#parse_csv_rows) to parse all rows.
 		"""#
 	notices: [
 		"""
@@ -0,0 +1,67 @@
+package metadata
+
+remap: functions: parse_csv_line: {
+	category: "Parse"
+	description: #"""
+		Parses a single CSV formatted row. Only the first row is parsed in case of multiline input value.
+		Use [`parse_csv_rows`](#parse_csv_rows) to parse all rows.
+		"""#
+	notices: [
+		"""
+			All values are returned as strings. We recommend manually coercing values to desired types as you see fit.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to parse."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "delimiter"
+			description: "The field delimiter to use when parsing. Must be a single-byte utf8 character."
+			required:    false
+			default:     ","
+			type: ["string"]
+		},
+		{
+			name:        "quote"
+			description: "The character used to quote fields. Must be a single-byte utf8 character."
+			required:    false
+			default:     "\""
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"delimiter must be a single-byte utf8 character",
+		"quote must be a single-byte utf8 character",
+		"`value` isn't a valid CSV string",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Parse a single CSV formatted row"
+			source: #"""
+				parse_csv_line!("foo,bar,\"foo \"\", bar\"")
+				"""#
+			return: ["foo", "bar", #"foo ", bar"#]
+		},
+		{
+			title: "Parse a single CSV formatted row with custom delimiter"
+			source: #"""
+				parse_csv_line!("foo bar", delimiter: " ")
+				"""#
+			return: ["foo", "bar"]
+		},
+		{
+			title: "Parse a single CSV formatted row with custom quote character"
+			source: #"""
+				parse_csv_line!("foo;'bar;baz'", delimiter: ";", quote: "'")
+				"""#
+			return: ["foo", "bar;baz"]
+		},
+	]
+}
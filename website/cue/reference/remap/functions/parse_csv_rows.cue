@@ -0,0 +1,76 @@
+package metadata
+
+remap: functions: parse_csv_rows: {
+	category: "Parse"
+	description: #"""
+		Parses all CSV formatted rows in `value`, following the quoting rules of
+		[RFC 4180](\#(urls.rfc_4180)). Empty lines are skipped.
+		"""#
+	notices: [
+		"""
+			All values are returned as strings. We recommend manually coercing values to desired types as you see fit.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to parse."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "delimiter"
+			description: "The field delimiter to use when parsing. Must be a single-byte utf8 character."
+			required:    false
+			default:     ","
+			type: ["string"]
+		},
+		{
+			name:        "quote"
+			description: "The character used to quote fields. Must be a single-byte utf8 character."
+			required:    false
+			default:     "\""
+			type: ["string"]
+		},
+		{
+			name:        "headers"
+			description: """
+				Whether to use the first row as headers. If `true`, every other row is returned as an
+				object keyed by the headers, instead of an array of fields.
+				"""
+			required: false
+			default:  false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"delimiter must be a single-byte utf8 character",
+		"quote must be a single-byte utf8 character",
+		"`value` isn't a valid CSV string",
+		"`headers` is `true` and a row has more fields than there are headers",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"If `headers` is `true`, rows with fewer fields than there are headers leave out the remaining keys.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Parse CSV formatted rows"
+			source: #"""
+				parse_csv_rows!("foo,bar\n\"foo \"\", bar\",baz")
+				"""#
+			return: [["foo", "bar"], [#"foo ", bar"#, "baz"]]
+		},
+		{
+			title: "Parse CSV formatted rows with headers"
+			source: #"""
+				parse_csv_rows!("name,age\nalice,42\nbob,39", headers: true)
+				"""#
+			return: [{name: "alice", age: "42"}, {name: "bob", age: "39"}]
+		},
+	]
+}
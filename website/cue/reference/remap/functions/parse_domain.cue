@@ -0,0 +1,60 @@
+package metadata
+
+remap: functions: parse_domain: {
+	category:    "Parse"
+	description: """
+		Splits the hostname `value` into its subdomain, domain, and public suffix, using the bundled
+		[Public Suffix List](\(urls.public_suffix_list)). Unlike splitting off the last two labels, this
+		correctly handles suffixes made up of multiple labels, such as `co.uk`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The hostname to split."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid hostname.",
+		"`value` is an IP address.",
+	]
+	return: {
+		types: ["object"]
+		rules: [
+			"The hostname is lowercased, and a trailing `.` is ignored.",
+			"`registrable_domain` is the domain followed by the suffix, which is the part of the hostname that can be registered with a domain registrar.",
+			"`subdomain`, `domain`, and `registrable_domain` are `null` if they aren't part of the hostname, for example if it's only a public suffix.",
+			"Top-level domains that aren't in the list are treated as single label public suffixes.",
+			"The list includes private suffixes, such as `github.io`, so `user.github.io` is a registrable domain of its own.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Parse hostname with a multi-label suffix"
+			source: #"""
+				parse_domain!("www.example.co.uk")
+				"""#
+			return: {
+				subdomain:          "www"
+				domain:             "example"
+				suffix:             "co.uk"
+				registrable_domain: "example.co.uk"
+			}
+		},
+		{
+			title: "Parse hostname with nested subdomains"
+			source: #"""
+				parse_domain!("api.eu.vector.dev")
+				"""#
+			return: {
+				subdomain:          "api.eu"
+				domain:             "vector"
+				suffix:             "dev"
+				registrable_domain: "vector.dev"
+			}
+		},
+	]
+}
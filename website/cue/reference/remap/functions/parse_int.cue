@@ -16,21 +16,22 @@ remap: functions: parse_int: {
 		{
 			name: "base"
 			description: """
-				The base the number is in. Must be between 2 and 36 (inclusive).
+				The base the number is in. Must be between 2 and 36 (inclusive), or 0.
 
-				If unspecified, will use the string prefix to try to
-				determine the base: "0b", 8 for "0" or "0o", 16 for "0x",
-				and 10 otherwise
+				If unspecified or 0, will use the string prefix to try to
+				determine the base: 2 for "0b", 8 for "0" or "0o", 16 for "0x",
+				and 10 otherwise. A prefix is also accepted when it matches
+				an explicit base, such as "0x" with base 16.
 				"""
 			required: false
 			type: ["integer"]
 		},
 	]
 	internal_failure_reasons: [
-		"base is not between 2 and 36",
+		"base is not 0, or between 2 and 36",
 		"number cannot be parsed in the base",
 	]
-	return: types: ["string"]
+	return: types: ["integer"]
 
 	examples: [
 		{
@@ -68,5 +69,12 @@ remap: functions: parse_int: {
 				"""#
 			return: 44
 		},
+		{
+			title: "Parse with detected base"
+			source: #"""
+				parse_int!("-0o755", base: 0)
+				"""#
+			return: -493
+		},
 	]
 }
@@ -6,7 +6,8 @@ remap: functions: parse_logfmt: {
 		Parses the `value` in [logfmt](\(urls.logfmt)).
 
 		* Keys and values can be wrapped using the `\"` character.
-		* `\"` characters can be escaped by the `\\` character.
+		* In wrapped values, the `\\"`, `\\\\`, `\\n`, `\\r` and `\\t` escape sequences are
+		  resolved to the character they represent. Other backslashes are kept as is.
 		* As per this [logfmt specification](\(urls.logfmt_specs)), the `parse_logfmt` function
 		  accepts standalone keys and assigns them a Boolean value of `true`.
 		"""
@@ -18,6 +19,17 @@ remap: functions: parse_logfmt: {
 			required:    true
 			type: ["string"]
 		},
+		{
+			name:        "infer_types"
+			description: """
+				Whether to convert unwrapped values that are integers, floats or booleans into those
+				types. Values that could be written differently once converted, such as `007`, remain
+				strings, as do all wrapped values.
+				"""
+			required: false
+			default:  false
+			type: ["boolean"]
+		},
 	]
 	internal_failure_reasons: [
 		"`value` isn't a properly formatted key/value string",
@@ -41,5 +53,21 @@ remap: functions: parse_logfmt: {
 				module:           "kafka.consumer.ConsumerFetcherManager"
 			}
 		},
+		{
+			title: "Parse logfmt log with types"
+			source: #"""
+				parse_logfmt!(
+					"level=info status=200 duration=0.25 cached=false request_id=\"0042\"",
+					infer_types: true
+				)
+				"""#
+			return: {
+				level:      "info"
+				status:     200
+				duration:   0.25
+				cached:     false
+				request_id: "0042"
+			}
+		},
 	]
 }
@@ -20,7 +20,15 @@ remap: functions: parse_query_string: {
 		},
 	]
 	internal_failure_reasons: []
-	return: types: ["object"]
+	return: {
+		types: ["object"]
+		rules: [
+			"Keys and values are percent-decoded, and `+` is decoded as a space.",
+			"Keys that occur multiple times are returned as an array of their values, in order.",
+			"Keys without a value, such as `flag` in `flag&x=1`, are returned with an empty string as their value.",
+			"A leading `?` is ignored.",
+		]
+	}
 
 	examples: [
 		{
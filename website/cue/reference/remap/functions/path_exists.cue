@@ -0,0 +1,48 @@
+package metadata
+
+remap: functions: path_exists: {
+	category:    "Path"
+	description: """
+		Returns whether at least one value in `value` is matched by the given path, in the subset of
+		the JSONPath syntax supported by [`query`](#query).
+
+		Unlike [`exists`](#exists), the path can contain wildcards, such as `.items[*].error`, to
+		check whether any element of an array or field of an object has a given field.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to check."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "path"
+			description: "The path to check. Literal paths are validated when the program is compiled."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`path` isn't a valid path.",
+	]
+	return: types: ["boolean"]
+
+	examples: [
+		{
+			title: "Check a wildcard path"
+			source: #"""
+				path_exists({ "items": [{ "id": 1 }, { "id": 2, "error": "timeout" }] }, ".items[*].error")
+				"""#
+			return: true
+		},
+		{
+			title: "Check a wildcard path without matches"
+			source: #"""
+				path_exists({ "items": [{ "id": 1 }] }, ".items[*].error")
+				"""#
+			return: false
+		},
+	]
+}
@@ -0,0 +1,56 @@
+package metadata
+
+remap: functions: percentage: {
+	category: "Number"
+	description: """
+		Calculates what percentage `part` is of `whole`, returning `default` instead of raising an
+		error when `whole` is zero.
+		"""
+
+	arguments: [
+		{
+			name:        "part"
+			description: "The portion of `whole`."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "whole"
+			description: "The total that `part` is a portion of."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "default"
+			description: "The value to return when `whole` is zero. Defaults to `null`."
+			required:    false
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["float", "any"]
+		rules: [
+			"The result is always a float, even if both arguments are integers, and isn't rounded.",
+			"The result can exceed `100.0` if `part` is greater than `whole`.",
+			"Returns `default` if `whole` is zero, which is `null` unless specified.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Calculate a percentage"
+			source: #"""
+				percentage(1, 4)
+				"""#
+			return: 25.0
+		},
+		{
+			title: "Zero total with a default"
+			source: #"""
+				percentage(1, 0, default: 0.0)
+				"""#
+			return: 0.0
+		},
+	]
+}
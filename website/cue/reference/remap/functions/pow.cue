@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: pow: {
+	category: "Number"
+	description: """
+		Raises the `base` to the power of the `exponent`.
+		"""
+
+	arguments: [
+		{
+			name:        "base"
+			description: "The number to raise."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "exponent"
+			description: "The power to raise `base` to."
+			required:    true
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: [
+		"`base` and `exponent` are integers, and the result doesn't fit in an integer.",
+		"`base` and `exponent` are integers, and `exponent` is negative.",
+		"The result is too large to be represented as a float, or isn't a real number, such as for `pow(-8.0, 0.5)`.",
+	]
+	return: {
+		types: ["integer", "float"]
+		rules: [
+			"Returns an integer if `base` and `exponent` are both integers, and a float otherwise.",
+			"Results that overflow are errors rather than saturating.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Raise an integer"
+			source: #"""
+				pow!(2, 10)
+				"""#
+			return: 1024
+		},
+		{
+			title: "Raise to a float exponent"
+			source: #"""
+				pow!(9, 0.5)
+				"""#
+			return: 3.0
+		},
+	]
+}
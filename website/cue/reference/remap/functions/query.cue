@@ -0,0 +1,61 @@
+package metadata
+
+remap: functions: query: {
+	category:    "Path"
+	description: """
+		Returns all values in `value` matched by the given path, in a subset of the JSONPath syntax.
+
+		The path can start with an optional `$`, followed by any number of these segments:
+
+		- `.field` or `."quoted field"` matches a field of an object.
+		- `[index]` matches an element of an array. Negative indices count from the end of the array.
+		- `["quoted field"]` matches a field of an object.
+		- `.*` or `[*]` matches every field of an object or every element of an array.
+
+		Segments that don't match anything, for example a field of a value that isn't an object,
+		are skipped, so an empty array is returned when nothing matches.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to query."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "path"
+			description: "The path to query. Literal paths are validated when the program is compiled."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`path` isn't a valid path.",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Query a nested field"
+			source: #"""
+				query({ "a": { "b": [1, 2, 3] } }, ".a.b[0]")
+				"""#
+			return: [1]
+		},
+		{
+			title: "Query with wildcards"
+			input: log: {
+				hosts: [
+					{name: "a"},
+					{name: "b"},
+					{ip: "10.0.0.1"},
+				]
+			}
+			source: #"""
+				query(., "$.hosts[*].name")
+				"""#
+			return: ["a", "b"]
+		},
+	]
+}
@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: random_bool: {
+	category: "Random"
+	description: """
+		Returns `true` with the given `probability`, and `false` otherwise.
+
+		When a `seed` is provided the result is deterministic: the same seed always produces the same
+		result, which makes it suitable for reproducible sampling and test fixtures.
+		"""
+
+	arguments: [
+		{
+			name:        "probability"
+			description: "The probability of returning `true`, between `0.0` and `1.0` inclusive."
+			required:    true
+			type: ["float", "integer"]
+		},
+		{
+			name:        "seed"
+			description: "A value, such as an event field, used to seed the random number generator."
+			required:    false
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: [
+		"`probability` is not between `0.0` and `1.0`",
+	]
+	return: {
+		types: ["boolean"]
+		rules: [
+			"`random_bool` is considered fallible if the supplied `probability` is an expression, and infallible if it's a valid literal.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Always return true"
+			source: #"""
+				random_bool(1.0)
+				"""#
+			return: true
+		},
+		{
+			title: "Seeded from an event field"
+			source: #"""
+				random_bool(0.5, seed: .request_id)
+				"""#
+			return: false
+		},
+	]
+}
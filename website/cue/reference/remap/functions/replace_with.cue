@@ -0,0 +1,66 @@
+package metadata
+
+remap: functions: replace_with: {
+	category:    "String"
+	description: """
+		Replaces each match of `pattern` in the `value` with the string returned by the closure.
+
+		The closure receives the capture groups of the match as an object, in the same shape that
+		`parse_regex` returns with `numeric_groups` enabled: the overall match is stored in the `"0"`
+		field, numbered capture groups in the following fields, and named capture groups under their
+		names.
+
+		The function uses the "function closure syntax" to allow reading the captures of each match.
+		The closure must return a string.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The original string."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "pattern"
+			description: "Replace all matches of this regular expression."
+			required:    true
+			type: ["regex"]
+		},
+		{
+			name:        "count"
+			description: "The maximum number of replacements to perform. -1 means replace all matches."
+			required:    false
+			default:     -1
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Uppercase matched words"
+			source: #"""
+				replace_with("Apples and Bananas", r'\b[a-z]\w*') -> |captures| { upcase(captures."0") }
+				"""#
+			return: "Apples AND Bananas"
+		},
+		{
+			title: "Replace the first match only"
+			source: #"""
+				replace_with("Apples and Bananas", r'\w+', count: 1) -> |captures| { upcase(captures."0") }
+				"""#
+			return: "APPLES and Bananas"
+		},
+		{
+			title: "Rewrite named capture groups"
+			source: #"""
+				replace_with("took 1500ms, then 250ms", r'(?P<ms>\d+)ms') -> |captures| {
+					to_string(to_float!(captures.ms) / 1000) + "s"
+				}
+				"""#
+			return: "took 1.5s, then 0.25s"
+		},
+	]
+}
@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: reverse: {
+	category: "Enumerate"
+	description: #"""
+		Reverses the order of the elements of an array, or the characters of a string.
+		"""#
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array or string to reverse."
+			required:    true
+			type: ["array", "string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["array", "string"]
+		rules: [
+			"""
+				Strings are reversed by grapheme cluster rather than by byte, so characters made up of
+				multiple code points, such as `é` written with a combining accent or emoji with skin tone
+				modifiers, are kept intact.
+				""",
+			"Invalid UTF-8 sequences in strings are replaced with the Unicode replacement character.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Reverse an array"
+			source: #"""
+				reverse([1, 2, 3])
+				"""#
+			return: [3, 2, 1]
+		},
+		{
+			title: "Reverse a string"
+			source: #"""
+				reverse("ñandú")
+				"""#
+			return: "údnañ"
+		},
+	]
+}
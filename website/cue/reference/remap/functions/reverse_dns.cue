@@ -0,0 +1,44 @@
+package metadata
+
+remap: functions: reverse_dns: {
+	category: "System"
+	description: """
+		Looks up the host name of the IP address `value` using the system resolver.
+		"""
+	notices: [
+		"""
+			Lookups block the event being processed until they complete or time out after one second. Successful
+			results are cached for a minute, so repeated lookups of the same address are cheap.
+			""",
+		"""
+			Applications embedding VRL can change the timeout and cache, or disable this function, in which case
+			programs calling it fail to compile.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The IPv4 or IPv6 address to look up."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` isn't a valid IP address.",
+		"The lookup fails, for example because the address has no host name.",
+		"The lookup times out.",
+		"Too many lookups are already waiting to run.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Look up the host name of an IP address"
+			source: #"""
+				reverse_dns!("127.0.0.1")
+				"""#
+			return: "localhost"
+		},
+	]
+}
@@ -3,7 +3,7 @@ package metadata
 remap: functions: round: {
 	category: "Number"
 	description: """
-		Rounds the `value` to the specified `precision`.
+		Rounds the `value` to the specified `precision`, using the rounding behavior given by `mode`.
 		"""
 
 	arguments: [
@@ -20,8 +20,24 @@ remap: functions: round: {
 			default:     0
 			type: ["integer"]
 		},
+		{
+			name:        "mode"
+			description: "How to round values that fall between two representable numbers."
+			required:    false
+			default:     "half_up"
+			enum: {
+				half_up:   "Round to the nearest number, rounding halfway cases away from zero."
+				half_even: "Round to the nearest number, rounding halfway cases to the nearest even digit (banker's rounding)."
+				floor:     "Round towards negative infinity."
+				ceil:      "Round towards positive infinity."
+				trunc:     "Round towards zero."
+			}
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`mode` isn't one of the supported rounding modes.",
 	]
-	internal_failure_reasons: []
 	return: {
 		types: ["integer", "float"]
 		rules: [
@@ -44,5 +60,12 @@ remap: functions: round: {
 				"""#
 			return: 4.35
 		},
+		{
+			title: "Round a number using banker's rounding"
+			source: #"""
+				round(2.5, mode: "half_even")
+				"""#
+			return: 2.0
+		},
 	]
 }
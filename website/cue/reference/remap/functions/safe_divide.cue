@@ -0,0 +1,62 @@
+package metadata
+
+remap: functions: safe_divide: {
+	category: "Number"
+	description: """
+		Divides the `numerator` by the `denominator`, returning `default` instead of raising an error
+		when the `denominator` is zero.
+		"""
+
+	arguments: [
+		{
+			name:        "numerator"
+			description: "The number to divide."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "denominator"
+			description: "The number to divide `numerator` by."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "default"
+			description: "The value to return when `denominator` is zero. Defaults to `null`."
+			required:    false
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["float", "any"]
+		rules: [
+			"Like the `/` operator, the result is always a float, even if both arguments are integers.",
+			"Returns `default` if `denominator` is zero, which is `null` unless specified.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Divide integers"
+			source: #"""
+				safe_divide(3, 2)
+				"""#
+			return: 1.5
+		},
+		{
+			title: "Divide by zero"
+			source: #"""
+				safe_divide(3, 0)
+				"""#
+			return: null
+		},
+		{
+			title: "Divide by zero with a default"
+			source: #"""
+				safe_divide(3, 0, default: 0)
+				"""#
+			return: 0
+		},
+	]
+}
@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: sample: {
+	category: "Random"
+	description: """
+		Returns `true` for roughly one out of every `rate` calls, and `false` otherwise.
+
+		When a `seed` is provided the decision is derived from a hash of the seed, so the same seed always
+		produces the same decision. This matches the behavior of the `sample` transform's `key_field`.
+		"""
+
+	arguments: [
+		{
+			name:        "rate"
+			description: "The rate at which to sample, expressed as `1/N`."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "seed"
+			description: "A value, such as an event field, used to deterministically sample the event."
+			required:    false
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: [
+		"`rate` is less than `1`",
+	]
+	return: {
+		types: ["boolean"]
+		rules: [
+			"`sample` is considered fallible if the supplied `rate` is an expression, and infallible if it's a valid literal.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Keep every event"
+			source: #"""
+				sample(1)
+				"""#
+			return: true
+		},
+		{
+			title: "Consistently sample one in ten traces"
+			source: #"""
+				sample(10, seed: .trace_id)
+				"""#
+			return: false
+		},
+	]
+}
@@ -0,0 +1,43 @@
+package metadata
+
+remap: functions: signum: {
+	category: "Number"
+	description: """
+		Returns the sign of the `value`: `-1` if it's negative, `0` if it's zero, and `1` if it's
+		positive.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The number to take the sign of."
+			required:    true
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["integer", "float"]
+		rules: [
+			"Returns an integer if `value` is an integer, and a float otherwise.",
+			"Both `0.0` and `-0.0` return `0.0`.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Sign of a negative integer"
+			source: #"""
+				signum(-42)
+				"""#
+			return: -1
+		},
+		{
+			title: "Sign of a positive float"
+			source: #"""
+				signum(4.2)
+				"""#
+			return: 1.0
+		},
+	]
+}
@@ -0,0 +1,65 @@
+package metadata
+
+remap: functions: slugify: {
+	category: "String"
+	description: """
+		Turns `value` into a slug that's safe to use in URLs, keys and partition names.
+
+		The value is lowercased, and every run of characters that aren't letters or digits, such as
+		whitespace and punctuation, is replaced by a single `separator`. Leading and trailing
+		separators are removed.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to slugify."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "separator"
+			description: "The separator to put between words."
+			required:    false
+			default:     "-"
+			type: ["string"]
+		},
+		{
+			name: "transliterate"
+			description: """
+				Whether to replace accented and other special letters by their closest ASCII
+				equivalent, such as `é` by `e` and `ß` by `ss`. Letters without a known equivalent
+				are kept as is.
+				"""
+			required: false
+			default:  true
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Slugify a title"
+			source: #"""
+				slugify("Crème Brûlée: A Recipe!")
+				"""#
+			return: "creme-brulee-a-recipe"
+		},
+		{
+			title: "Slugify with a custom separator"
+			source: #"""
+				slugify("Hello,  World", separator: "_")
+				"""#
+			return: "hello_world"
+		},
+		{
+			title: "Slugify without transliteration"
+			source: #"""
+				slugify("Straße 5", transliterate: false)
+				"""#
+			return: "straße-5"
+		},
+	]
+}
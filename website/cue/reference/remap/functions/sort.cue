@@ -0,0 +1,70 @@
+package metadata
+
+remap: functions: sort: {
+	category: "Enumerate"
+	description: #"""
+		Sorts the elements of the `value` array in ascending order, or in descending order if `descending` is
+		set. The sort is stable: elements that compare equal keep their original order, also when sorting in
+		descending order.
+
+		All elements need to be of the same type, except for integers and floats, which are compared by their
+		numeric value. Strings are compared byte-wise (lexically), booleans sort `false` before `true`, and
+		timestamps sort chronologically. Arrays and objects can't be sorted. Use `sort_by` to sort elements by
+		a computed key, for example objects by one of their fields.
+		"""#
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to sort."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "descending"
+			description: "Whether to sort in descending order."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+		{
+			name:        "numeric"
+			description: "Whether to compare elements numerically, parsing strings as numbers."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains elements of different types, other than integers and floats.",
+		"`value` contains arrays or objects.",
+		"`numeric` is set and `value` contains elements that aren't numbers or numeric strings.",
+	]
+	return: {
+		types: ["array"]
+	}
+
+	examples: [
+		{
+			title: "Sort numbers"
+			source: #"""
+				sort([3, 1.5, 2])
+				"""#
+			return: [1.5, 2, 3]
+		},
+		{
+			title: "Sort strings in descending order"
+			source: #"""
+				sort(["b", "c", "a"], descending: true)
+				"""#
+			return: ["c", "b", "a"]
+		},
+		{
+			title: "Sort numeric strings"
+			source: #"""
+				sort!(["10", "9", "100"], numeric: true)
+				"""#
+			return: ["9", "10", "100"]
+		},
+	]
+}
@@ -0,0 +1,73 @@
+package metadata
+
+remap: functions: sort_by: {
+	category: "Enumerate"
+	description: """
+		Sorts the elements of the `value` array by the key the closure returns for each element, in
+		ascending order, or in descending order if `descending` is set. The sort is stable: elements with
+		equal keys keep their original order, also when sorting in descending order.
+
+		Keys are compared the same way `sort` compares elements: all keys need to be of the same type, except
+		for integers and floats, which are compared by their numeric value.
+
+		The function uses the "function closure syntax" to allow reading
+		the index/value combination for each item in the array.
+
+		The same scoping rules apply to closure blocks as they do for
+		regular blocks, meaning, any variable defined in parent scopes
+		are accessible, and mutations to those variables are preserved,
+		but any new variables instantiated in the closure block are
+		unavailable outside of the block.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to sort."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "descending"
+			description: "Whether to sort in descending order."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+		{
+			name:        "numeric"
+			description: "Whether to compare keys numerically, parsing strings as numbers."
+			required:    false
+			default:     false
+			type: ["boolean"]
+		},
+	]
+	internal_failure_reasons: [
+		"The closure returns keys of different types, other than integers and floats.",
+		"The closure returns arrays or objects.",
+		"`numeric` is set and the closure returns keys that aren't numbers or numeric strings.",
+	]
+	return: {
+		types: ["array"]
+	}
+	examples: [
+		{
+			title: "Top events by duration"
+			input: log: {
+				events: [
+					{id: 1, duration: 12},
+					{id: 2, duration: 40},
+					{id: 3, duration: 25},
+				]
+			}
+			source: #"""
+				sorted = sort_by!(array!(.events), descending: true) -> |_index, value| { value.duration }
+				slice!(sorted, start: 0, end: 2)
+				"""#
+			return: [
+				{id: 2, duration: 40},
+				{id: 3, duration: 25},
+			]
+		},
+	]
+}
@@ -0,0 +1,36 @@
+package metadata
+
+remap: functions: sqrt: {
+	category: "Number"
+	description: """
+		Returns the square root of the `value`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The number to take the square root of."
+			required:    true
+			type: ["integer", "float"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is negative.",
+	]
+	return: {
+		types: ["float"]
+		rules: [
+			"Always returns a float, even if `value` is an integer.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Square root of an integer"
+			source: #"""
+				sqrt!(16)
+				"""#
+			return: 4.0
+		},
+	]
+}
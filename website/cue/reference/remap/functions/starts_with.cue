@@ -15,9 +15,12 @@ remap: functions: starts_with: {
 		},
 		{
 			name:        "substring"
-			description: "The substring that the `value` must start with."
-			required:    true
-			type: ["string"]
+			description: """
+				The substring that the `value` must start with. If an array of substrings is given, `value`
+				must start with any of them.
+				"""
+			required: true
+			type: ["string", "array"]
 		},
 		{
 			name:        "case_sensitive"
@@ -27,7 +30,9 @@ remap: functions: starts_with: {
 			default: true
 		},
 	]
-	internal_failure_reasons: []
+	internal_failure_reasons: [
+		"`substring` is an array containing a value that isn't a string",
+	]
 	return: types: ["boolean"]
 
 	examples: [
@@ -45,5 +50,12 @@ remap: functions: starts_with: {
 				"""#
 			return: true
 		},
+		{
+			title: "String starts with any"
+			source: #"""
+				starts_with("The Needle In The Haystack", ["A Needle", "The Needle"])
+				"""#
+			return: true
+		},
 	]
 }
@@ -0,0 +1,53 @@
+package metadata
+
+remap: functions: string_repeat: {
+	category: "String"
+	description: """
+		Repeats the `value` `count` times.
+
+		To protect against running out of memory, the repeated string can be at most 1MiB. If
+		`count` is a constant that can't stay within that limit, the program fails to compile.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to repeat."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "count"
+			description: "The number of times to repeat the string."
+			required:    true
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`count` is negative.",
+		"The repeated string would be larger than 1MiB.",
+	]
+	return: {
+		types: ["string"]
+		rules: [
+			"Returns an empty string if `count` is `0`.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Build a separator"
+			source: """
+				string_repeat("=", 10)
+				"""
+			return: "=========="
+		},
+		{
+			title: "Repeat a multi-character string"
+			source: """
+				string_repeat("ab", 3)
+				"""
+			return: "ababab"
+		},
+	]
+}
@@ -0,0 +1,39 @@
+package metadata
+
+remap: functions: tally: {
+	category:    "Enumerate"
+	description: """
+		Counts the occurrences of each distinct value in an array, returning an object that maps each value
+		to its count.
+
+		Values are counted by their string representation, so `1` and `"1"` are counted together.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array of values to count."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"An element of `value` isn't a string, integer, float, boolean, or timestamp.",
+	]
+	return: {
+		types: ["object"]
+	}
+	examples: [
+		{
+			title: "Count status codes"
+			source: #"""
+				tally!([200, 404, 200, 500])
+				"""#
+			return: {
+				"200": 2
+				"404": 1
+				"500": 1
+			}
+		},
+	]
+}
@@ -0,0 +1,55 @@
+package metadata
+
+remap: functions: tally_by: {
+	category:    "Enumerate"
+	description: """
+		Counts the elements of an array by the key returned by the closure for each element, returning an
+		object that maps each distinct key to the number of elements that produced it.
+
+		Keys are counted by their string representation, so the closure must return a string, integer,
+		float, boolean, or timestamp.
+
+		The function uses the "function closure syntax" to allow reading
+		the index/value combination for each item in the array.
+
+		The same scoping rules apply to closure blocks as they do for
+		regular blocks, meaning, any variable defined in parent scopes
+		are accessible, and mutations to those variables are preserved,
+		but any new variables instantiated in the closure block are
+		unavailable outside of the block.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to count."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"The closure returns a value that isn't a string, integer, float, boolean, or timestamp.",
+	]
+	return: {
+		types: ["object"]
+	}
+	examples: [
+		{
+			title: "Count requests by status code"
+			input: log: {
+				requests: [
+					{status: 200, path: "/"},
+					{status: 404, path: "/missing"},
+					{status: 200, path: "/about"},
+				]
+			}
+			source: #"""
+				tally_by(array!(.requests)) -> |_index, value| { int!(value.status) }
+				"""#
+			return: {
+				"200": 2
+				"404": 1
+			}
+		},
+	]
+}
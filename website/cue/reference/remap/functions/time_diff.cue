@@ -0,0 +1,62 @@
+package metadata
+
+remap: functions: time_diff: {
+	category: "Timestamp"
+	description: """
+		Returns the time from the `other` timestamp to the `value` timestamp, in whole `unit`s.
+
+		The difference is negative if `value` is earlier than `other`, and is truncated towards zero.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The timestamp to subtract `other` from."
+			required:    true
+			type: ["timestamp"]
+		},
+		{
+			name:        "other"
+			description: "The timestamp to subtract from `value`."
+			required:    true
+			type: ["timestamp"]
+		},
+		{
+			name:        "unit"
+			description: "The time unit of the difference."
+			type: ["string"]
+			required: false
+			enum: {
+				days:         "Express the difference in days"
+				hours:        "Express the difference in hours"
+				minutes:      "Express the difference in minutes"
+				seconds:      "Express the difference in seconds"
+				milliseconds: "Express the difference in milliseconds"
+				microseconds: "Express the difference in microseconds"
+				nanoseconds:  "Express the difference in nanoseconds"
+			}
+			default: "seconds"
+		},
+	]
+	internal_failure_reasons: [
+		"the difference in `microseconds` or `nanoseconds` doesn't fit in an integer",
+	]
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Difference in seconds"
+			source: #"""
+				time_diff(t'2021-01-01T00:01:30Z', t'2021-01-01T00:00:00Z')
+				"""#
+			return: 90
+		},
+		{
+			title: "Difference in hours"
+			source: #"""
+				time_diff(t'2021-01-01T00:00:00Z', t'2021-01-02T12:00:00Z', unit: "hours")
+				"""#
+			return: -36
+		},
+	]
+}
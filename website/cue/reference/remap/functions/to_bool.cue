@@ -20,8 +20,9 @@ remap: functions: to_bool: {
 	return: {
 		types: ["boolean"]
 		rules: [
-			#"If `value` is `"true"`, `"t"`, `"yes"`, or `"y"`, `true` is returned."#,
-			#"If `value` is `"false"`, `"f"`, `"no"`, `"n"`, or `"0"`, `false` is returned."#,
+			#"If `value` is `"true"`, `"t"`, `"yes"`, `"y"`, `"on"`, `"enabled"`, or `"1"`, `true` is returned."#,
+			#"If `value` is `"false"`, `"f"`, `"no"`, `"n"`, `"off"`, `"disabled"`, or `"0"`, `false` is returned."#,
+			#"Strings are compared case-insensitively, any other string results in an error."#,
 			#"If `value` is `0.0`, `false` is returned, otherwise `true` is returned."#,
 			#"If `value` is `0`, `false` is returned, otherwise `true` is returned."#,
 			#"If `value` is `null`, `false` is returned."#,
@@ -37,6 +38,13 @@ remap: functions: to_bool: {
 				"""
 			return: true
 		},
+		{
+			title: "Coerce to a Boolean (case-insensitive string)"
+			source: """
+				to_bool!("Off")
+				"""
+			return: false
+		},
 		{
 			title: "Coerce to a Boolean (float)"
 			source: """
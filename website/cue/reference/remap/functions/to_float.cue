@@ -15,9 +15,28 @@ remap: functions: to_float: {
 			required: true
 			type: ["integer", "float", "boolean", "string", "timestamp"]
 		},
+		{
+			name: "grouping"
+			description: """
+				The grouping (thousands) separator used in `value` if it's a string, such as `,` in `1,234.5`.
+				All occurrences of the separator are removed before parsing.
+				"""
+			required: false
+			type: ["string"]
+		},
+		{
+			name: "decimal"
+			description: """
+				The decimal separator used in `value` if it's a string, such as `,` in `1.234,5`. Defaults to `.`.
+				"""
+			required: false
+			type: ["string"]
+		},
 	]
 	internal_failure_reasons: [
 		"`value` isn't a supported float representation",
+		"`grouping` or `decimal` is empty, or both are the same",
+		"`decimal` is set to a separator other than `.`, and `value` contains a `.`",
 	]
 	return: {
 		types: ["float"]
@@ -38,6 +57,13 @@ remap: functions: to_float: {
 				"""
 			return: 3.145
 		},
+		{
+			title: "Coerce to a float (localized string)"
+			source: """
+				to_float!("1.234,56", grouping: ".", decimal: ",")
+				"""
+			return: 1234.56
+		},
 		{
 			title: "Coerce to a float (timestamp)"
 			source: """
@@ -15,10 +15,20 @@ remap: functions: to_int: {
 			required: true
 			type: ["integer", "float", "boolean", "string", "timestamp"]
 		},
+		{
+			name: "grouping"
+			description: """
+				The grouping (thousands) separator used in `value` if it's a string, such as `,` in `1,234`.
+				All occurrences of the separator are removed before parsing.
+				"""
+			required: false
+			type: ["string"]
+		},
 	]
 	internal_failure_reasons: [
 		"`value` is a string but the text is not an integer",
 		"`value` is not a string, int, or timestamp",
+		"`grouping` is empty",
 	]
 	return: {
 		types: ["integer"]
@@ -39,6 +49,13 @@ remap: functions: to_int: {
 				"""
 			return: 2
 		},
+		{
+			title: "Coerce to an int (localized string)"
+			source: """
+				to_int!("1,234,567", grouping: ",")
+				"""
+			return: 1234567
+		},
 		{
 			title: "Coerce to an int (timestamp)"
 			source: """
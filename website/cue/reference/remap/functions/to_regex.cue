@@ -5,7 +5,14 @@ remap: functions: to_regex: {
 	description: """
 		Coerces the `value` into a regex.
 		"""
-	notices: ["Compiling a regular expression is an expensive operation and can limit Vector throughput. Don't use this function unless you are absolutely sure there is no other way!"]
+	notices: [
+		"Compiling a regular expression is an expensive operation and can limit Vector throughput. Don't use this function unless you are absolutely sure there is no other way!",
+		"""
+			Unlike regex literals, which are compiled once when the program is loaded, `to_regex` compiles its
+			pattern at runtime. The most recently compiled pattern is cached, so repeatedly converting the same
+			pattern is cheap, but alternating between different patterns recompiles them each time.
+			""",
+	]
 
 	arguments: [
 		{
@@ -17,6 +24,7 @@ remap: functions: to_regex: {
 	]
 	internal_failure_reasons: [
 		"`value` is not a string.",
+		"`value` is not a valid regex pattern.",
 	]
 	return: {
 		types: ["regex"]
@@ -33,5 +41,12 @@ remap: functions: to_regex: {
 				"""#
 			return: "^foo$"
 		},
+		{
+			title: "Match against a dynamic pattern"
+			source: #"""
+				match("foobar", to_regex("^foo") ?? r'')
+				"""#
+			return: true
+		},
 	]
 }
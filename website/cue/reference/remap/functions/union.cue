@@ -0,0 +1,50 @@
+package metadata
+
+remap: functions: union: {
+	category: "Enumerate"
+	description: #"""
+		Returns the union of arrays, treated as sets of values.
+
+		Duplicate values are removed, and the first occurrence of each value is kept, in order.
+		"""#
+
+	arguments: [
+		{
+			name: "value"
+			description: """
+				The first array. If `other` is omitted, an array of the arrays to combine.
+				"""
+			required: true
+			type: ["array"]
+		},
+		{
+			name:        "other"
+			description: "The second array."
+			required:    false
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`other` is omitted and `value` contains an element that isn't an array",
+	]
+	return: {
+		types: ["array"]
+	}
+
+	examples: [
+		{
+			title: "Union of two arrays"
+			source: #"""
+				union(["a", "b", "a"], ["c", "b"])
+				"""#
+			return: ["a", "b", "c"]
+		},
+		{
+			title: "Union of many arrays"
+			source: #"""
+				union([[1, 2], [2, 3], [3, 4]])
+				"""#
+			return: [1, 2, 3, 4]
+		},
+	]
+}
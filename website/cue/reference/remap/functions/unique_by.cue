@@ -0,0 +1,51 @@
+package metadata
+
+remap: functions: unique_by: {
+	category:    "Enumerate"
+	description: """
+		Returns the unique elements of an array, where two elements are considered the same if the closure
+		returns the same value for both. Only the first occurrence of each element is kept, and the order of
+		the elements is preserved.
+
+		The function uses the "function closure syntax" to allow reading
+		the index/value combination for each item in the array.
+
+		The same scoping rules apply to closure blocks as they do for
+		regular blocks, meaning, any variable defined in parent scopes
+		are accessible, and mutations to those variables are preserved,
+		but any new variables instantiated in the closure block are
+		unavailable outside of the block.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to deduplicate."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["array"]
+	}
+	examples: [
+		{
+			title: "Deduplicate events by ID"
+			input: log: {
+				events: [
+					{id: 1, message: "first"},
+					{id: 2, message: "second"},
+					{id: 1, message: "duplicate"},
+				]
+			}
+			source: #"""
+				unique_by(array!(.events)) -> |_index, value| { value.id }
+				"""#
+			return: [
+				{id: 1, message: "first"},
+				{id: 2, message: "second"},
+			]
+		},
+	]
+}
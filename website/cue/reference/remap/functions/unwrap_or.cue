@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: unwrap_or: {
+	category:    "Type"
+	description: """
+		Returns the result of `value`, or `default` if `value` results in an error. Unlike the `??`
+		operator, this can be used wherever a function call is allowed, such as inside closures passed
+		to other functions.
+
+		Only errors are handled, an `abort` inside `value` still terminates the program.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The expression to evaluate, which can be fallible."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "default"
+			description: "The value to return if `value` results in an error. Only evaluated in that case."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["any"]
+		rules: [
+			#"Returns the result of `value` if it succeeds."#,
+			#"Returns `default` if `value` results in an error."#,
+		]
+	}
+
+	examples: [
+		{
+			title: "Fall back on error"
+			source: """
+				unwrap_or(to_int("foo"), 0)
+				"""
+			return: 0
+		},
+		{
+			title: "Successful expression"
+			source: """
+				unwrap_or(to_int("12"), 0)
+				"""
+			return: 12
+		},
+	]
+}
@@ -0,0 +1,57 @@
+package metadata
+
+remap: functions: windows: {
+	category: "Array"
+	description: """
+		Returns the sliding windows of `size` consecutive elements of the `value` array, starting a new
+		window every `step` elements.
+
+		Windows that would extend past the end of the array are dropped, so every returned window has
+		exactly `size` elements. If `size` is larger than the array, the result is empty.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to take the windows of."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "size"
+			description: "The number of elements in each window."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "step"
+			description: "The number of elements to advance between the start of each window."
+			required:    false
+			default:     1
+			type: ["integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`size` is less than 1",
+		"`step` is less than 1",
+		"The windows would contain more than 1Mi (1,048,576) elements in total.",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Overlapping windows"
+			source: #"""
+				windows!([1, 2, 3, 4], 2)
+				"""#
+			return: [[1, 2], [2, 3], [3, 4]]
+		},
+		{
+			title: "Windows with a step"
+			source: #"""
+				windows!([1, 2, 3, 4, 5], 2, step: 2)
+				"""#
+			return: [[1, 2], [3, 4]]
+		},
+	]
+}
@@ -0,0 +1,53 @@
+package metadata
+
+remap: functions: without_fields: {
+	category:    "Object"
+	description: """
+		Returns a copy of `value` without the fields at the given `paths`, the complement of
+		[`only_fields`](\(urls.vrl_functions)#\(remap.functions.only_fields.anchor)). Paths that
+		don't exist in `value` are ignored.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to project."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name: "paths"
+			description: """
+				The paths of the fields to remove, such as `message` or `user.email`. Literal paths are
+				validated when the program is compiled.
+				"""
+			required: true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`paths` contains a value that isn't a valid field path.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Remove a subset of fields"
+			input: log: {
+				message: "login"
+				user: {
+					id:    "u123"
+					email: "user@example.com"
+				}
+				debug: true
+			}
+			source: #"""
+				. = without_fields(., ["debug", "user.email"])
+				"""#
+			return: {
+				message: "login"
+				user: id: "u123"
+			}
+		},
+	]
+}
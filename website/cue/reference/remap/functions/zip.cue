@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: zip: {
+	category: "Array"
+	description: """
+		Iterates over several arrays in parallel, producing an array of arrays where each element contains the
+		elements found at the same index in each of the input arrays.
+
+		The result is truncated to the length of the shortest input array.
+		"""
+
+	arguments: [
+		{
+			name:        "array_0"
+			description: "The first array, or an array of arrays to zip together if `array_1` isn't provided."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "array_1"
+			description: "The second array."
+			required:    false
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: [
+		"`array_1` isn't provided and not every element of `array_0` is an array.",
+	]
+	return: {
+		types: ["array"]
+		rules: [
+			"`zip` is considered fallible if only `array_0` is provided, and infallible otherwise.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Zip two arrays"
+			source: #"""
+				zip(["a", "b", "c"], [1, 2, 3])
+				"""#
+			return: [["a", 1], ["b", 2], ["c", 3]]
+		},
+		{
+			title: "Zip an array of arrays"
+			source: #"""
+				zip!([["a", "b"], [1, 2], [true, false]])
+				"""#
+			return: [["a", 1, true], ["b", 2, false]]
+		},
+	]
+}
@@ -26,6 +26,7 @@ urls: {
 	aws_athena:                                 "https://aws.amazon.com/athena/"
 	aws_athena_console:                         "https://console.aws.amazon.com/athena/home"
 	aws_canonical_user_id:                      "\(aws_docs)/general/latest/gr/acct-identifiers.html#FindingCanonicalId"
+	aws_cloudfront_log_format:                  "\(aws_docs)/AmazonCloudFront/latest/DeveloperGuide/AccessLogs.html#LogFileFormat"
 	aws_cloudwatch:                             "https://aws.amazon.com/cloudwatch/"
 	aws_cloudwatch_logs:                        "\(aws_docs)/AmazonCloudWatch/latest/logs/WhatIsCloudWatchLogs.html"
 	aws_cloudwatch_logs_api:                    "\(aws_docs)/AmazonCloudWatchLogs/latest/APIReference/Welcome.html"
@@ -272,6 +273,7 @@ urls: {
 	iso_8601:                                   "\(wikipedia)/wiki/ISO_8601"
 	iso3166_2:                                  "\(wikipedia)/wiki/ISO_3166-2"
 	issue_1694:                                 "\(vector_repo)/issues/1694"
+	jaro_winkler_distance:                      "\(wikipedia)/wiki/Jaro%E2%80%93Winkler_distance"
 	journalctl:                                 "https://www.freedesktop.org/software/systemd/man/journalctl.html"
 	journald:                                   "https://www.freedesktop.org/software/systemd/man/systemd-journald.service.html"
 	json:                                       "\(wikipedia)/wiki/JSON"
@@ -299,6 +301,7 @@ urls: {
 	leveldb:                                    "\(github)/google/leveldb"
 	leveldb_sys_2:                              "https://crates.io/crates/leveldb-sys"
 	leveldb_sys_3:                              "\(github)/vectordotdev/leveldb-sys/tree/v3.0.0"
+	levenshtein_distance:                       "\(wikipedia)/wiki/Levenshtein_distance"
 	librdkafka:                                 "\(github)/edenhill/librdkafka"
 	librdkafka_config:                          "\(github)/edenhill/librdkafka/blob/master/CONFIGURATION.md"
 	linux_capability:                           "https://man7.org/linux/man-pages/man7/capabilities.7.html"
@@ -407,6 +410,7 @@ urls: {
 	prometheus_remote_write:                    "https://prometheus.io/docs/prometheus/latest/configuration/configuration/#remote_write"
 	prometheus_remote_write_protocol:           "https://docs.google.com/document/d/1LPhVRSFkGNSuU1fBd81ulhsCPR4hkSZyyBj1SZ8fWOM/edit#heading=h.n0d0vphea3fe"
 	protobuf:                                   "https://developers.google.com/protocol-buffers"
+	public_suffix_list:                         "https://publicsuffix.org/"
 	pulsar:                                     "https://pulsar.apache.org/"
 	pulsar_protocol:                            "https://pulsar.apache.org/docs/en/develop-binary-protocol/"
 	raspbian:                                   "https://www.raspbian.org/"